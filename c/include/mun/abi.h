@@ -3,10 +3,18 @@
 
 #include <stdint.h>
 
+/*
+ * Vector types, e.g. `f32x4`, cross the ABI as an unsigned integer of the same size: a 64-bit
+ * vector as a `uint64_t` and a 128-bit vector as a 128-bit integer. C has no portable 128-bit
+ * integer type and its alignment differs between compilers, so functions with vector types in
+ * their signature can only be called from a Rust host, using the vector types of the `mun_abi`
+ * crate.
+ */
+
 /**
  * Defines the current ABI version
  */
-#define MUN_ABI_VERSION 300
+#define MUN_ABI_VERSION 400
 
 /**
  * Represents the kind of memory management a struct uses.
@@ -251,6 +259,29 @@ typedef struct MunTypeDefinition {
     union MunTypeDefinitionData data;
 } MunTypeDefinition;
 
+/**
+ * Represents a constant definition. A constant definition contains the name,
+ * type, and a pointer to the value of a constant that was evaluated at
+ * compile time.
+ *
+ * `value` points to the value in the same memory layout that Mun uses for the
+ * type of the constant.
+ */
+typedef struct MunConstantDefinition {
+    /**
+     * Constant name
+     */
+    const char *name;
+    /**
+     * Constant type
+     */
+    union MunTypeId type_id;
+    /**
+     * Pointer to the constant's value
+     */
+    const void *value;
+} MunConstantDefinition;
+
 /**
  * Represents a module declaration.
  */
@@ -275,6 +306,14 @@ typedef struct MunModuleInfo {
      * Number of module types
      */
     uint32_t num_types;
+    /**
+     * Module constants
+     */
+    const struct MunConstantDefinition *constants;
+    /**
+     * Number of module constants
+     */
+    uint32_t num_constants;
 } MunModuleInfo;
 
 /**
@@ -542,6 +542,26 @@ struct MunErrorHandle mun_runtime_get_type_info_by_id(struct MunRuntime runtime,
  */
 struct MunErrorHandle mun_runtime_update(struct MunRuntime runtime, bool *updated);
 
+/**
+ * Calls `callback` with `user_data`. If Mun code that is invoked from within
+ * `callback` panics (e.g. because an array was indexed out of bounds), the
+ * stack is unwound up to this function and a non-zero error handle that
+ * describes the panic is returned. If the Mun code was compiled with debug
+ * information, the description includes a stack trace of the Mun functions
+ * that were active when the panic occurred.
+ *
+ * If a non-zero error handle is returned, it must be manually destructed using
+ * [`mun_error_destroy`].
+ *
+ * # Safety
+ *
+ * This function receives raw pointers as parameters. If `callback` is a null
+ * pointer, an error will be returned. All stack frames between this function
+ * and the panicking Mun function must support unwinding, e.g. C++ code or C
+ * code that is compiled with `-fexceptions`.
+ */
+struct MunErrorHandle mun_runtime_catch_panic(void (*callback)(void*), void *user_data);
+
 /**
  * Notifies the runtime an additional references exists to the function. This
  * ensures that the data is kept alive even if [`mun_function_release`] is
@@ -3,7 +3,7 @@ mod ops;
 use std::ffi::OsString;
 
 use clap::{Parser, Subcommand};
-use ops::{build, init, language_server, new, start};
+use ops::{build, explain, fmt, init, language_server, new, start};
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -21,6 +21,12 @@ enum Command {
     /// Compiles a local Mun file into a module
     Build(build::Args),
 
+    /// Formats all Mun source files of a project
+    Fmt(fmt::Args),
+
+    /// Prints a detailed explanation of a diagnostic code
+    Explain(explain::Args),
+
     /// Create a new Mun project at the specified location
     New(new::Args),
 
@@ -55,6 +61,8 @@ where
     let args = Args::parse_from(args);
     match args.command {
         Command::Build(args) => build::build(args),
+        Command::Fmt(args) => fmt::fmt(args),
+        Command::Explain(args) => explain::explain(args),
         Command::LanguageServer(args) => language_server::language_server(args),
         Command::New(args) => new::new(args),
         Command::Init(args) => init::init(args),
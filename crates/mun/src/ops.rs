@@ -1,4 +1,6 @@
 pub mod build;
+pub mod explain;
+pub mod fmt;
 pub mod init;
 pub mod language_server;
 pub mod new;
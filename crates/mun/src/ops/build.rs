@@ -4,8 +4,8 @@ use std::{
 };
 
 use anyhow::anyhow;
-use mun_compiler::{Config, DisplayColor, Target};
-use mun_project::MANIFEST_FILENAME;
+use mun_compiler::{optimization_level, Config, DisplayColor, EmitKind, MessageFormat, Target};
+use mun_project::{Package, Workspace, DEV_PROFILE, MANIFEST_FILENAME, RELEASE_PROFILE};
 
 use crate::ExitStatus;
 
@@ -16,24 +16,62 @@ pub enum UseColor {
     Auto,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum DiagnosticFormat {
+    Human,
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum EmitType {
+    Obj,
+    Asm,
+    LlvmBc,
+    Ir,
+    Staticlib,
+    Munlib,
+}
+
 #[derive(clap::Args)]
 pub struct Args {
     /// Path to the manifest of the project
     #[clap(long)]
     manifest_path: Option<PathBuf>,
 
-    /// Optimization level [0,3]
-    #[clap(long, short = 'O', default_value_t = 2)]
-    opt_level: u8,
+    /// Optimization level [0,3], overrides the optimization level of the
+    /// profile
+    #[clap(long, short = 'O')]
+    opt_level: Option<u8>,
+
+    /// Build with the `release` profile
+    #[clap(long, conflicts_with = "profile")]
+    release: bool,
+
+    /// Build with the specified profile [default: dev]
+    #[clap(long)]
+    profile: Option<String>,
 
     /// Use color in output
     #[clap(long, value_enum)]
     color: Option<UseColor>,
 
-    /// Emits IR instead of a *.munlib
-    #[clap(long)]
+    /// The output format for diagnostics
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: DiagnosticFormat,
+
+    /// Comma-separated list of artifacts to emit for every assembly
+    /// [default: munlib]
+    #[clap(long, value_enum, value_delimiter = ',')]
+    emit: Vec<EmitType>,
+
+    /// Emits IR instead of a *.munlib, equivalent to `--emit=ir`
+    #[clap(long, conflicts_with = "emit")]
     emit_ir: bool,
 
+    /// Emits debug information, regardless of the setting of the profile
+    #[clap(short = 'g')]
+    debug: bool,
+
     /// Run the compiler in watch mode. Watch input files and trigger
     /// recompilation on changes.
     #[clap(long)]
@@ -42,6 +80,11 @@ pub struct Args {
     /// Target for machine code
     #[clap(long, value_parser=parse_target_triple)]
     target: Option<Target>,
+
+    /// Build all members of the workspace. This is the default for manifests
+    /// that only contain a `[workspace]` section.
+    #[clap(long)]
+    workspace: bool,
 }
 
 fn parse_target_triple(target_triple: &str) -> Result<Target, String> {
@@ -55,14 +98,6 @@ fn parse_target_triple(target_triple: &str) -> Result<Target, String> {
 pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
     log::trace!("starting build");
 
-    let optimization_lvl = match args.opt_level {
-        0 => mun_compiler::OptimizationLevel::None,
-        1 => mun_compiler::OptimizationLevel::Less,
-        2 => mun_compiler::OptimizationLevel::Default,
-        3 => mun_compiler::OptimizationLevel::Aggressive,
-        _ => return Err(anyhow!("Only optimization levels 0-3 are supported")),
-    };
-
     let display_colors = args
         .color
         .map(|clr| match clr {
@@ -81,8 +116,105 @@ pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
         })
         .unwrap_or(DisplayColor::Auto);
 
-    // Locate the manifest
-    let manifest_path = match &args.manifest_path {
+    let message_format = match args.message_format {
+        DiagnosticFormat::Human => MessageFormat::Human,
+        DiagnosticFormat::Json => MessageFormat::Json,
+    };
+
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+
+    log::info!("located build manifest at: {}", manifest_path.display());
+
+    let build_workspace = args.workspace
+        || (Workspace::is_workspace_manifest(&manifest_path)?
+            && Package::from_file(&manifest_path).is_err());
+
+    // Determine the settings of the selected profile
+    let profile_name = if args.release {
+        RELEASE_PROFILE
+    } else {
+        args.profile.as_deref().unwrap_or(DEV_PROFILE)
+    };
+    let mut compiler_options = if build_workspace {
+        let workspace = Workspace::from_file(&manifest_path)?;
+        let profile = workspace
+            .profile(profile_name)
+            .ok_or_else(|| anyhow!("profile `{}` is not defined", profile_name))?;
+        Config::from_profile(profile, workspace.root())
+    } else {
+        let package = Package::from_file(&manifest_path)?;
+        let profile = package
+            .manifest()
+            .profile(profile_name)
+            .ok_or_else(|| anyhow!("profile `{}` is not defined", profile_name))?;
+        Config::from_profile(profile, package.root())
+    };
+
+    if let Some(opt_level) = args.opt_level {
+        compiler_options.optimization_lvl = optimization_level(opt_level)
+            .ok_or_else(|| anyhow!("Only optimization levels 0-3 are supported"))?;
+    }
+    if let Some(target) = args.target {
+        compiler_options.target = target;
+    }
+    if args.emit_ir {
+        compiler_options.emit = vec![EmitKind::LlvmIr];
+    } else if !args.emit.is_empty() {
+        compiler_options.emit = args
+            .emit
+            .iter()
+            .map(|emit| match emit {
+                EmitType::Obj => EmitKind::Object,
+                EmitType::Asm => EmitKind::Assembly,
+                EmitType::LlvmBc => EmitKind::LlvmBitcode,
+                EmitType::Ir => EmitKind::LlvmIr,
+                EmitType::Staticlib => EmitKind::StaticLib,
+                EmitType::Munlib => EmitKind::Munlib,
+            })
+            .collect();
+    }
+    if args.debug {
+        compiler_options.debug_info = true;
+    }
+
+    if args.watch && message_format == MessageFormat::Json {
+        return Err(anyhow!(
+            "`--message-format=json` is not supported in watch mode"
+        ));
+    }
+
+    if build_workspace {
+        if args.watch {
+            return Err(anyhow!("watch mode is not supported for workspaces"));
+        }
+        mun_compiler::compile_workspace(
+            &manifest_path,
+            compiler_options,
+            display_colors,
+            message_format,
+        )
+    } else if args.watch {
+        mun_compiler_daemon::compile_and_watch_manifest(
+            &manifest_path,
+            compiler_options,
+            display_colors,
+        )
+    } else {
+        mun_compiler::compile_manifest(
+            &manifest_path,
+            compiler_options,
+            display_colors,
+            message_format,
+        )
+    }
+    .map(Into::into)
+}
+
+/// Returns the canonical path of the manifest that was specified by the user,
+/// or if no path was specified, searches for a manifest in the current
+/// directory or one of its parents.
+pub(crate) fn locate_manifest(manifest_path: Option<&Path>) -> Result<PathBuf, anyhow::Error> {
+    match manifest_path {
         None => {
             let current_dir =
                 std::env::current_dir().expect("could not determine current working directory");
@@ -92,37 +224,15 @@ pub fn build(args: Args) -> Result<ExitStatus, anyhow::Error> {
                     MANIFEST_FILENAME,
                     current_dir.display()
                 )
-            })?
+            })
         }
-        Some(path) => std::fs::canonicalize(Path::new(&path)).map_err(|_error| {
+        Some(path) => std::fs::canonicalize(path).map_err(|_error| {
             anyhow::anyhow!(
                 "'{}' does not refer to a valid manifest path",
                 path.display()
             )
-        })?,
-    };
-
-    log::info!("located build manifest at: {}", manifest_path.display());
-
-    let compiler_options = Config {
-        target: args
-            .target
-            .unwrap_or_else(|| Target::host_target().expect("unable to determine host target")),
-        optimization_lvl,
-        out_dir: None,
-        emit_ir: args.emit_ir,
-    };
-
-    if args.watch {
-        mun_compiler_daemon::compile_and_watch_manifest(
-            &manifest_path,
-            compiler_options,
-            display_colors,
-        )
-    } else {
-        mun_compiler::compile_manifest(&manifest_path, compiler_options, display_colors)
+        }),
     }
-    .map(Into::into)
 }
 
 /// Find a Mun manifest file in the specified directory or one of its parents.
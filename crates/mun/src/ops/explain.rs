@@ -0,0 +1,20 @@
+use anyhow::anyhow;
+use mun_diagnostics::DiagnosticCode;
+
+use crate::ExitStatus;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The code of the diagnostic to explain, e.g. `E0009`
+    code: String,
+}
+
+/// This method is invoked when the executable is run with the `explain`
+/// argument indicating that a user requested a long-form description of a
+/// diagnostic.
+pub fn explain(args: Args) -> Result<ExitStatus, anyhow::Error> {
+    let code = DiagnosticCode::find(&args.code)
+        .ok_or_else(|| anyhow!("`{}` is not a valid diagnostic code", args.code))?;
+    print!("{}", code.explanation());
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,56 @@
+use std::{fs, path::PathBuf};
+
+use mun_project::Package;
+
+use crate::{ops::build::locate_manifest, ExitStatus};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Path to the manifest of the project
+    #[clap(long)]
+    manifest_path: Option<PathBuf>,
+
+    /// Only check whether the source files are formatted correctly. Lists the
+    /// files that would be reformatted and exits with an error if there are
+    /// any.
+    #[clap(long)]
+    check: bool,
+}
+
+/// This method is invoked when the executable is run with the `fmt` argument
+/// indicating that a user requested us to format all source files of a
+/// project.
+pub fn fmt(args: Args) -> Result<ExitStatus, anyhow::Error> {
+    let manifest_path = locate_manifest(args.manifest_path.as_deref())?;
+    let package = Package::from_file(&manifest_path)?;
+
+    let mut success = true;
+    for source_path in mun_compiler::iter_source_files(&package.source_directory()) {
+        let text = fs::read_to_string(&source_path)
+            .map_err(|e| anyhow::anyhow!("could not read '{}': {}", source_path.display(), e))?;
+
+        let formatted = match mun_fmt::format(&text) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                eprintln!("error: could not format '{}': {}", source_path.display(), e);
+                success = false;
+                continue;
+            }
+        };
+
+        if formatted == text {
+            continue;
+        }
+
+        if args.check {
+            println!("would reformat: {}", source_path.display());
+            success = false;
+        } else {
+            fs::write(&source_path, formatted).map_err(|e| {
+                anyhow::anyhow!("could not write '{}': {}", source_path.display(), e)
+            })?;
+        }
+    }
+
+    Ok(success.into())
+}
@@ -53,6 +53,297 @@ fn mun_emit_ir() {
     assert!(ir_path.is_file());
 }
 
+/// Verifies that multiple artifacts can be emitted by a single build.
+#[test]
+fn mun_emit_multiple() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+
+    build(&project_path, &["--emit=obj,asm,llvm-bc,ir"]);
+    for extension in ["o", "s", "bc", "ll"] {
+        assert!(project_path
+            .join("target/mod")
+            .with_extension(extension)
+            .is_file());
+    }
+    assert!(!project_path.join("target/mod.munlib").exists());
+
+    build(&project_path, &["--emit", "munlib,ir"]);
+    assert!(project_path.join("target/mod.ll").is_file());
+    assert!(project_path.join("target/mod.munlib").is_file());
+}
+
+/// Verifies that `mun fmt` formats the source files of a project and that
+/// `mun fmt --check` reports unformatted files.
+#[test]
+fn mun_fmt() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+
+    // A newly created project is already formatted
+    assert_eq!(fmt(&project_path, &["--check"]), mun::ExitStatus::Success);
+
+    let source_path = project_path.join("src/mod.mun");
+    std::fs::write(&source_path, "pub fn main()->f64{3.14159}").unwrap();
+    assert_eq!(fmt(&project_path, &["--check"]), mun::ExitStatus::Error);
+
+    assert_eq!(fmt(&project_path, &[]), mun::ExitStatus::Success);
+    assert_eq!(
+        std::fs::read_to_string(&source_path).unwrap(),
+        "pub fn main() -> f64 {\n    3.14159\n}\n"
+    );
+    assert_eq!(fmt(&project_path, &["--check"]), mun::ExitStatus::Success);
+}
+
+#[test]
+fn mun_explain() {
+    let explain = |code: &str| run_with_args(["mun", "explain", code]);
+    assert_eq!(explain("E0009").unwrap(), mun::ExitStatus::Success);
+    assert_eq!(explain("e0001").unwrap(), mun::ExitStatus::Success);
+    assert!(explain("E9999").is_err());
+}
+
+/// Verifies that the profile selected with `--release` or `--profile`
+/// determines the output directory.
+#[test]
+fn mun_build_profiles() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path().join(PROJECT_NAME);
+    let args: Vec<OsString> = vec!["mun".into(), "new".into(), project_path.as_path().into()];
+    assert_eq!(run_with_args(args).unwrap(), mun::ExitStatus::Success);
+
+    let manifest_path = project_path.join("mun.toml");
+    let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+    std::fs::write(
+        &manifest_path,
+        format!("{manifest}\n[profile.dist]\ninherits = \"release\"\nout-dir = \"dist\"\n"),
+    )
+    .unwrap();
+
+    build(&project_path, &["--release"]);
+    assert!(project_path.join("target/release/mod.munlib").is_file());
+
+    build(&project_path, &["--profile", "dist"]);
+    assert!(project_path.join("dist/mod.munlib").is_file());
+
+    let args: Vec<OsString> = vec![
+        "mun".into(),
+        "build".into(),
+        "--manifest-path".into(),
+        manifest_path.into(),
+        "--profile".into(),
+        "unknown".into(),
+    ];
+    assert!(run_with_args(args).is_err());
+}
+
+/// Verifies that the partition strategy of a profile determines which
+/// assemblies are emitted and that the runtime loads them.
+#[test]
+fn mun_build_partition() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let project_path = project_dir.path();
+    std::fs::create_dir_all(project_path.join("src/foo")).unwrap();
+    std::fs::write(
+        project_path.join("mun.toml"),
+        "[package]\nname = \"partition\"\nversion = \"0.1.0\"\n\n\
+         [profile.single]\ninherits = \"dev\"\npartition = \"single\"\n\n\
+         [profile.dirs]\ninherits = \"dev\"\npartition = \"per-directory\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project_path.join("src/mod.mun"),
+        "pub fn main() -> i32 {\n    package::foo::value() + package::foo::bar::value()\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project_path.join("src/foo.mun"),
+        "pub fn value() -> i32 {\n    40\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project_path.join("src/foo/bar.mun"),
+        "pub fn value() -> i32 {\n    2\n}\n",
+    )
+    .unwrap();
+
+    for (profile, assemblies) in [
+        ("single", vec!["mod.munlib"]),
+        ("dirs", vec!["mod.munlib", "foo.munlib"]),
+    ] {
+        build(project_path, &["--profile", profile]);
+
+        let target_path = project_path.join("target").join(profile);
+        let mut emitted = std::fs::read_dir(&target_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|file_name| file_name.ends_with(".munlib"))
+            .collect::<Vec<_>>();
+        emitted.sort();
+        let mut expected = assemblies;
+        expected.sort_unstable();
+        assert_eq!(emitted, expected);
+
+        // Safety: since we compiled the code ourselves, loading the library should be
+        // safe
+        let builder = Runtime::builder(target_path.join("mod.munlib"));
+        let runtime = unsafe { builder.finish() }.unwrap();
+        let result: i32 = runtime.invoke("main", ()).unwrap();
+        assert_eq!(result, 42);
+    }
+}
+
+/// Verifies that a package can use the public items of a path dependency and
+/// that the resulting assemblies can be loaded by the runtime.
+#[test]
+fn mun_build_path_dependency() {
+    let project_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+
+    let app_path = project_dir.path().join("app");
+    let lib_path = project_dir.path().join("lib");
+    for (path, manifest, sources) in [
+        (
+            &app_path,
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nmath = { path = \"../lib\" }\n",
+            vec![(
+                "mod.mun",
+                "use math::ops::Pair;\n\npub fn main() -> i32 {\n    math::ops::add(Pair { a: 40, b: 2 })\n}\n",
+            )],
+        ),
+        (
+            &lib_path,
+            "[package]\nname = \"lib\"\nversion = \"0.1.0\"\n",
+            vec![
+                ("mod.mun", ""),
+                (
+                    "ops.mun",
+                    "pub struct Pair { pub a: i32, pub b: i32 }\n\npub fn add(pair: Pair) -> i32 {\n    pair.a + pair.b\n}\n",
+                ),
+            ],
+        ),
+    ] {
+        std::fs::create_dir_all(path.join("src")).unwrap();
+        std::fs::write(path.join("mun.toml"), manifest).unwrap();
+        for (file_name, contents) in sources {
+            std::fs::write(path.join("src").join(file_name), contents).unwrap();
+        }
+    }
+
+    build(&app_path, &[]);
+
+    let library_path = app_path.join("target/mod.munlib");
+    assert!(library_path.is_file());
+    assert!(app_path.join("target/lib$ops.munlib").is_file());
+
+    // Safety: since we compiled the code ourselves, loading the library should be
+    // safe
+    let builder = Runtime::builder(&library_path);
+    let runtime = unsafe { builder.finish() }.unwrap();
+    let result: i32 = runtime.invoke("main", ()).unwrap();
+    assert_eq!(result, 42);
+}
+
+/// Verifies that `mun build --workspace` builds all members of a workspace
+/// into the shared output directory.
+#[test]
+fn mun_build_workspace() {
+    let workspace_dir = tempfile::Builder::new()
+        .prefix(PROJECT_DIR)
+        .tempdir()
+        .unwrap();
+    let workspace_path = workspace_dir.path();
+
+    std::fs::write(
+        workspace_path.join("mun.toml"),
+        "[workspace]\nmembers = [\"app\", \"tools\", \"lib\"]\n",
+    )
+    .unwrap();
+    for (name, dependencies, source) in [
+        (
+            "app",
+            "math = { path = \"../lib\" }",
+            "pub fn main() -> i32 {\n    math::double(21)\n}\n",
+        ),
+        (
+            "tools",
+            "math = { path = \"../lib\" }",
+            "pub fn main() -> i32 {\n    math::double(2)\n}\n",
+        ),
+        ("lib", "", "pub fn double(a: i32) -> i32 {\n    a * 2\n}\n"),
+    ] {
+        let path = workspace_path.join(name);
+        std::fs::create_dir_all(path.join("src")).unwrap();
+        std::fs::write(
+            path.join("mun.toml"),
+            format!(
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{dependencies}\n"
+            ),
+        )
+        .unwrap();
+        std::fs::write(path.join("src/mod.mun"), source).unwrap();
+    }
+
+    build(workspace_path, &["--workspace"]);
+
+    // `lib` is compiled once, under the name in its manifest, and shared by all
+    // members that depend on it.
+    let target_path = workspace_path.join("target");
+    let lib_assembly = std::fs::read(target_path.join("lib/lib.munlib")).unwrap();
+    assert!(!target_path.join("lib/mod.munlib").exists());
+    for (member, expected) in [("app", 42), ("tools", 4)] {
+        let library_path = target_path.join(member).join("mod.munlib");
+        assert_eq!(
+            std::fs::read(target_path.join(member).join("lib.munlib")).unwrap(),
+            lib_assembly
+        );
+
+        // Safety: since we compiled the code ourselves, loading the library should be
+        // safe
+        let builder = Runtime::builder(&library_path);
+        let runtime = unsafe { builder.finish() }.unwrap();
+        let result: i32 = runtime.invoke("main", ()).unwrap();
+        assert_eq!(result, expected);
+    }
+}
+
+fn fmt(project: &Path, args: &[&str]) -> mun::ExitStatus {
+    let args: Vec<OsString> = vec![
+        OsString::from("mun"),
+        OsString::from("fmt"),
+        OsString::from("--manifest-path"),
+        OsString::from(project.join("mun.toml")),
+    ]
+    .into_iter()
+    .chain(args.iter().map(|&arg| arg.into()))
+    .collect();
+    run_with_args(args).unwrap()
+}
+
 fn build(project: &Path, args: &[&str]) {
     let args: Vec<OsString> = vec![
         OsString::from("mun"),
@@ -65,7 +65,7 @@ mod tests {
     #[test]
     fn test_assembly_info_dependencies() {
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, &[], &[]);
+        let module = fake_module_info(&module_path, &[], &[], &[]);
 
         let dispatch_table = fake_dispatch_table(&[], &mut []);
         let type_lut = fake_type_lut(&[], &mut [], &[]);
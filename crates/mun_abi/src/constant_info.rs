@@ -0,0 +1,79 @@
+use std::{
+    ffi::{c_void, CStr},
+    os::raw::c_char,
+    str,
+};
+
+use crate::type_id::TypeId;
+
+/// Represents a constant definition. A constant definition contains the name,
+/// type, and a pointer to the value of a constant that was evaluated at
+/// compile time.
+///
+/// `value` points to the value in the same memory layout that Mun uses for the
+/// type of the constant.
+#[repr(C)]
+#[derive(Clone)]
+pub struct ConstantDefinition<'a> {
+    /// Constant name
+    pub name: *const c_char,
+    /// Constant type
+    pub type_id: TypeId<'a>,
+    /// Pointer to the constant's value
+    pub value: *const c_void,
+}
+
+impl ConstantDefinition<'_> {
+    /// Returns the constant's name.
+    pub fn name(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(CStr::from_ptr(self.name).to_bytes()) }
+    }
+}
+
+unsafe impl Send for ConstantDefinition<'_> {}
+unsafe impl Sync for ConstantDefinition<'_> {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ConstantDefinition<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("ConstantDefinition", 2)?;
+        s.serialize_field("name", self.name())?;
+        s.serialize_field("type_id", &self.type_id)?;
+        s.skip_field("value")?;
+        s.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use crate::{
+        test_utils::{fake_constant_definition, FAKE_CONSTANT_NAME},
+        type_id::HasStaticTypeId,
+    };
+
+    #[test]
+    fn test_constant_definition_name() {
+        let name = CString::new(FAKE_CONSTANT_NAME).expect("Invalid fake constant name.");
+        let value = 5i32;
+        let constant = fake_constant_definition(&name, i32::type_id().clone(), &value);
+
+        assert_eq!(constant.name(), FAKE_CONSTANT_NAME);
+    }
+
+    #[test]
+    fn test_constant_definition_value() {
+        let name = CString::new(FAKE_CONSTANT_NAME).expect("Invalid fake constant name.");
+        let value = 1.5f32;
+        let constant = fake_constant_definition(&name, f32::type_id().clone(), &value);
+
+        assert_eq!(&constant.type_id, f32::type_id());
+        assert_eq!(unsafe { *constant.value.cast::<f32>() }, value);
+    }
+}
@@ -7,6 +7,7 @@
 use std::{ffi::CStr, fmt};
 
 pub use assembly_info::AssemblyInfo;
+pub use constant_info::ConstantDefinition;
 pub use dispatch_table::DispatchTable;
 pub use function_info::{FunctionDefinition, FunctionPrototype, FunctionSignature};
 pub use module_info::ModuleInfo;
@@ -15,9 +16,11 @@ pub use struct_info::{StructDefinition, StructMemoryKind};
 pub use type_id::{ArrayTypeId, HasStaticTypeId, PointerTypeId, TypeId};
 pub use type_info::{HasStaticTypeName, TypeDefinition, TypeDefinitionData};
 pub use type_lut::TypeLut;
+pub use vector::{F32x2, F32x4, F64x2, I32x2, I32x4};
 
 // C bindings can be manually generated by running `cargo gen-abi`.
 mod assembly_info;
+mod constant_info;
 mod dispatch_table;
 mod function_info;
 mod module_info;
@@ -27,13 +30,14 @@ mod struct_info;
 mod type_id;
 mod type_info;
 mod type_lut;
+mod vector;
 
 #[cfg(test)]
 mod test_utils;
 
 /// Defines the current ABI version
 #[allow(clippy::zero_prefixed_literal)]
-pub const ABI_VERSION: u32 = 00_03_00;
+pub const ABI_VERSION: u32 = 00_04_00;
 /// Defines the name for the `get_info` function
 pub const GET_INFO_FN_NAME: &str = "get_info";
 /// Defines the name for the `get_version` function
@@ -1,6 +1,6 @@
 use std::{ffi::CStr, os::raw::c_char, slice, str};
 
-use crate::{FunctionDefinition, TypeDefinition};
+use crate::{ConstantDefinition, FunctionDefinition, TypeDefinition};
 
 /// Represents a module declaration.
 #[repr(C)]
@@ -15,6 +15,10 @@ pub struct ModuleInfo<'a> {
     pub num_functions: u32,
     /// Number of module types
     pub num_types: u32,
+    /// Module constants
+    pub(crate) constants: *const ConstantDefinition<'a>,
+    /// Number of module constants
+    pub num_constants: u32,
 }
 
 impl<'a> ModuleInfo<'a> {
@@ -55,6 +59,15 @@ impl<'a> ModuleInfo<'a> {
             unsafe { slice::from_raw_parts(self.types, self.num_types as usize) }
         }
     }
+
+    /// Returns the module's constants.
+    pub fn constants(&self) -> &[ConstantDefinition<'a>] {
+        if self.num_constants == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.constants, self.num_constants as usize) }
+        }
+    }
 }
 
 unsafe impl Send for ModuleInfo<'_> {}
@@ -68,10 +81,11 @@ impl serde::Serialize for ModuleInfo<'_> {
     {
         use serde::ser::SerializeStruct;
 
-        let mut s = serializer.serialize_struct("ModuleInfo", 3)?;
+        let mut s = serializer.serialize_struct("ModuleInfo", 4)?;
         s.serialize_field("path", self.path())?;
         s.serialize_field("functions", self.functions())?;
         s.serialize_field("types", self.types())?;
+        s.serialize_field("constants", self.constants())?;
         s.end()
     }
 }
@@ -82,8 +96,9 @@ mod tests {
 
     use crate::{
         test_utils::{
-            fake_fn_prototype, fake_module_info, fake_struct_definition, fake_type_definition,
-            FAKE_FN_NAME, FAKE_MODULE_PATH, FAKE_STRUCT_NAME,
+            fake_constant_definition, fake_fn_prototype, fake_module_info, fake_struct_definition,
+            fake_type_definition, FAKE_CONSTANT_NAME, FAKE_FN_NAME, FAKE_MODULE_PATH,
+            FAKE_STRUCT_NAME,
         },
         type_id::HasStaticTypeId,
         FunctionDefinition, StructMemoryKind, TypeDefinition, TypeDefinitionData,
@@ -92,7 +107,7 @@ mod tests {
     #[test]
     fn test_module_info_path() {
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, &[], &[]);
+        let module = fake_module_info(&module_path, &[], &[], &[]);
 
         assert_eq!(module.path(), FAKE_MODULE_PATH);
     }
@@ -102,10 +117,11 @@ mod tests {
         let functions = &[];
         let types = &[];
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, types);
+        let module = fake_module_info(&module_path, functions, types, &[]);
 
         assert_eq!(module.functions().len(), functions.len());
         assert_eq!(module.types().len(), types.len());
+        assert_eq!(module.constants().len(), 0);
     }
 
     #[test]
@@ -128,7 +144,7 @@ mod tests {
         let types = [type_info];
 
         let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
-        let module = fake_module_info(&module_path, functions, &types);
+        let module = fake_module_info(&module_path, functions, &types, &[]);
 
         let result_functions = module.functions();
         assert_eq!(result_functions.len(), functions.len());
@@ -156,4 +172,26 @@ mod tests {
             assert_eq!(lhs.field_types(), rhs.field_types());
         }
     }
+
+    #[test]
+    fn test_module_info_constants() {
+        let constant_name = CString::new(FAKE_CONSTANT_NAME).expect("Invalid fake constant name.");
+        let value = 42u8;
+        let constants = [fake_constant_definition(
+            &constant_name,
+            u8::type_id().clone(),
+            &value,
+        )];
+
+        let module_path = CString::new(FAKE_MODULE_PATH).expect("Invalid fake module path.");
+        let module = fake_module_info(&module_path, &[], &[], &constants);
+
+        let result_constants = module.constants();
+        assert_eq!(result_constants.len(), constants.len());
+        for (lhs, rhs) in result_constants.iter().zip(constants.iter()) {
+            assert_eq!(lhs.name(), rhs.name());
+            assert_eq!(lhs.type_id, rhs.type_id);
+            assert_eq!(lhs.value, rhs.value);
+        }
+    }
 }
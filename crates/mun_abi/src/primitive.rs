@@ -1,6 +1,6 @@
 //! A module that defines information for built-in (or primitive) types.
 
-use crate::{Guid, HasStaticTypeId, TypeId};
+use crate::{F32x2, F32x4, F64x2, Guid, HasStaticTypeId, I32x2, I32x4, TypeId};
 
 /// Defines functions for built-in types like f32, i32, etc.
 pub trait PrimitiveType: HasStaticTypeId {
@@ -50,6 +50,11 @@ define_primitives! {
     f32 => "core::f32",
     f64 => "core::f64",
     bool => "core::bool",
+    F32x2 => "core::f32x2",
+    F32x4 => "core::f32x4",
+    F64x2 => "core::f64x2",
+    I32x2 => "core::i32x2",
+    I32x4 => "core::i32x4",
     () => "core::empty",
     std::ffi::c_void => "core::void"
 }
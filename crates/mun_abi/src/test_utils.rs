@@ -5,13 +5,15 @@ use std::{
 
 use crate::{
     type_id::{HasStaticTypeId, TypeId},
-    AssemblyInfo, DispatchTable, FunctionDefinition, FunctionPrototype, FunctionSignature, Guid,
-    ModuleInfo, StructDefinition, StructMemoryKind, TypeDefinition, TypeDefinitionData, TypeLut,
+    AssemblyInfo, ConstantDefinition, DispatchTable, FunctionDefinition, FunctionPrototype,
+    FunctionSignature, Guid, ModuleInfo, StructDefinition, StructMemoryKind, TypeDefinition,
+    TypeDefinitionData, TypeLut,
 };
 
 pub(crate) const FAKE_TYPE_GUID: Guid =
     Guid([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
 pub(crate) const FAKE_TYPE_ID: TypeId<'static> = TypeId::Concrete(FAKE_TYPE_GUID);
+pub(crate) const FAKE_CONSTANT_NAME: &str = "CONSTANT_NAME";
 pub(crate) const FAKE_DEPENDENCY: &str = "path/to/dependency.munlib";
 pub(crate) const FAKE_FIELD_NAME: &str = "field_name";
 pub(crate) const FAKE_FN_NAME: &str = "fn_name";
@@ -88,6 +90,7 @@ pub(crate) fn fake_module_info<'a>(
     path: &CStr,
     functions: &[FunctionDefinition<'a>],
     types: &[TypeDefinition<'a>],
+    constants: &[ConstantDefinition<'a>],
 ) -> ModuleInfo<'a> {
     ModuleInfo {
         path: path.as_ptr(),
@@ -95,6 +98,20 @@ pub(crate) fn fake_module_info<'a>(
         num_functions: functions.len() as u32,
         types: types.as_ptr(),
         num_types: types.len() as u32,
+        constants: constants.as_ptr(),
+        num_constants: constants.len() as u32,
+    }
+}
+
+pub(crate) fn fake_constant_definition<'a, T>(
+    name: &CStr,
+    type_id: TypeId<'a>,
+    value: &T,
+) -> ConstantDefinition<'a> {
+    ConstantDefinition {
+        name: name.as_ptr(),
+        type_id,
+        value: (value as *const T).cast(),
     }
 }
 
@@ -0,0 +1,97 @@
+//! A module that defines the Rust equivalents of the SIMD vector primitives of
+//! Mun, e.g. `f32x4`.
+//!
+//! Mun passes vectors to and from functions as an integer with the same size
+//! as the vector. The vector types are therefore transparent wrappers around
+//! such an integer, which makes them ABI compatible with Mun functions.
+//!
+//! C has no portable 128-bit integer type, so functions with vector types in
+//! their signature can only be called from a Rust host.
+
+use std::fmt;
+
+macro_rules! define_vectors {
+    ($($(#[$attr:meta])* $name:ident: [$elem:ty; $lanes:literal] as $bits:ty),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Default)]
+            pub struct $name($bits);
+
+            impl $name {
+                /// Constructs a vector from its lanes.
+                pub fn from_array(lanes: [$elem; $lanes]) -> Self {
+                    // SAFETY: The lanes and the integer have the same size and every bit
+                    // pattern is a valid integer.
+                    Self(unsafe { std::mem::transmute::<[$elem; $lanes], $bits>(lanes) })
+                }
+
+                /// Returns the lanes of the vector.
+                pub fn to_array(self) -> [$elem; $lanes] {
+                    // SAFETY: The lanes and the integer have the same size and every bit
+                    // pattern is a valid lane value.
+                    unsafe { std::mem::transmute::<$bits, [$elem; $lanes]>(self.0) }
+                }
+            }
+
+            impl From<[$elem; $lanes]> for $name {
+                fn from(lanes: [$elem; $lanes]) -> Self {
+                    Self::from_array(lanes)
+                }
+            }
+
+            impl From<$name> for [$elem; $lanes] {
+                fn from(vector: $name) -> Self {
+                    vector.to_array()
+                }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.to_array() == other.to_array()
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&self.to_array()).finish()
+                }
+            }
+        )+
+    };
+}
+
+define_vectors! {
+    /// A vector with two `f32` lanes, equivalent to the Mun type `f32x2`.
+    F32x2: [f32; 2] as u64,
+    /// A vector with four `f32` lanes, equivalent to the Mun type `f32x4`.
+    F32x4: [f32; 4] as u128,
+    /// A vector with two `f64` lanes, equivalent to the Mun type `f64x2`.
+    F64x2: [f64; 2] as u128,
+    /// A vector with two `i32` lanes, equivalent to the Mun type `i32x2`.
+    I32x2: [i32; 2] as u64,
+    /// A vector with four `i32` lanes, equivalent to the Mun type `i32x4`.
+    I32x4: [i32; 4] as u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{F32x4, I32x2};
+
+    #[test]
+    fn lanes_round_trip() {
+        let vector = F32x4::from_array([1.0, -2.0, 3.5, 0.25]);
+        assert_eq!(vector.to_array(), [1.0, -2.0, 3.5, 0.25]);
+        assert_eq!(vector, F32x4::from([1.0, -2.0, 3.5, 0.25]));
+
+        let vector = I32x2::from([i32::MIN, 7]);
+        assert_eq!(<[i32; 2]>::from(vector), [i32::MIN, 7]);
+    }
+
+    #[test]
+    fn layout_matches_vector() {
+        assert_eq!(std::mem::size_of::<F32x4>(), 16);
+        assert_eq!(std::mem::align_of::<F32x4>(), std::mem::align_of::<u128>());
+        assert_eq!(std::mem::size_of::<I32x2>(), 8);
+    }
+}
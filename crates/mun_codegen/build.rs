@@ -0,0 +1,12 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    // Cargo reruns the build script whenever a file in the package changes, so this
+    // identifies the build of the code generator. It is part of the fingerprint of
+    // every assembly.
+    let build_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the unix epoch")
+        .as_nanos();
+    println!("cargo:rustc-env=MUN_CODEGEN_BUILD_ID={build_id}");
+}
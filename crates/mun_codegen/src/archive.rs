@@ -0,0 +1,194 @@
+//! Writes static libraries in the `ar` archive format, which is understood by
+//! the linkers of all supported platforms.
+
+use std::{io::Write, path::Path};
+
+/// The flavor of `ar` archive to write.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveKind {
+    /// The System V (GNU) format, used on Linux and Windows.
+    Gnu,
+    /// The BSD format with a `__.SYMDEF SORTED` symbol table, used on macOS.
+    Bsd,
+}
+
+/// The magic string at the start of every archive
+const ARCHIVE_MAGIC: &[u8] = b"!<arch>\n";
+
+/// The size of the header that precedes every member
+const MEMBER_HEADER_SIZE: usize = 60;
+
+/// Writes a static library to `output_path` that contains a single object
+/// file. The symbol table of the archive lists `symbols`, which must be the
+/// names of the global symbols defined by the object file, as they appear in
+/// its symbol table.
+pub fn write_archive(
+    output_path: &Path,
+    kind: ArchiveKind,
+    object_name: &str,
+    object: &[u8],
+    symbols: &[String],
+) -> Result<(), std::io::Error> {
+    let bytes = match kind {
+        ArchiveKind::Gnu => gnu_archive(object_name, object, symbols),
+        ArchiveKind::Bsd => bsd_archive(object_name, object, symbols),
+    };
+    std::fs::File::create(output_path)?.write_all(&bytes)
+}
+
+/// Builds a System V archive. The symbol table member is named `/` and
+/// contains big-endian offsets.
+fn gnu_archive(object_name: &str, object: &[u8], symbols: &[String]) -> Vec<u8> {
+    let mut symbol_table = Vec::new();
+    symbol_table.extend_from_slice(&(symbols.len() as u32).to_be_bytes());
+    let offsets_start = symbol_table.len();
+    symbol_table.resize(offsets_start + 4 * symbols.len(), 0);
+    for symbol in symbols {
+        symbol_table.extend_from_slice(symbol.as_bytes());
+        symbol_table.push(0);
+    }
+
+    // The object follows the padded symbol table
+    let object_offset = ARCHIVE_MAGIC.len() + MEMBER_HEADER_SIZE + padded_len(symbol_table.len());
+    for index in 0..symbols.len() {
+        let start = offsets_start + 4 * index;
+        symbol_table[start..start + 4].copy_from_slice(&(object_offset as u32).to_be_bytes());
+    }
+
+    let mut archive = ARCHIVE_MAGIC.to_vec();
+    write_member(&mut archive, "/", "0", &symbol_table);
+    debug_assert_eq!(archive.len(), object_offset);
+    write_member(&mut archive, &format!("{object_name}/"), "644", object);
+    archive
+}
+
+/// Builds a BSD archive. Member names are stored after the header (`#1/<len>`)
+/// and are padded such that the contents of every member are aligned to 8
+/// bytes, which is required by `ld64`.
+fn bsd_archive(object_name: &str, object: &[u8], symbols: &[String]) -> Vec<u8> {
+    const SYMDEF_NAME: &str = "__.SYMDEF SORTED";
+
+    let mut symbols = symbols.iter().collect::<Vec<_>>();
+    symbols.sort();
+
+    let mut string_table = Vec::new();
+    let mut string_offsets = Vec::with_capacity(symbols.len());
+    for symbol in &symbols {
+        string_offsets.push(string_table.len() as u32);
+        string_table.extend_from_slice(symbol.as_bytes());
+        string_table.push(0);
+    }
+    string_table.resize(string_table.len().next_multiple_of(8), 0);
+
+    let symdef_name = bsd_member_name(SYMDEF_NAME, ARCHIVE_MAGIC.len());
+    let symdef_len = 4 + 8 * symbols.len() + 4 + string_table.len();
+    let object_header_offset =
+        ARCHIVE_MAGIC.len() + MEMBER_HEADER_SIZE + padded_len(symdef_name.len() + symdef_len);
+
+    let mut symdef = symdef_name;
+    symdef.extend_from_slice(&((8 * symbols.len()) as u32).to_le_bytes());
+    for string_offset in string_offsets {
+        symdef.extend_from_slice(&string_offset.to_le_bytes());
+        symdef.extend_from_slice(&(object_header_offset as u32).to_le_bytes());
+    }
+    symdef.extend_from_slice(&(string_table.len() as u32).to_le_bytes());
+    symdef.extend_from_slice(&string_table);
+
+    let name_len = symdef.len() - symdef_len;
+    let mut archive = ARCHIVE_MAGIC.to_vec();
+    write_member(&mut archive, &format!("#1/{name_len}"), "644", &symdef);
+    debug_assert_eq!(archive.len(), object_header_offset);
+
+    let mut object_member = bsd_member_name(object_name, object_header_offset);
+    let name_len = object_member.len();
+    object_member.extend_from_slice(object);
+    write_member(
+        &mut archive,
+        &format!("#1/{name_len}"),
+        "644",
+        &object_member,
+    );
+    archive
+}
+
+/// Returns the NUL-padded name of a BSD member whose header starts at
+/// `header_offset`, such that the contents of the member that follow the
+/// name are aligned to 8 bytes.
+fn bsd_member_name(name: &str, header_offset: usize) -> Vec<u8> {
+    let contents_offset = header_offset + MEMBER_HEADER_SIZE;
+    let name_len = (contents_offset + name.len() + 1).next_multiple_of(8) - contents_offset;
+    let mut bytes = name.as_bytes().to_vec();
+    bytes.resize(name_len, 0);
+    bytes
+}
+
+/// Returns the length of member contents including the padding that aligns the
+/// next member to an even offset.
+fn padded_len(len: usize) -> usize {
+    len.next_multiple_of(2)
+}
+
+/// Appends a member with the specified header `name` and octal `mode` to the
+/// archive.
+fn write_member(archive: &mut Vec<u8>, name: &str, mode: &str, contents: &[u8]) {
+    let header = format!(
+        "{name:<16}{mtime:<12}{uid:<6}{gid:<6}{mode:<8}{size:<10}`\n",
+        mtime = 0,
+        uid = 0,
+        gid = 0,
+        size = contents.len()
+    );
+    debug_assert_eq!(header.len(), MEMBER_HEADER_SIZE);
+    archive.extend_from_slice(header.as_bytes());
+    archive.extend_from_slice(contents);
+    if contents.len() % 2 == 1 {
+        archive.push(b'\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bsd_archive, gnu_archive, MEMBER_HEADER_SIZE};
+
+    #[test]
+    fn gnu_symbol_table_points_to_object() {
+        let symbols = vec!["foo_get_info".to_owned(), "foo_get_version".to_owned()];
+        let archive = gnu_archive("lib.o", b"object", &symbols);
+
+        assert!(archive.starts_with(b"!<arch>\n/               0"));
+        let symbol_table = &archive[8 + MEMBER_HEADER_SIZE..];
+        assert_eq!(symbol_table[..4], 2u32.to_be_bytes());
+
+        let object_offset = u32::from_be_bytes(symbol_table[4..8].try_into().unwrap()) as usize;
+        assert_eq!(symbol_table[8..12], symbol_table[4..8]);
+        assert!(archive[object_offset..].starts_with(b"lib.o/          "));
+        assert!(archive.ends_with(b"object"));
+    }
+
+    #[test]
+    fn bsd_members_are_aligned() {
+        let symbols = vec!["_foo_get_version".to_owned(), "_foo_get_info".to_owned()];
+        let archive = bsd_archive("lib.o", b"object", &symbols);
+
+        let symdef = &archive[8 + MEMBER_HEADER_SIZE..];
+        assert!(symdef.starts_with(b"__.SYMDEF SORTED\0"));
+
+        // Symbols are sorted, so `_foo_get_info` is the first entry
+        let ranlib = &symdef[20..];
+        assert_eq!(ranlib[..4], 16u32.to_le_bytes());
+        let object_offset = u32::from_le_bytes(ranlib[8..12].try_into().unwrap()) as usize;
+        let string_table = &ranlib[4 + 16 + 4..];
+        assert!(string_table.starts_with(b"_foo_get_info\0_foo_get_version\0"));
+
+        let object_header = &archive[object_offset..];
+        assert!(object_header.starts_with(b"#1/"));
+        let name_len: usize = std::str::from_utf8(&object_header[3..16])
+            .unwrap()
+            .trim_end()
+            .parse()
+            .unwrap();
+        let contents_offset = object_offset + MEMBER_HEADER_SIZE + name_len;
+        assert_eq!(contents_offset % 8, 0);
+        assert_eq!(&archive[contents_offset..], b"object");
+    }
+}
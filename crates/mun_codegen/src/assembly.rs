@@ -2,7 +2,8 @@ use std::{path::Path, sync::Arc};
 
 use anyhow::anyhow;
 use apple_codesign::{SigningSettings, UnifiedSigner};
-use inkwell::context::Context;
+use inkwell::{context::Context, module::Linkage, targets::FileType};
+use mun_abi as abi;
 use tempfile::NamedTempFile;
 
 use crate::{
@@ -16,6 +17,7 @@ use crate::{
 pub struct Assembly<'db, 'ink, 'ctx> {
     code_gen: &'ctx CodeGenContext<'db, 'ink>,
     module: inkwell::module::Module<'ink>,
+    static_symbol_prefix: String,
 }
 
 impl<'db, 'ink, 'ctx> Assembly<'db, 'ink, 'ctx> {
@@ -23,8 +25,13 @@ impl<'db, 'ink, 'ctx> Assembly<'db, 'ink, 'ctx> {
     pub fn new(
         code_gen: &'ctx CodeGenContext<'db, 'ink>,
         module: inkwell::module::Module<'ink>,
+        static_symbol_prefix: String,
     ) -> Self {
-        Self { code_gen, module }
+        Self {
+            code_gen,
+            module,
+            static_symbol_prefix,
+        }
     }
 
     /// Tries to convert the assembly into an `ObjectFile`.
@@ -36,12 +43,125 @@ impl<'db, 'ink, 'ctx> Assembly<'db, 'ink, 'ctx> {
         )
     }
 
-    /// Tries to write the `Assembly`'s IR to file.
-    pub fn write_ir_to_file(self, output_path: &Path) -> Result<(), anyhow::Error> {
-        self.module
-            .print_to_file(output_path)
+    /// Tries to write the `Assembly` to file as an artifact of the specified
+    /// kind.
+    ///
+    /// Machine code is generated from a copy of the module, because LLVM's
+    /// code generation passes modify the IR they operate on.
+    pub fn write_to_file(&self, kind: EmitKind, output_path: &Path) -> Result<(), anyhow::Error> {
+        let file_type = match kind {
+            EmitKind::Object => FileType::Object,
+            EmitKind::Assembly => FileType::Assembly,
+            EmitKind::LlvmBitcode => {
+                return if self.module.write_bitcode_to_path(output_path) {
+                    Ok(())
+                } else {
+                    Err(anyhow!(
+                        "could not write bitcode to {}",
+                        output_path.display()
+                    ))
+                };
+            }
+            EmitKind::LlvmIr => {
+                return self
+                    .module
+                    .print_to_file(output_path)
+                    .map_err(|e| anyhow!("{}", e));
+            }
+            EmitKind::StaticLib => return self.write_static_library(output_path),
+            EmitKind::Munlib => {
+                return Err(anyhow!(
+                    "munlibs are linked from object files and cannot be written directly"
+                ))
+            }
+        };
+
+        self.code_gen
+            .target_machine
+            .write_to_file(&self.module.clone(), file_type, output_path)
             .map_err(|e| anyhow!("{}", e))
     }
+
+    /// Tries to write the `Assembly` to file as a static library. The entry
+    /// points of the assembly are exported under names that are prefixed with
+    /// the [`crate::ModuleGroup::static_symbol_prefix`]. All other symbols are
+    /// made internal, so multiple assemblies can be linked into one binary.
+    fn write_static_library(&self, output_path: &Path) -> Result<(), anyhow::Error> {
+        let target = self.code_gen.db.target();
+        let prefix = &self.static_symbol_prefix;
+        let module = self.module.clone();
+
+        let mut symbols = Vec::new();
+        for function in module.get_functions() {
+            if function.count_basic_blocks() == 0 {
+                continue;
+            }
+
+            let name = function.get_name().to_string_lossy();
+            if ENTRY_POINTS.contains(&name.as_ref()) {
+                let exported_name = format!("{prefix}{name}");
+                function.as_global_value().set_name(&exported_name);
+                function.set_linkage(Linkage::External);
+
+                // Mach-O prefixes the names of all C symbols with an underscore
+                symbols.push(if target.options.is_like_osx {
+                    format!("_{exported_name}")
+                } else {
+                    exported_name
+                });
+            } else {
+                function.set_linkage(Linkage::Internal);
+            }
+        }
+
+        for global in module.get_globals() {
+            if !global.is_declaration() && !global.get_name().to_bytes().starts_with(b"llvm.") {
+                global.set_linkage(Linkage::Internal);
+            }
+        }
+
+        ObjectFile::new(&target, &self.code_gen.target_machine, &module)?
+            .into_static_library(output_path, &symbols)
+    }
+}
+
+/// The names of the functions through which the runtime accesses an assembly
+pub(crate) const ENTRY_POINTS: [&str; 3] = [
+    abi::GET_VERSION_FN_NAME,
+    abi::GET_INFO_FN_NAME,
+    abi::SET_ALLOCATOR_HANDLE_FN_NAME,
+];
+
+/// The kinds of artifacts that can be emitted for a module group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EmitKind {
+    /// A relocatable object file for the target machine
+    Object,
+    /// A textual assembly listing for the target machine
+    Assembly,
+    /// LLVM bitcode
+    LlvmBitcode,
+    /// Textual LLVM IR
+    LlvmIr,
+    /// A static library that can be linked into a host binary, whose
+    /// assemblies are registered with the runtime without hot reloading
+    StaticLib,
+    /// A Mun library that is linked and can be loaded by the runtime
+    Munlib,
+}
+
+impl EmitKind {
+    /// Returns the file extension of artifacts of this kind.
+    pub fn extension(self) -> &'static str {
+        match self {
+            EmitKind::Object => "o",
+            EmitKind::Assembly => "s",
+            EmitKind::LlvmBitcode => "bc",
+            EmitKind::LlvmIr => "ll",
+            EmitKind::StaticLib => "a",
+            EmitKind::Munlib => TargetAssembly::EXTENSION,
+        }
+    }
 }
 
 /// Builds an assembly for the specified file
@@ -121,39 +241,43 @@ pub(crate) fn build_target_assembly(
     Arc::new(TargetAssembly { file })
 }
 
-/// An `AssemblyIr` is a reference to an IR file stored on disk.
+/// `AssemblyArtifacts` are references to files stored on disk that were
+/// emitted for a single module group, such as object files or LLVM IR.
 #[derive(Debug)]
-pub struct AssemblyIr {
-    file: NamedTempFile,
+pub struct AssemblyArtifacts {
+    files: Vec<(EmitKind, NamedTempFile)>,
 }
 
-impl PartialEq for AssemblyIr {
+impl PartialEq for AssemblyArtifacts {
     fn eq(&self, other: &Self) -> bool {
-        self.path().eq(other.path())
+        self.iter().eq(other.iter())
     }
 }
 
-impl Eq for AssemblyIr {}
-
-impl AssemblyIr {
-    pub const EXTENSION: &'static str = "ll";
+impl Eq for AssemblyArtifacts {}
 
-    /// Returns the current location of the IR File.
-    pub fn path(&self) -> &Path {
-        self.file.path()
+impl AssemblyArtifacts {
+    /// Returns the kinds and current locations of all artifacts.
+    pub fn iter(&self) -> impl Iterator<Item = (EmitKind, &Path)> {
+        self.files.iter().map(|(kind, file)| (*kind, file.path()))
     }
 
-    /// Copies the assembly to the specified location
-    pub fn copy_to<P: AsRef<Path>>(&self, destination: P) -> Result<(), std::io::Error> {
-        std::fs::copy(self.path(), destination).map(|_| ())
+    /// Returns the current location of the artifact of the specified kind, if
+    /// it was emitted.
+    pub fn path(&self, kind: EmitKind) -> Option<&Path> {
+        self.iter()
+            .find_map(|(emitted_kind, path)| (emitted_kind == kind).then_some(path))
     }
 }
 
-/// Builds an IR file for the specified module.
-pub(crate) fn build_assembly_ir(
+/// Builds the artifacts of the specified kinds for the specified module.
+/// Munlibs are not part of the artifacts, they are built by
+/// [`CodeGenDatabase::target_assembly`].
+pub(crate) fn build_assembly_artifacts(
     db: &dyn CodeGenDatabase,
     module_group: ModuleGroupId,
-) -> Arc<AssemblyIr> {
+    kinds: Vec<EmitKind>,
+) -> Arc<AssemblyArtifacts> {
     // Setup the code generation context
     let inkwell_context = Context::create();
     let code_gen_context = CodeGenContext::new(&inkwell_context, db);
@@ -161,13 +285,18 @@ pub(crate) fn build_assembly_ir(
     // Build an assembly for the module
     let assembly = build_assembly(db, &code_gen_context, module_group);
 
-    // Construct a temporary file for the assembly
-    let file = NamedTempFile::new().expect("could not create temp file for shared object");
-
-    // Write the assembly's IR to disk
-    assembly
-        .write_ir_to_file(file.path())
-        .expect("could not write to temp file");
-
-    Arc::new(AssemblyIr { file })
+    // Write every requested artifact to a temporary file
+    let files = kinds
+        .into_iter()
+        .filter(|&kind| kind != EmitKind::Munlib)
+        .map(|kind| {
+            let file = NamedTempFile::new().expect("could not create temp file for artifact");
+            assembly
+                .write_to_file(kind, file.path())
+                .expect("could not write to temp file");
+            (kind, file)
+        })
+        .collect();
+
+    Arc::new(AssemblyArtifacts { files })
 }
@@ -15,12 +15,28 @@ mod object_file;
 pub mod symbols;
 
 /// Optimizes the specified LLVM `Module` using the default passes for the given
-/// `OptimizationLevel`.
-fn optimize_module(module: &Module<'_>, optimization_lvl: OptimizationLevel) {
+/// `OptimizationLevel`. With link-time optimization (`lto`) the functions of
+/// the module are additionally inlined into each other.
+fn optimize_module(module: &Module<'_>, optimization_lvl: OptimizationLevel, lto: bool) {
     let pass_builder = PassManagerBuilder::create();
     pass_builder.set_optimization_level(optimization_lvl);
+    if lto {
+        pass_builder.set_inliner_with_threshold(inline_threshold(optimization_lvl));
+    }
 
     let module_pass_manager = PassManager::create(());
     pass_builder.populate_module_pass_manager(&module_pass_manager);
+    if lto {
+        pass_builder.populate_lto_pass_manager(&module_pass_manager, false, true);
+    }
     module_pass_manager.run_on(module);
 }
+
+/// Returns the inlining threshold that LLVM uses for the given
+/// `OptimizationLevel`.
+fn inline_threshold(optimization_lvl: OptimizationLevel) -> u32 {
+    match optimization_lvl {
+        OptimizationLevel::None | OptimizationLevel::Less | OptimizationLevel::Default => 225,
+        OptimizationLevel::Aggressive => 275,
+    }
+}
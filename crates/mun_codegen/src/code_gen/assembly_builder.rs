@@ -1,8 +1,11 @@
-use inkwell::module::{Linkage, Module};
+use inkwell::{
+    attributes::{Attribute, AttributeLoc},
+    module::{Linkage, Module},
+};
 use rustc_hash::FxHashSet;
 
 use crate::{
-    assembly::Assembly,
+    assembly::{Assembly, ENTRY_POINTS},
     code_gen::{optimize_module, symbols, CodeGenContext, CodeGenerationError},
     ir::{file::gen_file_ir, file_group::gen_file_group_ir},
     value::{IrTypeContext, IrValueContext},
@@ -84,6 +87,8 @@ impl<'db, 'ink, 'ctx, 't> AssemblyBuilder<'db, 'ink, 'ctx, 't> {
             .referenced_modules
             .union(&direct_children)
             .filter_map(|&module| self.module_group_partition.group_for_module(module))
+            // A group can contain modules that reference each other
+            .filter(|&group_id| group_id != self.module_group_id)
             .collect::<FxHashSet<_>>()
             .into_iter()
             .map(|group_id| {
@@ -100,6 +105,7 @@ impl<'db, 'ink, 'ctx, 't> AssemblyBuilder<'db, 'ink, 'ctx, 't> {
             &module_group.name,
             &file.function_definitions,
             &file.type_definitions,
+            &file.constant_definitions,
             &group_ir.dispatch_table,
             &group_ir.type_table,
             &self.code_gen.hir_types,
@@ -107,12 +113,44 @@ impl<'db, 'ink, 'ctx, 't> AssemblyBuilder<'db, 'ink, 'ctx, 't> {
             dependencies,
         );
 
+        // Emit unwind tables for all functions so a panic can unwind through Mun code
+        // to the host.
+        let uwtable = self
+            .code_gen
+            .context
+            .create_enum_attribute(Attribute::get_named_enum_kind_id("uwtable"), 0);
+        for function in self.assembly_module.get_functions() {
+            if function.count_basic_blocks() > 0 {
+                function.add_attribute(AttributeLoc::Function, uwtable);
+            }
+        }
+
+        // With link-time optimization all functions other than the entry points are
+        // only referenced from within the assembly, so they can be inlined and
+        // removed.
+        if self.code_gen.lto {
+            for function in self.assembly_module.get_functions() {
+                let name = function.get_name().to_string_lossy();
+                if function.count_basic_blocks() > 0 && !ENTRY_POINTS.contains(&name.as_ref()) {
+                    function.set_linkage(Linkage::Internal);
+                }
+            }
+        }
+
         // Optimize the assembly module
-        optimize_module(&self.assembly_module, self.code_gen.optimization_level);
+        optimize_module(
+            &self.assembly_module,
+            self.code_gen.optimization_level,
+            self.code_gen.lto,
+        );
 
         // Debug print the IR
         //println!("{}", assembly_module.print_to_string().to_string());
 
-        Ok(Assembly::new(self.code_gen, self.assembly_module))
+        Ok(Assembly::new(
+            self.code_gen,
+            self.assembly_module,
+            module_group.static_symbol_prefix(self.code_gen.db),
+        ))
     }
 }
@@ -1,6 +1,11 @@
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use inkwell::{context::Context, module::Module, targets::TargetMachine, types::StructType};
+use inkwell::{
+    context::Context,
+    module::Module,
+    targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetMachine, TargetTriple},
+    types::StructType,
+};
 
 use crate::{ir::ty::HirTypeCache, CodeGenDatabase};
 
@@ -20,6 +25,18 @@ pub struct CodeGenContext<'db, 'ink> {
     /// The optimization level
     pub optimization_level: inkwell::OptimizationLevel,
 
+    /// Whether or not to emit runtime checks
+    pub runtime_checks: bool,
+
+    /// Whether or not to emit runtime checks for integer arithmetic overflow
+    pub overflow_checks: bool,
+
+    /// Whether or not to use link-time optimization
+    pub lto: bool,
+
+    /// Whether or not to emit debug information
+    pub debug_info: bool,
+
     /// The target to generate code for
     pub target_machine: Rc<TargetMachine>,
 }
@@ -28,12 +45,16 @@ impl<'db, 'ink> CodeGenContext<'db, 'ink> {
     /// Constructs a new `CodeGenContext` from an LLVM context and a
     /// `CodeGenDatabase`.
     pub fn new(context: &'ink Context, db: &'db dyn CodeGenDatabase) -> Self {
-        let target_machine = db.target_machine().0;
+        let target_machine = Rc::new(create_target_machine(db));
         Self {
             context,
             rust_types: RefCell::new(HashMap::default()),
             hir_types: HirTypeCache::new(context, db, target_machine.get_target_data()),
             optimization_level: db.optimization_level(),
+            runtime_checks: db.runtime_checks(),
+            overflow_checks: db.overflow_checks(),
+            lto: db.lto(),
+            debug_info: db.debug_info(),
             target_machine,
             db,
         }
@@ -48,3 +69,36 @@ impl<'db, 'ink> CodeGenContext<'db, 'ink> {
         module
     }
 }
+
+/// Constructs the primary interface to the complete machine description for the
+/// target machine. All target-specific information should be accessible through
+/// this interface.
+///
+/// LLVM objects are not thread-safe, so every context constructs its own
+/// target machine. This enables generating multiple assemblies in parallel.
+fn create_target_machine(db: &dyn CodeGenDatabase) -> TargetMachine {
+    // Get the HIR target
+    let target = db.target();
+
+    // Initialize the supported targets
+    Target::initialize_x86(&InitializationConfig::default());
+    Target::initialize_aarch64(&InitializationConfig::default());
+    Target::initialize_webassembly(&InitializationConfig::default());
+
+    // Retrieve the LLVM target using the specified target.
+    let target_triple = TargetTriple::create(&db.target().llvm_target);
+    let llvm_target = Target::from_triple(&target_triple)
+        .expect("could not find llvm target tripple for Mun target");
+
+    // Construct target machine for machine code generation
+    llvm_target
+        .create_target_machine(
+            &target_triple,
+            &target.options.cpu,
+            &target.options.features,
+            db.optimization_level(),
+            RelocMode::PIC,
+            CodeModel::Default,
+        )
+        .expect("could not create llvm target machine")
+}
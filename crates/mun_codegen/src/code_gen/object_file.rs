@@ -4,7 +4,11 @@ use inkwell::targets::{FileType, TargetMachine};
 use mun_target::spec;
 use tempfile::NamedTempFile;
 
-use crate::{code_gen::CodeGenerationError, linker};
+use crate::{
+    archive::{self, ArchiveKind},
+    code_gen::CodeGenerationError,
+    linker,
+};
 
 pub struct ObjectFile {
     target: spec::Target,
@@ -46,4 +50,22 @@ impl ObjectFile {
 
         Ok(())
     }
+
+    /// Archives the object file into a static library, whose symbol table
+    /// contains the specified global `symbols` of the object file.
+    pub fn into_static_library(
+        self,
+        output_path: &Path,
+        symbols: &[String],
+    ) -> Result<(), anyhow::Error> {
+        let kind = if self.target.options.is_like_osx {
+            ArchiveKind::Bsd
+        } else {
+            ArchiveKind::Gnu
+        };
+        let object = std::fs::read(self.obj_file.path())?;
+        archive::write_archive(output_path, kind, "mun.o", &object, symbols)?;
+
+        Ok(())
+    }
 }
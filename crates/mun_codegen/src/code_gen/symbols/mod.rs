@@ -253,6 +253,51 @@ fn get_function_definition_array<'ink, 'a>(
         .into_const_private_global("fn.get_info.functions", context)
 }
 
+/// Construct a global that holds a reference to all constants and stores their
+/// values in private globals. e.g.:
+/// `MunConstantDefinition[] definitions = { ... }`
+fn get_constant_definition_array<'ink, 'a>(
+    db: &dyn HirDatabase,
+    context: &IrValueContext<'ink, '_, '_>,
+    constants: impl Iterator<Item = &'a mun_hir::Const>,
+    hir_types: &HirTypeCache<'_, 'ink>,
+    ir_type_builder: &TypeIdBuilder<'ink, '_, '_, '_>,
+) -> Global<'ink, [ir::ConstantDefinition<'ink>]> {
+    let module = context.module;
+    constants
+        .sorted_by_cached_key(|c| c.full_name(db))
+        .map(|c| {
+            let name = c.full_name(db);
+            let ty = c.ty(db);
+
+            // Store the value of the constant in a private global
+            let value = c
+                .eval(db)
+                .expect("constants must be evaluated before code generation");
+            let value = hir_types.get_const_value(&value, &ty);
+            let global = module.add_global(value.get_type(), None, &format!("const::<{name}>"));
+            global.set_initializer(&value);
+            global.set_constant(true);
+            global.set_linkage(Linkage::Private);
+
+            let name_str = CString::new(name.clone())
+                .expect("constant name is not a valid CString")
+                .intern(format!("const::<{name}>::name"), context);
+
+            ir::ConstantDefinition {
+                name: name_str.as_value(context),
+                type_id: ir_type_builder.construct_from_type_id(&hir_types.type_id(&ty)),
+                value: Value {
+                    value: global
+                        .as_pointer_value()
+                        .const_cast(<*const std::ffi::c_void>::get_ir_type(context.type_context)),
+                },
+            }
+        })
+        .into_value(context)
+        .into_const_private_global("fn.get_info.constants", context)
+}
+
 /// Generate the type lookup table information. e.g.:
 /// ```c
 /// MunTypeLut typeLut = { ... }
@@ -355,6 +400,7 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
     module_name: &str,
     function_definitions: &HashSet<mun_hir::Function>,
     type_definitions: &HashSet<mun_hir::Ty>,
+    constant_definitions: &HashSet<mun_hir::Const>,
     dispatch_table: &DispatchTable<'ink>,
     type_table: &TypeTable<'ink>,
     hir_types: &HirTypeCache<'db, 'ink>,
@@ -382,6 +428,15 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
         &ir_type_builder,
     );
 
+    let num_constants = constant_definitions.len() as u32;
+    let constants = get_constant_definition_array(
+        db,
+        context,
+        constant_definitions.iter(),
+        hir_types,
+        &ir_type_builder,
+    );
+
     // Construct the module info struct
     let module_info = ir::ModuleInfo {
         path: CString::new(module_name)
@@ -392,6 +447,8 @@ pub(super) fn gen_reflection_ir<'db, 'ink>(
         num_functions,
         types,
         num_types,
+        constants: constants.as_value(context),
+        num_constants,
     };
 
     // Construct the dispatch table struct
@@ -1,9 +1,9 @@
-use std::{rc::Rc, sync::Arc};
+use std::sync::Arc;
 
-use by_address::ByAddress;
-use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target, TargetTriple};
-
-use crate::{AssemblyIr, ModuleGroupId, ModulePartition, TargetAssembly};
+use crate::{
+    fingerprint::Fingerprint, AssemblyArtifacts, EmitKind, ModuleGroupId, ModulePartition,
+    PartitionStrategy, TargetAssembly,
+};
 
 /// The `CodeGenDatabase` enables caching of code generation stages.
 /// Inkwell/LLVM objects are not stored in the cache because they are not
@@ -19,51 +19,47 @@ pub trait CodeGenDatabase: mun_hir::HirDatabase {
     #[salsa::input]
     fn optimization_level(&self) -> inkwell::OptimizationLevel;
 
+    /// Set the strategy used to group modules into assemblies
+    #[salsa::input]
+    fn partition_strategy(&self) -> PartitionStrategy;
+
+    /// Set whether runtime checks are emitted for out-of-bounds array accesses
+    /// and integer division by zero
+    #[salsa::input]
+    fn runtime_checks(&self) -> bool;
+
+    /// Set whether runtime checks are emitted for integer arithmetic overflow
+    #[salsa::input]
+    fn overflow_checks(&self) -> bool;
+
+    /// Set whether calls to functions in other module groups of the same
+    /// package are inlined with link-time optimization
+    #[salsa::input]
+    fn lto(&self) -> bool;
+
+    /// Set whether debug information is emitted
+    #[salsa::input]
+    fn debug_info(&self) -> bool;
+
     /// Returns the current module partition
     #[salsa::invoke(crate::module_partition::build_partition)]
     fn module_partition(&self) -> Arc<ModulePartition>;
 
-    /// Returns the inkwell target machine that completely describes the code
-    /// generation target. All target-specific information should be
-    /// accessible through this interface.
-    fn target_machine(&self) -> ByAddress<Rc<inkwell::targets::TargetMachine>>;
+    /// Returns a hash of all the inputs of the assembly of the specified module
+    /// group.
+    #[salsa::invoke(crate::fingerprint::module_group_fingerprint)]
+    fn module_group_fingerprint(&self, module_group: ModuleGroupId) -> Fingerprint;
 
-    /// Returns a file containing the IR for the specified module.
-    #[salsa::invoke(crate::assembly::build_assembly_ir)]
-    fn assembly_ir(&self, module_group: ModuleGroupId) -> Arc<AssemblyIr>;
+    /// Returns files containing the artifacts of the specified kinds for the
+    /// specified module.
+    #[salsa::invoke(crate::assembly::build_assembly_artifacts)]
+    fn assembly_artifacts(
+        &self,
+        module_group: ModuleGroupId,
+        kinds: Vec<EmitKind>,
+    ) -> Arc<AssemblyArtifacts>;
 
     /// Returns a fully linked shared object for the specified module.
     #[salsa::invoke(crate::assembly::build_target_assembly)]
     fn target_assembly(&self, module_group: ModuleGroupId) -> Arc<TargetAssembly>;
 }
-
-/// Constructs the primary interface to the complete machine description for the
-/// target machine. All target-specific information should be accessible through
-/// this interface.
-fn target_machine(db: &dyn CodeGenDatabase) -> ByAddress<Rc<inkwell::targets::TargetMachine>> {
-    // Get the HIR target
-    let target = db.target();
-
-    // Initialize the x86 target
-    Target::initialize_x86(&InitializationConfig::default());
-    Target::initialize_aarch64(&InitializationConfig::default());
-
-    // Retrieve the LLVM target using the specified target.
-    let target_triple = TargetTriple::create(&db.target().llvm_target);
-    let llvm_target = Target::from_triple(&target_triple)
-        .expect("could not find llvm target tripple for Mun target");
-
-    // Construct target machine for machine code generation
-    let target_machine = llvm_target
-        .create_target_machine(
-            &target_triple,
-            &target.options.cpu,
-            &target.options.features,
-            db.optimization_level(),
-            RelocMode::PIC,
-            CodeModel::Default,
-        )
-        .expect("could not create llvm target machine");
-
-    ByAddress(Rc::new(target_machine))
-}
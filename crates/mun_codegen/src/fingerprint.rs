@@ -0,0 +1,241 @@
+//! A [`Fingerprint`] summarizes everything that influences the assembly of a
+//! module group. If the fingerprint of a group did not change since a previous
+//! compilation, the assembly of that compilation can be reused.
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use mun_hir::Package;
+use mun_syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
+use rustc_hash::{FxHashSet, FxHasher};
+
+use crate::{CodeGenDatabase, ModuleGroupId};
+
+/// A hash of all the inputs of the assembly of a module group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s.trim(), 16).map(Fingerprint)
+    }
+}
+
+/// Computes the fingerprint of the specified module group.
+///
+/// Besides the complete source of the modules in the group, the fingerprint
+/// includes the source of all other modules, excluding function bodies. A
+/// module group only depends on the signatures of the items in other groups,
+/// which means changing the implementation of a function does not affect the
+/// assemblies of other groups. With link-time optimization, the complete
+/// source of the modules that define imported functions is included as well.
+pub(crate) fn module_group_fingerprint(
+    db: &dyn CodeGenDatabase,
+    module_group: ModuleGroupId,
+) -> Fingerprint {
+    let mut hasher = FxHasher::default();
+
+    // The compiler and its configuration
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    env!("MUN_CODEGEN_BUILD_ID").hash(&mut hasher);
+    mun_abi::ABI_VERSION.hash(&mut hasher);
+    let target = db.target();
+    target.llvm_target.hash(&mut hasher);
+    target.data_layout.hash(&mut hasher);
+    target.options.cpu.hash(&mut hasher);
+    target.options.features.hash(&mut hasher);
+    (db.optimization_level() as u32).hash(&mut hasher);
+    db.runtime_checks().hash(&mut hasher);
+    db.overflow_checks().hash(&mut hasher);
+    db.lto().hash(&mut hasher);
+    db.debug_info().hash(&mut hasher);
+
+    // The modules in the group
+    let module_partition = db.module_partition();
+    let group = &module_partition[module_group];
+    group.name.hash(&mut hasher);
+    group.static_symbol_prefix(db).hash(&mut hasher);
+
+    let group_files = group.files(db).collect::<FxHashSet<_>>();
+    for file_id in group.files(db) {
+        db.file_relative_path(file_id).as_str().hash(&mut hasher);
+        db.file_text(file_id).hash(&mut hasher);
+    }
+
+    // The modules whose functions are compiled into the group
+    if db.lto() {
+        let mut imported_files = FxHashSet::default();
+        for function in group.imported_fns(db) {
+            let Some(file_id) = function.module(db).file_id(db) else {
+                continue;
+            };
+            if imported_files.insert(file_id) {
+                db.file_relative_path(file_id).as_str().hash(&mut hasher);
+                db.file_text(file_id).hash(&mut hasher);
+            }
+        }
+    }
+
+    // Debug information refers to the source files by their absolute path
+    if db.debug_info() {
+        let packages = db.packages();
+        for package in packages.iter() {
+            packages[package].source_directory.hash(&mut hasher);
+        }
+    }
+
+    // The interface of all other modules
+    for package in Package::all(db) {
+        for module in package.modules(db) {
+            let Some(file_id) = module.file_id(db) else {
+                continue;
+            };
+            if group_files.contains(&file_id) {
+                continue;
+            }
+
+            package.name(db).hash(&mut hasher);
+            db.file_relative_path(file_id).as_str().hash(&mut hasher);
+            hash_interface(&db.parse(file_id).syntax_node(), &mut hasher);
+        }
+    }
+
+    Fingerprint(hasher.finish())
+}
+
+/// Hashes all tokens of a source file that are not part of the body of a
+/// function, ignoring whitespace and comments. The initializers of constants
+/// are hashed completely, since their values are part of the interface.
+fn hash_interface(syntax: &SyntaxNode, hasher: &mut FxHasher) {
+    let is_function_body = |node: &SyntaxNode| {
+        node.kind() == SyntaxKind::BLOCK_EXPR
+            && node
+                .parent()
+                .is_some_and(|parent| parent.kind() == SyntaxKind::FUNCTION_DEF)
+    };
+    let tokens = syntax
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|token| !token.kind().is_trivia())
+        .filter(|token| {
+            !token
+                .parent()
+                .into_iter()
+                .flat_map(|parent| parent.ancestors())
+                .any(|node| is_function_body(&node))
+        });
+
+    for token in tokens {
+        token.text().hash(hasher);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mun_hir_input::{SourceDatabase, WithFixture};
+
+    use super::Fingerprint;
+    use crate::{mock::MockDatabase, CodeGenDatabase, ModuleGroupId};
+
+    const FIXTURE: &str = r#"
+    //- /mod.mun
+    pub fn main() -> i32 { foo::foo() }
+
+    //- /foo.mun
+    pub fn foo() -> i32 { 1 }
+    "#;
+
+    fn fingerprints(db: &MockDatabase) -> Vec<Fingerprint> {
+        db.module_partition()
+            .iter()
+            .map(|(id, _)| db.module_group_fingerprint(id))
+            .collect()
+    }
+
+    fn group_by_name(db: &MockDatabase, name: &str) -> ModuleGroupId {
+        db.module_partition()
+            .iter()
+            .find(|(_, group)| group.name == name)
+            .map(|(id, _)| id)
+            .unwrap_or_else(|| panic!("no module group named `{name}`"))
+    }
+
+    fn change_file(db: &mut MockDatabase, group: ModuleGroupId, text: &str) {
+        let file_id = db.module_partition()[group]
+            .files(db)
+            .next()
+            .expect("module group without files");
+        db.set_file_text(file_id, Arc::from(text));
+    }
+
+    #[test]
+    fn body_changes_only_affect_own_group() {
+        let mut db = MockDatabase::with_files(FIXTURE);
+        let foo_group = group_by_name(&db, "foo");
+
+        let before = fingerprints(&db);
+        change_file(&mut db, foo_group, "pub fn foo() -> i32 { 2 }");
+        let after = fingerprints(&db);
+
+        for ((id, _), (before, after)) in db
+            .module_partition()
+            .iter()
+            .zip(before.into_iter().zip(after))
+        {
+            assert_eq!(id == foo_group, before != after);
+        }
+    }
+
+    #[test]
+    fn block_const_changes_affect_all_groups() {
+        let mut db = MockDatabase::with_files(
+            r#"
+        //- /mod.mun
+        pub fn main() -> i32 { foo::FOO }
+
+        //- /foo.mun
+        pub const FOO: i32 = { 1 };
+        "#,
+        );
+        let foo_group = group_by_name(&db, "foo");
+
+        let before = fingerprints(&db);
+        change_file(&mut db, foo_group, "pub const FOO: i32 = { 2 };");
+        let after = fingerprints(&db);
+
+        assert!(before.into_iter().zip(after).all(|(a, b)| a != b));
+    }
+
+    #[test]
+    fn signature_changes_affect_all_groups() {
+        let mut db = MockDatabase::with_files(FIXTURE);
+        let foo_group = group_by_name(&db, "foo");
+
+        let before = fingerprints(&db);
+        change_file(&mut db, foo_group, "pub fn foo() -> i64 { 1 }");
+        let after = fingerprints(&db);
+
+        assert!(before.into_iter().zip(after).all(|(a, b)| a != b));
+    }
+
+    #[test]
+    fn parse_fingerprint() {
+        let fingerprint = Fingerprint(0x0123_4567_89ab_cdef);
+        assert_eq!(fingerprint.to_string(), "0123456789abcdef");
+        assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+    }
+}
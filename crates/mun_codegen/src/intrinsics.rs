@@ -26,4 +26,18 @@ intrinsics! {
     ///
     /// Note that the elements in the array are left uninitialized.
     pub fn new_array(type_handle: *const ffi::c_void, length: usize, alloc_handle: *mut ffi::c_void) -> *const *mut ffi::c_void;
+
+    /// Aborts the execution of Mun code with the specified null-terminated `message`. The `file`
+    /// and `line` describe the location in the source that caused the panic.
+    ///
+    /// This function never returns, instead the stack is unwound up to the host.
+    pub fn panic(message: *const u8, file: *const u8, line: u32) -> ();
+
+    /// Links the call `frame` record of a function that is entered into the call stack of the
+    /// current thread. The call stack is used to report stack traces when a panic occurs.
+    pub fn push_frame(frame: *mut ffi::c_void) -> ();
+
+    /// Unlinks the call `frame` record of a function that returns from the call stack of the
+    /// current thread.
+    pub fn pop_frame(frame: *mut ffi::c_void) -> ();
 }
@@ -10,6 +10,7 @@ use inkwell::{
 
 mod array;
 pub mod body;
+pub(crate) mod debug_info;
 #[macro_use]
 pub(crate) mod dispatch_table;
 pub mod file;
@@ -101,6 +102,22 @@ impl<'ink> IsIrType<'ink> for isize {
     }
 }
 
+impl<'ink> IsIrType<'ink> for u8 {
+    type Type = IntType<'ink>;
+
+    fn ir_type(context: &'ink Context, _target: &TargetData) -> Self::Type {
+        context.i8_type()
+    }
+}
+
+impl<'ink> IsIrType<'ink> for u32 {
+    type Type = IntType<'ink>;
+
+    fn ir_type(context: &'ink Context, _target: &TargetData) -> Self::Type {
+        context.i32_type()
+    }
+}
+
 pub trait IsPointerType<'ink> {
     fn ir_type(context: &'ink Context, target: &TargetData) -> PointerType<'ink>;
 }
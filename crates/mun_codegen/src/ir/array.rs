@@ -65,6 +65,19 @@ impl<'ink> RuntimeArrayValue<'ink> {
             .expect("could not get `length` from array struct")
     }
 
+    /// Generate code to fetch the length of the array.
+    pub fn get_length(&self, builder: &Builder<'ink>) -> IntValue<'ink> {
+        let length_ptr = self.get_length_ptr(builder);
+        let value_name = self
+            .get_array_ptr(builder)
+            .get_name()
+            .to_string_lossy()
+            .into_owned();
+        builder
+            .build_load(length_ptr, &format!("{}.length", &value_name))
+            .into_int_value()
+    }
+
     /// Generate code to fetch the capacity of the array.
     pub fn get_capacity(&self, builder: &Builder<'ink>) -> IntValue<'ink> {
         let array_ptr = self.get_array_ptr(builder);
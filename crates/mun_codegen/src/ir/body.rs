@@ -4,24 +4,29 @@ use inkwell::{
     basic_block::BasicBlock,
     builder::Builder,
     context::Context,
+    debug_info::{DILocation, DISubprogram},
+    intrinsics::Intrinsic as LlvmIntrinsic,
+    module::Module,
+    types::{BasicType, VectorType},
     values::{
         AggregateValueEnum, BasicMetadataValueEnum, BasicValueEnum, CallSiteValue, FloatValue,
-        FunctionValue, GlobalValue, IntValue, PointerValue, StructValue,
+        FunctionValue, GlobalValue, IntValue, PointerValue, StructValue, VectorValue,
     },
     AddressSpace, FloatPredicate, IntPredicate,
 };
 use mun_abi as abi;
 use mun_hir::{
-    ArithOp, BinaryOp, Body, CmpOp, Expr, ExprId, HirDatabase, HirDisplay, InferenceResult,
-    Literal, LogicOp, Name, Ordering, Pat, PatId, Path, ResolveBitness, Resolver, Statement,
-    TyKind, UnaryOp, ValueNs,
+    method_resolution::{BuiltinMethod, OverflowMode},
+    ArithOp, BinaryOp, Body, CmpOp, ConstValue, Expr, ExprId, HirDatabase, HirDisplay,
+    InferenceResult, Literal, LogicOp, Name, Ordering, Pat, PatId, Path, ResolveBitness, Resolver,
+    ScalarTy, Statement, Ty, TyKind, UnaryOp, ValueNs, VectorTy,
 };
 
 use crate::{
     intrinsics,
     ir::{
-        dispatch_table::DispatchTable, ty::HirTypeCache, type_table::TypeTable, RuntimeArrayValue,
-        RuntimeReferenceValue,
+        debug_info::DebugInfo, dispatch_table::DispatchTable, ty::HirTypeCache,
+        type_table::TypeTable, RuntimeArrayValue, RuntimeReferenceValue,
     },
     module_group::ModuleGroup,
     value::Global,
@@ -44,6 +49,7 @@ pub(crate) struct ExternalGlobals<'ink> {
 pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
     context: &'ink Context,
     db: &'db dyn HirDatabase,
+    module: &'t Module<'ink>,
     body: Arc<Body>,
     infer: Arc<InferenceResult>,
     builder: Builder<'ink>,
@@ -59,6 +65,12 @@ pub(crate) struct BodyIrGenerator<'db, 'ink, 't> {
     hir_function: mun_hir::Function,
     external_globals: ExternalGlobals<'ink>,
     module_group: &'t ModuleGroup,
+    runtime_checks: bool,
+    overflow_checks: bool,
+    lto: bool,
+    debug_info: Option<(&'t DebugInfo<'db, 'ink, 't>, DISubprogram<'ink>)>,
+    debug_location: Option<DILocation<'ink>>,
+    call_frame: Option<PointerValue<'ink>>,
 }
 
 impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
@@ -66,6 +78,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     pub fn new(
         context: &'ink Context,
         db: &'db dyn HirDatabase,
+        module: &'t Module<'ink>,
         function: (mun_hir::Function, FunctionValue<'ink>),
         function_map: &'t HashMap<mun_hir::Function, FunctionValue<'ink>>,
         dispatch_table: &'t DispatchTable<'ink>,
@@ -73,6 +86,10 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         external_globals: ExternalGlobals<'ink>,
         hir_types: &'t HirTypeCache<'db, 'ink>,
         module_group: &'t ModuleGroup,
+        runtime_checks: bool,
+        overflow_checks: bool,
+        lto: bool,
+        debug_info: Option<&'t DebugInfo<'db, 'ink, 't>>,
     ) -> Self {
         let (hir_function, ir_function) = function;
 
@@ -85,9 +102,18 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let body_ir = context.append_basic_block(ir_function, "body");
         builder.position_at_end(body_ir);
 
+        // Attach the debug information of the function
+        let debug_info = debug_info.map(|debug_info| {
+            (
+                debug_info,
+                debug_info.gen_function(hir_function, ir_function),
+            )
+        });
+
         BodyIrGenerator {
             context,
             db,
+            module,
             body,
             infer,
             builder,
@@ -103,6 +129,12 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             external_globals,
             hir_types,
             module_group,
+            runtime_checks,
+            overflow_checks,
+            lto,
+            debug_info,
+            debug_location: None,
+            call_frame: None,
         }
     }
 
@@ -118,8 +150,18 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     let name = name.to_string();
                     let param = self.fn_value.get_nth_param(i as u32).unwrap();
                     let builder = self.new_alloca_builder();
+                    // Vectors are passed as integers, see `gen_to_argument`
+                    let param = match self.infer[*pat].as_vector() {
+                        Some(vector_ty) => builder.build_bitcast(
+                            param,
+                            self.hir_types.get_vector_type(vector_ty),
+                            "vector",
+                        ),
+                        None => param,
+                    };
                     let param_ptr = builder.build_alloca(param.get_type(), &name);
                     builder.build_store(param_ptr, param);
+                    self.gen_variable_debug_info(*pat, param_ptr, &name, Some(i as u32 + 1));
                     self.pat_to_local.insert(*pat, param_ptr);
                     self.pat_to_name.insert(*pat, name);
                 }
@@ -136,6 +178,11 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             }
         }
 
+        // Record the call frame to be able to report stack traces
+        if self.debug_info.is_some() {
+            self.gen_push_call_frame();
+        }
+
         // Generate code for the body of the function
         let ret_value = self.gen_expr(self.body.body_expr());
 
@@ -152,13 +199,149 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .clone();
         if !block_ret_type.is_never() {
             if fn_ret_type.is_empty() {
-                self.builder.build_return(None);
+                self.gen_return_value(None);
             } else if let Some(value) = ret_value {
-                self.builder.build_return(Some(&value));
+                self.gen_return_value(Some(value));
             }
         }
     }
 
+    /// Allocates the call frame record of the function and links it into the
+    /// call stack of the current thread. The runtime walks the call stack to
+    /// report a stack trace when a panic occurs.
+    fn gen_push_call_frame(&mut self) {
+        let ptr_type = self.context.i8_type().ptr_type(AddressSpace::default());
+        let i32_type = self.context.i32_type();
+        let frame_type = self.context.struct_type(
+            &[
+                ptr_type.into(),
+                ptr_type.into(),
+                ptr_type.into(),
+                i32_type.into(),
+                i32_type.into(),
+            ],
+            false,
+        );
+        let frame = self
+            .new_alloca_builder()
+            .build_alloca(frame_type, "call_frame");
+
+        let function_name = self
+            .builder
+            .build_global_string_ptr(&self.hir_function.full_name(self.db), "function_name");
+        let file_id = self.hir_function.file_id(self.db);
+        let file = self.builder.build_global_string_ptr(
+            self.db.file_relative_path(file_id).as_str(),
+            "function_file",
+        );
+        let frame_value = frame_type.const_named_struct(&[
+            ptr_type.const_null().into(),
+            function_name.as_pointer_value().into(),
+            file.as_pointer_value().into(),
+            i32_type.const_zero().into(),
+            i32_type.const_zero().into(),
+        ]);
+        self.builder.build_store(frame, frame_value);
+        self.call_frame = Some(frame);
+
+        let push_frame = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::push_frame,
+        );
+        let frame = self
+            .builder
+            .build_pointer_cast(frame, ptr_type, "call_frame_ptr");
+        self.builder.build_call(push_frame, &[frame.into()], "");
+    }
+
+    /// Stores the location of `expr` in the call frame record of the function,
+    /// if the function records its call frame. This is done before calling
+    /// other functions so the location of the call shows up in stack traces.
+    fn gen_call_frame_location(&mut self, expr: ExprId) {
+        let Some(frame) = self.call_frame else {
+            return;
+        };
+        let (line, column) = self.expr_line_col(expr);
+        let i32_type = self.context.i32_type();
+        for (index, value, name) in [(3, line, "frame_line"), (4, column, "frame_column")] {
+            let ptr = self
+                .builder
+                .build_struct_gep(frame, index, name)
+                .expect("call frame record has a location");
+            self.builder
+                .build_store(ptr, i32_type.const_int(u64::from(value), false));
+        }
+    }
+
+    /// Generates a return from the function with the specified `value`. If the
+    /// function records its call frame, it is unlinked from the call stack
+    /// first.
+    fn gen_return_value(&mut self, value: Option<BasicValueEnum<'ink>>) {
+        if let Some(frame) = self.call_frame {
+            let pop_frame = self.dispatch_table.gen_intrinsic_lookup(
+                self.external_globals.dispatch_table,
+                &self.builder,
+                &intrinsics::pop_frame,
+            );
+            let frame = self.builder.build_pointer_cast(
+                frame,
+                self.context.i8_type().ptr_type(AddressSpace::default()),
+                "call_frame_ptr",
+            );
+            self.builder.build_call(pop_frame, &[frame.into()], "");
+        }
+
+        match value {
+            Some(value) => {
+                let fn_sig = self.hir_function.ty(self.db).callable_sig(self.db).unwrap();
+                let value = self.gen_to_argument(fn_sig.ret(), value);
+                self.builder.build_return(Some(&value))
+            }
+            None => self.builder.build_return(None),
+        };
+    }
+
+    /// Converts `value` of type `ty` to the representation that is used to pass
+    /// it to, or return it from, a function. Vectors are passed as an integer
+    /// with the same size, all other values are passed as is.
+    fn gen_to_argument(&self, ty: &Ty, value: BasicValueEnum<'ink>) -> BasicValueEnum<'ink> {
+        match ty.interned() {
+            &TyKind::Vector(vector_ty) => self.builder.build_bitcast(
+                value,
+                self.hir_types.get_vector_abi_type(vector_ty),
+                "vector_bits",
+            ),
+            _ => value,
+        }
+    }
+
+    /// Converts a function argument or return `value` of type `ty` back to the
+    /// representation that is used in the body of a function. This is the
+    /// inverse of [`Self::gen_to_argument`].
+    fn gen_from_argument(&self, ty: &Ty, value: BasicValueEnum<'ink>) -> BasicValueEnum<'ink> {
+        match ty.interned() {
+            &TyKind::Vector(vector_ty) => self.builder.build_bitcast(
+                value,
+                self.hir_types.get_vector_type(vector_ty),
+                "vector",
+            ),
+            _ => value,
+        }
+    }
+
+    /// Returns the one-based line and column of the start of `expr`, or zeros
+    /// if the location of the expression is unknown.
+    fn expr_line_col(&self, expr: ExprId) -> (u32, u32) {
+        let file_id = self.hir_function.file_id(self.db);
+        self.hir_function
+            .expr_range(self.db, expr)
+            .map_or((0, 0), |range| {
+                let line_col = self.db.line_index(file_id).line_col(range.start());
+                (line_col.line + 1, line_col.col_utf16 + 1)
+            })
+    }
+
     pub fn gen_fn_wrapper(&mut self) {
         let fn_sig = self.hir_function.ty(self.db).callable_sig(self.db).unwrap();
         let args: Vec<BasicMetadataValueEnum<'_>> = fn_sig
@@ -215,6 +398,59 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates IR for the specified expression. Dependending on the type of
     /// expression an IR value is returned.
     fn gen_expr(&mut self, expr: ExprId) -> Option<inkwell::values::BasicValueEnum<'ink>> {
+        let parent_location = self.set_debug_location(expr);
+        let value = self.gen_expr_inner(expr);
+        if let Some(location) = parent_location {
+            self.builder.set_current_debug_location(location);
+            self.debug_location = Some(location);
+        }
+        value
+    }
+
+    /// Sets the debug location of the instructions that are generated next to
+    /// the location of `expr`. Returns the previous debug location.
+    fn set_debug_location(&mut self, expr: ExprId) -> Option<DILocation<'ink>> {
+        let (debug_info, scope) = self.debug_info?;
+        let range = self.hir_function.expr_range(self.db, expr)?;
+        let location =
+            debug_info.location(scope, self.hir_function.file_id(self.db), range.start());
+        self.builder.set_current_debug_location(location);
+        self.debug_location.replace(location)
+    }
+
+    /// Declares the debug information of the variable that is bound by `pat`
+    /// and stored in `storage`. Parameters are identified by their one-based
+    /// `arg_no`.
+    fn gen_variable_debug_info(
+        &self,
+        pat: PatId,
+        storage: PointerValue<'ink>,
+        name: &str,
+        arg_no: Option<u32>,
+    ) {
+        let Some((debug_info, scope)) = self.debug_info else {
+            return;
+        };
+        let Some(range) = self.hir_function.pat_range(self.db, pat) else {
+            return;
+        };
+        debug_info.declare_variable(
+            scope,
+            storage,
+            name,
+            &self.infer[pat],
+            arg_no,
+            self.hir_function.file_id(self.db),
+            range.start(),
+            self.builder
+                .get_insert_block()
+                .expect("the builder must be positioned in a block"),
+        );
+    }
+
+    /// Generates IR for the specified expression without changing the debug
+    /// location.
+    fn gen_expr_inner(&mut self, expr: ExprId) -> Option<BasicValueEnum<'ink>> {
         let body = self.body.clone();
         match &body[expr] {
             Expr::Block {
@@ -231,9 +467,14 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 self.gen_binary_op(expr, *lhs, *rhs, op.expect("missing op"))
             }
             Expr::UnaryOp { expr, op } => self.gen_unary_op(*expr, *op),
-            Expr::MethodCall { .. } => {
-                unimplemented!("Method calls are not yet implemented in the IR generator")
-            }
+            Expr::MethodCall {
+                receiver, ref args, ..
+            } => match self.infer.builtin_method_resolution(expr) {
+                Some(builtin) => Some(self.gen_builtin_method_call(expr, *receiver, args, builtin)),
+                None => {
+                    unimplemented!("Method calls are not yet implemented in the IR generator")
+                }
+            },
             Expr::Call {
                 ref callee,
                 ref args,
@@ -244,12 +485,17 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                         // Get all the arguments
                         let args: Vec<BasicMetadataValueEnum<'_>> = args
                             .iter()
-                            .map(|expr| self.gen_expr(*expr).expect("expected a value").into())
+                            .map(|expr| {
+                                let value = self.gen_expr(*expr).expect("expected a value");
+                                self.gen_to_argument(&self.infer[*expr], value).into()
+                            })
                             .collect();
 
+                        self.gen_call_frame_location(expr);
                         self.gen_call(def, &args)
                             .try_as_basic_value()
                             .left()
+                            .map(|value| self.gen_from_argument(&self.infer[expr], value))
                             // If the called function is a void function it doesn't return anything.
                             // If this method (`gen_expr`) returns None we assume the return value
                             // is `never`. We return a const unit struct here to ensure that at
@@ -265,6 +511,9 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     Some(mun_hir::CallableDef::Struct(_)) => {
                         Some(self.gen_named_tuple_lit(expr, args))
                     }
+                    Some(mun_hir::CallableDef::PrimitiveType(_)) => {
+                        Some(self.gen_vector_lit(expr, args))
+                    }
                     None => panic!("expected a callable expression"),
                 }
             }
@@ -282,6 +531,12 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 name,
             } => self.gen_field(expr, *receiver_expr, name),
             Expr::Array(exprs) => self.gen_array(expr, exprs).map(Into::into),
+            Expr::ArrayRepeat {
+                initializer,
+                repeat,
+            } => self
+                .gen_array_repeat(expr, *initializer, *repeat)
+                .map(Into::into),
             Expr::Index { base, index } => self.gen_index(expr, *base, *index),
             Expr::Missing => unimplemented!("unimplemented expr type {:?}", &body[expr]),
         }
@@ -471,6 +726,22 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         self.gen_struct_alloc(hir_struct, args)
     }
 
+    /// Generates IR for a vector literal, e.g. `f32x4(1.0, 2.0, 3.0, 4.0)`
+    fn gen_vector_lit(&mut self, type_expr: ExprId, args: &[ExprId]) -> BasicValueEnum<'ink> {
+        let vector_ty = self.infer[type_expr]
+            .as_vector()
+            .expect("expected a vector type");
+        let mut value = self.hir_types.get_vector_type(vector_ty).get_undef();
+        for (i, arg) in args.iter().enumerate() {
+            let lane = self.gen_expr(*arg).expect("expected a lane value");
+            let index = self.context.i32_type().const_int(i as u64, false);
+            value = self
+                .builder
+                .build_insert_element(value, lane, index, "init");
+        }
+        value.into()
+    }
+
     /// Generates IR for a unit struct literal, e.g `Foo`
     fn gen_unit_struct_lit(&mut self, type_expr: ExprId) -> BasicValueEnum<'ink> {
         let struct_ty = self.infer[type_expr].clone();
@@ -548,6 +819,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                     .get_basic_type(&pat_ty)
                     .expect("expected basic type");
                 let ptr = builder.build_alloca(ty, &name.to_string());
+                self.gen_variable_debug_info(pat, ptr, &name.to_string(), None);
                 self.pat_to_local.insert(pat, ptr);
                 self.pat_to_name.insert(pat, name.to_string());
                 if !(pat_ty.is_empty() || pat_ty.is_never()) {
@@ -586,7 +858,14 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 }
             }
             ValueNs::StructId(_) => self.gen_unit_struct_lit(expr),
-            ValueNs::FunctionId(_) => panic!("unable to generate path expression from a function"),
+            ValueNs::ConstId(_) => {
+                let value = mun_hir::eval_const_expr(self.db, self.body.owner(), expr)
+                    .expect("constants must be evaluated before code generation");
+                self.hir_types.get_const_value(&value, &self.infer[expr])
+            }
+            ValueNs::FunctionId(_) | ValueNs::PrimitiveType(_) => {
+                panic!("unable to generate path expression from a function")
+            }
         }
     }
 
@@ -624,7 +903,10 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
                 .pat_to_local
                 .get(&pat)
                 .expect("unresolved local binding"),
-            ValueNs::FunctionId(_) | ValueNs::StructId(_) => {
+            ValueNs::FunctionId(_)
+            | ValueNs::StructId(_)
+            | ValueNs::ConstId(_)
+            | ValueNs::PrimitiveType(_) => {
                 panic!("no support for module definitions")
             }
         }
@@ -633,7 +915,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates IR to calculate a binary operation between two expressions.
     fn gen_binary_op(
         &mut self,
-        _tgt_expr: ExprId,
+        tgt_expr: ExprId,
         lhs: ExprId,
         rhs: ExprId,
         op: BinaryOp,
@@ -642,7 +924,8 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         match lhs_type.interned() {
             TyKind::Bool => self.gen_binary_op_bool(lhs, rhs, op),
             TyKind::Float(_) => self.gen_binary_op_float(lhs, rhs, op),
-            TyKind::Int(ty) => self.gen_binary_op_int(lhs, rhs, op, ty.signedness),
+            TyKind::Int(ty) => self.gen_binary_op_int(tgt_expr, lhs, rhs, op, ty.signedness),
+            &TyKind::Vector(ty) => self.gen_binary_op_vector(tgt_expr, lhs, rhs, op, ty),
             TyKind::Struct(s) => {
                 if s.data(self.db).memory_kind == mun_hir::StructMemoryKind::Value {
                     self.gen_binary_op_value_struct(lhs, rhs, op)
@@ -668,10 +951,70 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             TyKind::Float(_) => self.gen_unary_op_float(expr, op),
             &TyKind::Int(int_ty) => self.gen_unary_op_int(expr, op, int_ty.signedness),
             TyKind::Bool => self.gen_unary_op_bool(expr, op),
+            &TyKind::Vector(vector_ty) => self.gen_unary_op_vector(expr, op, vector_ty),
             _ => unimplemented!("unimplemented operation op{0}", ty.display(self.db)),
         }
     }
 
+    /// Generates IR to calculate a unary operation on each lane of a vector.
+    fn gen_unary_op_vector(
+        &mut self,
+        expr: ExprId,
+        op: UnaryOp,
+        vector_ty: VectorTy,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let value = self.gen_expr(expr).expect("no value").into_vector_value();
+        match (op, vector_ty.element) {
+            (UnaryOp::Neg, ScalarTy::Float(_)) => {
+                Some(self.builder.build_float_neg(value, "neg").into())
+            }
+            (UnaryOp::Neg, ScalarTy::Int(int_ty)) => {
+                if self.overflow_checks && int_ty.signedness.is_signed() {
+                    Some(self.gen_overflow_checked_neg_vector(expr, value).into())
+                } else {
+                    Some(self.builder.build_int_neg(value, "neg").into())
+                }
+            }
+            (UnaryOp::Not, ScalarTy::Int(_)) => Some(self.builder.build_not(value, "not").into()),
+            (UnaryOp::Not, ScalarTy::Float(_)) => {
+                unimplemented!("Operator {:?} is not implemented for float vectors", op)
+            }
+        }
+    }
+
+    /// Generates IR to negate each lane of a signed integer vector. If the
+    /// negation of any lane overflows, a panic is reported at the location of
+    /// `expr`.
+    fn gen_overflow_checked_neg_vector(
+        &mut self,
+        expr: ExprId,
+        value: VectorValue<'ink>,
+    ) -> VectorValue<'ink> {
+        let zero = value.get_type().const_zero();
+        let result = self
+            .gen_llvm_intrinsic_call(
+                "llvm.ssub.with.overflow",
+                &[zero.into(), value.into()],
+                "with_overflow",
+            )
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(result, 0, "neg")
+            .expect("missing result")
+            .into_vector_value();
+        let overflow = self
+            .builder
+            .build_extract_value(result, 1, "overflow")
+            .expect("missing overflow flags");
+        let any_overflow = self
+            .gen_llvm_intrinsic_call("llvm.vector.reduce.or", &[overflow], "any_overflow")
+            .into_int_value();
+        let no_overflow = self.builder.build_not(any_overflow, "no_overflow");
+        self.gen_runtime_check(expr, no_overflow, "attempt to negate with overflow");
+        value
+    }
+
     /// Generates IR to calculate a unary operation on a floating point value.
     fn gen_unary_op_float(&mut self, expr: ExprId, op: UnaryOp) -> Option<BasicValueEnum<'ink>> {
         let value: FloatValue<'ink> = self
@@ -700,7 +1043,24 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         match op {
             UnaryOp::Neg => {
                 if signedness == mun_hir::Signedness::Signed {
-                    Some(self.builder.build_int_neg(value, "neg").into())
+                    if self.overflow_checks {
+                        let zero = value.get_type().const_zero();
+                        let (value, overflow) = self.gen_arith_with_overflow_int(
+                            zero,
+                            value,
+                            ArithOp::Subtract,
+                            signedness,
+                        );
+                        let no_overflow = self.builder.build_not(overflow, "no_overflow");
+                        self.gen_runtime_check(
+                            expr,
+                            no_overflow,
+                            "attempt to negate with overflow",
+                        );
+                        Some(value.into())
+                    } else {
+                        Some(self.builder.build_int_neg(value, "neg").into())
+                    }
                 } else {
                     unimplemented!("Operator {:?} is not implemented for unsigned integer", op)
                 }
@@ -822,6 +1182,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates IR to calculate a binary operation between two integer values.
     fn gen_binary_op_int(
         &mut self,
+        expr: ExprId,
         lhs_expr: ExprId,
         rhs_expr: ExprId,
         op: BinaryOp,
@@ -838,13 +1199,14 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             .expect("no rhs value")
             .into_int_value();
         match op {
-            BinaryOp::ArithOp(op) => {
-                Some(self.gen_arith_bin_op_int(lhs, rhs, op, signedness).into())
-            }
+            BinaryOp::ArithOp(op) => Some(
+                self.gen_arith_bin_op_int(expr, lhs, rhs, op, signedness)
+                    .into(),
+            ),
             BinaryOp::CmpOp(op) => Some(self.gen_cmp_bin_op_int(lhs, rhs, op, signedness).into()),
             BinaryOp::Assignment { op } => {
                 let rhs = match op {
-                    Some(op) => self.gen_arith_bin_op_int(lhs, rhs, op, signedness),
+                    Some(op) => self.gen_arith_bin_op_int(expr, lhs, rhs, op, signedness),
                     None => rhs,
                 };
                 let place = self.gen_place_expr(lhs_expr)?;
@@ -857,6 +1219,227 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates IR to calculate a binary operation between two vectors. The
+    /// operation is applied to each lane separately; integer lanes are checked
+    /// for overflow like integers are. Two vectors are equal if all their
+    /// lanes are equal.
+    fn gen_binary_op_vector(
+        &mut self,
+        expr: ExprId,
+        lhs_expr: ExprId,
+        rhs_expr: ExprId,
+        op: BinaryOp,
+        vector_ty: VectorTy,
+    ) -> Option<BasicValueEnum<'ink>> {
+        let lhs = self
+            .gen_expr(lhs_expr)
+            .expect("no lhs value")
+            .into_vector_value();
+        let rhs = self
+            .gen_expr(rhs_expr)
+            .expect("no rhs value")
+            .into_vector_value();
+        match op {
+            BinaryOp::ArithOp(op) => Some(
+                self.gen_arith_bin_op_vector(expr, lhs, rhs, op, vector_ty)
+                    .into(),
+            ),
+            BinaryOp::CmpOp(CmpOp::Eq { negated }) => Some(
+                self.gen_eq_bin_op_vector(lhs, rhs, negated, vector_ty)
+                    .into(),
+            ),
+            BinaryOp::Assignment { op } => {
+                let rhs = match op {
+                    Some(op) => self.gen_arith_bin_op_vector(expr, lhs, rhs, op, vector_ty),
+                    None => rhs,
+                };
+                let place = self.gen_place_expr(lhs_expr)?;
+                self.builder.build_store(place, rhs);
+                Some(self.gen_empty())
+            }
+            BinaryOp::CmpOp(CmpOp::Ord { .. }) | BinaryOp::LogicOp(_) => {
+                unreachable!("Operator {:?} is not implemented for vectors", op)
+            }
+        }
+    }
+
+    /// Generates IR that compares two vectors for equality. The vectors are
+    /// equal if all their lanes are equal, and unequal if any lane differs.
+    fn gen_eq_bin_op_vector(
+        &mut self,
+        lhs: VectorValue<'ink>,
+        rhs: VectorValue<'ink>,
+        negated: bool,
+        vector_ty: VectorTy,
+    ) -> IntValue<'ink> {
+        let lanes = match (vector_ty.element, negated) {
+            (ScalarTy::Float(_), false) => {
+                self.builder
+                    .build_float_compare(FloatPredicate::OEQ, lhs, rhs, "eq_lanes")
+            }
+            (ScalarTy::Float(_), true) => {
+                self.builder
+                    .build_float_compare(FloatPredicate::ONE, lhs, rhs, "neq_lanes")
+            }
+            (ScalarTy::Int(_), false) => {
+                self.builder
+                    .build_int_compare(IntPredicate::EQ, lhs, rhs, "eq_lanes")
+            }
+            (ScalarTy::Int(_), true) => {
+                self.builder
+                    .build_int_compare(IntPredicate::NE, lhs, rhs, "neq_lanes")
+            }
+        };
+        if negated {
+            self.gen_llvm_intrinsic_call("llvm.vector.reduce.or", &[lanes.into()], "neq")
+                .into_int_value()
+        } else {
+            self.gen_llvm_intrinsic_call("llvm.vector.reduce.and", &[lanes.into()], "eq")
+                .into_int_value()
+        }
+    }
+
+    /// Generates a check that panics with the specified `message` if any lane
+    /// of `condition` is true.
+    fn gen_vector_runtime_check(
+        &mut self,
+        expr: ExprId,
+        condition: VectorValue<'ink>,
+        name: &str,
+        message: &str,
+    ) {
+        let any = self
+            .gen_llvm_intrinsic_call("llvm.vector.reduce.or", &[condition.into()], name)
+            .into_int_value();
+        let none = self.builder.build_not(any, "check");
+        self.gen_runtime_check(expr, none, message);
+    }
+
+    fn gen_arith_bin_op_vector(
+        &mut self,
+        expr: ExprId,
+        lhs: VectorValue<'ink>,
+        rhs: VectorValue<'ink>,
+        op: ArithOp,
+        vector_ty: VectorTy,
+    ) -> VectorValue<'ink> {
+        // Division is checked per lane, like the division of two integers
+        if let ScalarTy::Int(int_ty) = vector_ty.element {
+            if self.runtime_checks && matches!(op, ArithOp::Divide | ArithOp::Remainder) {
+                let is_zero = self.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    rhs,
+                    rhs.get_type().const_zero(),
+                    "is_zero",
+                );
+                let message = if op == ArithOp::Divide {
+                    "attempt to divide by zero"
+                } else {
+                    "attempt to calculate the remainder with a divisor of zero"
+                };
+                self.gen_vector_runtime_check(expr, is_zero, "any_zero", message);
+            }
+
+            if (self.runtime_checks || self.overflow_checks)
+                && int_ty.signedness.is_signed()
+                && matches!(op, ArithOp::Divide | ArithOp::Remainder)
+            {
+                let ty = lhs.get_type();
+                let element_ty = ty.get_element_type().into_int_type();
+                let min = element_ty
+                    .const_all_ones()
+                    .const_rshr(element_ty.const_int(1, false))
+                    .const_not();
+                let min = VectorType::const_vector(&vec![min; ty.get_size() as usize]);
+                let minus_one = VectorType::const_vector(&vec![
+                    element_ty.const_all_ones();
+                    ty.get_size() as usize
+                ]);
+                let is_min = self
+                    .builder
+                    .build_int_compare(IntPredicate::EQ, lhs, min, "is_min");
+                let is_minus_one = self.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    rhs,
+                    minus_one,
+                    "is_minus_one",
+                );
+                let overflow = self.builder.build_and(is_min, is_minus_one, "overflow");
+                let message = if op == ArithOp::Divide {
+                    "attempt to divide with overflow"
+                } else {
+                    "attempt to calculate the remainder with overflow"
+                };
+                self.gen_vector_runtime_check(expr, overflow, "any_overflow", message);
+            }
+
+            if self.overflow_checks {
+                match op {
+                    ArithOp::Add | ArithOp::Subtract | ArithOp::Multiply => {
+                        return self.gen_overflow_checked_arith_vector(
+                            expr,
+                            lhs,
+                            rhs,
+                            op,
+                            int_ty.signedness,
+                        );
+                    }
+                    ArithOp::LeftShift => self.gen_vector_shift_amount_check(
+                        expr,
+                        rhs,
+                        "attempt to shift left with overflow",
+                    ),
+                    ArithOp::RightShift => self.gen_vector_shift_amount_check(
+                        expr,
+                        rhs,
+                        "attempt to shift right with overflow",
+                    ),
+                    _ => (),
+                }
+            }
+        }
+
+        match vector_ty.element {
+            ScalarTy::Float(_) => match op {
+                ArithOp::Add => self.builder.build_float_add(lhs, rhs, "add"),
+                ArithOp::Subtract => self.builder.build_float_sub(lhs, rhs, "sub"),
+                ArithOp::Divide => self.builder.build_float_div(lhs, rhs, "div"),
+                ArithOp::Multiply => self.builder.build_float_mul(lhs, rhs, "mul"),
+                ArithOp::Remainder => self.builder.build_float_rem(lhs, rhs, "rem"),
+                ArithOp::LeftShift
+                | ArithOp::RightShift
+                | ArithOp::BitAnd
+                | ArithOp::BitOr
+                | ArithOp::BitXor => {
+                    unreachable!("Operator {:?} is not implemented for float vectors", op)
+                }
+            },
+            ScalarTy::Int(int_ty) => match op {
+                ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
+                ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
+                ArithOp::Multiply => self.builder.build_int_mul(lhs, rhs, "mul"),
+                ArithOp::LeftShift => self.builder.build_left_shift(lhs, rhs, "left_shift"),
+                ArithOp::RightShift => self.builder.build_right_shift(
+                    lhs,
+                    rhs,
+                    int_ty.signedness.is_signed(),
+                    "right_shift",
+                ),
+                ArithOp::BitAnd => self.builder.build_and(lhs, rhs, "bit_and"),
+                ArithOp::BitOr => self.builder.build_or(lhs, rhs, "bit_or"),
+                ArithOp::BitXor => self.builder.build_xor(lhs, rhs, "bit_xor"),
+                ArithOp::Divide if int_ty.signedness.is_signed() => {
+                    self.builder.build_int_signed_div(lhs, rhs, "div")
+                }
+                ArithOp::Divide => self.builder.build_int_unsigned_div(lhs, rhs, "div"),
+                ArithOp::Remainder if int_ty.signedness.is_signed() => {
+                    self.builder.build_int_signed_rem(lhs, rhs, "rem")
+                }
+                ArithOp::Remainder => self.builder.build_int_unsigned_rem(lhs, rhs, "rem"),
+            },
+        }
+    }
+
     /// Generates IR to calculate a binary operation between two heap struct
     /// values (e.g. a Mun `struct(gc)`).
     fn gen_binary_op_heap_struct(
@@ -989,29 +1572,87 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
     fn gen_arith_bin_op_int(
         &mut self,
+        expr: ExprId,
         lhs: IntValue<'ink>,
         rhs: IntValue<'ink>,
         op: ArithOp,
         signedness: mun_hir::Signedness,
     ) -> IntValue<'ink> {
+        if self.runtime_checks && matches!(op, ArithOp::Divide | ArithOp::Remainder) {
+            let is_nonzero = self.builder.build_int_compare(
+                IntPredicate::NE,
+                rhs,
+                rhs.get_type().const_zero(),
+                "is_nonzero",
+            );
+            let message = if op == ArithOp::Divide {
+                "attempt to divide by zero"
+            } else {
+                "attempt to calculate the remainder with a divisor of zero"
+            };
+            self.gen_runtime_check(expr, is_nonzero, message);
+        }
+
+        // Dividing the minimum value of a signed type by -1 overflows, which LLVM
+        // treats as undefined behavior.
+        if (self.runtime_checks || self.overflow_checks)
+            && signedness.is_signed()
+            && matches!(op, ArithOp::Divide | ArithOp::Remainder)
+        {
+            let ty = lhs.get_type();
+            let min = ty
+                .const_all_ones()
+                .const_rshr(ty.const_int(1, false))
+                .const_not();
+            let is_min = self
+                .builder
+                .build_int_compare(IntPredicate::EQ, lhs, min, "is_min");
+            let is_minus_one = self.builder.build_int_compare(
+                IntPredicate::EQ,
+                rhs,
+                ty.const_all_ones(),
+                "is_minus_one",
+            );
+            let overflow = self.builder.build_and(is_min, is_minus_one, "overflow");
+            let no_overflow = self.builder.build_not(overflow, "no_overflow");
+            let message = if op == ArithOp::Divide {
+                "attempt to divide with overflow"
+            } else {
+                "attempt to calculate the remainder with overflow"
+            };
+            self.gen_runtime_check(expr, no_overflow, message);
+        }
+
         match op {
-            ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
-            ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
+            ArithOp::Add | ArithOp::Subtract | ArithOp::Multiply => {
+                if self.overflow_checks {
+                    self.gen_overflow_checked_arith_int(expr, lhs, rhs, op, signedness)
+                } else {
+                    self.gen_wrapping_arith_int(lhs, rhs, op)
+                }
+            }
             ArithOp::Divide => match signedness {
                 mun_hir::Signedness::Signed => self.builder.build_int_signed_div(lhs, rhs, "div"),
                 mun_hir::Signedness::Unsigned => {
                     self.builder.build_int_unsigned_div(lhs, rhs, "div")
                 }
             },
-            ArithOp::Multiply => self.builder.build_int_mul(lhs, rhs, "mul"),
             ArithOp::Remainder => match signedness {
                 mun_hir::Signedness::Signed => self.builder.build_int_signed_rem(lhs, rhs, "rem"),
                 mun_hir::Signedness::Unsigned => {
                     self.builder.build_int_unsigned_rem(lhs, rhs, "rem")
                 }
             },
-            ArithOp::LeftShift => self.builder.build_left_shift(lhs, rhs, "left_shift"),
+            ArithOp::LeftShift => {
+                if self.overflow_checks {
+                    self.gen_shift_amount_check(expr, rhs, "attempt to shift left with overflow");
+                }
+                self.builder.build_left_shift(lhs, rhs, "left_shift")
+            }
             ArithOp::RightShift => {
+                if self.overflow_checks {
+                    self.gen_shift_amount_check(expr, rhs, "attempt to shift right with overflow");
+                }
                 self.builder
                     .build_right_shift(lhs, rhs, signedness.is_signed(), "right_shift")
             }
@@ -1021,6 +1662,243 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         }
     }
 
+    /// Generates a check that panics with the specified `message` if the shift
+    /// amount `rhs` is not less than the bit width of its type. Negative
+    /// amounts are treated as large unsigned amounts.
+    fn gen_shift_amount_check(&mut self, expr: ExprId, rhs: IntValue<'ink>, message: &str) {
+        let ty = rhs.get_type();
+        let in_range = self.builder.build_int_compare(
+            IntPredicate::ULT,
+            rhs,
+            ty.const_int(u64::from(ty.get_bit_width()), false),
+            "in_range",
+        );
+        self.gen_runtime_check(expr, in_range, message);
+    }
+
+    /// Generates a check that panics with the specified `message` if the shift
+    /// amount in any lane of `rhs` is not less than the bit width of a lane.
+    fn gen_vector_shift_amount_check(
+        &mut self,
+        expr: ExprId,
+        rhs: VectorValue<'ink>,
+        message: &str,
+    ) {
+        let ty = rhs.get_type();
+        let element_ty = ty.get_element_type().into_int_type();
+        let bit_width = element_ty.const_int(u64::from(element_ty.get_bit_width()), false);
+        let bit_width = VectorType::const_vector(&vec![bit_width; ty.get_size() as usize]);
+        let out_of_range =
+            self.builder
+                .build_int_compare(IntPredicate::UGE, rhs, bit_width, "out_of_range");
+        self.gen_vector_runtime_check(expr, out_of_range, "any_out_of_range", message);
+    }
+
+    /// Generates IR to add, subtract, or multiply two integers. The result
+    /// wraps around at the boundary of the type.
+    fn gen_wrapping_arith_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+    ) -> IntValue<'ink> {
+        match op {
+            ArithOp::Add => self.builder.build_int_add(lhs, rhs, "add"),
+            ArithOp::Subtract => self.builder.build_int_sub(lhs, rhs, "sub"),
+            ArithOp::Multiply => self.builder.build_int_mul(lhs, rhs, "mul"),
+            _ => unreachable!("operator {:?} cannot overflow", op),
+        }
+    }
+
+    /// Generates IR to add, subtract, or multiply two integers using one of the
+    /// LLVM `*.with.overflow` intrinsics. Returns the wrapped result and a flag
+    /// that indicates whether the operation overflowed.
+    fn gen_arith_with_overflow_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+    ) -> (IntValue<'ink>, IntValue<'ink>) {
+        let (intrinsic, name) = arith_with_overflow_intrinsic(op, signedness);
+        let result = self
+            .gen_llvm_intrinsic_call(intrinsic, &[lhs.into(), rhs.into()], "with_overflow")
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(result, 0, name)
+            .expect("missing result")
+            .into_int_value();
+        let overflow = self
+            .builder
+            .build_extract_value(result, 1, "overflow")
+            .expect("missing overflow flag")
+            .into_int_value();
+        (value, overflow)
+    }
+
+    /// Generates IR to add, subtract, or multiply two integers. If the
+    /// operation overflows, a panic is reported at the location of `expr`.
+    fn gen_overflow_checked_arith_int(
+        &mut self,
+        expr: ExprId,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+    ) -> IntValue<'ink> {
+        let (value, overflow) = self.gen_arith_with_overflow_int(lhs, rhs, op, signedness);
+        let no_overflow = self.builder.build_not(overflow, "no_overflow");
+        let message = match op {
+            ArithOp::Add => "attempt to add with overflow",
+            ArithOp::Subtract => "attempt to subtract with overflow",
+            _ => "attempt to multiply with overflow",
+        };
+        self.gen_runtime_check(expr, no_overflow, message);
+        value
+    }
+
+    /// Generates IR to add, subtract, or multiply each lane of two integer
+    /// vectors. If the operation overflows in any lane, a panic is reported at
+    /// the location of `expr`.
+    fn gen_overflow_checked_arith_vector(
+        &mut self,
+        expr: ExprId,
+        lhs: VectorValue<'ink>,
+        rhs: VectorValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+    ) -> VectorValue<'ink> {
+        let (intrinsic, name) = arith_with_overflow_intrinsic(op, signedness);
+        let result = self
+            .gen_llvm_intrinsic_call(intrinsic, &[lhs.into(), rhs.into()], "with_overflow")
+            .into_struct_value();
+        let value = self
+            .builder
+            .build_extract_value(result, 0, name)
+            .expect("missing result")
+            .into_vector_value();
+        let overflow = self
+            .builder
+            .build_extract_value(result, 1, "overflow")
+            .expect("missing overflow flags")
+            .into_vector_value();
+        let message = match op {
+            ArithOp::Add => "attempt to add with overflow",
+            ArithOp::Subtract => "attempt to subtract with overflow",
+            _ => "attempt to multiply with overflow",
+        };
+        self.gen_vector_runtime_check(expr, overflow, "any_overflow", message);
+        value
+    }
+
+    /// Generates IR to add, subtract, or multiply two integers. If the
+    /// operation overflows, the result is clamped to the numeric bounds of the
+    /// type.
+    fn gen_saturating_arith_int(
+        &mut self,
+        lhs: IntValue<'ink>,
+        rhs: IntValue<'ink>,
+        op: ArithOp,
+        signedness: mun_hir::Signedness,
+    ) -> IntValue<'ink> {
+        let intrinsic = match (op, signedness) {
+            (ArithOp::Add, mun_hir::Signedness::Signed) => "llvm.sadd.sat",
+            (ArithOp::Add, mun_hir::Signedness::Unsigned) => "llvm.uadd.sat",
+            (ArithOp::Subtract, mun_hir::Signedness::Signed) => "llvm.ssub.sat",
+            (ArithOp::Subtract, mun_hir::Signedness::Unsigned) => "llvm.usub.sat",
+            (ArithOp::Multiply, _) => {
+                // LLVM has no intrinsic for saturating multiplication, so the bound is
+                // selected based on the overflow flag.
+                let (value, overflow) = self.gen_arith_with_overflow_int(lhs, rhs, op, signedness);
+                let ty = value.get_type();
+                let bound = if signedness.is_signed() {
+                    // The result of a signed multiplication is negative if the signs of
+                    // the operands differ.
+                    let max = ty.const_all_ones().const_rshr(ty.const_int(1, false));
+                    let min = max.const_not();
+                    let signs_differ = self.builder.build_xor(lhs, rhs, "signs");
+                    let is_negative = self.builder.build_int_compare(
+                        IntPredicate::SLT,
+                        signs_differ,
+                        ty.const_zero(),
+                        "is_negative",
+                    );
+                    self.builder
+                        .build_select(is_negative, min, max, "bound")
+                        .into_int_value()
+                } else {
+                    ty.const_all_ones()
+                };
+                return self
+                    .builder
+                    .build_select(overflow, bound, value, "mul")
+                    .into_int_value();
+            }
+            _ => unreachable!("operator {:?} cannot overflow", op),
+        };
+
+        self.gen_llvm_intrinsic_call(intrinsic, &[lhs.into(), rhs.into()], "saturating")
+            .into_int_value()
+    }
+
+    /// Generates a call to the LLVM intrinsic with the specified name,
+    /// overloaded for the type of the first operand.
+    fn gen_llvm_intrinsic_call(
+        &mut self,
+        intrinsic: &str,
+        args: &[BasicValueEnum<'ink>],
+        name: &str,
+    ) -> BasicValueEnum<'ink> {
+        let function = LlvmIntrinsic::find(intrinsic)
+            .and_then(|intrinsic| intrinsic.get_declaration(self.module, &[args[0].get_type()]))
+            .unwrap_or_else(|| panic!("unknown LLVM intrinsic `{intrinsic}`"));
+        let args = args.iter().map(|&arg| arg.into()).collect::<Vec<_>>();
+        self.builder
+            .build_call(function, &args, name)
+            .try_as_basic_value()
+            .left()
+            .expect("LLVM intrinsic must return a value")
+    }
+
+    /// Generates IR for a call to a builtin method.
+    fn gen_builtin_method_call(
+        &mut self,
+        expr: ExprId,
+        receiver: ExprId,
+        args: &[ExprId],
+        builtin: BuiltinMethod,
+    ) -> BasicValueEnum<'ink> {
+        match builtin {
+            BuiltinMethod::IntArith { op, mode } => {
+                let TyKind::Int(ty) = self.infer[receiver].interned() else {
+                    unreachable!("integer method called on a non-integer type")
+                };
+                let signedness = ty.signedness;
+                let lhs = self
+                    .gen_expr(receiver)
+                    .map(|value| self.opt_deref_value(receiver, value))
+                    .expect("no receiver value")
+                    .into_int_value();
+                let rhs = self
+                    .gen_expr(args[0])
+                    .map(|value| self.opt_deref_value(args[0], value))
+                    .expect("no argument value")
+                    .into_int_value();
+                match mode {
+                    OverflowMode::Wrapping => self.gen_wrapping_arith_int(lhs, rhs, op),
+                    OverflowMode::Checked => {
+                        self.gen_overflow_checked_arith_int(expr, lhs, rhs, op, signedness)
+                    }
+                    OverflowMode::Saturating => {
+                        self.gen_saturating_arith_int(lhs, rhs, op, signedness)
+                    }
+                }
+                .into()
+            }
+        }
+    }
+
     fn gen_arith_bin_op_float(
         &mut self,
         lhs: FloatValue<'ink>,
@@ -1090,7 +1968,8 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// the dispatch table; if false is returned the function should be
     /// called directly.
     fn should_use_dispatch_table(&self, function: mun_hir::Function) -> bool {
-        self.module_group.should_runtime_link_fn(self.db, function)
+        self.module_group
+            .should_runtime_link_fn(self.db, function, self.lto)
     }
 
     /// Generates IR for a function call.
@@ -1210,11 +2089,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let ret_value = ret_expr.and_then(|expr| self.gen_expr(expr));
 
         // Construct a return statement from the returned value of the body
-        if let Some(value) = ret_value {
-            self.builder.build_return(Some(&value));
-        } else {
-            self.builder.build_return(None);
-        }
+        self.gen_return_value(ret_value);
 
         None
     }
@@ -1375,6 +2250,20 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         receiver_expr: ExprId,
         name: &Name,
     ) -> Option<BasicValueEnum<'ink>> {
+        if let Some(vector_ty) = self.infer[receiver_expr].as_vector() {
+            let lane_idx = vector_ty.lane_index(name).expect("expected a vector lane");
+            let receiver_value = self.gen_expr(receiver_expr)?.into_vector_value();
+            let index = self
+                .context
+                .i32_type()
+                .const_int(u64::from(lane_idx), false);
+            return Some(self.builder.build_extract_element(
+                receiver_value,
+                index,
+                &format!("{vector_ty}.{name}"),
+            ));
+        }
+
         let hir_struct = self.infer[receiver_expr]
             .as_struct()
             .expect("expected a struct");
@@ -1428,6 +2317,35 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         receiver_expr: ExprId,
         name: &Name,
     ) -> Option<PointerValue<'ink>> {
+        if let Some(vector_ty) = self.infer[receiver_expr].as_vector() {
+            // The lanes of a vector are laid out in memory like an array
+            let lane_idx = vector_ty.lane_index(name).expect("expected a vector lane");
+            let receiver_ptr = self.gen_place_expr(receiver_expr)?;
+            let element_ty = self
+                .hir_types
+                .get_basic_type(&vector_ty.element_ty())
+                .expect("expected a basic lane type");
+            let lanes_ptr = self
+                .builder
+                .build_bitcast(
+                    receiver_ptr,
+                    element_ty.ptr_type(AddressSpace::default()),
+                    "lanes",
+                )
+                .into_pointer_value();
+            let index = self
+                .context
+                .i32_type()
+                .const_int(u64::from(lane_idx), false);
+            return Some(unsafe {
+                self.builder.build_in_bounds_gep(
+                    lanes_ptr,
+                    &[index],
+                    &format!("{vector_ty}->{name}"),
+                )
+            });
+        }
+
         let hir_struct = self.infer[receiver_expr]
             .as_struct()
             .expect("expected a struct");
@@ -1461,6 +2379,96 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates code to construct an array literal at runtime. Returns `None`
     /// if the code generation for the array literal never returns.
     fn gen_array(&mut self, expr: ExprId, exprs: &[ExprId]) -> Option<RuntimeArrayValue<'ink>> {
+        let array = self.gen_array_alloc(expr, exprs.len() as u64);
+        let array_elements = array.get_elements(&self.builder);
+        for (idx, expr) in exprs.iter().enumerate() {
+            let element_ptr = unsafe {
+                self.builder.build_gep(
+                    array_elements,
+                    &[self.context.i64_type().const_int(idx as u64, false)],
+                    &format!("{}[{}]", array_elements.get_name().to_string_lossy(), idx),
+                )
+            };
+
+            let expr_value = self.gen_expr(*expr)?;
+            self.builder.build_store(element_ptr, expr_value);
+        }
+
+        // Once all values have been stored in the array, update the length of the array
+        let length = array.length_ty().const_int(exprs.len() as u64, false);
+        let array_length_ptr = array.get_length_ptr(&self.builder);
+        self.builder.build_store(array_length_ptr, length);
+
+        Some(array)
+    }
+
+    /// Generates an array that contains `repeat` copies of the initializer,
+    /// e.g. `[0; 4]`. The length was evaluated at compile time and the
+    /// initializer is guaranteed to be a value type, so it is only evaluated
+    /// once.
+    fn gen_array_repeat(
+        &mut self,
+        expr: ExprId,
+        initializer: ExprId,
+        repeat: ExprId,
+    ) -> Option<RuntimeArrayValue<'ink>> {
+        let length = match mun_hir::eval_const_expr(self.db, self.body.owner(), repeat) {
+            Ok(ConstValue::Int(length)) => length as u64,
+            _ => unreachable!("the length of an array must be evaluated before code generation"),
+        };
+
+        let value = self.gen_expr(initializer)?;
+        let array = self.gen_array_alloc(expr, length);
+        let array_elements = array.get_elements(&self.builder);
+
+        // Store the value in every element of the array with a loop
+        if length > 0 {
+            let index_ty = self.context.i64_type();
+            let entry_block = self
+                .builder
+                .get_insert_block()
+                .expect("the builder must be positioned in a block");
+            let loop_block = self.context.append_basic_block(self.fn_value, "repeat");
+            let exit_block = self
+                .context
+                .append_basic_block(self.fn_value, "afterrepeat");
+            self.builder.build_unconditional_branch(loop_block);
+
+            self.builder.position_at_end(loop_block);
+            let index = self.builder.build_phi(index_ty, "index");
+            index.add_incoming(&[(&index_ty.const_zero(), entry_block)]);
+            let index_value = index.as_basic_value().into_int_value();
+            let element_ptr = unsafe {
+                self.builder
+                    .build_gep(array_elements, &[index_value], "element")
+            };
+            self.builder.build_store(element_ptr, value);
+
+            let next_index =
+                self.builder
+                    .build_int_add(index_value, index_ty.const_int(1, false), "next_index");
+            index.add_incoming(&[(&next_index, loop_block)]);
+            let condition = self.builder.build_int_compare(
+                IntPredicate::ULT,
+                next_index,
+                index_ty.const_int(length, false),
+                "repeat_cond",
+            );
+            self.builder
+                .build_conditional_branch(condition, loop_block, exit_block);
+            self.builder.position_at_end(exit_block);
+        }
+
+        let length = array.length_ty().const_int(length, false);
+        let array_length_ptr = array.get_length_ptr(&self.builder);
+        self.builder.build_store(array_length_ptr, length);
+
+        Some(array)
+    }
+
+    /// Allocates a new array with the specified capacity for the array
+    /// expression. The length of the array is not set.
+    fn gen_array_alloc(&mut self, expr: ExprId, capacity: u64) -> RuntimeArrayValue<'ink> {
         let array_ty = &self.infer[expr];
         let element_ty = array_ty
             .as_array()
@@ -1489,10 +2497,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
 
         let allocator_handle = self.get_allocator_handle_ptr();
 
-        let length_value = self
-            .hir_types
-            .get_usize_type()
-            .const_int(exprs.len() as u64, false);
+        let length_value = self.hir_types.get_usize_type().const_int(capacity, false);
 
         // An object pointer adds an extra layer of indirection to allow for hot
         // reloading. To make it struct type agnostic, it is stored in a `*const
@@ -1526,28 +2531,8 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
             )
             .into_pointer_value();
 
-        let array = RuntimeArrayValue::from_ptr(array_ptr, array_ty)
-            .expect("unable to convert pointer to typed reference");
-        let array_elements = array.get_elements(&self.builder);
-        for (idx, expr) in exprs.iter().enumerate() {
-            let element_ptr = unsafe {
-                self.builder.build_gep(
-                    array_elements,
-                    &[self.context.i64_type().const_int(idx as u64, false)],
-                    &format!("{}[{}]", array_elements.get_name().to_string_lossy(), idx),
-                )
-            };
-
-            let expr_value = self.gen_expr(*expr)?;
-            self.builder.build_store(element_ptr, expr_value);
-        }
-
-        // Once all values have been stored in the array, update the length of the array
-        let length = array.length_ty().const_int(exprs.len() as u64, false);
-        let array_length_ptr = array.get_length_ptr(&self.builder);
-        self.builder.build_store(array_length_ptr, length);
-
-        Some(array)
+        RuntimeArrayValue::from_ptr(array_ptr, array_ty)
+            .expect("unable to convert pointer to typed reference")
     }
 
     /// Generates an index into an array
@@ -1564,7 +2549,7 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
     /// Generates an index into an array
     fn gen_place_index(
         &mut self,
-        _expr: ExprId,
+        expr: ExprId,
         base: ExprId,
         index: ExprId,
     ) -> Option<PointerValue<'ink>> {
@@ -1573,8 +2558,17 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         let base = unsafe {
             RuntimeArrayValue::from_ptr_unchecked(self.gen_expr(base)?.into_pointer_value())
         };
+        let index_signedness = match self.infer[index].interned() {
+            TyKind::Int(int_ty) => int_ty.signedness,
+            _ => mun_hir::Signedness::Unsigned,
+        };
         let index = self.gen_expr(index)?.into_int_value();
 
+        if self.runtime_checks {
+            let in_bounds = self.gen_in_bounds(base, index, index_signedness);
+            self.gen_runtime_check(expr, in_bounds, "index out of bounds");
+        }
+
         let elements = base.get_elements(&self.builder);
         Some(unsafe {
             self.builder.build_gep(
@@ -1585,6 +2579,91 @@ impl<'db, 'ink, 't> BodyIrGenerator<'db, 'ink, 't> {
         })
     }
 
+    /// Generates code that returns true if `index` is smaller than the length
+    /// of `array`. Negative indices are always out of bounds.
+    fn gen_in_bounds(
+        &self,
+        array: RuntimeArrayValue<'ink>,
+        index: IntValue<'ink>,
+        index_signedness: mun_hir::Signedness,
+    ) -> IntValue<'ink> {
+        let length = array.get_length(&self.builder);
+        let length_ty = length.get_type();
+        let index_ty = index.get_type();
+
+        // Compare both values as unsigned integers of the widest type. A negative index
+        // is sign extended, which makes it larger than any valid length.
+        let (index, length) = if index_ty.get_bit_width() > length_ty.get_bit_width() {
+            let length = self
+                .builder
+                .build_int_z_extend(length, index_ty, "length_ext");
+            (index, length)
+        } else {
+            let index = if index_signedness.is_signed() {
+                self.builder
+                    .build_int_s_extend_or_bit_cast(index, length_ty, "index_ext")
+            } else {
+                self.builder
+                    .build_int_z_extend_or_bit_cast(index, length_ty, "index_ext")
+            };
+            (index, length)
+        };
+
+        self.builder
+            .build_int_compare(IntPredicate::ULT, index, length, "in_bounds")
+    }
+
+    /// Generates a check that panics with the specified `message` if
+    /// `condition` is false. The location of `expr` is reported as the cause
+    /// of the panic.
+    fn gen_runtime_check(&mut self, expr: ExprId, condition: IntValue<'ink>, message: &str) {
+        let panic_block = self.context.append_basic_block(self.fn_value, "panic");
+        let continue_block = self.context.append_basic_block(self.fn_value, "check_ok");
+        self.builder
+            .build_conditional_branch(condition, continue_block, panic_block);
+
+        self.builder.position_at_end(panic_block);
+        self.gen_panic(expr, message);
+
+        self.builder.position_at_end(continue_block);
+    }
+
+    /// Generates a call to the `panic` intrinsic, which unwinds the stack up to
+    /// the host. The location of `expr` is reported as the cause of the panic.
+    fn gen_panic(&mut self, expr: ExprId, message: &str) {
+        let panic_fn = self.dispatch_table.gen_intrinsic_lookup(
+            self.external_globals.dispatch_table,
+            &self.builder,
+            &intrinsics::panic,
+        );
+
+        self.gen_call_frame_location(expr);
+        let (line, _) = self.expr_line_col(expr);
+        let file = self
+            .db
+            .file_relative_path(self.hir_function.file_id(self.db));
+
+        let message = self
+            .builder
+            .build_global_string_ptr(message, "panic_message");
+        let file = self
+            .builder
+            .build_global_string_ptr(file.as_str(), "panic_file");
+        self.builder.build_call(
+            panic_fn,
+            &[
+                message.as_pointer_value().into(),
+                file.as_pointer_value().into(),
+                self.context
+                    .i32_type()
+                    .const_int(u64::from(line), false)
+                    .into(),
+            ],
+            "",
+        );
+        self.builder.build_unreachable();
+    }
+
     /// Returns a pointer to the allocator handle
     fn get_allocator_handle_ptr(&self) -> PointerValue<'ink> {
         self.builder
@@ -1612,3 +2691,21 @@ fn deref_heap_value<'ink>(
 
     builder.build_load(mem_ptr, "deref")
 }
+
+/// Returns the LLVM `*.with.overflow` intrinsic that adds, subtracts, or
+/// multiplies integers of the specified signedness, together with the name of
+/// its result.
+fn arith_with_overflow_intrinsic(
+    op: ArithOp,
+    signedness: mun_hir::Signedness,
+) -> (&'static str, &'static str) {
+    match (op, signedness) {
+        (ArithOp::Add, mun_hir::Signedness::Signed) => ("llvm.sadd.with.overflow", "add"),
+        (ArithOp::Add, mun_hir::Signedness::Unsigned) => ("llvm.uadd.with.overflow", "add"),
+        (ArithOp::Subtract, mun_hir::Signedness::Signed) => ("llvm.ssub.with.overflow", "sub"),
+        (ArithOp::Subtract, mun_hir::Signedness::Unsigned) => ("llvm.usub.with.overflow", "sub"),
+        (ArithOp::Multiply, mun_hir::Signedness::Signed) => ("llvm.smul.with.overflow", "mul"),
+        (ArithOp::Multiply, mun_hir::Signedness::Unsigned) => ("llvm.umul.with.overflow", "mul"),
+        _ => unreachable!("operator {:?} cannot overflow", op),
+    }
+}
@@ -0,0 +1,543 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use inkwell::{
+    basic_block::BasicBlock,
+    context::Context,
+    debug_info::{
+        debug_metadata_version, AsDIScope, DICompileUnit, DIFile, DIFlags, DIFlagsConstants,
+        DILocation, DIScope, DISubprogram, DIType, DWARFEmissionKind, DWARFSourceLanguage,
+        DebugInfoBuilder,
+    },
+    module::{FlagBehavior, Module},
+    types::StructType,
+    values::{FunctionValue, PointerValue},
+    AddressSpace,
+};
+use mun_hir::{HasSource, HasVisibility, HirDatabase, HirDisplay, Ty, TyKind};
+use mun_hir_input::FileId;
+use mun_syntax::{ast::NameOwner, AstNode, TextSize};
+
+use crate::{code_gen::CodeGenContext, ir::ty::HirTypeCache, module_group::ModuleGroup};
+
+/// The DWARF encodings of base types, see section 7.8 of the DWARF 4
+/// specification.
+const DW_ATE_BOOLEAN: u32 = 0x02;
+const DW_ATE_FLOAT: u32 = 0x04;
+const DW_ATE_SIGNED: u32 = 0x05;
+const DW_ATE_UNSIGNED: u32 = 0x07;
+
+/// The version of DWARF to emit.
+const DWARF_VERSION: u64 = 4;
+
+/// Generates the debug information of the functions, variables and types in a
+/// module, which enables debuggers to map machine code back to Mun source
+/// code.
+pub(crate) struct DebugInfo<'db, 'ink, 't> {
+    db: &'db dyn HirDatabase,
+    context: &'ink Context,
+    hir_types: &'t HirTypeCache<'db, 'ink>,
+    builder: DebugInfoBuilder<'ink>,
+    compile_unit: DICompileUnit<'ink>,
+    is_optimized: bool,
+    files: RefCell<HashMap<FileId, DIFile<'ink>>>,
+    types: RefCell<HashMap<Ty, DIType<'ink>>>,
+    structs_in_progress: RefCell<HashSet<mun_hir::Struct>>,
+}
+
+impl<'db, 'ink, 't> DebugInfo<'db, 'ink, 't> {
+    /// Constructs a new `DebugInfo` that adds debug information to `module`,
+    /// which contains the code of `module_group`.
+    pub fn new(
+        code_gen: &'t CodeGenContext<'db, 'ink>,
+        module: &Module<'ink>,
+        module_group: &ModuleGroup,
+    ) -> Self {
+        let db = code_gen.db;
+        let (filename, directory) = module_group
+            .files(db)
+            .next()
+            .map(|file_id| file_path(db, file_id))
+            .unwrap_or_default();
+
+        let is_optimized = code_gen.optimization_level != inkwell::OptimizationLevel::None;
+        let (builder, compile_unit) = module.create_debug_info_builder(
+            true,
+            DWARFSourceLanguage::C,
+            &filename,
+            &directory,
+            "mun",
+            is_optimized,
+            "",
+            0,
+            "",
+            DWARFEmissionKind::Full,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+
+        let i32_type = code_gen.context.i32_type();
+        module.add_basic_value_flag(
+            "Debug Info Version",
+            FlagBehavior::Warning,
+            i32_type.const_int(u64::from(debug_metadata_version()), false),
+        );
+        let is_like_msvc = code_gen
+            .target_machine
+            .get_triple()
+            .as_str()
+            .to_string_lossy()
+            .contains("msvc");
+        if is_like_msvc {
+            module.add_basic_value_flag(
+                "CodeView",
+                FlagBehavior::Warning,
+                i32_type.const_int(1, false),
+            );
+        } else {
+            module.add_basic_value_flag(
+                "Dwarf Version",
+                FlagBehavior::Warning,
+                i32_type.const_int(DWARF_VERSION, false),
+            );
+        }
+
+        Self {
+            db,
+            context: code_gen.context,
+            hir_types: &code_gen.hir_types,
+            builder,
+            compile_unit,
+            is_optimized,
+            files: RefCell::default(),
+            types: RefCell::default(),
+            structs_in_progress: RefCell::default(),
+        }
+    }
+
+    /// Constructs the debug information of `function` and attaches it to its
+    /// IR function. The returned subprogram is the scope of all locations and
+    /// variables in the body of the function.
+    pub fn gen_function(
+        &self,
+        function: mun_hir::Function,
+        fn_value: FunctionValue<'ink>,
+    ) -> DISubprogram<'ink> {
+        let file_id = function.file_id(self.db);
+        let file = self.file(file_id);
+        let source = function.source(self.db);
+        let line = self.line(
+            file_id,
+            source.value.name().map_or_else(
+                || source.value.syntax().text_range().start(),
+                |name| name.syntax().text_range().start(),
+            ),
+        );
+
+        let fn_sig = function
+            .ty(self.db)
+            .callable_sig(self.db)
+            .expect("a function must have a signature");
+        let param_types: Vec<_> = fn_sig
+            .params()
+            .iter()
+            .filter_map(|ty| self.ty(ty))
+            .collect();
+        let subroutine_type = self.builder.create_subroutine_type(
+            file,
+            self.ty(fn_sig.ret()),
+            &param_types,
+            DIFlags::ZERO,
+        );
+
+        let subprogram = self.builder.create_function(
+            file.as_debug_info_scope(),
+            &function.name(self.db).to_string(),
+            fn_value.get_name().to_str().ok(),
+            file,
+            line,
+            subroutine_type,
+            !function.visibility(self.db).is_externally_visible(),
+            true,
+            line,
+            DIFlags::PROTOTYPED,
+            self.is_optimized,
+        );
+        fn_value.set_subprogram(subprogram);
+        subprogram
+    }
+
+    /// Returns the location of `offset` in the specified file.
+    pub fn location(
+        &self,
+        scope: DISubprogram<'ink>,
+        file_id: FileId,
+        offset: TextSize,
+    ) -> DILocation<'ink> {
+        let line_col = self.db.line_index(file_id).line_col(offset);
+        self.builder.create_debug_location(
+            self.context,
+            line_col.line + 1,
+            line_col.col_utf16 + 1,
+            scope.as_debug_info_scope(),
+            None,
+        )
+    }
+
+    /// Declares that `storage` holds the value of a variable or, if `arg_no`
+    /// is specified, a parameter. The declaration is added to the end of
+    /// `block`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn declare_variable(
+        &self,
+        scope: DISubprogram<'ink>,
+        storage: PointerValue<'ink>,
+        name: &str,
+        ty: &Ty,
+        arg_no: Option<u32>,
+        file_id: FileId,
+        offset: TextSize,
+        block: BasicBlock<'ink>,
+    ) {
+        let Some(di_type) = self.ty(ty) else {
+            return;
+        };
+
+        let file = self.file(file_id);
+        let line = self.line(file_id, offset);
+        let variable = match arg_no {
+            Some(arg_no) => self.builder.create_parameter_variable(
+                scope.as_debug_info_scope(),
+                name,
+                arg_no,
+                file,
+                line,
+                di_type,
+                true,
+                DIFlags::ZERO,
+            ),
+            None => self.builder.create_auto_variable(
+                scope.as_debug_info_scope(),
+                name,
+                file,
+                line,
+                di_type,
+                true,
+                DIFlags::ZERO,
+                0,
+            ),
+        };
+
+        let location = self.location(scope, file_id, offset);
+        self.builder
+            .insert_declare_at_end(storage, Some(variable), None, location, block);
+    }
+
+    /// Constructs all deferred debug information. This must be called after
+    /// all functions have been generated and before the module is verified or
+    /// optimized.
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+
+    /// Returns the debug information of a source file.
+    fn file(&self, file_id: FileId) -> DIFile<'ink> {
+        *self.files.borrow_mut().entry(file_id).or_insert_with(|| {
+            let (filename, directory) = file_path(self.db, file_id);
+            self.builder.create_file(&filename, &directory)
+        })
+    }
+
+    /// Returns the one-based line of `offset` in the specified file.
+    fn line(&self, file_id: FileId, offset: TextSize) -> u32 {
+        self.db.line_index(file_id).line_col(offset).line + 1
+    }
+
+    /// Returns the debug information of a HIR type, or `None` if the type has
+    /// no runtime representation.
+    fn ty(&self, ty: &Ty) -> Option<DIType<'ink>> {
+        if let Some(di_type) = self.types.borrow().get(ty) {
+            return Some(*di_type);
+        }
+
+        let name = ty.display(self.db).to_string();
+        let di_type = match ty.interned() {
+            TyKind::Bool => self.basic_type(&name, 8, DW_ATE_BOOLEAN),
+            TyKind::Int(int_ty) => {
+                let size_in_bits = self.hir_types.get_int_type(*int_ty).get_bit_width();
+                let encoding = match int_ty.signedness {
+                    mun_hir::Signedness::Signed => DW_ATE_SIGNED,
+                    mun_hir::Signedness::Unsigned => DW_ATE_UNSIGNED,
+                };
+                self.basic_type(&name, u64::from(size_in_bits), encoding)
+            }
+            TyKind::Float(float_ty) => {
+                let float_type = self.hir_types.get_float_type(*float_ty);
+                let size_in_bits = self.hir_types.target_data().get_bit_size(&float_type);
+                self.basic_type(&name, size_in_bits, DW_ATE_FLOAT)
+            }
+            &TyKind::Vector(vector_ty) => {
+                // Vectors are described as an array of their lanes
+                let target_data = self.hir_types.target_data();
+                let vector_type = self.hir_types.get_vector_type(vector_ty);
+                let element_type = self.ty(&vector_ty.element_ty())?;
+                #[allow(clippy::single_range_in_vec_init)]
+                let lanes = [0..i64::from(vector_ty.lanes)];
+                self.builder
+                    .create_array_type(
+                        element_type,
+                        target_data.get_bit_size(&vector_type),
+                        target_data.get_abi_alignment(&vector_type) * 8,
+                        &lanes,
+                    )
+                    .as_type()
+            }
+            TyKind::Tuple(_, substs) if substs.is_empty() => return None,
+            TyKind::Tuple(_, substs) => {
+                let llvm_type = self.hir_types.get_tuple_type(substs);
+                let fields = substs
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, ty)| (idx.to_string(), ty.clone()));
+                self.composite_type(&name, llvm_type, fields, self.compile_unit.get_file(), 0)
+            }
+            TyKind::Struct(hir_struct) => match hir_struct.data(self.db).memory_kind {
+                mun_hir::StructMemoryKind::Gc => {
+                    // GC values are pointers to pointers
+                    let struct_type = self.struct_type(*hir_struct);
+                    self.pointer_type(self.pointer_type(struct_type))
+                }
+                mun_hir::StructMemoryKind::Value => self.struct_type(*hir_struct),
+            },
+            TyKind::Array(element_ty) => {
+                let array_type = self.array_type(&name, element_ty)?;
+                self.pointer_type(self.pointer_type(array_type))
+            }
+            _ => return None,
+        };
+
+        self.types.borrow_mut().insert(ty.clone(), di_type);
+        Some(di_type)
+    }
+
+    /// Returns the debug information of a base type.
+    fn basic_type(&self, name: &str, size_in_bits: u64, encoding: u32) -> DIType<'ink> {
+        self.builder
+            .create_basic_type(name, size_in_bits, encoding, DIFlags::ZERO)
+            .expect("base types must have a name")
+            .as_type()
+    }
+
+    /// Returns the debug information of a pointer to `pointee`.
+    fn pointer_type(&self, pointee: DIType<'ink>) -> DIType<'ink> {
+        let target_data = self.hir_types.target_data();
+        let size_in_bits = u64::from(target_data.get_pointer_byte_size(None)) * 8;
+        self.builder
+            .create_pointer_type(
+                "",
+                pointee,
+                size_in_bits,
+                size_in_bits as u32,
+                AddressSpace::default(),
+            )
+            .as_type()
+    }
+
+    /// Returns the debug information of the layout of a struct. Structs that
+    /// (indirectly) refer to themselves refer to a forward declaration of the
+    /// struct instead.
+    fn struct_type(&self, hir_struct: mun_hir::Struct) -> DIType<'ink> {
+        let name = hir_struct.name(self.db).to_string();
+        let file_id = hir_struct.file_id(self.db);
+        let file = self.file(file_id);
+        let source = hir_struct.source(self.db);
+        let line = self.line(
+            file_id,
+            source.value.name().map_or_else(
+                || source.value.syntax().text_range().start(),
+                |name| name.syntax().text_range().start(),
+            ),
+        );
+
+        if !self.structs_in_progress.borrow_mut().insert(hir_struct) {
+            return self
+                .builder
+                .create_struct_type(
+                    file.as_debug_info_scope(),
+                    &name,
+                    file,
+                    line,
+                    0,
+                    0,
+                    DIFlags::FWD_DECL,
+                    None,
+                    &[],
+                    0,
+                    None,
+                    "",
+                )
+                .as_type();
+        }
+
+        let llvm_type = self.hir_types.get_struct_type(hir_struct);
+        let fields = hir_struct
+            .fields(self.db)
+            .into_iter()
+            .map(|field| (field.name(self.db).to_string(), field.ty(self.db)));
+        let di_type = self.composite_type(&name, llvm_type, fields, file, line);
+
+        self.structs_in_progress.borrow_mut().remove(&hir_struct);
+        di_type
+    }
+
+    /// Returns the debug information of the heap-allocated part of an array,
+    /// which consists of its length, capacity and elements.
+    fn array_type(&self, name: &str, element_ty: &Ty) -> Option<DIType<'ink>> {
+        let target_data = self.hir_types.target_data();
+        let llvm_type = self.hir_types.get_array_type(element_ty);
+        let file = self.compile_unit.get_file();
+        let scope = self.compile_unit.as_debug_info_scope();
+
+        let usize_type = self.basic_type(
+            "usize",
+            u64::from(target_data.get_pointer_byte_size(None)) * 8,
+            DW_ATE_UNSIGNED,
+        );
+        // The number of elements is only known at runtime, so the elements are
+        // described as an array with a single empty subrange.
+        let element_type = self.ty(element_ty)?;
+        #[allow(clippy::single_range_in_vec_init)]
+        let elements_type = self
+            .builder
+            .create_array_type(element_type, 0, 0, &[0..0])
+            .as_type();
+
+        let members: Vec<_> = [
+            ("length", usize_type),
+            ("capacity", usize_type),
+            ("elements", elements_type),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (member_name, member_type))| {
+            self.member_type(scope, member_name, file, 0, llvm_type, idx, member_type)
+        })
+        .collect();
+
+        Some(
+            self.builder
+                .create_struct_type(
+                    scope,
+                    name,
+                    file,
+                    0,
+                    target_data.get_bit_size(&llvm_type),
+                    target_data.get_abi_alignment(&llvm_type) * 8,
+                    DIFlags::ZERO,
+                    None,
+                    &members,
+                    0,
+                    None,
+                    "",
+                )
+                .as_type(),
+        )
+    }
+
+    /// Returns the debug information of a struct or tuple with the specified
+    /// fields, laid out as `llvm_type`.
+    fn composite_type(
+        &self,
+        name: &str,
+        llvm_type: StructType<'ink>,
+        fields: impl Iterator<Item = (String, Ty)>,
+        file: DIFile<'ink>,
+        line: u32,
+    ) -> DIType<'ink> {
+        let target_data = self.hir_types.target_data();
+        let scope = file.as_debug_info_scope();
+        let members: Vec<_> = fields
+            .enumerate()
+            .filter_map(|(idx, (field_name, field_ty))| {
+                let field_type = self.ty(&field_ty)?;
+                Some(self.member_type(scope, &field_name, file, line, llvm_type, idx, field_type))
+            })
+            .collect();
+
+        self.builder
+            .create_struct_type(
+                scope,
+                name,
+                file,
+                line,
+                target_data.get_bit_size(&llvm_type),
+                target_data.get_abi_alignment(&llvm_type) * 8,
+                DIFlags::ZERO,
+                None,
+                &members,
+                0,
+                None,
+                "",
+            )
+            .as_type()
+    }
+
+    /// Returns the debug information of the member at index `idx` of
+    /// `llvm_type`.
+    #[allow(clippy::too_many_arguments)]
+    fn member_type(
+        &self,
+        scope: DIScope<'ink>,
+        name: &str,
+        file: DIFile<'ink>,
+        line: u32,
+        llvm_type: StructType<'ink>,
+        idx: usize,
+        member_type: DIType<'ink>,
+    ) -> DIType<'ink> {
+        let target_data = self.hir_types.target_data();
+        let llvm_member_type = llvm_type
+            .get_field_type_at_index(idx as u32)
+            .expect("a member must have a type");
+        let offset_in_bytes = target_data
+            .offset_of_element(&llvm_type, idx as u32)
+            .expect("a member must have an offset");
+        self.builder
+            .create_member_type(
+                scope,
+                name,
+                file,
+                line,
+                target_data.get_store_size(&llvm_member_type) * 8,
+                target_data.get_abi_alignment(&llvm_member_type) * 8,
+                offset_in_bytes * 8,
+                DIFlags::ZERO,
+                member_type,
+            )
+            .as_type()
+    }
+}
+
+/// Returns the path of a source file relative to the source directory of its
+/// package, and the source directory itself if it is known.
+fn file_path(db: &dyn HirDatabase, file_id: FileId) -> (String, String) {
+    let source_root = db.file_source_root(file_id);
+    let packages = db.packages();
+    let directory = packages
+        .iter()
+        .map(|package| &packages[package])
+        .find(|package| package.source_root == source_root)
+        .and_then(|package| package.source_directory.clone())
+        .unwrap_or_else(PathBuf::new);
+
+    (
+        db.file_relative_path(file_id).to_string(),
+        directory.to_string_lossy().into_owned(),
+    )
+}
@@ -186,6 +186,8 @@ pub(crate) struct DispatchTableBuilder<'db, 'ink, 't> {
     table_type: inkwell::types::StructType<'ink>,
     // The group of modules for which the dispatch table is being build
     module_group: &'t ModuleGroup,
+    // Whether functions of other module groups are compiled into this group
+    lto: bool,
     // The set of modules that is referenced
     referenced_modules: FxHashSet<mun_hir::Module>,
 }
@@ -197,6 +199,7 @@ struct TypedDispatchableFunction<'ink> {
 
 impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
     /// Creates a new builder that can generate a dispatch function.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         context: &'ink Context,
         target_data: TargetData,
@@ -205,6 +208,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         intrinsics: &BTreeMap<FunctionPrototype, FunctionType<'ink>>,
         hir_types: &'t HirTypeCache<'db, 'ink>,
         module_group: &'t ModuleGroup,
+        lto: bool,
     ) -> Self {
         let mut table = Self {
             db,
@@ -218,6 +222,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
             table_type: context.opaque_struct_type("DispatchTable"),
             hir_types,
             module_group,
+            lto,
             referenced_modules: FxHashSet::default(),
         };
 
@@ -259,7 +264,10 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
         if let Expr::Call { callee, .. } = expr {
             match infer[*callee].as_callable_def() {
                 Some(mun_hir::CallableDef::Function(def)) => {
-                    if self.module_group.should_runtime_link_fn(self.db, def) {
+                    if self
+                        .module_group
+                        .should_runtime_link_fn(self.db, def, self.lto)
+                    {
                         let fn_module = def.module(self.db);
                         if !def.is_extern(self.db) && !self.module_group.contains(fn_module) {
                             self.referenced_modules.insert(fn_module);
@@ -267,7 +275,7 @@ impl<'db, 'ink, 't> DispatchTableBuilder<'db, 'ink, 't> {
                         self.collect_fn_def(def);
                     }
                 }
-                Some(mun_hir::CallableDef::Struct(_)) => (),
+                Some(mun_hir::CallableDef::Struct(_) | mun_hir::CallableDef::PrimitiveType(_)) => {}
                 None => panic!("expected a callable expression"),
             }
         }
@@ -1,12 +1,16 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
-use inkwell::module::Module;
+use inkwell::module::{Linkage, Module};
+use itertools::Itertools;
 use mun_hir::{HasVisibility, ModuleDef};
 
 use super::body::ExternalGlobals;
 use crate::{
     code_gen::CodeGenContext,
-    ir::{body::BodyIrGenerator, file_group::FileGroupIr, function, type_table::TypeTable},
+    ir::{
+        body::BodyIrGenerator, debug_info::DebugInfo, file_group::FileGroupIr, function,
+        type_table::TypeTable,
+    },
     module_group::ModuleGroup,
     value::Global,
 };
@@ -20,6 +24,8 @@ pub struct FileIr<'ink> {
     pub function_definitions: HashSet<mun_hir::Function>,
     /// The types defined in this file
     pub type_definitions: HashSet<mun_hir::Ty>,
+    /// The `mun_hir::Const`s whose values are exposed by the file.
+    pub constant_definitions: HashSet<mun_hir::Const>,
 }
 
 /// Generates IR for the specified file.
@@ -36,6 +42,7 @@ pub(crate) fn gen_file_ir<'ink>(
     // Use a `BTreeMap` to guarantee deterministically ordered output.ures
     let mut functions = HashMap::new();
     let mut type_definitions = HashSet::new();
+    let mut constant_definitions = HashSet::new();
     let mut wrapper_functions = BTreeMap::new();
     for def in module_group
         .iter()
@@ -63,6 +70,18 @@ pub(crate) fn gen_file_ir<'ink>(
         if let ModuleDef::Struct(s) = def {
             type_definitions.insert(s.ty(code_gen.db));
         }
+        if let ModuleDef::Const(c) = def {
+            if c.visibility(code_gen.db).is_externally_visible() {
+                constant_definitions.insert(c);
+            }
+        }
+    }
+
+    // Functions of other module groups are only called from within this group
+    for &f in &group_ir.imported_functions {
+        let fun = function::gen_prototype(code_gen.db, hir_types, f, &llvm_module);
+        fun.set_linkage(Linkage::Internal);
+        functions.insert(f, fun);
     }
 
     let external_globals = {
@@ -87,12 +106,17 @@ pub(crate) fn gen_file_ir<'ink>(
 
     // Construct requirements for generating the bodies
     let fn_pass_manager = function::create_pass_manager(&llvm_module, code_gen.optimization_level);
+    let debug_info = code_gen
+        .debug_info
+        .then(|| DebugInfo::new(code_gen, &llvm_module, module_group));
 
-    // Generate the function bodies
-    for (hir_function, llvm_function) in functions.iter() {
+    // Generate the function bodies. Bodies are generated in a deterministic order
+    // because they may add globals to the module, e.g. for panic messages.
+    for (hir_function, llvm_function) in functions.iter().sorted_by_key(|(f, _)| **f) {
         let mut code_gen = BodyIrGenerator::new(
             code_gen.context,
             code_gen.db,
+            &llvm_module,
             (*hir_function, *llvm_function),
             &functions,
             &group_ir.dispatch_table,
@@ -100,16 +124,22 @@ pub(crate) fn gen_file_ir<'ink>(
             external_globals.clone(),
             &code_gen.hir_types,
             module_group,
+            code_gen.runtime_checks,
+            code_gen.overflow_checks,
+            code_gen.lto,
+            debug_info.as_ref(),
         );
 
         code_gen.gen_fn_body();
-        fn_pass_manager.run_on(llvm_function);
     }
 
+    // Wrapper functions have no counterpart in the source code, so they are
+    // generated without debug information.
     for (hir_function, llvm_function) in wrapper_functions.iter() {
         let mut code_gen = BodyIrGenerator::new(
             code_gen.context,
             code_gen.db,
+            &llvm_module,
             (*hir_function, *llvm_function),
             &functions,
             &group_ir.dispatch_table,
@@ -117,9 +147,20 @@ pub(crate) fn gen_file_ir<'ink>(
             external_globals.clone(),
             &code_gen.hir_types,
             module_group,
+            code_gen.runtime_checks,
+            code_gen.overflow_checks,
+            code_gen.lto,
+            None,
         );
 
         code_gen.gen_fn_wrapper();
+    }
+
+    // The debug information must be complete before the functions are optimized
+    if let Some(debug_info) = &debug_info {
+        debug_info.finalize();
+    }
+    for llvm_function in functions.values().chain(wrapper_functions.values()) {
         fn_pass_manager.run_on(llvm_function);
     }
 
@@ -134,5 +175,6 @@ pub(crate) fn gen_file_ir<'ink>(
         llvm_module,
         function_definitions,
         type_definitions,
+        constant_definitions,
     }
 }
@@ -30,6 +30,9 @@ pub struct FileGroupIr<'ink> {
     /// The modules that contain code that was referenced from this group of
     /// modules
     pub(crate) referenced_modules: FxHashSet<mun_hir::Module>,
+    /// The functions of other module groups that are compiled into this group
+    /// with link-time optimization
+    pub(crate) imported_functions: Vec<mun_hir::Function>,
 }
 
 /// Generates IR that is shared among the group's files.
@@ -43,42 +46,50 @@ pub(crate) fn gen_file_group_ir<'ink>(
     let mut intrinsics_map = BTreeMap::new();
     let mut needs_alloc = false;
 
-    // Collect all intrinsic functions, wrapper function, and generate struct
-    // declarations.
-    for def in module_group
+    // With link-time optimization the functions of other module groups that are
+    // called directly are compiled into this group as well.
+    let imported_functions = if code_gen.lto {
+        module_group.imported_fns(code_gen.db)
+    } else {
+        Vec::new()
+    };
+    let functions: Vec<_> = module_group
         .iter()
         .flat_map(|module| module.declarations(code_gen.db))
-    {
-        match def {
-            ModuleDef::Function(f) if !f.is_extern(code_gen.db) => {
-                intrinsics::collect_fn_body(
-                    code_gen.context,
-                    code_gen.target_machine.get_target_data(),
-                    code_gen.db,
-                    &mut intrinsics_map,
-                    &mut needs_alloc,
-                    &f.body(code_gen.db),
-                    &f.infer(code_gen.db),
-                );
+        .filter_map(|def| match def {
+            ModuleDef::Function(f) if !f.is_extern(code_gen.db) => Some(f),
+            _ => None,
+        })
+        .chain(imported_functions.iter().copied())
+        .collect();
 
-                let fn_sig = f.ty(code_gen.db).callable_sig(code_gen.db).unwrap();
-                if f.visibility(code_gen.db).is_externally_visible()
-                    && !fn_sig.marshallable(code_gen.db)
-                {
-                    intrinsics::collect_wrapper_body(
-                        code_gen.context,
-                        code_gen.target_machine.get_target_data(),
-                        &mut intrinsics_map,
-                        &mut needs_alloc,
-                    );
-                }
-            }
-            // TODO: Extern types for functions?
-            ModuleDef::Module(_)
-            | ModuleDef::Struct(_)
-            | ModuleDef::PrimitiveType(_)
-            | ModuleDef::TypeAlias(_)
-            | ModuleDef::Function(_) => (),
+    // Collect all intrinsic functions and wrapper functions.
+    for &f in &functions {
+        intrinsics::collect_fn_body(
+            code_gen.context,
+            code_gen.target_machine.get_target_data(),
+            code_gen.db,
+            &mut intrinsics_map,
+            &mut needs_alloc,
+            code_gen.runtime_checks,
+            code_gen.overflow_checks,
+            code_gen.debug_info,
+            &f.body(code_gen.db),
+            &f.infer(code_gen.db),
+        );
+
+        // Imported functions are never exposed, so they don't need a wrapper
+        let fn_sig = f.ty(code_gen.db).callable_sig(code_gen.db).unwrap();
+        if module_group.contains(f.module(code_gen.db))
+            && f.visibility(code_gen.db).is_externally_visible()
+            && !fn_sig.marshallable(code_gen.db)
+        {
+            intrinsics::collect_wrapper_body(
+                code_gen.context,
+                code_gen.target_machine.get_target_data(),
+                &mut intrinsics_map,
+                &mut needs_alloc,
+            );
         }
     }
 
@@ -91,19 +102,13 @@ pub(crate) fn gen_file_group_ir<'ink>(
         &intrinsics_map,
         &code_gen.hir_types,
         module_group,
+        code_gen.lto,
     );
-    for def in module_group
-        .iter()
-        .flat_map(|module| module.declarations(code_gen.db))
-    {
-        if let ModuleDef::Function(f) = def {
-            // Find all functions that must be present in the dispatch table
-            if !f.is_extern(code_gen.db) {
-                let body = f.body(code_gen.db);
-                let infer = f.infer(code_gen.db);
-                dispatch_table_builder.collect_body(&body, &infer);
-            }
-        }
+    for &f in &functions {
+        // Find all functions that must be present in the dispatch table
+        let body = f.body(code_gen.db);
+        let infer = f.infer(code_gen.db);
+        dispatch_table_builder.collect_body(&body, &infer);
     }
 
     let (dispatch_table, referenced_modules) = dispatch_table_builder.build();
@@ -140,9 +145,15 @@ pub(crate) fn gen_file_group_ir<'ink>(
             ModuleDef::Function(f) => {
                 type_table_builder.collect_fn(f);
             }
+            ModuleDef::Const(c) => {
+                type_table_builder.collect_const(c);
+            }
             ModuleDef::PrimitiveType(_) | ModuleDef::TypeAlias(_) | ModuleDef::Module(_) => (),
         }
     }
+    for &f in &imported_functions {
+        type_table_builder.collect_fn(f);
+    }
 
     let type_table = type_table_builder.build();
 
@@ -163,5 +174,6 @@ pub(crate) fn gen_file_group_ir<'ink>(
         type_table,
         allocator_handle_type,
         referenced_modules,
+        imported_functions,
     }
 }
@@ -1,7 +1,11 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use inkwell::{context::Context, targets::TargetData, types::FunctionType};
-use mun_hir::{Body, Expr, ExprId, HirDatabase, InferenceResult, ValueNs};
+use mun_hir::{
+    method_resolution::{BuiltinMethod, OverflowMode},
+    ArithOp, BinaryOp, Body, Expr, ExprId, HirDatabase, InferenceResult, IntTy, ScalarTy, Ty,
+    TyKind, UnaryOp, ValueNs, VectorTy,
+};
 
 use crate::{
     intrinsics::{self, Intrinsic},
@@ -33,12 +37,19 @@ fn collect_expr<'ink>(
     db: &'_ dyn HirDatabase,
     intrinsics: &mut IntrinsicsMap<'ink>,
     needs_alloc: &mut bool,
+    runtime_checks: bool,
+    overflow_checks: bool,
     expr_id: ExprId,
     body: &Arc<Body>,
     infer: &InferenceResult,
 ) {
     let expr = &body[expr_id];
 
+    // Expressions with runtime checks call the `panic` intrinsic if a check fails
+    if needs_runtime_check(expr_id, expr, infer, runtime_checks, overflow_checks) {
+        collect_intrinsic(context, target, &intrinsics::panic, intrinsics);
+    }
+
     // If this expression is a call, store it in the dispatch table
     if let Expr::Call { callee, .. } = expr {
         match infer[*callee].as_callable_def() {
@@ -47,7 +58,7 @@ fn collect_expr<'ink>(
                 // self.collect_intrinsic(module, entries, &intrinsics::drop);
                 *needs_alloc = true;
             }
-            Some(mun_hir::CallableDef::Function(_)) => (),
+            Some(mun_hir::CallableDef::Function(_) | mun_hir::CallableDef::PrimitiveType(_)) => (),
             None => panic!("expected a callable expression"),
         }
     }
@@ -67,7 +78,7 @@ fn collect_expr<'ink>(
         }
     }
 
-    if let Expr::Array(_) = expr {
+    if let Expr::Array(_) | Expr::ArrayRepeat { .. } = expr {
         collect_intrinsic(context, target, &intrinsics::new_array, intrinsics);
         *needs_alloc = true;
     }
@@ -80,6 +91,8 @@ fn collect_expr<'ink>(
             db,
             intrinsics,
             needs_alloc,
+            runtime_checks,
+            overflow_checks,
             expr_id,
             body,
             infer,
@@ -87,22 +100,100 @@ fn collect_expr<'ink>(
     });
 }
 
+/// Returns true if the code generated for the expression contains a runtime
+/// check: indexing an array, dividing integers or integer vectors, integer
+/// arithmetic, negation or shifts that can overflow, or calling a `checked_*`
+/// integer method.
+fn needs_runtime_check(
+    expr_id: ExprId,
+    expr: &Expr,
+    infer: &InferenceResult,
+    runtime_checks: bool,
+    overflow_checks: bool,
+) -> bool {
+    match expr {
+        Expr::Index { .. } => runtime_checks,
+        Expr::UnaryOp {
+            expr,
+            op: UnaryOp::Neg,
+        } => {
+            overflow_checks
+                && int_element_ty(&infer[*expr])
+                    .is_some_and(|(int_ty, _)| int_ty.signedness.is_signed())
+        }
+        Expr::BinaryOp {
+            lhs,
+            op: Some(BinaryOp::ArithOp(op) | BinaryOp::Assignment { op: Some(op) }),
+            ..
+        } => {
+            let Some((int_ty, is_vector)) = int_element_ty(&infer[*lhs]) else {
+                return false;
+            };
+            match op {
+                ArithOp::Divide | ArithOp::Remainder => {
+                    runtime_checks || (overflow_checks && int_ty.signedness.is_signed())
+                }
+                // Only scalar arithmetic and shifts are checked for overflow
+                ArithOp::Add
+                | ArithOp::Subtract
+                | ArithOp::Multiply
+                | ArithOp::LeftShift
+                | ArithOp::RightShift => overflow_checks && !is_vector,
+                ArithOp::BitAnd | ArithOp::BitOr | ArithOp::BitXor => false,
+            }
+        }
+        Expr::MethodCall { .. } => matches!(
+            infer.builtin_method_resolution(expr_id),
+            Some(BuiltinMethod::IntArith {
+                mode: OverflowMode::Checked,
+                ..
+            })
+        ),
+        _ => false,
+    }
+}
+
+/// Returns the integer type of an integer or of the lanes of an integer
+/// vector, and whether the type is a vector.
+fn int_element_ty(ty: &Ty) -> Option<(IntTy, bool)> {
+    match ty.interned() {
+        TyKind::Int(int_ty) => Some((*int_ty, false)),
+        TyKind::Vector(VectorTy {
+            element: ScalarTy::Int(int_ty),
+            ..
+        }) => Some((*int_ty, true)),
+        _ => None,
+    }
+}
+
 /// Collects all intrinsics from the specified `body`.
+#[allow(clippy::too_many_arguments)]
 pub fn collect_fn_body<'ink>(
     context: &'ink Context,
     target: TargetData,
     db: &dyn HirDatabase,
     intrinsics: &mut IntrinsicsMap<'ink>,
     needs_alloc: &mut bool,
+    runtime_checks: bool,
+    overflow_checks: bool,
+    call_frames: bool,
     body: &Arc<Body>,
     infer: &InferenceResult,
 ) {
+    // Functions that record their call frame link it into the call stack
+    if call_frames {
+        collect_intrinsic(context, &target, &intrinsics::push_frame, intrinsics);
+        collect_intrinsic(context, &target, &intrinsics::pop_frame, intrinsics);
+    }
+
     collect_expr(
         context,
         &target,
         db,
         intrinsics,
         needs_alloc,
+        runtime_checks,
+        overflow_checks,
         body.body_expr(),
         body,
         infer,
@@ -5,13 +5,15 @@ use inkwell::{
     targets::TargetData,
     types::{
         AnyTypeEnum, BasicType, BasicTypeEnum, FloatType, FunctionType, IntType, PointerType,
-        StructType,
+        StructType, VectorType,
     },
+    values::BasicValueEnum,
     AddressSpace,
 };
-use mun_abi::Guid;
+use mun_abi::{self as abi, Guid};
 use mun_hir::{
-    FloatBitness, HirDatabase, HirDisplay, IntBitness, ResolveBitness, Signedness, Ty, TyKind,
+    ConstValue, FloatBitness, HirDatabase, HirDisplay, IntBitness, ResolveBitness, ScalarTy,
+    Signedness, Ty, TyKind, VectorTy,
 };
 use smallvec::SmallVec;
 
@@ -43,6 +45,11 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         }
     }
 
+    /// Returns the data layout of the target
+    pub fn target_data(&self) -> &TargetData {
+        &self.target_data
+    }
+
     /// Returns the type of the specified floating-point type
     pub fn get_float_type(&self, ty: mun_hir::FloatTy) -> FloatType<'ink> {
         match ty.bitness {
@@ -63,6 +70,22 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         }
     }
 
+    /// Returns the type of the specified vector type
+    pub fn get_vector_type(&self, ty: VectorTy) -> VectorType<'ink> {
+        match ty.element {
+            ScalarTy::Float(float_ty) => self.get_float_type(float_ty).vec_type(ty.lanes),
+            ScalarTy::Int(int_ty) => self.get_int_type(int_ty).vec_type(ty.lanes),
+        }
+    }
+
+    /// Returns the type that is used to pass the specified vector type to and
+    /// from functions: an integer with the same size as the vector. This
+    /// matches the Rust representation of vectors, see `mun_abi::F32x4`.
+    pub fn get_vector_abi_type(&self, ty: VectorTy) -> IntType<'ink> {
+        let bits = self.target_data.get_bit_size(&self.get_vector_type(ty));
+        self.context.custom_width_int_type(bits as u32)
+    }
+
     /// Returns the type for booleans
     pub fn get_bool_type(&self) -> IntType<'ink> {
         self.context.bool_type()
@@ -216,7 +239,7 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             .params()
             .iter()
             .map(|p| {
-                self.get_basic_type(p)
+                self.get_argument_type(p)
                     .expect("could not convert function argument to basic type")
                     .into()
             })
@@ -226,7 +249,7 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         match return_type.interned() {
             TyKind::Tuple(0, _) => self.context.void_type().fn_type(&param_tys, false),
             _ => self
-                .get_basic_type(return_type)
+                .get_argument_type(return_type)
                 .expect("could not convert return value")
                 .fn_type(&param_tys, false),
         }
@@ -265,6 +288,7 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
+            TyKind::Vector(vector_ty) => Some(self.get_vector_type(*vector_ty).into()),
             TyKind::Struct(struct_ty) => Some(self.get_struct_reference_type(*struct_ty)),
             TyKind::Bool => Some(self.get_bool_type().into()),
             TyKind::Array(element_ty) => Some(self.get_array_reference_type(element_ty).into()),
@@ -272,6 +296,17 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         }
     }
 
+    /// Returns the inkwell type of the specified HIR type as it is passed to
+    /// and returned from functions. This is the same as the basic type,
+    /// except for vectors which are passed as integers. If the type cannot be
+    /// represented as a basic type enum, `None` is returned.
+    pub fn get_argument_type(&self, ty: &mun_hir::Ty) -> Option<BasicTypeEnum<'ink>> {
+        match ty.interned() {
+            &TyKind::Vector(vector_ty) => Some(self.get_vector_abi_type(vector_ty).into()),
+            _ => self.get_basic_type(ty),
+        }
+    }
+
     /// Returns the inkwell type of the specified HIR type as a basic value that
     /// is usable from the public API. Internally this means that struct
     /// types are always pointers. If the type cannot be represented as a
@@ -281,6 +316,7 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
+            TyKind::Vector(vector_ty) => Some(self.get_vector_abi_type(*vector_ty).into()),
             TyKind::Struct(struct_ty) => Some(self.get_public_struct_reference_type(*struct_ty)),
             TyKind::Bool => Some(self.get_bool_type().into()),
             TyKind::Array(element_ty) => Some(self.get_array_reference_type(element_ty).into()),
@@ -295,6 +331,7 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
             TyKind::Tuple(_, substs) => Some(self.get_tuple_type(substs).into()),
             TyKind::Float(float_ty) => Some(self.get_float_type(*float_ty).into()),
             TyKind::Int(int_ty) => Some(self.get_int_type(*int_ty).into()),
+            TyKind::Vector(vector_ty) => Some(self.get_vector_type(*vector_ty).into()),
             TyKind::Struct(struct_ty) => Some(self.get_struct_type(*struct_ty).into()),
             TyKind::FnDef(mun_hir::CallableDef::Function(fn_ty), type_params) => {
                 if !type_params.is_empty() {
@@ -308,6 +345,45 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
         }
     }
 
+    /// Returns an IR constant for a value that was evaluated at compile time.
+    pub fn get_const_value(&self, value: &ConstValue, ty: &Ty) -> BasicValueEnum<'ink> {
+        match (value, ty.interned()) {
+            (ConstValue::Bool(value), _) => self
+                .get_bool_type()
+                .const_int(u64::from(*value), false)
+                .into(),
+            (ConstValue::Int(value), TyKind::Int(int_ty)) => self
+                .get_int_type(*int_ty)
+                .const_int_arbitrary_precision(&[*value as u64, (*value >> 64) as u64])
+                .into(),
+            (ConstValue::Float(value), TyKind::Float(float_ty)) => {
+                self.get_float_type(*float_ty).const_float(*value).into()
+            }
+            (ConstValue::Struct(fields), TyKind::Struct(hir_struct)) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .zip(hir_struct.fields(self.db))
+                    .map(|(value, field)| self.get_const_value(value, &field.ty(self.db)))
+                    .collect();
+                self.get_struct_type(*hir_struct)
+                    .const_named_struct(&fields)
+                    .into()
+            }
+            (ConstValue::Vector(lanes), TyKind::Vector(vector_ty)) => {
+                let element_ty = vector_ty.element_ty();
+                let lanes: Vec<_> = lanes
+                    .iter()
+                    .map(|lane| self.get_const_value(lane, &element_ty))
+                    .collect();
+                VectorType::const_vector(&lanes).into()
+            }
+            _ => unreachable!(
+                "constant value does not match its type `{}`",
+                ty.display(self.db)
+            ),
+        }
+    }
+
     /// Returns the empty type
     pub fn get_empty_type(&self) -> StructType<'ink> {
         self.context.struct_type(&[], false)
@@ -355,6 +431,21 @@ impl<'db, 'ink> HirTypeCache<'db, 'ink> {
                 }
             }
             TyKind::Bool => bool::type_id().clone(),
+            &TyKind::Vector(ty) => match (ty.element, ty.lanes) {
+                (ScalarTy::Float(float_ty), lanes) => match (float_ty.bitness, lanes) {
+                    (FloatBitness::X32, 2) => abi::F32x2::type_id().clone(),
+                    (FloatBitness::X32, 4) => abi::F32x4::type_id().clone(),
+                    (FloatBitness::X64, 2) => abi::F64x2::type_id().clone(),
+                    _ => unreachable!("unsupported vector type {ty}"),
+                },
+                (ScalarTy::Int(int_ty), lanes) => {
+                    match (int_ty.signedness, int_ty.bitness, lanes) {
+                        (Signedness::Signed, IntBitness::X32, 2) => abi::I32x2::type_id().clone(),
+                        (Signedness::Signed, IntBitness::X32, 4) => abi::I32x4::type_id().clone(),
+                        _ => unreachable!("unsupported vector type {ty}"),
+                    }
+                }
+            },
             &TyKind::Struct(s) => self
                 .struct_to_type_id
                 .borrow_mut()
@@ -10,7 +10,7 @@ use inkwell::{
     types::ArrayType,
     values::PointerValue,
 };
-use mun_hir::{Body, ExprId, HirDatabase, InferenceResult};
+use mun_hir::{Body, ExprId, HasVisibility, HirDatabase, InferenceResult};
 
 use crate::{
     ir::{
@@ -153,10 +153,10 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
                 Some(mun_hir::CallableDef::Function(hir_fn)) => {
                     self.maybe_collect_fn_signature(hir_fn);
                 }
-                Some(mun_hir::CallableDef::Struct(_)) => (),
+                Some(mun_hir::CallableDef::Struct(_) | mun_hir::CallableDef::PrimitiveType(_)) => {}
                 None => panic!("expected a callable expression"),
             }
-        } else if let mun_hir::Expr::Array(..) = expr {
+        } else if let mun_hir::Expr::Array(..) | mun_hir::Expr::ArrayRepeat { .. } = expr {
             self.collect_type(self.hir_types.type_id(&infer[expr_id]));
         }
 
@@ -203,6 +203,14 @@ impl<'db, 'ink, 't> TypeTableBuilder<'db, 'ink, 't> {
         self.collect_expr(body.body_expr(), &body, &infer);
     }
 
+    /// Collects `TypeInfo` from the type of a constant if it's exposed
+    /// externally.
+    pub fn collect_const(&mut self, hir_const: mun_hir::Const) {
+        if hir_const.visibility(self.db).is_externally_visible() {
+            self.collect_type(self.hir_types.type_id(&hir_const.ty(self.db)));
+        }
+    }
+
     /// Collects unique `TypeInfo` from the specified struct type.
     pub fn collect_struct(&mut self, hir_struct: mun_hir::Struct) {
         let type_info = self.hir_types.type_id(&hir_struct.ty(self.db));
@@ -108,6 +108,13 @@ pub struct StructDefinition<'ink> {
     pub memory_kind: abi::StructMemoryKind,
 }
 
+#[derive(AsValue)]
+pub struct ConstantDefinition<'ink> {
+    pub name: Value<'ink, *const u8>,
+    pub type_id: TypeId<'ink>,
+    pub value: Value<'ink, *const std::ffi::c_void>,
+}
+
 #[derive(AsValue)]
 pub struct ModuleInfo<'ink> {
     pub path: Value<'ink, *const u8>,
@@ -115,6 +122,8 @@ pub struct ModuleInfo<'ink> {
     pub types: Value<'ink, *const TypeDefinition<'ink>>,
     pub num_functions: u32,
     pub num_types: u32,
+    pub constants: Value<'ink, *const ConstantDefinition<'ink>>,
+    pub num_constants: u32,
 }
 
 #[derive(AsValue)]
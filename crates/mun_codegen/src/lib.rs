@@ -1,17 +1,19 @@
 pub use inkwell::{builder::Builder, context::Context, module::Module, OptimizationLevel};
 
 pub use crate::{
-    assembly::{AssemblyIr, TargetAssembly},
+    assembly::{AssemblyArtifacts, EmitKind, TargetAssembly},
     code_gen::AssemblyBuilder,
     db::{CodeGenDatabase, CodeGenDatabaseStorage},
+    fingerprint::Fingerprint,
     module_group::ModuleGroup,
-    module_partition::{ModuleGroupId, ModulePartition},
+    module_partition::{ModuleGroupId, ModulePartition, PartitionStrategy},
 };
 
 /// This library generates machine code from HIR using inkwell which is a safe
 /// wrapper around LLVM.
 mod code_gen;
 mod db;
+mod fingerprint;
 #[macro_use]
 mod ir;
 mod assembly;
@@ -24,6 +26,7 @@ mod test;
 pub mod value;
 
 mod apple;
+mod archive;
 pub(crate) mod intrinsics;
 mod linker;
 mod module_group;
@@ -43,6 +43,7 @@ pub fn create_with_target(target: &spec::Target) -> Box<dyn Linker> {
         LinkerFlavor::Ld => Box::new(LdLinker::new(target)),
         LinkerFlavor::Ld64 => Box::new(Ld64Linker::new(target)),
         LinkerFlavor::Msvc => Box::new(MsvcLinker::new(target)),
+        LinkerFlavor::WasmLd => Box::new(WasmLdLinker::new(target)),
     }
 }
 
@@ -88,6 +89,9 @@ impl Linker for LdLinker {
         // Link as dynamic library
         self.args.push("--shared".to_owned());
 
+        // Enable unwinding through the code of the library
+        self.args.push("--eh-frame-hdr".to_owned());
+
         // Specify output path
         self.args.push("-o".to_owned());
         self.args.push(path_str.to_owned());
@@ -254,3 +258,62 @@ impl Linker for MsvcLinker {
             .map_err(LinkerError::LinkError)
     }
 }
+
+struct WasmLdLinker {
+    args: Vec<String>,
+}
+
+impl WasmLdLinker {
+    fn new(target: &spec::Target) -> Self {
+        WasmLdLinker {
+            args: target
+                .options
+                .pre_link_args
+                .iter()
+                .cloned()
+                .map(Cow::into_owned)
+                .collect(),
+        }
+    }
+}
+
+impl Linker for WasmLdLinker {
+    fn add_object(&mut self, path: &Path) -> Result<(), LinkerError> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| LinkerError::PathError(path.to_owned()))?
+            .to_owned();
+        self.args.push(path_str);
+        Ok(())
+    }
+
+    fn build_shared_object(&mut self, path: &Path) -> Result<(), LinkerError> {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| LinkerError::PathError(path.to_owned()))?;
+
+        // WebAssembly has no stable dynamic linking, so link a module without an entry
+        // point that exports the entry points of the assembly. Symbols that are not
+        // defined by the assembly are imported from the host.
+        self.args.push("--no-entry".to_owned());
+        self.args.push("--allow-undefined".to_owned());
+        self.args
+            .push(format!("--export={}", abi::GET_INFO_FN_NAME));
+        self.args
+            .push(format!("--export={}", abi::GET_VERSION_FN_NAME));
+        self.args
+            .push(format!("--export={}", abi::SET_ALLOCATOR_HANDLE_FN_NAME));
+
+        // Specify output path
+        self.args.push("-o".to_owned());
+        self.args.push(path_str.to_owned());
+
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<(), LinkerError> {
+        lld_rs::link(lld_rs::LldFlavor::Wasm, &self.args)
+            .ok()
+            .map_err(LinkerError::LinkError)
+    }
+}
@@ -8,7 +8,7 @@ use parking_lot::Mutex;
 
 use crate::{
     db::{CodeGenDatabase, CodeGenDatabaseStorage},
-    OptimizationLevel,
+    OptimizationLevel, PartitionStrategy,
 };
 
 /// A mock implementation of the IR database. It can be used to set up a simple
@@ -42,6 +42,11 @@ impl Default for MockDatabase {
             events: Mutex::default(),
         };
         db.set_optimization_level(OptimizationLevel::Default);
+        db.set_partition_strategy(PartitionStrategy::default());
+        db.set_runtime_checks(true);
+        db.set_overflow_checks(false);
+        db.set_lto(false);
+        db.set_debug_info(false);
         db.set_target(Target::host_target().unwrap());
         db
     }
@@ -6,7 +6,7 @@ use std::{
     iter::FromIterator,
 };
 
-use mun_hir::{HasVisibility, HirDatabase};
+use mun_hir::{Expr, HasVisibility, HirDatabase, HOT_RELOAD_ATTRIBUTE};
 use mun_hir_input::FileId;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -110,12 +110,74 @@ impl ModuleGroup {
     /// Returns true if the specified function should be included in the
     /// dispatch table of this module group if it is used from within this
     /// module group.
+    ///
+    /// With link-time optimization (`lto`), functions of other module groups
+    /// in the same package are compiled into this module group instead,
+    /// unless they are marked as a hot-reload boundary.
     pub fn should_runtime_link_fn(
         &self,
         db: &dyn HirDatabase,
         function: mun_hir::Function,
+        lto: bool,
     ) -> bool {
-        function.is_extern(db) || !self.modules.contains(&function.module(db))
+        if function.is_extern(db) {
+            return true;
+        }
+        if self.modules.contains(&function.module(db)) {
+            return false;
+        }
+        !(lto && self.can_import_fn(db, function))
+    }
+
+    /// Returns true if the specified function of another module group can be
+    /// compiled into this module group, which makes it possible to call it
+    /// directly.
+    fn can_import_fn(&self, db: &dyn HirDatabase, function: mun_hir::Function) -> bool {
+        let module = function.module(db);
+        !function.is_extern(db)
+            && !self.modules.contains(&module)
+            && self
+                .ordered_modules
+                .first()
+                .is_some_and(|first| first.package() == module.package())
+            && !function.attrs(db).has(HOT_RELOAD_ATTRIBUTE)
+    }
+
+    /// Returns the functions of other module groups that are compiled into
+    /// this module group when link-time optimization is enabled. These are
+    /// all functions that are called, directly or indirectly, from this module
+    /// group without going through the dispatch table.
+    pub fn imported_fns(&self, db: &dyn HirDatabase) -> Vec<mun_hir::Function> {
+        let mut queue: Vec<_> = self
+            .iter()
+            .flat_map(|module| module.declarations(db))
+            .filter_map(|def| match def {
+                mun_hir::ModuleDef::Function(f) if !f.is_extern(db) => Some(f),
+                _ => None,
+            })
+            .collect();
+
+        let mut imported = FxHashSet::default();
+        while let Some(function) = queue.pop() {
+            let body = function.body(db);
+            let infer = function.infer(db);
+            for (_, expr) in body.exprs() {
+                let Expr::Call { callee, .. } = expr else {
+                    continue;
+                };
+                if let Some(mun_hir::CallableDef::Function(callee)) =
+                    infer[*callee].as_callable_def()
+                {
+                    if self.can_import_fn(db, callee) && imported.insert(callee) {
+                        queue.push(callee);
+                    }
+                }
+            }
+        }
+
+        let mut imported: Vec<_> = imported.into_iter().collect();
+        imported.sort();
+        imported
     }
 
     /// Returns the `mun_hir::FileId`s that are included in this module group.
@@ -129,4 +191,90 @@ impl ModuleGroup {
     pub fn relative_file_path(&self) -> mun_paths::RelativePathBuf {
         mun_paths::RelativePathBuf::from(self.name.replace("::", "$"))
     }
+
+    /// Returns the prefix of the names under which the entry points of this
+    /// module group are exported from a static library.
+    ///
+    /// The prefix starts with the name of the package of the module group,
+    /// followed by the path of the module group within the package. All parts
+    /// end with `__` and every byte in them that is not alphanumeric,
+    /// including `_`, is escaped as `_` followed by its hexadecimal value. For
+    /// example, the prefix of the root module of the package `app` is `app__`
+    /// and the prefix of the module group `foo_bar::baz` in the package
+    /// `my-lib` is `my_2dlib__foo_5fbar__baz__`.
+    pub fn static_symbol_prefix(&self, db: &dyn HirDatabase) -> String {
+        let package = self.ordered_modules.first().map(|module| module.package());
+
+        // The name of the module group of a package that other packages depend on
+        // already starts with the name of the package.
+        let mut path = if self.name == "mod" {
+            Vec::new()
+        } else {
+            self.name.split("::").map(ToOwned::to_owned).collect()
+        };
+        if package.and_then(|package| package.qualifier(db)).is_none() {
+            let package_name = package
+                .and_then(|package| package.name(db))
+                .unwrap_or_else(|| String::from("mod"));
+            path.insert(0, package_name);
+        }
+
+        let mut prefix = String::new();
+        for part in &path {
+            for byte in part.bytes() {
+                if byte.is_ascii_alphanumeric() {
+                    prefix.push(char::from(byte));
+                } else {
+                    prefix.push_str(&format!("_{byte:02x}"));
+                }
+            }
+            prefix.push_str("__");
+        }
+        prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mun_hir_input::WithFixture;
+
+    use crate::{mock::MockDatabase, CodeGenDatabase};
+
+    #[test]
+    fn static_symbol_prefix_is_unique_per_package() {
+        let db = MockDatabase::with_files(
+            r#"
+        //- /mod.mun package:app deps:my_math
+        pub fn main() -> i32 { my_math::double(21) }
+
+        //- /foo_bar.mun package:app
+        pub fn foo() {}
+
+        //- /foo_bar/baz.mun package:app
+        pub fn baz() {}
+
+        //- /mod.mun package:my_math
+        pub fn double(a: i32) -> i32 { a * 2 }
+
+        //- /foo_bar.mun package:my_math
+        pub fn foo() {}
+        "#,
+        );
+
+        let prefixes = db
+            .module_partition()
+            .iter()
+            .map(|(_, group)| group.static_symbol_prefix(&db))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            prefixes,
+            [
+                "app__",
+                "app__foo_5fbar__",
+                "app__foo_5fbar__baz__",
+                "my_5fmath__",
+                "my_5fmath__foo_5fbar__",
+            ]
+        );
+    }
 }
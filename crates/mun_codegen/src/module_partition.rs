@@ -1,4 +1,4 @@
-use std::{ops::Index, sync::Arc};
+use std::{collections::BTreeMap, ops::Index, sync::Arc};
 
 use mun_hir_input::FileId;
 use rustc_hash::FxHashMap;
@@ -71,20 +71,123 @@ impl Index<ModuleGroupId> for ModulePartition {
     }
 }
 
+/// Describes how the modules of a package are grouped into assemblies.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum PartitionStrategy {
+    /// Every module is compiled into its own assembly.
+    #[default]
+    PerModule,
+
+    /// All modules whose source files are located in the same directory are
+    /// compiled into a single assembly.
+    PerDirectory,
+
+    /// All modules of a package are compiled into a single assembly.
+    SingleAssembly,
+}
+
 /// Builds a module partition from the contents of the database
 pub(crate) fn build_partition(db: &dyn CodeGenDatabase) -> Arc<ModulePartition> {
+    let strategy = db.partition_strategy();
     let mut partition = ModulePartition::default();
-    for module in mun_hir::Package::all(db)
-        .into_iter()
-        .flat_map(|package| package.modules(db))
-    {
-        let name = if module.name(db).is_some() {
-            module.full_name(db)
-        } else {
-            String::from("mod")
-        };
-
-        partition.add_group(db, ModuleGroup::new(db, name, vec![module]));
+    for package in mun_hir::Package::all(db) {
+        // Determine the module that names the group of every module. Groups are kept in
+        // a `BTreeMap` to ensure they are added in a deterministic order.
+        let mut groups: BTreeMap<mun_hir::Module, Vec<mun_hir::Module>> = BTreeMap::new();
+        for module in package.modules(db) {
+            let group = match strategy {
+                PartitionStrategy::PerModule => module,
+                PartitionStrategy::PerDirectory => module.parent(db).unwrap_or(module),
+                PartitionStrategy::SingleAssembly => package.root_module(db),
+            };
+            groups.entry(group).or_default().push(module);
+        }
+
+        for (group, modules) in groups {
+            // The root module of a dependency is named after the package
+            let name = group.full_name(db);
+            let name = if name.is_empty() {
+                String::from("mod")
+            } else {
+                name
+            };
+
+            partition.add_group(db, ModuleGroup::new(db, name, modules));
+        }
     }
     Arc::new(partition)
 }
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use mun_hir_input::WithFixture;
+
+    use super::PartitionStrategy;
+    use crate::{mock::MockDatabase, CodeGenDatabase};
+
+    fn partition(strategy: PartitionStrategy) -> String {
+        let mut db = MockDatabase::with_files(
+            r#"
+        //- /mod.mun
+        pub fn main() {}
+
+        //- /foo.mun
+        pub fn foo() {}
+
+        //- /foo/bar.mun
+        pub fn bar() {}
+
+        //- /foo/baz.mun
+        pub fn baz() {}
+
+        //- /foo/baz/qux.mun
+        pub fn qux() {}
+        "#,
+        );
+        db.set_partition_strategy(strategy);
+
+        db.module_partition()
+            .iter()
+            .map(|(_, group)| {
+                let modules = group
+                    .iter()
+                    .map(|module| module.full_name(&db))
+                    .map(|name| {
+                        if name.is_empty() {
+                            "mod".to_owned()
+                        } else {
+                            name
+                        }
+                    })
+                    .join(", ");
+                format!("{}: {}", group.relative_file_path(), modules)
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn per_module() {
+        insta::assert_snapshot!(partition(PartitionStrategy::PerModule), @r"
+            mod: mod
+            foo: foo
+            foo$bar: foo::bar
+            foo$baz: foo::baz
+            foo$baz$qux: foo::baz::qux
+            ");
+    }
+
+    #[test]
+    fn per_directory() {
+        insta::assert_snapshot!(partition(PartitionStrategy::PerDirectory), @r"
+            mod: mod, foo
+            foo: foo::bar, foo::baz
+            foo$baz: foo::baz::qux
+            ");
+    }
+
+    #[test]
+    fn single_assembly() {
+        insta::assert_snapshot!(partition(PartitionStrategy::SingleAssembly), @"mod: mod, foo, foo::bar, foo::baz, foo::baz::qux");
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     code_gen::{AssemblyBuilder, CodeGenContext},
     ir::{file::gen_file_ir, file_group::gen_file_group_ir},
     mock::MockDatabase,
-    CodeGenDatabase,
+    CodeGenDatabase, EmitKind,
 };
 
 #[test]
@@ -25,6 +25,143 @@ fn array_index_assign() {
     );
 }
 
+#[test]
+fn array_index_runtime_checks() {
+    test_snapshot_unoptimized(
+        "array_index_runtime_checks",
+        r"
+    pub fn get(a: [i32], index: usize) -> i32 {
+        a[index]
+    }
+
+    pub fn div(a: i32, b: i32) -> i32 {
+        a / b
+    }
+    ",
+    );
+}
+
+#[test]
+fn arithmetic_overflow_checks() {
+    test_snapshot_with_overflow_checks(
+        "arithmetic_overflow_checks",
+        r"
+    pub fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    pub fn sub(a: u8, b: u8) -> u8 {
+        a - b
+    }
+
+    pub fn mul_assign(a: i64, b: i64) -> i64 {
+        let c = a;
+        c *= b;
+        c
+    }
+    ",
+    );
+}
+
+#[test]
+fn negation_shift_and_division_overflow_checks() {
+    test_snapshot_with_overflow_checks(
+        "negation_shift_and_division_overflow_checks",
+        r"
+    pub fn neg(a: i32) -> i32 {
+        -a
+    }
+
+    pub fn neg_vector(a: i32x2) -> i32x2 {
+        -a
+    }
+
+    pub fn shl(a: u8, b: u8) -> u8 {
+        a << b
+    }
+
+    pub fn shr_assign(a: i64, b: i64) -> i64 {
+        let c = a;
+        c >>= b;
+        c
+    }
+
+    pub fn div(a: i16, b: i16) -> i16 {
+        a / b
+    }
+    ",
+    );
+}
+
+#[test]
+fn builtin_int_methods() {
+    test_snapshot_unoptimized(
+        "builtin_int_methods",
+        r"
+    pub fn wrapping(a: u8, b: u8) -> u8 {
+        a.wrapping_add(b).wrapping_sub(b).wrapping_mul(b)
+    }
+
+    pub fn checked(a: i32, b: i32) -> i32 {
+        a.checked_add(b).checked_sub(b).checked_mul(b)
+    }
+
+    pub fn saturating_signed(a: i16, b: i16) -> i16 {
+        a.saturating_add(b).saturating_sub(b).saturating_mul(b)
+    }
+
+    pub fn saturating_unsigned(a: u64, b: u64) -> u64 {
+        a.saturating_mul(b)
+    }
+    ",
+    );
+}
+
+#[test]
+fn array_index_without_runtime_checks() {
+    test_snapshot_without_runtime_checks(
+        "array_index_without_runtime_checks",
+        r"
+    pub fn get(a: [i32], index: usize) -> i32 {
+        a[index]
+    }
+
+    pub fn div(a: i32, b: i32) -> i32 {
+        a / b
+    }
+    ",
+    );
+}
+
+#[test]
+fn debug_info() {
+    test_snapshot_with_debug_info(
+        "debug_info",
+        r"
+    pub struct Foo {
+        a: i32,
+        next: Foo,
+    }
+
+    pub struct(value) Bar(f64, bool);
+
+    pub fn sum(values: [i64], bar: Bar) -> i64 {
+        let total = 0
+        let i = 0
+        while i < 3 {
+            total += values[i]
+            i += 1
+        }
+        total
+    }
+
+    pub fn get_a(foo: Foo) -> i32 {
+        foo.a
+    }
+    ",
+    );
+}
+
 #[test]
 fn array_index() {
     test_snapshot(
@@ -50,6 +187,57 @@ fn array_literal() {
     );
 }
 
+#[test]
+fn vector() {
+    test_snapshot_unoptimized(
+        "vector",
+        r"
+    pub fn scale(v: f32x4, s: f32) -> f32x4 {
+        v * f32x4(s, s, s, s)
+    }
+
+    pub fn main(a: f32x4, b: i32x2) -> f32 {
+        let c = scale(a, 2.0) - -a;
+        c.w = 1.0;
+        let d = (b & i32x2(1, 3)) << b;
+        c.x + c.w
+    }
+
+    pub fn div(a: i32x4, b: i32x4) -> i32x4 {
+        a / b % b
+    }
+
+    pub fn eq(a: f32x2, b: f32x2) -> bool {
+        a == b
+    }
+
+    pub fn neq(a: i32x2, b: i32x2) -> bool {
+        a != b
+    }
+    ",
+    );
+}
+
+#[test]
+fn constants() {
+    test_snapshot_unoptimized(
+        "constants",
+        r"
+    pub struct(value) Point { x: f32, y: f32 }
+
+    const LEN: usize = 2 * 2;
+    pub const ORIGIN: Point = Point { x: 1.0, y: 2.0 };
+    pub const SCALE: f32x2 = f32x2(2.0, 0.5);
+
+    pub fn main(value: i64) -> f32 {
+        let a = [value; LEN];
+        let b = ORIGIN;
+        b.x * SCALE.y
+    }
+    ",
+    );
+}
+
 #[test]
 fn multi_file() {
     test_snapshot(
@@ -68,6 +256,33 @@ fn multi_file() {
     );
 }
 
+#[test]
+fn multi_file_lto() {
+    test_snapshot_with_lto(
+        "multi_file_lto",
+        r"
+    //- /mod.mun
+    pub fn main() -> f32 {
+        math::dot(1.0, 2.0, 3.0, 4.0) + math::reloadable(2.0)
+    }
+
+    //- /math.mun
+    pub fn dot(x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+        mul(x1, x2) + mul(y1, y2)
+    }
+
+    fn mul(a: f32, b: f32) -> f32 {
+        a * b
+    }
+
+    #[hot_reload]
+    pub fn reloadable(a: f32) -> f32 {
+        a
+    }
+    ",
+    );
+}
+
 #[test]
 fn issue_262() {
     test_snapshot(
@@ -967,6 +1182,63 @@ fn incremental_compilation() {
     // TODO: Add support for multiple files in a group
 }
 
+#[test]
+fn cross_compile_object_files() {
+    const ELF_MAGIC: &[u8] = b"\x7fELF\x02\x01";
+    const WASM_MAGIC: &[u8] = b"\0asm\x01\0\0\0";
+    const EM_X86_64: u16 = 62;
+    const EM_AARCH64: u16 = 183;
+
+    let targets: [(&str, &[u8], Option<u16>); 5] = [
+        ("x86_64-unknown-linux-gnu", ELF_MAGIC, Some(EM_X86_64)),
+        ("x86_64-unknown-linux-musl", ELF_MAGIC, Some(EM_X86_64)),
+        ("aarch64-unknown-linux-gnu", ELF_MAGIC, Some(EM_AARCH64)),
+        ("aarch64-unknown-linux-musl", ELF_MAGIC, Some(EM_AARCH64)),
+        ("wasm32-unknown-unknown", WASM_MAGIC, None),
+    ];
+
+    for (triple, magic, machine) in targets {
+        let (mut db, file_id) = MockDatabase::with_single_file(
+            r#"
+            pub struct Foo { a: i64, b: f64 }
+
+            pub fn new_foo(a: i64) -> Foo {
+                Foo { a, b: 2.0 }
+            }
+
+            pub fn sum(values: [i64], foo: Foo) -> i64 {
+                values[0] + values[1] + foo.a
+            }
+            "#,
+        );
+        db.set_optimization_level(OptimizationLevel::Default);
+        db.set_target(Target::search(triple).unwrap());
+
+        let module_group_id = db
+            .module_partition()
+            .group_for_file(file_id)
+            .expect("could not find ModuleGroupId for file");
+        let artifacts =
+            db.assembly_artifacts(module_group_id, vec![EmitKind::Object, EmitKind::LlvmIr]);
+
+        let ir = std::fs::read_to_string(artifacts.path(EmitKind::LlvmIr).unwrap()).unwrap();
+        assert!(
+            ir.contains(&format!("target triple = \"{triple}\"")),
+            "{triple}: {ir}"
+        );
+
+        let object = std::fs::read(artifacts.path(EmitKind::Object).unwrap()).unwrap();
+        assert!(object.starts_with(magic), "{triple}");
+        if let Some(machine) = machine {
+            assert_eq!(
+                u16::from_le_bytes([object[18], object[19]]),
+                machine,
+                "{triple}"
+            );
+        }
+    }
+}
+
 #[test]
 fn nested_structs() {
     test_snapshot(
@@ -1077,9 +1349,43 @@ fn test_snapshot_unoptimized(name: &str, text: &str) {
     test_snapshot_with_optimization(name, text, OptimizationLevel::None);
 }
 
+fn test_snapshot_without_runtime_checks(name: &str, text: &str) {
+    test_snapshot_with_config(name, text, OptimizationLevel::None, |db| {
+        db.set_runtime_checks(false);
+    });
+}
+
+fn test_snapshot_with_overflow_checks(name: &str, text: &str) {
+    test_snapshot_with_config(name, text, OptimizationLevel::None, |db| {
+        db.set_overflow_checks(true);
+    });
+}
+
+fn test_snapshot_with_lto(name: &str, text: &str) {
+    test_snapshot_with_config(name, text, OptimizationLevel::None, |db| {
+        db.set_lto(true);
+    });
+}
+
+fn test_snapshot_with_debug_info(name: &str, text: &str) {
+    test_snapshot_with_config(name, text, OptimizationLevel::None, |db| {
+        db.set_debug_info(true);
+    });
+}
+
 fn test_snapshot_with_optimization(name: &str, text: &str, opt: OptimizationLevel) {
+    test_snapshot_with_config(name, text, opt, |_| {});
+}
+
+fn test_snapshot_with_config(
+    name: &str,
+    text: &str,
+    opt: OptimizationLevel,
+    configure: impl FnOnce(&mut MockDatabase),
+) {
     let mut db = MockDatabase::with_files(text);
     db.set_optimization_level(opt);
+    configure(&mut db);
     db.set_target(Target::host_target().unwrap());
 
     // Build and extra diagnostics
@@ -99,6 +99,7 @@ fn test_abi_compatibility() {
             )),
           ),
         ],
+        constants: [],
       ),
       dispatch_table: DispatchTable(
         prototypes: [
@@ -177,3 +178,62 @@ fn test_abi_compatibility() {
     )
     "#);
 }
+
+#[test]
+fn test_abi_constants() {
+    let driver = CompileTestDriver::from_file(
+        r#"
+    pub struct(value) Point { x: f32, y: f32 }
+
+    pub const MAX_SPEED: f32 = 10.0 * 2.0;
+    pub const LIVES: i32 = 3;
+    pub const DEBUG: bool = !false;
+    pub const ORIGIN: Point = Point { x: 1.0, y: -1.0 };
+    const PRIVATE: i32 = 1;
+
+    pub fn lives() -> i32 { LIVES + PRIVATE }
+    "#,
+    );
+
+    // Safety: We compiled the code ourselves, therefor loading the library is safe
+    let lib = unsafe { MunLibrary::new(driver.lib_path()) }
+        .expect("Failed to load generated Mun library.");
+    let info = unsafe { lib.get_info() };
+
+    // Only public constants are exposed
+    let constants = info.symbols.constants();
+    insta::assert_ron_snapshot!(constants, @r#"
+    [
+      ConstantDefinition(
+        name: "DEBUG",
+        type_id: Concrete("7882816d-2074-3703-cbf8-6b812d3bc984"),
+      ),
+      ConstantDefinition(
+        name: "LIVES",
+        type_id: Concrete("17797a74-19d6-3217-d235-954317885bfa"),
+      ),
+      ConstantDefinition(
+        name: "MAX_SPEED",
+        type_id: Concrete("50196237-a86b-f281-50fb-83f550b08221"),
+      ),
+      ConstantDefinition(
+        name: "ORIGIN",
+        type_id: Concrete("59b2ba22-7193-ecc8-ec99-aabce268904e"),
+      ),
+    ]
+    "#);
+
+    let value = |name: &str| {
+        constants
+            .iter()
+            .find(|constant| constant.name() == name)
+            .unwrap_or_else(|| panic!("constant `{name}` is not exposed"))
+            .value
+    };
+    unsafe {
+        assert_eq!(*value("MAX_SPEED").cast::<f32>(), 20.0);
+        assert_eq!(*value("LIVES").cast::<i32>(), 3);
+        assert!(*value("DEBUG").cast::<bool>());
+        assert_eq!(*value("ORIGIN").cast::<[f32; 2]>(), [1.0, -1.0]);
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(target_os = "linux")]
+
+use std::ffi::c_void;
+
+use mun_abi as abi;
+use mun_codegen::EmitKind;
+use mun_libloader::TempLibrary;
+use mun_runtime::{Runtime, StaticAssembly};
+use mun_test::CompileTestDriver;
+
+/// Emits a static library, links it into a shared object the way a host
+/// binary would, and registers the exported assembly with the runtime.
+#[test]
+fn static_library_is_linkable() {
+    let driver = CompileTestDriver::from_file_with_config(
+        r#"
+    pub fn fibonacci(n: i64) -> i64 {
+        if n <= 1 { n } else { fibonacci(n - 1) + fibonacci(n - 2) }
+    }
+
+    pub fn main() -> i64 { fibonacci(10) }
+    "#,
+        |mut config| {
+            config.emit = vec![EmitKind::StaticLib];
+            config
+        },
+    );
+
+    let archive_path = driver
+        .lib_path()
+        .with_extension(EmitKind::StaticLib.extension());
+    assert!(!driver.lib_path().exists());
+
+    // Only the prefixed entry points may be pulled in by a linker
+    let shared_object_path = archive_path.with_extension("so");
+    let args = [
+        "--shared",
+        "--whole-archive",
+        archive_path.to_str().unwrap(),
+        "--no-whole-archive",
+        "-o",
+        shared_object_path.to_str().unwrap(),
+    ]
+    .map(String::from);
+    lld_rs::link(lld_rs::LldFlavor::Elf, &args)
+        .ok()
+        .expect("could not link static library");
+
+    // Safety: We compiled the code ourselves, so loading the library is safe and
+    // the symbols have these signatures
+    let library = unsafe { TempLibrary::new(&shared_object_path) }.unwrap();
+    let library = library.library();
+    let assembly = unsafe {
+        assert!(library.get::<unsafe extern "C" fn()>(b"get_info").is_err());
+        StaticAssembly {
+            get_version: *library
+                .get(format!("mod__{}", abi::GET_VERSION_FN_NAME).as_bytes())
+                .unwrap(),
+            get_info: *library
+                .get(format!("mod__{}", abi::GET_INFO_FN_NAME).as_bytes())
+                .unwrap(),
+            set_allocator_handle: *library
+                .get::<unsafe extern "C" fn(*mut c_void)>(
+                    format!("mod__{}", abi::SET_ALLOCATOR_HANDLE_FN_NAME).as_bytes(),
+                )
+                .unwrap(),
+        }
+    };
+
+    // Safety: The assembly was generated by the compiler
+    let runtime = unsafe {
+        Runtime::static_builder()
+            .add_static_assembly(assembly)
+            .finish()
+    }
+    .expect("could not link static assembly");
+    let result: i64 = runtime.invoke("main", ()).unwrap();
+    assert_eq!(result, 55);
+}
@@ -33,7 +33,20 @@ impl CompilerDatabase {
     pub fn set_config(&mut self, config: &Config) {
         self.set_target(config.target.clone());
         self.set_optimization_level(config.optimization_lvl);
+        self.set_partition_strategy(config.partition_strategy);
+        self.set_runtime_checks(config.runtime_checks);
+        self.set_overflow_checks(config.overflow_checks);
+        self.set_lto(config.lto);
+        self.set_debug_info(config.debug_info);
     }
 }
 
 impl salsa::Database for CompilerDatabase {}
+
+impl salsa::ParallelDatabase for CompilerDatabase {
+    fn snapshot(&self) -> salsa::Snapshot<Self> {
+        salsa::Snapshot::new(CompilerDatabase {
+            storage: self.storage.snapshot(),
+        })
+    }
+}
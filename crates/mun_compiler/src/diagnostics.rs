@@ -2,7 +2,7 @@
 mod tests {
     use std::io::Cursor;
 
-    use crate::{Config, DisplayColor, Driver, PathOrInline, RelativePathBuf};
+    use crate::{Config, DisplayColor, Driver, MessageFormat, PathOrInline, RelativePathBuf};
 
     /// Compile passed source code and return all compilation errors
     fn compilation_errors(source_code: &str) -> String {
@@ -27,6 +27,43 @@ mod tests {
         String::from_utf8(compilation_errors).unwrap()
     }
 
+    /// Compile passed source code and return all compilation errors as
+    /// pretty-printed JSON records
+    fn json_compilation_errors(source_code: &str) -> String {
+        let input = PathOrInline::Inline {
+            rel_path: RelativePathBuf::from("main.mun"),
+            contents: source_code.to_owned(),
+        };
+
+        let (driver, _) = Driver::with_file(Config::default(), input).unwrap();
+
+        let mut compilation_errors = Vec::<u8>::new();
+        let count = driver
+            .emit_diagnostics_as(
+                MessageFormat::Json,
+                DisplayColor::Disable,
+                &mut compilation_errors,
+            )
+            .unwrap();
+        Driver::emit_build_finished(
+            MessageFormat::Json,
+            count.errors == 0,
+            count,
+            &mut compilation_errors,
+        )
+        .unwrap();
+
+        String::from_utf8(compilation_errors)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let record: serde_json::Value = serde_json::from_str(line).unwrap();
+                serde_json::to_string_pretty(&record).unwrap()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     #[test]
     fn test_syntax_error() {
         insta::assert_snapshot!(compilation_errors("\n\nfn main(\n struct Foo\n"));
@@ -107,4 +144,75 @@ mod tests {
     fn test_expected_function() {
         insta::assert_snapshot!(compilation_errors("\n\nfn foo() { let a = 3; a(); }"));
     }
+
+    #[test]
+    fn test_lint_warnings() {
+        insta::assert_snapshot!(compilation_errors(
+            "pub fn main() -> i32 {\nlet a = 3;\nreturn 5;\nhelper()\n}\n\nfn helper() -> i32 { 4 }"
+        ));
+    }
+
+    #[test]
+    fn test_json_syntax_error() {
+        insta::assert_snapshot!(json_compilation_errors("\n\nfn main(\n struct Foo\n"));
+    }
+
+    #[test]
+    fn test_json_duplicate_definition_error() {
+        insta::assert_snapshot!(json_compilation_errors("\n\nfn foo(){}\n\nfn foo(){}"));
+    }
+
+    #[test]
+    fn test_json_missing_fields_error() {
+        insta::assert_snapshot!(json_compilation_errors(
+            "struct Foo { a: i32, b: bool }\n\nfn main() {\nlet a = Foo { a: 1 };\n}"
+        ));
+    }
+
+    #[test]
+    fn test_json_lint_warning() {
+        insta::assert_snapshot!(json_compilation_errors(
+            "pub fn main() -> i32 {\nlet a = 3;\n5\n}"
+        ));
+    }
+
+    #[test]
+    fn test_json_no_errors() {
+        insta::assert_snapshot!(json_compilation_errors("pub fn main() -> i32 { 5 }"));
+    }
+
+    /// Verifies that the erroneous example in the explanation of every code
+    /// reports a diagnostic with that code and that the corrected example
+    /// compiles without errors.
+    #[test]
+    fn test_explanation_examples() {
+        for code in mun_diagnostics::DiagnosticCode::all() {
+            let examples = code
+                .explanation()
+                .split("```mun\n")
+                .skip(1)
+                .map(|block| block.split("```").next().unwrap())
+                .collect::<Vec<_>>();
+            let (erroneous, corrected) = (examples[0], examples[examples.len() - 1]);
+
+            // Examples that span multiple files or packages cannot be compiled
+            // from a single file.
+            if erroneous.contains("// ") {
+                continue;
+            }
+
+            let errors = json_compilation_errors(erroneous);
+            assert!(
+                errors.contains(&format!("\"code\": \"{code}\"")),
+                "the example of {code} does not report {code}:\n{errors}"
+            );
+
+            // Lints, like unused variables, are allowed in the corrected example
+            let errors = json_compilation_errors(corrected);
+            assert!(
+                !errors.contains("\"severity\": \"error\""),
+                "the corrected example of {code} reports errors:\n{errors}"
+            );
+        }
+    }
 }
@@ -0,0 +1,230 @@
+//! Emits diagnostics as machine-readable JSON records. Every record is written
+//! on a single line so consumers can process the output line by line.
+
+use mun_diagnostics::DiagnosticForWith;
+use mun_hir::{diagnostics::Severity, HirDatabase};
+use mun_hir_input::{FileId, LineIndex};
+use mun_syntax::{SyntaxError, TextRange};
+use serde_derive::Serialize;
+
+/// A single record of the JSON output.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum Message {
+    /// A diagnostic reported by the compiler
+    Diagnostic(JsonDiagnostic),
+
+    /// The final record of a build
+    BuildFinished {
+        success: bool,
+        error_count: usize,
+        warning_count: usize,
+    },
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic {
+    /// A unique code that identifies the kind of diagnostic, or the name of
+    /// the lint that was violated
+    code: Option<String>,
+    severity: &'static str,
+    message: String,
+
+    /// The file that contains the primary span
+    file: String,
+    spans: Vec<JsonSpan>,
+    notes: Vec<String>,
+    suggestions: Vec<JsonSuggestion>,
+}
+
+/// A location in a source file. Lines and columns are one-based, columns are
+/// counted in UTF-16 code units.
+#[derive(Serialize)]
+struct JsonLocation {
+    file: String,
+    byte_start: u32,
+    byte_end: u32,
+    line_start: u32,
+    column_start: u32,
+    line_end: u32,
+    column_end: u32,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    #[serde(flatten)]
+    location: JsonLocation,
+    is_primary: bool,
+    label: Option<String>,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion {
+    message: String,
+    edits: Vec<JsonEdit>,
+}
+
+#[derive(Serialize)]
+struct JsonEdit {
+    #[serde(flatten)]
+    location: JsonLocation,
+    replacement: String,
+}
+
+impl JsonLocation {
+    fn new(file: &str, line_index: &LineIndex, range: TextRange) -> Self {
+        let start = line_index.line_col(range.start());
+        let end = line_index.line_col(range.end());
+        JsonLocation {
+            file: file.to_owned(),
+            byte_start: range.start().into(),
+            byte_end: range.end().into(),
+            line_start: start.line + 1,
+            column_start: start.col_utf16 + 1,
+            line_end: end.line + 1,
+            column_end: end.col_utf16 + 1,
+        }
+    }
+
+    fn in_file(db: &impl HirDatabase, file_id: FileId, range: TextRange) -> Self {
+        Self::new(
+            db.file_relative_path(file_id).as_str(),
+            &db.line_index(file_id),
+            range,
+        )
+    }
+}
+
+/// Writes a single record followed by a newline to the output stream.
+fn write_message(message: &Message, writer: &mut dyn std::io::Write) -> std::io::Result<()> {
+    serde_json::to_writer(&mut *writer, message)?;
+    writeln!(writer)
+}
+
+/// Writes the specified syntax error as a JSON record to the output stream.
+pub(crate) fn emit_syntax_error(
+    syntax_error: &SyntaxError,
+    relative_file_path: &str,
+    line_index: &LineIndex,
+    writer: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    let location = syntax_error.location();
+    let range = TextRange::new(location.offset(), location.end_offset());
+    let message = syntax_error.to_string();
+    write_message(
+        &Message::Diagnostic(JsonDiagnostic {
+            code: Some(mun_diagnostics::codes::SYNTAX_ERROR.to_string()),
+            severity: "error",
+            message: format!("syntax error: {message}"),
+            file: relative_file_path.to_owned(),
+            spans: vec![JsonSpan {
+                location: JsonLocation::new(relative_file_path, line_index, range),
+                is_primary: true,
+                label: Some(message),
+            }],
+            notes: Vec::new(),
+            suggestions: Vec::new(),
+        }),
+        writer,
+    )
+}
+
+/// Writes a diagnostic that is the result of HIR validation as a JSON record
+/// to the output stream. Fixes that are available for the diagnostic are
+/// included as suggestions.
+pub(crate) fn emit_hir_diagnostic(
+    diagnostic: &dyn mun_hir::Diagnostic,
+    db: &impl HirDatabase,
+    file_id: FileId,
+    writer: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    let suggestions = mun_diagnostics::fix(db, diagnostic)
+        .into_iter()
+        .map(|fix| JsonSuggestion {
+            message: fix.label,
+            edits: fix
+                .source_change
+                .source_file_edits
+                .into_iter()
+                .flat_map(|file_edit| {
+                    file_edit.edit.into_iter().map(move |indel| JsonEdit {
+                        location: JsonLocation::in_file(db, file_edit.file_id, indel.delete),
+                        replacement: indel.insert,
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+
+    let message = diagnostic.with_diagnostic(db, |diagnostic| {
+        let title = diagnostic.title();
+        let primary = diagnostic.primary_annotation();
+        let mut spans = vec![JsonSpan {
+            location: JsonLocation::in_file(
+                db,
+                file_id,
+                primary
+                    .as_ref()
+                    .map_or_else(|| diagnostic.range(), |annotation| annotation.range),
+            ),
+            is_primary: true,
+            label: primary.map(|annotation| annotation.message),
+        }];
+        spans.extend(
+            diagnostic
+                .secondary_annotations()
+                .into_iter()
+                .map(|annotation| JsonSpan {
+                    location: JsonLocation::in_file(
+                        db,
+                        annotation.range.file_id,
+                        annotation.range.value,
+                    ),
+                    is_primary: false,
+                    label: Some(annotation.message),
+                }),
+        );
+
+        JsonDiagnostic {
+            code: diagnostic
+                .code()
+                .map(|code| code.to_string())
+                .or_else(|| diagnostic.lint().map(|lint| lint.name.to_owned())),
+            severity: match diagnostic.severity() {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            message: title,
+            file: db.file_relative_path(file_id).to_string(),
+            spans,
+            notes: diagnostic.footer(),
+            suggestions: Vec::new(),
+        }
+    });
+
+    write_message(
+        &Message::Diagnostic(JsonDiagnostic {
+            suggestions,
+            ..message
+        }),
+        writer,
+    )
+}
+
+/// Writes the record that summarizes the result of a build to the output
+/// stream.
+pub(crate) fn emit_build_finished(
+    success: bool,
+    error_count: usize,
+    warning_count: usize,
+    writer: &mut dyn std::io::Write,
+) -> std::io::Result<()> {
+    write_message(
+        &Message::BuildFinished {
+            success,
+            error_count,
+            warning_count,
+        },
+        writer,
+    )
+}
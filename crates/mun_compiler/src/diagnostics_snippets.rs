@@ -1,8 +1,8 @@
 use std::{collections::HashMap, sync::Arc};
 
 use annotate_snippets::{Annotation, AnnotationType, Renderer, Slice, Snippet, SourceAnnotation};
-use mun_diagnostics::DiagnosticForWith;
-use mun_hir::HirDatabase;
+use mun_diagnostics::{DiagnosticCode, DiagnosticForWith};
+use mun_hir::{diagnostics::Severity, HirDatabase};
 use mun_hir_input::{FileId, LineIndex};
 use mun_paths::RelativePathBuf;
 use mun_syntax::SyntaxError;
@@ -17,13 +17,14 @@ pub(crate) fn emit_syntax_error(
     writer: &mut dyn std::io::Write,
 ) -> std::io::Result<()> {
     let syntax_error_text = syntax_error.to_string();
+    let code = mun_diagnostics::codes::SYNTAX_ERROR;
     let location = syntax_error.location();
     let line = line_index.line_col(location.offset()).line;
     let line_offset = line_index.line_offset(line);
 
     let snippet = Snippet {
         title: Some(Annotation {
-            id: None,
+            id: Some(code.as_str()),
             label: Some("syntax error"),
             annotation_type: AnnotationType::Error,
         }),
@@ -50,7 +51,7 @@ pub(crate) fn emit_syntax_error(
         Renderer::plain()
     };
     let display = renderer.render(snippet);
-    write!(writer, "{display}")
+    writeln!(writer, "{display}")
 }
 
 /// Emits all diagnostics that are a result of HIR validation.
@@ -83,6 +84,7 @@ fn emit_diagnostic(
     }
 
     // Get the basic info from the diagnostic
+    let code = diagnostic.code();
     let title = diagnostic.title();
     let range = diagnostic.range();
 
@@ -134,13 +136,17 @@ fn emit_diagnostic(
     };
 
     let footer = diagnostic.footer();
+    let annotation_type = match diagnostic.severity() {
+        Severity::Error => AnnotationType::Error,
+        Severity::Warning => AnnotationType::Warning,
+    };
 
     // Construct an annotation snippet to be able to emit it.
     let snippet = Snippet {
         title: Some(Annotation {
-            id: None,
+            id: code.map(DiagnosticCode::as_str),
             label: Some(&title),
-            annotation_type: AnnotationType::Error,
+            annotation_type,
         }),
         slices: annotations
             .iter()
@@ -170,7 +176,7 @@ fn emit_diagnostic(
                                 usize::from(annotation.range.end()) - line_offset,
                             ),
                             label: annotation.message.as_str(),
-                            annotation_type: AnnotationType::Error,
+                            annotation_type,
                         })
                         .collect(),
                     fold: true,
@@ -194,5 +200,5 @@ fn emit_diagnostic(
         Renderer::plain()
     };
     let display = renderer.render(snippet);
-    write!(writer, "{display}")
+    writeln!(writer, "{display}")
 }
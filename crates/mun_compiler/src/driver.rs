@@ -1,48 +1,89 @@
 //! `Driver` is a stateful compiler frontend that enables incremental
 //! compilation by retaining state from previous compilation.
 
-use mun_codegen::{AssemblyIr, CodeGenDatabase, ModuleGroup, TargetAssembly};
-use mun_hir::{AstDatabase, DiagnosticSink, Module};
-use mun_hir_input::{FileId, PackageSet, SourceDatabase, SourceRoot, SourceRootId};
+use mun_codegen::{CodeGenDatabase, Fingerprint, ModuleGroup, ModuleGroupId, TargetAssembly};
+use mun_hir::{
+    diagnostics::Severity, salsa::ParallelDatabase, AstDatabase, DiagnosticSink, Module,
+};
+use mun_hir_input::{
+    FileId, LintLevel, PackageId, PackageSet, SourceDatabase, SourceRoot, SourceRootId,
+};
 use mun_paths::RelativePathBuf;
 
 use crate::{
-    compute_source_relative_path, db::CompilerDatabase, ensure_package_output_dir, is_source_file,
-    PathOrInline, RelativePath,
+    compute_source_relative_path, db::CompilerDatabase, ensure_package_output_dir,
+    ensure_workspace_output_dir, is_source_file, PathOrInline, RelativePath,
 };
 
 mod config;
 mod display_color;
+mod message_format;
 
 use std::{
     collections::HashMap,
     convert::TryInto,
     io::Cursor,
+    num::NonZeroUsize,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 
-use mun_project::{Package, LOCKFILE_NAME};
+use mun_project::{Package, Workspace, LOCKFILE_NAME};
 use walkdir::WalkDir;
 
-pub use self::{config::Config, display_color::DisplayColor};
-use crate::diagnostics_snippets::{emit_hir_diagnostic, emit_syntax_error};
+pub use self::{
+    config::{optimization_level, Config, EmitKind},
+    display_color::DisplayColor,
+    message_format::MessageFormat,
+};
+use crate::{diagnostics_json, diagnostics_snippets};
 
 pub const WORKSPACE: SourceRootId = SourceRootId(0);
 
+/// The directory, relative to the output directory, in which the assemblies of
+/// previous builds are cached together with their fingerprints.
+pub const INCREMENTAL_CACHE_DIR: &str = "incremental";
+
+/// The extension of the files that store the fingerprint of a cached assembly.
+const FINGERPRINT_EXTENSION: &str = "fingerprint";
+
+/// The number of diagnostics that were emitted, by severity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCount {
+    pub errors: usize,
+    pub warnings: usize,
+}
+
 pub struct Driver {
     db: CompilerDatabase,
     out_dir: PathBuf,
 
+    /// The output directories of the packages that are built by this driver,
+    /// if they differ from `out_dir`. Assemblies of dependencies are written
+    /// to the output directories of all packages that depend on them.
+    package_out_dirs: Vec<(PackageId, PathBuf)>,
+
+    /// The source directories of the loaded dependencies together with the
+    /// source roots in which their files are stored.
+    dependency_source_roots: Vec<(PathBuf, SourceRootId)>,
+
     source_root: SourceRoot,
     path_to_file_id: HashMap<RelativePathBuf, FileId>,
     file_id_to_path: HashMap<FileId, RelativePathBuf>,
     next_file_id: usize,
 
-    module_to_temp_assembly_path: HashMap<Module, PathBuf>,
+    group_to_temp_assembly_path: HashMap<String, PathBuf>,
 
-    emit_ir: bool,
+    /// Whether a munlib is emitted for every module group
+    emit_munlib: bool,
+
+    /// The kinds of artifacts, other than munlibs, that are emitted for every
+    /// module group
+    emit_artifacts: Vec<EmitKind>,
 }
 
 impl Driver {
@@ -51,12 +92,15 @@ impl Driver {
         Self {
             db: CompilerDatabase::new(&config),
             out_dir,
+            package_out_dirs: Vec::new(),
+            dependency_source_roots: Vec::new(),
             source_root: SourceRoot::default(),
             path_to_file_id: HashMap::default(),
             file_id_to_path: HashMap::default(),
             next_file_id: 0,
-            module_to_temp_assembly_path: HashMap::default(),
-            emit_ir: config.emit_ir,
+            group_to_temp_assembly_path: HashMap::default(),
+            emit_munlib: config.emit.contains(&EmitKind::Munlib),
+            emit_artifacts: emit_artifacts(&config.emit),
         }
     }
 
@@ -120,15 +164,7 @@ impl Driver {
 
         for source_file_path in iter_source_files(&source_directory) {
             let relative_path = compute_source_relative_path(&source_directory, &source_file_path)?;
-
-            // Load the contents of the file
-            let file_contents = std::fs::read_to_string(&source_file_path).map_err(|e| {
-                anyhow::anyhow!(
-                    "could not read contents of '{}': {}",
-                    source_file_path.display(),
-                    e
-                )
-            })?;
+            let file_contents = read_source_file(&source_file_path)?;
 
             let file_id = driver.alloc_file_id(&relative_path)?;
             driver.db.set_file_text(file_id, Arc::from(file_contents));
@@ -144,11 +180,312 @@ impl Driver {
             .set_source_root(WORKSPACE, Arc::new(driver.source_root.clone()));
 
         let mut package_set = PackageSet::default();
-        package_set.add_package(WORKSPACE);
+        let package_id = package_set.add_package(WORKSPACE);
+        package_set.set_name(package_id, package.name());
+        set_lint_levels(&package, package_id, &mut package_set)?;
+        package_set.set_source_directory(package_id, source_directory);
+        driver.load_dependencies(
+            &package,
+            package_id,
+            &mut package_set,
+            &mut HashMap::default(),
+            &mut vec![canonical_manifest_path(&package)?],
+        )?;
         driver.db.set_packages(Arc::new(package_set));
 
         Ok((package, driver))
     }
+
+    /// Constructs a driver that compiles all members of the workspace with the
+    /// specified manifest in a single database. Every member is written to a
+    /// subdirectory of the shared output directory of the workspace, named
+    /// after the member, together with the assemblies of its dependencies.
+    ///
+    /// The source files of the members are not tracked by path, changes to
+    /// them are only picked up when a new driver is constructed.
+    pub fn with_workspace_path<P: AsRef<Path>>(
+        workspace_path: P,
+        config: Config,
+    ) -> Result<(Workspace, Driver), anyhow::Error> {
+        let workspace = Workspace::from_file(workspace_path)?;
+
+        // Determine output directory
+        let output_dir = ensure_workspace_output_dir(&workspace, &config)
+            .map_err(|e| anyhow::anyhow!("could not create workspace output directory: {}", e))?;
+
+        // Construct the driver
+        let mut driver = Driver::with_config(config, output_dir.clone());
+
+        // Load the members first to ensure their source roots and packages have the
+        // same index as the member
+        let mut package_set = PackageSet::default();
+        let mut member_ids = Vec::with_capacity(workspace.members().len());
+        for member in workspace.members() {
+            if !member.source_directory().is_dir() {
+                anyhow::bail!(
+                    "the source directory of workspace member `{}` does not exist",
+                    member.name()
+                );
+            }
+            let source_root_id = next_source_root_id(&package_set)?;
+            driver.load_source_root(member, source_root_id)?;
+            let package_id = package_set.add_package(source_root_id);
+            set_lint_levels(member, package_id, &mut package_set)?;
+            package_set.set_name(package_id, member.name());
+            package_set.set_source_directory(package_id, member.source_directory());
+
+            let member_output_dir = output_dir.join(member.name());
+            std::fs::create_dir_all(&member_output_dir)
+                .map_err(|e| anyhow::anyhow!("could not create member output directory: {}", e))?;
+            driver
+                .package_out_dirs
+                .push((package_id, member_output_dir));
+            member_ids.push(package_id);
+        }
+
+        // Dependencies that are shared between members are only loaded once. Members
+        // that other members depend on are not loaded again.
+        let mut loaded = workspace
+            .members()
+            .iter()
+            .zip(member_ids.iter())
+            .map(|(member, &package_id)| Ok((canonical_manifest_path(member)?, package_id)))
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+        for (member, package_id) in workspace.members().iter().zip(member_ids) {
+            driver.load_dependencies(
+                member,
+                package_id,
+                &mut package_set,
+                &mut loaded,
+                &mut vec![canonical_manifest_path(member)?],
+            )?;
+        }
+        driver.db.set_packages(Arc::new(package_set));
+
+        Ok((workspace, driver))
+    }
+
+    /// Loads all the source files of the specified package into the database
+    /// as the source root with the given id. The files are not tracked by
+    /// path.
+    fn load_source_root(
+        &mut self,
+        package: &Package,
+        source_root_id: SourceRootId,
+    ) -> anyhow::Result<()> {
+        self.read_source_root(
+            &package.source_directory(),
+            source_root_id,
+            &SourceRoot::default(),
+        )
+    }
+
+    /// Reads all the source files in `source_directory` into the database as
+    /// the source root with the given id. Files that are also part of the
+    /// `previous` contents of the source root keep their `FileId`, to keep the
+    /// cache as valid as possible.
+    fn read_source_root(
+        &mut self,
+        source_directory: &Path,
+        source_root_id: SourceRootId,
+        previous: &SourceRoot,
+    ) -> anyhow::Result<()> {
+        let previous_file_ids = previous
+            .files()
+            .map(|file_id| {
+                (
+                    previous.relative_path(file_id).to_relative_path_buf(),
+                    file_id,
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut source_root = SourceRoot::default();
+        for source_file_path in iter_source_files(source_directory) {
+            let relative_path = compute_source_relative_path(source_directory, &source_file_path)?;
+            let file_contents = read_source_file(&source_file_path)?;
+
+            let file_id = if let Some(&file_id) = previous_file_ids.get(&relative_path) {
+                if *self.db.file_text(file_id) == *file_contents {
+                    source_root.insert_file(file_id, relative_path);
+                    continue;
+                }
+                file_id
+            } else {
+                let file_id = FileId(
+                    self.next_file_id
+                        .try_into()
+                        .map_err(|_e| anyhow::anyhow!("too many active source files"))?,
+                );
+                self.next_file_id += 1;
+                file_id
+            };
+            self.db.set_file_text(file_id, Arc::from(file_contents));
+            self.db.set_file_source_root(file_id, source_root_id);
+            source_root.insert_file(file_id, relative_path);
+        }
+        self.db
+            .set_source_root(source_root_id, Arc::new(source_root));
+        Ok(())
+    }
+
+    /// Loads the source files of the dependencies of `package` into the
+    /// database, each dependency in its own source root, and records them in
+    /// the `package_set`. Dependencies are loaded recursively; `loaded` maps
+    /// the manifest paths of packages that were already loaded to their id.
+    /// `stack` contains the manifest paths of the packages that are currently
+    /// being loaded, to detect cyclic dependencies.
+    ///
+    /// The source files of dependencies are not tracked by path, changes to
+    /// them are picked up by [`Driver::reload_dependency_containing`].
+    fn load_dependencies(
+        &mut self,
+        package: &Package,
+        package_id: PackageId,
+        package_set: &mut PackageSet,
+        loaded: &mut HashMap<PathBuf, PackageId>,
+        stack: &mut Vec<PathBuf>,
+    ) -> anyhow::Result<()> {
+        for (name, dependency) in package.dependencies()? {
+            let manifest_path = canonical_manifest_path(&dependency)?;
+            if stack.contains(&manifest_path) {
+                anyhow::bail!(
+                    "cyclic dependency detected: package `{}` depends on `{}`, which in turn depends on `{}`",
+                    package.name(),
+                    dependency.name(),
+                    package.name()
+                );
+            }
+
+            if let Some(&dependency_id) = loaded.get(&manifest_path) {
+                // Workspace members are loaded before their dependencies, so a cycle
+                // between members is not on the stack.
+                if depends_on(package_set, dependency_id, package_id) {
+                    anyhow::bail!(
+                        "cyclic dependency detected: package `{}` depends on `{}`, which in turn depends on `{}`",
+                        package.name(),
+                        dependency.name(),
+                        package.name()
+                    );
+                }
+                package_set.add_dependency(package_id, dependency_id, name);
+                continue;
+            }
+
+            // The name of a dependency qualifies all its symbols, so it has to uniquely
+            // identify a package.
+            if package_set
+                .iter()
+                .any(|id| package_set[id].name.as_deref() == Some(dependency.name()))
+            {
+                anyhow::bail!(
+                    "multiple packages are named `{}`, which is the name of dependency `{}`",
+                    dependency.name(),
+                    name
+                );
+            }
+
+            if !dependency.source_directory().is_dir() {
+                anyhow::bail!(
+                    "the source directory of dependency `{}` does not exist",
+                    name
+                );
+            }
+            let source_root_id = next_source_root_id(package_set)?;
+            self.load_source_root(&dependency, source_root_id)?;
+
+            // The path of a dependency is relative to the package that depends on it,
+            // so it is resolved to compare it to the paths of changed files
+            let source_directory = dependency.source_directory().canonicalize().map_err(|e| {
+                anyhow::anyhow!(
+                    "could not resolve path '{}': {}",
+                    dependency.source_directory().display(),
+                    e
+                )
+            })?;
+            self.dependency_source_roots
+                .push((source_directory, source_root_id));
+
+            let dependency_id = package_set.add_package(source_root_id);
+            set_lint_levels(&dependency, dependency_id, package_set)?;
+            package_set.set_name(dependency_id, dependency.name());
+            package_set.set_source_directory(dependency_id, dependency.source_directory());
+            package_set.add_dependency(package_id, dependency_id, name);
+            loaded.insert(manifest_path.clone(), dependency_id);
+
+            stack.push(manifest_path);
+            self.load_dependencies(&dependency, dependency_id, package_set, loaded, stack)?;
+            stack.pop();
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies the lint levels that are configured in the manifest of `package` to
+/// the package with the specified id.
+fn set_lint_levels(
+    package: &Package,
+    package_id: PackageId,
+    package_set: &mut PackageSet,
+) -> anyhow::Result<()> {
+    for (name, level) in package.manifest().lints() {
+        if mun_hir::lint::Lint::find(name).is_none() {
+            anyhow::bail!(
+                "unknown lint `{}` in the manifest of package `{}`",
+                name,
+                package.name()
+            );
+        }
+
+        let level = match level {
+            mun_project::LintLevel::Allow => LintLevel::Allow,
+            mun_project::LintLevel::Warn => LintLevel::Warn,
+            mun_project::LintLevel::Deny => LintLevel::Deny,
+        };
+        package_set.set_lint_level(package_id, name.clone(), level);
+    }
+    Ok(())
+}
+
+/// Returns true if the package `from` is the package `to` or directly or
+/// indirectly depends on it.
+fn depends_on(packages: &PackageSet, from: PackageId, to: PackageId) -> bool {
+    from == to
+        || packages[from]
+            .dependencies
+            .iter()
+            .any(|dependency| depends_on(packages, dependency.package, to))
+}
+
+/// Returns the id of the source root of the next package that is added to the
+/// `package_set`.
+fn next_source_root_id(package_set: &PackageSet) -> anyhow::Result<SourceRootId> {
+    Ok(SourceRootId(
+        package_set
+            .iter()
+            .count()
+            .try_into()
+            .map_err(|_e| anyhow::anyhow!("too many packages"))?,
+    ))
+}
+
+/// Reads the contents of the source file at the specified path.
+fn read_source_file(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("could not read contents of '{}': {}", path.display(), e))
+}
+
+/// Returns the canonicalized path of the manifest of the specified package,
+/// which uniquely identifies the package.
+fn canonical_manifest_path(package: &Package) -> anyhow::Result<PathBuf> {
+    package.manifest_path().canonicalize().map_err(|e| {
+        anyhow::anyhow!(
+            "could not resolve path '{}': {}",
+            package.manifest_path().display(),
+            e
+        )
+    })
 }
 
 impl Driver {
@@ -205,15 +542,27 @@ impl Driver {
 }
 
 impl Driver {
-    /// Emits all diagnostic messages currently in the database; returns true if
-    /// errors were emitted.
+    /// Emits all diagnostic messages currently in the database as human
+    /// readable snippets; returns true if errors were emitted.
     pub fn emit_diagnostics(
         &self,
         writer: &mut dyn std::io::Write,
         display_color: DisplayColor,
     ) -> Result<bool, anyhow::Error> {
+        self.emit_diagnostics_as(MessageFormat::Human, display_color, writer)
+            .map(|count| count.errors > 0)
+    }
+
+    /// Emits all diagnostics in the specified format to the `writer`. Returns
+    /// the number of errors and warnings that were emitted.
+    pub fn emit_diagnostics_as(
+        &self,
+        message_format: MessageFormat,
+        display_color: DisplayColor,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<DiagnosticCount, anyhow::Error> {
         let emit_colors = display_color.should_enable();
-        let mut has_error = false;
+        let mut count = DiagnosticCount::default();
 
         for package in mun_hir::Package::all(&self.db) {
             for module in package.modules(&self.db) {
@@ -225,30 +574,51 @@ impl Driver {
 
                     // Emit all syntax diagnostics
                     for syntax_error in parse.errors().iter() {
-                        emit_syntax_error(
-                            syntax_error,
-                            relative_file_path.as_str(),
-                            &source_code,
-                            &line_index,
-                            emit_colors,
-                            writer,
-                        )?;
-                        has_error = true;
+                        match message_format {
+                            MessageFormat::Human => diagnostics_snippets::emit_syntax_error(
+                                syntax_error,
+                                relative_file_path.as_str(),
+                                &source_code,
+                                &line_index,
+                                emit_colors,
+                                writer,
+                            )?,
+                            MessageFormat::Json => diagnostics_json::emit_syntax_error(
+                                syntax_error,
+                                relative_file_path.as_str(),
+                                &line_index,
+                                writer,
+                            )?,
+                        }
+                        count.errors += 1;
                     }
 
-                    // Emit all HIR diagnostics
+                    // Emit all HIR diagnostics and lint violations
                     let mut error = None;
-                    module.diagnostics(
-                        &self.db,
-                        &mut DiagnosticSink::new(|d| {
-                            has_error = true;
-                            if let Err(e) =
-                                emit_hir_diagnostic(d, &self.db, file_id, emit_colors, writer)
-                            {
-                                error = Some(e);
-                            };
-                        }),
-                    );
+                    let mut sink = DiagnosticSink::new(|d| {
+                        match d.severity() {
+                            Severity::Error => count.errors += 1,
+                            Severity::Warning => count.warnings += 1,
+                        }
+                        let result = match message_format {
+                            MessageFormat::Human => diagnostics_snippets::emit_hir_diagnostic(
+                                d,
+                                &self.db,
+                                file_id,
+                                emit_colors,
+                                writer,
+                            ),
+                            MessageFormat::Json => {
+                                diagnostics_json::emit_hir_diagnostic(d, &self.db, file_id, writer)
+                            }
+                        };
+                        if let Err(e) = result {
+                            error = Some(e);
+                        };
+                    });
+                    module.diagnostics(&self.db, &mut sink);
+                    module.lints(&self.db, &mut sink);
+                    drop(sink);
 
                     // If an error occurred when emitting HIR diagnostics, return early with the
                     // error.
@@ -259,7 +629,21 @@ impl Driver {
             }
         }
 
-        Ok(has_error)
+        Ok(count)
+    }
+
+    /// Writes the record that concludes a build to the specified `writer`.
+    /// Nothing is written for human readable output.
+    pub fn emit_build_finished(
+        message_format: MessageFormat,
+        success: bool,
+        count: DiagnosticCount,
+        writer: &mut dyn std::io::Write,
+    ) -> Result<(), anyhow::Error> {
+        if message_format == MessageFormat::Json {
+            diagnostics_json::emit_build_finished(success, count.errors, count.warnings, writer)?;
+        }
+        Ok(())
     }
 
     /// Returns all diagnostics as a human readable string
@@ -300,7 +684,7 @@ impl Driver {
             .group_for_file(file_id)
             .expect("could not find file in module parition");
         self.path_for_module_group(&module_partition[module_group_id])
-            .with_extension(AssemblyIr::EXTENSION)
+            .with_extension(EmitKind::LlvmIr.extension())
     }
 
     /// Get the path where the driver will write the assembly for the specified
@@ -322,34 +706,105 @@ impl Driver {
             .group_for_module(module)
             .expect("could not find file in module parition");
         self.path_for_module_group(&module_partition[module_group_id])
-            .with_extension(AssemblyIr::EXTENSION)
+            .with_extension(EmitKind::LlvmIr.extension())
     }
 
     /// Returns the output path for the specified module group without an
-    /// extension
+    /// extension. If the group is written to multiple output directories, the
+    /// path in the first directory is returned.
     fn path_for_module_group(&self, module_group: &ModuleGroup) -> PathBuf {
-        module_group.relative_file_path().to_path(&self.out_dir)
+        self.paths_for_module_group(module_group)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| module_group.relative_file_path().to_path(&self.out_dir))
+    }
+
+    /// Returns all the output paths for the specified module group without an
+    /// extension.
+    fn paths_for_module_group(&self, module_group: &ModuleGroup) -> Vec<PathBuf> {
+        let relative_path = module_group.relative_file_path();
+        if self.package_out_dirs.is_empty() {
+            return vec![relative_path.to_path(&self.out_dir)];
+        }
+
+        let Some(package) = module_group.iter().next().map(Module::package) else {
+            return Vec::new();
+        };
+        let packages = self.db.packages();
+        self.package_out_dirs
+            .iter()
+            .filter(|(root, _)| depends_on(&packages, *root, package.id()))
+            .map(|(_, out_dir)| relative_path.to_path(out_dir))
+            .collect()
     }
 
     /// Writes all assemblies. If `force` is false, the binary will not be
     /// written if there are no changes since last time it was written.
+    ///
+    /// Assemblies of independent module groups are generated in parallel, but
+    /// they are always written in the same order.
     pub fn write_all_assemblies(&mut self, force: bool) -> Result<(), anyhow::Error> {
         let _lock = self.acquire_filesystem_output_lock();
 
+        // Generate all assemblies that are not cached
+        let module_partition = self.db.module_partition();
+        let module_groups = module_partition
+            .iter()
+            .map(|(module_group_id, _)| module_group_id)
+            .filter(|&module_group_id| {
+                !self.emit_artifacts.is_empty()
+                    || (self.emit_munlib && !self.is_cached(module_group_id))
+            })
+            .collect::<Vec<_>>();
+        let num_threads = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        self.generate_assemblies(&module_groups, num_threads);
+
         // Create a copy of all current files
-        for package in mun_hir::Package::all(&self.db) {
-            for module in package.modules(&self.db) {
-                if self.emit_ir {
-                    self.write_assembly_ir(module)?;
-                } else {
-                    self.write_target_assembly(module, force)?;
-                }
+        for (module_group_id, _) in module_partition.iter() {
+            if !self.emit_artifacts.is_empty() {
+                self.write_assembly_artifacts(module_group_id)?;
+            }
+            if self.emit_munlib {
+                self.write_target_assembly(module_group_id, force)?;
             }
         }
 
         Ok(())
     }
 
+    /// Generates the assemblies of the specified module groups on at most
+    /// `num_threads` threads. Every thread queries a snapshot of the database,
+    /// which stores the results so subsequent queries for the assemblies
+    /// return immediately.
+    fn generate_assemblies(&self, module_groups: &[ModuleGroupId], num_threads: usize) {
+        let num_threads = num_threads.min(module_groups.len());
+        if num_threads <= 1 {
+            return;
+        }
+
+        let next_group = AtomicUsize::new(0);
+        std::thread::scope(|scope| {
+            for _ in 0..num_threads {
+                let db = self.db.snapshot();
+                let next_group = &next_group;
+                let emit_munlib = self.emit_munlib;
+                let emit_artifacts = &self.emit_artifacts;
+                scope.spawn(move || {
+                    while let Some(&module_group_id) =
+                        module_groups.get(next_group.fetch_add(1, Ordering::Relaxed))
+                    {
+                        if !emit_artifacts.is_empty() {
+                            db.assembly_artifacts(module_group_id, emit_artifacts.clone());
+                        }
+                        if emit_munlib {
+                            db.target_assembly(module_group_id);
+                        }
+                    }
+                });
+            }
+        });
+    }
+
     /// Acquires a filesystem lock on the output directory. This ensures that
     /// multiple instances cannot write to the same output directory and
     /// that the runtime does not start reading before we finished writing.
@@ -375,39 +830,55 @@ impl Driver {
         }
     }
 
-    /// Generates an assembly for the target machine and specified module and
-    /// stores it in the output location. If `force` is false, the binary
+    /// Generates an assembly for the target machine and specified module group
+    /// and stores it in the output location. If `force` is false, the binary
     /// will not be written if there are no changes since last time it was
     /// written. Returns `true` if the assembly was written, `false`
     /// if it was up to date.
+    ///
+    /// If the fingerprint of the module group matches that of an assembly in
+    /// the incremental cache, the cached assembly is used instead of
+    /// generating a new one.
     fn write_target_assembly(
         &mut self,
-        module: Module,
+        module_group_id: ModuleGroupId,
         force: bool,
     ) -> Result<bool, anyhow::Error> {
-        log::trace!("writing target assembly for {:?}", module);
-
-        // Find the module group to which the module belongs
         let module_partition = self.db.module_partition();
-        let module_group_id = module_partition
-            .group_for_module(module)
-            .expect("could not find the module in the module partition");
         let module_group = &module_partition[module_group_id];
+        log::trace!("writing target assembly for {}", module_group.name);
+
+        // Determine the filenames of the group
+        let assembly_paths = self
+            .paths_for_module_group(module_group)
+            .into_iter()
+            .map(|path| path.with_extension(TargetAssembly::EXTENSION))
+            .collect::<Vec<_>>();
+
+        // Is there an assembly from a previous build with the same inputs?
+        let fingerprint = self.db.module_group_fingerprint(module_group_id);
+        let cached_assembly_path = self.cached_assembly_path(module_group);
+        if self.is_cached(module_group_id) {
+            log::trace!("reusing cached assembly for {}", module_group.name);
+            let mut written = false;
+            for assembly_path in assembly_paths {
+                if force || !have_same_contents(&cached_assembly_path, &assembly_path) {
+                    std::fs::copy(&cached_assembly_path, &assembly_path)?;
+                    written = true;
+                }
+            }
+            return Ok(written);
+        }
 
         // Get the compiled assembly
         let assembly = self.db.target_assembly(module_group_id);
 
-        // Determine the filename of the group
-        let assembly_path = self
-            .path_for_module_group(module_group)
-            .with_extension(TargetAssembly::EXTENSION);
-
         // Did the assembly change since last time?
         if !force
-            && assembly_path.is_file()
+            && assembly_paths.iter().all(|path| path.is_file())
             && self
-                .module_to_temp_assembly_path
-                .get(&module)
+                .group_to_temp_assembly_path
+                .get(&module_group.name)
                 .map(AsRef::as_ref)
                 == Some(assembly.path())
         {
@@ -415,43 +886,103 @@ impl Driver {
         }
 
         // It did change or we are forced, so write it to disk
-        assembly.copy_to(&assembly_path)?;
+        for assembly_path in assembly_paths {
+            assembly.copy_to(&assembly_path)?;
+        }
+        write_cached_assembly(&assembly, &cached_assembly_path, fingerprint)?;
 
         // Store the information so we maybe don't have to write it next time
-        self.module_to_temp_assembly_path
-            .insert(module, assembly.path().to_path_buf());
+        self.group_to_temp_assembly_path
+            .insert(module_group.name.clone(), assembly.path().to_path_buf());
 
         Ok(true)
     }
 
-    /// Generates IR for the specified module and stores it in the output
-    /// location.
-    fn write_assembly_ir(&mut self, module: mun_hir::Module) -> Result<(), anyhow::Error> {
-        log::trace!("writing assembly IR for {:?}", module);
+    /// Returns true if the incremental cache contains an assembly for the
+    /// specified module group that was generated from the same inputs.
+    fn is_cached(&self, module_group_id: ModuleGroupId) -> bool {
+        let module_partition = self.db.module_partition();
+        read_fingerprint(&self.cached_assembly_path(&module_partition[module_group_id]))
+            == Some(self.db.module_group_fingerprint(module_group_id))
+    }
+
+    /// Returns the path at which the assembly of the specified module group is
+    /// cached between builds. Groups are stored in a directory per package,
+    /// named after the package, because groups of different packages may have
+    /// the same name. The driver ensures that all packages with a manifest
+    /// have a unique name; a package without a name only occurs when a single
+    /// file is compiled, its groups are stored in the root of the cache.
+    fn cached_assembly_path(&self, module_group: &ModuleGroup) -> PathBuf {
+        let mut cache_dir = self.out_dir.join(INCREMENTAL_CACHE_DIR);
+        if let Some(name) = module_group
+            .iter()
+            .next()
+            .and_then(|module| module.package().name(&self.db))
+        {
+            cache_dir.push(name);
+        }
+
+        module_group
+            .relative_file_path()
+            .to_path(cache_dir)
+            .with_extension(TargetAssembly::EXTENSION)
+    }
 
-        // Find the module group to which the module belongs
+    /// Generates the requested artifacts, other than the munlib, for the
+    /// specified module group and stores them next to the munlib in the output
+    /// location.
+    fn write_assembly_artifacts(
+        &mut self,
+        module_group_id: ModuleGroupId,
+    ) -> Result<(), anyhow::Error> {
         let module_partition = self.db.module_partition();
-        let module_group_id = module_partition
-            .group_for_module(module)
-            .expect("could not find the module in the module partition");
         let module_group = &module_partition[module_group_id];
+        log::trace!("writing assembly artifacts for {}", module_group.name);
 
-        // Get the compiled assembly
-        let assembly_ir = self.db.assembly_ir(module_group_id);
-
-        // Determine the filename of the group
-        let assembly_path = self
-            .path_for_module_group(module_group)
-            .with_extension(AssemblyIr::EXTENSION);
+        // Get the compiled artifacts
+        let artifacts = self
+            .db
+            .assembly_artifacts(module_group_id, self.emit_artifacts.clone());
 
         // Write to disk
-        assembly_ir.copy_to(assembly_path)?;
+        for path in self.paths_for_module_group(module_group) {
+            for (kind, artifact_path) in artifacts.iter() {
+                std::fs::copy(artifact_path, path.with_extension(kind.extension()))?;
+            }
+        }
 
         Ok(())
     }
 }
 
 impl Driver {
+    /// Returns the source directories of all dependencies that were loaded
+    /// by this driver.
+    pub fn dependency_source_directories(&self) -> impl Iterator<Item = &Path> {
+        self.dependency_source_roots
+            .iter()
+            .map(|(source_directory, _)| source_directory.as_path())
+    }
+
+    /// Reloads all source files of the dependency whose source directory
+    /// contains the specified `path`, which may refer to a file that was
+    /// modified, created or removed. Returns `false` if the path is not part of
+    /// a dependency.
+    pub fn reload_dependency_containing(&mut self, path: &Path) -> anyhow::Result<bool> {
+        let Some((source_directory, source_root_id)) = self
+            .dependency_source_roots
+            .iter()
+            .find(|(source_directory, _)| path.starts_with(source_directory))
+            .cloned()
+        else {
+            return Ok(false);
+        };
+
+        let previous = self.db.source_root(source_root_id);
+        self.read_source_root(&source_directory, source_root_id, &previous)?;
+        Ok(true)
+    }
+
     /// Returns the `FileId` of the file with the given relative path
     pub fn get_file_id_for_path<P: AsRef<RelativePath>>(&self, path: P) -> Option<FileId> {
         self.path_to_file_id.get(path.as_ref()).copied()
@@ -529,6 +1060,62 @@ impl Driver {
     }
 }
 
+/// Reads the fingerprint of the cached assembly at the specified path. Returns
+/// `None` if there is no cached assembly.
+fn read_fingerprint(assembly_path: &Path) -> Option<Fingerprint> {
+    if !assembly_path.is_file() {
+        return None;
+    }
+
+    std::fs::read_to_string(assembly_path.with_extension(FINGERPRINT_EXTENSION))
+        .ok()?
+        .parse()
+        .ok()
+}
+
+/// Stores an assembly in the incremental cache. The previous fingerprint is
+/// removed before the assembly is replaced, so an interrupted write can never
+/// leave an assembly with a mismatching fingerprint.
+fn write_cached_assembly(
+    assembly: &TargetAssembly,
+    assembly_path: &Path,
+    fingerprint: Fingerprint,
+) -> Result<(), anyhow::Error> {
+    let fingerprint_path = assembly_path.with_extension(FINGERPRINT_EXTENSION);
+    if fingerprint_path.exists() {
+        std::fs::remove_file(&fingerprint_path)?;
+    }
+
+    if let Some(parent) = assembly_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    assembly.copy_to(assembly_path)?;
+    std::fs::write(fingerprint_path, fingerprint.to_string())?;
+    Ok(())
+}
+
+/// Returns the sorted, deduplicated kinds of artifacts in `emit` that are not
+/// munlibs.
+fn emit_artifacts(emit: &[EmitKind]) -> Vec<EmitKind> {
+    let mut kinds = emit
+        .iter()
+        .copied()
+        .filter(|&kind| kind != EmitKind::Munlib)
+        .collect::<Vec<_>>();
+    kinds.sort_unstable();
+    kinds.dedup();
+    kinds
+}
+
+/// Returns true if both files exist and have the same contents.
+fn have_same_contents(a: &Path, b: &Path) -> bool {
+    match (std::fs::read(a), std::fs::read(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Returns an iterator over all Mun source files in the specified directory.
 pub fn iter_source_files(source_dir: &Path) -> impl Iterator<Item = PathBuf> {
     WalkDir::new(source_dir)
         .into_iter()
@@ -536,3 +1123,295 @@ pub fn iter_source_files(source_dir: &Path) -> impl Iterator<Item = PathBuf> {
         .filter(|e| is_source_file(e.path()))
         .map(|e| e.path().to_path_buf())
 }
+
+#[cfg(test)]
+mod tests {
+    use mun_codegen::{CodeGenDatabase, TargetAssembly};
+    use mun_paths::RelativePathBuf;
+
+    use super::{Config, Driver, INCREMENTAL_CACHE_DIR};
+    use crate::PathOrInline;
+
+    fn driver(out_dir: &std::path::Path, contents: &str) -> Driver {
+        let config = Config {
+            out_dir: Some(out_dir.to_path_buf()),
+            ..Config::default()
+        };
+        let (driver, _) = Driver::with_file(
+            config,
+            PathOrInline::Inline {
+                rel_path: RelativePathBuf::from("mod.mun"),
+                contents: contents.to_owned(),
+            },
+        )
+        .unwrap();
+        driver
+    }
+
+    #[test]
+    fn reuses_cached_assembly() {
+        let out_dir = tempfile::tempdir().unwrap();
+        let assembly_path = out_dir
+            .path()
+            .join("mod")
+            .with_extension(TargetAssembly::EXTENSION);
+        let cached_path = out_dir
+            .path()
+            .join(INCREMENTAL_CACHE_DIR)
+            .join("mod")
+            .with_extension(TargetAssembly::EXTENSION);
+
+        driver(out_dir.path(), "pub fn main() -> i32 { 1 }")
+            .write_all_assemblies(false)
+            .unwrap();
+        assert!(cached_path.is_file());
+        assert!(cached_path.with_extension("fingerprint").is_file());
+
+        // Replace the cached assembly to detect whether it is reused
+        std::fs::write(&cached_path, b"cached").unwrap();
+        std::fs::remove_file(&assembly_path).unwrap();
+        driver(out_dir.path(), "pub fn main() -> i32 { 1 }")
+            .write_all_assemblies(false)
+            .unwrap();
+        assert_eq!(std::fs::read(&assembly_path).unwrap(), b"cached");
+
+        // Changing the source invalidates the cache
+        driver(out_dir.path(), "pub fn main() -> i32 { 2 }")
+            .write_all_assemblies(false)
+            .unwrap();
+        assert_ne!(std::fs::read(&assembly_path).unwrap(), b"cached");
+        assert_ne!(std::fs::read(&cached_path).unwrap(), b"cached");
+    }
+
+    #[test]
+    fn caches_assemblies_per_workspace_member() {
+        let workspace_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            workspace_dir.path().join("mun.toml"),
+            "[workspace]\nmembers = [\"a\", \"b\"]\n",
+        )
+        .unwrap();
+        for (name, value) in [("a", 1), ("b", 2)] {
+            let source_dir = workspace_dir.path().join(name).join("src");
+            std::fs::create_dir_all(&source_dir).unwrap();
+            std::fs::write(
+                workspace_dir.path().join(name).join("mun.toml"),
+                format!("[package]\nname=\"{name}\"\nauthors=[]\nversion=\"0.1.0\"\n"),
+            )
+            .unwrap();
+            std::fs::write(
+                source_dir.join("mod.mun"),
+                format!("pub fn main() -> i32 {{ {value} }}"),
+            )
+            .unwrap();
+        }
+
+        let out_dir = tempfile::tempdir().unwrap();
+        let build = || {
+            let config = Config {
+                out_dir: Some(out_dir.path().to_path_buf()),
+                ..Config::default()
+            };
+            let (_, mut driver) =
+                Driver::with_workspace_path(workspace_dir.path().join("mun.toml"), config).unwrap();
+            driver.write_all_assemblies(false).unwrap();
+        };
+        let path = |dir: &std::path::Path, member: &str| {
+            dir.join(member)
+                .join("mod")
+                .with_extension(TargetAssembly::EXTENSION)
+        };
+        let cache_dir = out_dir.path().join(INCREMENTAL_CACHE_DIR);
+
+        // The root modules of both members are cached separately
+        build();
+        let cached_a = std::fs::read(path(&cache_dir, "a")).unwrap();
+        let cached_b = std::fs::read(path(&cache_dir, "b")).unwrap();
+        assert_ne!(cached_a, cached_b);
+        assert_eq!(std::fs::read(path(out_dir.path(), "a")).unwrap(), cached_a);
+        assert_eq!(std::fs::read(path(out_dir.path(), "b")).unwrap(), cached_b);
+
+        // Both are reused by the next build
+        std::fs::write(path(&cache_dir, "a"), b"cached a").unwrap();
+        std::fs::write(path(&cache_dir, "b"), b"cached b").unwrap();
+        build();
+        assert_eq!(
+            std::fs::read(path(out_dir.path(), "a")).unwrap(),
+            b"cached a"
+        );
+        assert_eq!(
+            std::fs::read(path(out_dir.path(), "b")).unwrap(),
+            b"cached b"
+        );
+    }
+
+    #[test]
+    fn reloads_changed_dependency() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        for (name, manifest, source) in [
+            (
+                "app",
+                "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nmath = { path = \"../lib\" }\n",
+                "pub fn main() -> i32 { math::value() }",
+            ),
+            (
+                "lib",
+                "[package]\nname = \"lib\"\nversion = \"0.1.0\"\n",
+                "pub fn value() -> i32 { 1 }",
+            ),
+        ] {
+            let source_dir = root.join(name).join("src");
+            std::fs::create_dir_all(&source_dir).unwrap();
+            std::fs::write(root.join(name).join("mun.toml"), manifest).unwrap();
+            std::fs::write(source_dir.join("mod.mun"), source).unwrap();
+        }
+
+        let out_dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            out_dir: Some(out_dir.path().to_path_buf()),
+            ..Config::default()
+        };
+        let (_, mut driver) = Driver::with_package_path(root.join("app/mun.toml"), config).unwrap();
+        let lib_source_dir = root.join("lib/src");
+        assert_eq!(
+            driver.dependency_source_directories().collect::<Vec<_>>(),
+            vec![lib_source_dir.as_path()]
+        );
+        driver.write_all_assemblies(false).unwrap();
+        let lib_assembly_path = out_dir
+            .path()
+            .join("lib")
+            .with_extension(TargetAssembly::EXTENSION);
+        let before = std::fs::read(&lib_assembly_path).unwrap();
+
+        // Paths outside of the dependencies are ignored
+        assert!(!driver
+            .reload_dependency_containing(&root.join("app/src/mod.mun"))
+            .unwrap());
+
+        std::fs::write(
+            lib_source_dir.join("mod.mun"),
+            "pub fn value() -> i32 { 2 }",
+        )
+        .unwrap();
+        assert!(driver
+            .reload_dependency_containing(&lib_source_dir.join("mod.mun"))
+            .unwrap());
+        driver.write_all_assemblies(false).unwrap();
+        assert_ne!(std::fs::read(&lib_assembly_path).unwrap(), before);
+    }
+
+    #[test]
+    fn parallel_generation_is_deterministic() {
+        const MODULES: [&str; 6] = ["a", "b", "c", "d", "e", "f"];
+
+        let package_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            package_dir.path().join("mun.toml"),
+            "[package]\nname=\"test\"\nauthors=[]\nversion=\"0.1.0\"\n",
+        )
+        .unwrap();
+        let source_dir = package_dir.path().join("src");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        let main_body = MODULES
+            .iter()
+            .map(|name| format!("{name}::{name}()"))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        std::fs::write(
+            source_dir.join("mod.mun"),
+            format!("pub fn main() -> i32 {{ {main_body} }}"),
+        )
+        .unwrap();
+        for (value, name) in MODULES.iter().enumerate() {
+            std::fs::write(
+                source_dir.join(name).with_extension("mun"),
+                format!("pub fn {name}() -> i32 {{ {value} }}"),
+            )
+            .unwrap();
+        }
+
+        let build = |out_dir: &std::path::Path, num_threads: usize| {
+            let config = Config {
+                out_dir: Some(out_dir.to_path_buf()),
+                ..Config::default()
+            };
+            let (_, mut driver) =
+                Driver::with_package_path(package_dir.path().join("mun.toml"), config).unwrap();
+            let module_partition = driver.db.module_partition();
+            let module_groups = module_partition
+                .iter()
+                .map(|(module_group_id, _)| module_group_id)
+                .collect::<Vec<_>>();
+            assert!(module_groups.len() > num_threads);
+
+            // Generate the assemblies on multiple threads, or on the current thread if
+            // `num_threads` is one, and write them from the cached results.
+            driver.generate_assemblies(&module_groups, num_threads);
+            for module_group_id in module_groups {
+                driver
+                    .write_target_assembly(module_group_id, false)
+                    .unwrap();
+            }
+        };
+
+        let parallel_dir = tempfile::tempdir().unwrap();
+        let sequential_dir = tempfile::tempdir().unwrap();
+        build(parallel_dir.path(), 4);
+        build(sequential_dir.path(), 1);
+
+        for name in std::iter::once("mod").chain(MODULES) {
+            let file_name = std::path::Path::new(name).with_extension(TargetAssembly::EXTENSION);
+            assert_eq!(
+                std::fs::read(parallel_dir.path().join(&file_name)).unwrap(),
+                std::fs::read(sequential_dir.path().join(&file_name)).unwrap(),
+                "assembly `{name}` differs"
+            );
+        }
+    }
+
+    #[test]
+    fn emits_debug_info() {
+        let package_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            package_dir.path().join("mun.toml"),
+            "[package]\nname=\"test\"\nauthors=[]\nversion=\"0.1.0\"\n",
+        )
+        .unwrap();
+        let source_dir = package_dir.path().join("src");
+        std::fs::create_dir_all(&source_dir).unwrap();
+        std::fs::write(source_dir.join("mod.mun"), "pub fn main() -> i32 { 1 }").unwrap();
+
+        let build = |debug_info: bool| {
+            let out_dir = tempfile::tempdir().unwrap();
+            let config = Config {
+                out_dir: Some(out_dir.path().to_path_buf()),
+                debug_info,
+                ..Config::default()
+            };
+            let (_, mut driver) =
+                Driver::with_package_path(package_dir.path().join("mun.toml"), config).unwrap();
+            driver.write_all_assemblies(false).unwrap();
+            std::fs::read(
+                out_dir
+                    .path()
+                    .join("mod")
+                    .with_extension(TargetAssembly::EXTENSION),
+            )
+            .unwrap()
+        };
+        let contains = |haystack: &[u8], needle: &[u8]| {
+            haystack
+                .windows(needle.len())
+                .any(|window| window == needle)
+        };
+
+        let assembly = build(true);
+        assert!(contains(&assembly, b".debug_info"));
+        assert!(contains(&assembly, source_dir.to_string_lossy().as_bytes()));
+
+        let assembly = build(false);
+        assert!(!contains(&assembly, b".debug_info"));
+    }
+}
@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-pub use mun_codegen::OptimizationLevel;
+pub use mun_codegen::{EmitKind, OptimizationLevel, PartitionStrategy};
+use mun_project::{AssemblyPartition, Profile};
 use mun_target::spec::Target;
 
 /// Describes all the permanent settings that are used during compilations.
@@ -16,8 +17,25 @@ pub struct Config {
     /// specified all output is stored in a temporary directory.
     pub out_dir: Option<PathBuf>,
 
-    /// Whether or not to emit an IR file instead of a munlib.
-    pub emit_ir: bool,
+    /// The kinds of artifacts that are emitted for every module group.
+    pub emit: Vec<EmitKind>,
+
+    /// How modules are grouped into assemblies.
+    pub partition_strategy: PartitionStrategy,
+
+    /// Whether or not to emit runtime checks for out-of-bounds array accesses
+    /// and integer division by zero.
+    pub runtime_checks: bool,
+
+    /// Whether or not to emit runtime checks for integer arithmetic overflow.
+    pub overflow_checks: bool,
+
+    /// Whether or not to use link-time optimization, which inlines calls
+    /// across module groups.
+    pub lto: bool,
+
+    /// Whether or not to emit debug information.
+    pub debug_info: bool,
 }
 
 impl Default for Config {
@@ -29,7 +47,47 @@ impl Default for Config {
             target: target.unwrap(),
             optimization_lvl: OptimizationLevel::Default,
             out_dir: None,
-            emit_ir: false,
+            emit: vec![EmitKind::Munlib],
+            partition_strategy: PartitionStrategy::default(),
+            runtime_checks: true,
+            overflow_checks: false,
+            lto: false,
+            debug_info: false,
         }
     }
 }
+
+impl Config {
+    /// Constructs a configuration for the host target from the settings of the
+    /// specified profile. The output directory of the profile is resolved
+    /// relative to `root`.
+    pub fn from_profile(profile: &Profile, root: &Path) -> Self {
+        Config {
+            optimization_lvl: optimization_level(profile.opt_level)
+                .unwrap_or(OptimizationLevel::Aggressive),
+            out_dir: Some(root.join(profile.output_directory())),
+            partition_strategy: match profile.partition {
+                AssemblyPartition::PerModule => PartitionStrategy::PerModule,
+                AssemblyPartition::PerDirectory => PartitionStrategy::PerDirectory,
+                AssemblyPartition::Single => PartitionStrategy::SingleAssembly,
+            },
+            runtime_checks: profile.runtime_checks,
+            overflow_checks: profile.overflow_checks,
+            lto: profile.lto,
+            debug_info: profile.debug,
+            ..Config::default()
+        }
+    }
+}
+
+/// Returns the optimization level that corresponds with the specified numeric
+/// level [0,3], or `None` if the level is out of range.
+pub fn optimization_level(level: u8) -> Option<OptimizationLevel> {
+    match level {
+        0 => Some(OptimizationLevel::None),
+        1 => Some(OptimizationLevel::Less),
+        2 => Some(OptimizationLevel::Default),
+        3 => Some(OptimizationLevel::Aggressive),
+        _ => None,
+    }
+}
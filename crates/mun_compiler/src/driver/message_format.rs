@@ -0,0 +1,12 @@
+/// The format in which the compiler reports diagnostics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Human readable snippets of the source code that are annotated with the
+    /// diagnostics.
+    #[default]
+    Human,
+
+    /// One JSON object per line for every diagnostic, followed by a record
+    /// that summarizes the build.
+    Json,
+}
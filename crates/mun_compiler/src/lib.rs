@@ -3,12 +3,13 @@
 
 mod db;
 pub mod diagnostics;
+mod diagnostics_json;
 mod diagnostics_snippets;
 mod driver;
 
 use std::{
     ffi::OsStr,
-    io::stderr,
+    io::{stderr, stdout},
     path::{Path, PathBuf},
 };
 
@@ -16,12 +17,15 @@ pub use annotate_snippets::AnnotationType;
 pub use mun_codegen::OptimizationLevel;
 pub use mun_hir_input::FileId;
 pub use mun_paths::{RelativePath, RelativePathBuf};
-use mun_project::Package;
+use mun_project::{Package, Workspace};
 pub use mun_target::spec::Target;
 
 pub use crate::{
     db::CompilerDatabase,
-    driver::{Config, DisplayColor, Driver},
+    driver::{
+        iter_source_files, optimization_level, Config, DiagnosticCount, DisplayColor, Driver,
+        EmitKind, MessageFormat,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -87,22 +91,68 @@ pub fn ensure_package_output_dir(
     Ok(out_dir)
 }
 
+/// Returns and creates the shared output dir for the specified workspace
+pub fn ensure_workspace_output_dir(
+    workspace: &Workspace,
+    config: &Config,
+) -> Result<PathBuf, anyhow::Error> {
+    let out_dir = config
+        .out_dir
+        .clone()
+        .unwrap_or_else(|| workspace.target_directory());
+    std::fs::create_dir_all(&out_dir)?;
+    Ok(out_dir)
+}
+
 pub fn compile_manifest(
     manifest_path: &Path,
     config: Config,
     emit_colors: DisplayColor,
+    message_format: MessageFormat,
 ) -> Result<bool, anyhow::Error> {
     let (_package, mut driver) = Driver::with_package_path(manifest_path, config)?;
+    build(&mut driver, emit_colors, message_format)
+}
 
-    // Emit diagnostics. If one of the snippets is an error, abort gracefully.
-    if driver.emit_diagnostics(&mut stderr(), emit_colors)? {
-        return Ok(false);
-    };
+/// Compiles all members of the workspace with the specified manifest. Returns
+/// `false` if any of the members contains errors.
+pub fn compile_workspace(
+    manifest_path: &Path,
+    config: Config,
+    emit_colors: DisplayColor,
+    message_format: MessageFormat,
+) -> Result<bool, anyhow::Error> {
+    let (_workspace, mut driver) = Driver::with_workspace_path(manifest_path, config)?;
+    build(&mut driver, emit_colors, message_format)
+}
 
-    // Write out all assemblies
-    driver.write_all_assemblies(false)?;
+/// Emits the diagnostics of the driver and writes out all assemblies if there
+/// are no errors. Human readable diagnostics are written to stderr, JSON
+/// records are written to stdout.
+fn build(
+    driver: &mut Driver,
+    emit_colors: DisplayColor,
+    message_format: MessageFormat,
+) -> Result<bool, anyhow::Error> {
+    let count = match message_format {
+        MessageFormat::Human => {
+            driver.emit_diagnostics_as(message_format, emit_colors, &mut stderr())
+        }
+        MessageFormat::Json => {
+            driver.emit_diagnostics_as(message_format, emit_colors, &mut stdout())
+        }
+    }?;
+
+    // If one of the diagnostics is an error, abort gracefully. Warnings do not
+    // prevent the assemblies from being written.
+    let success = count.errors == 0;
+    if success {
+        // Write out all assemblies
+        driver.write_all_assemblies(false)?;
+    }
 
-    Ok(true)
+    Driver::emit_build_finished(message_format, success, count, &mut stdout())?;
+    Ok(success)
 }
 
 /// Determines the relative path of a file to the source directory.
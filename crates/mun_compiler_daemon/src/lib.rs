@@ -1,6 +1,6 @@
 use std::{
     io::stderr,
-    path::Path,
+    path::{Path, PathBuf},
     sync::{mpsc::channel, Arc},
     time::Duration,
 };
@@ -9,7 +9,7 @@ use mun_compiler::{compute_source_relative_path, is_source_file, Config, Display
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 
 /// Compiles and watches the package at the specified path. Recompiles changes
-/// that occur.
+/// that occur in the source files of the package or of its dependencies.
 pub fn compile_and_watch_manifest(
     manifest_path: &Path,
     config: Config,
@@ -18,13 +18,22 @@ pub fn compile_and_watch_manifest(
     // Create the compiler driver
     let (package, mut driver) = Driver::with_package_path(manifest_path, config)?;
 
-    // Start watching the source directory
+    // Start watching the source directory and the source directories of all
+    // dependencies
     let (watcher_tx, watcher_rx) = channel();
     let mut watcher: RecommendedWatcher = Watcher::new(watcher_tx, Duration::from_millis(10))?;
     let source_directory = package.source_directory();
 
     watcher.watch(&source_directory, RecursiveMode::Recursive)?;
     println!("Watching: {}", source_directory.display());
+    let dependency_directories = driver
+        .dependency_source_directories()
+        .map(Path::to_path_buf)
+        .collect::<Vec<PathBuf>>();
+    for directory in &dependency_directories {
+        watcher.watch(directory, RecursiveMode::Recursive)?;
+        println!("Watching: {}", directory.display());
+    }
 
     // Emit all current errors, and write the assemblies if no errors occured
     if !driver.emit_diagnostics(&mut stderr(), display_color)? {
@@ -43,6 +52,32 @@ pub fn compile_and_watch_manifest(
     while !should_quit.load(std::sync::atomic::Ordering::SeqCst) {
         if let Ok(event) = watcher_rx.recv_timeout(Duration::from_millis(1)) {
             use notify::DebouncedEvent::{Create, Remove, Rename, Write};
+
+            // The files of a dependency are reloaded all at once when one of them
+            // changes
+            let changed_paths = match &event {
+                Write(path) | Create(path) | Remove(path) => vec![path],
+                Rename(from, to) => vec![from, to],
+                _ => Vec::new(),
+            };
+            let mut reloaded_dependency = false;
+            for path in changed_paths
+                .into_iter()
+                .filter(|path| is_source_file(path))
+            {
+                if driver.reload_dependency_containing(path)? {
+                    log::info!("Reloading the dependency containing {}", path.display());
+                    reloaded_dependency = true;
+                    break;
+                }
+            }
+            if reloaded_dependency {
+                if !driver.emit_diagnostics(&mut stderr(), display_color)? {
+                    driver.write_all_assemblies(false)?;
+                }
+                continue;
+            }
+
             match event {
                 Write(ref path) if is_source_file(path) => {
                     let relative_path = compute_source_relative_path(&source_directory, path)?;
@@ -0,0 +1,144 @@
+//! Stable codes that identify the different kinds of diagnostics. A code never
+//! changes once it has been assigned, which makes it possible to search for a
+//! diagnostic or to refer to it from configuration.
+//!
+//! Every code has a long-form explanation in `codes/<code>.md` that can be
+//! shown with `mun explain <code>`.
+
+use std::fmt;
+
+/// A stable identifier of a kind of diagnostic, e.g. `E0009`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode {
+    code: &'static str,
+    explanation: &'static str,
+}
+
+impl DiagnosticCode {
+    /// Returns the textual representation of the code, e.g. `E0009`.
+    pub fn as_str(self) -> &'static str {
+        self.code
+    }
+
+    /// Returns a long-form description of the diagnostic in markdown, including
+    /// examples of erroneous code and how to fix it.
+    pub fn explanation(self) -> &'static str {
+        self.explanation
+    }
+
+    /// Finds the code with the specified textual representation. The lookup is
+    /// case-insensitive.
+    pub fn find(code: &str) -> Option<DiagnosticCode> {
+        ALL.iter()
+            .copied()
+            .find(|candidate| candidate.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Returns all registered codes in ascending order.
+    pub fn all() -> &'static [DiagnosticCode] {
+        ALL
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+/// Declares a constant for every code, optionally associated with the HIR
+/// diagnostic it is reported for.
+macro_rules! diagnostic_codes {
+    ($($name:ident = $code:ident $(for $hir:ident)?,)*) => {
+        $(
+            pub const $name: DiagnosticCode = DiagnosticCode {
+                code: stringify!($code),
+                explanation: include_str!(concat!("codes/", stringify!($code), ".md")),
+            };
+        )*
+
+        const ALL: &[DiagnosticCode] = &[$($name),*];
+
+        /// Returns the code of the specified HIR diagnostic.
+        pub(crate) fn hir_diagnostic_code(
+            diagnostic: &dyn mun_hir::Diagnostic,
+        ) -> Option<DiagnosticCode> {
+            $($(
+                if diagnostic.downcast_ref::<mun_hir::diagnostics::$hir>().is_some() {
+                    return Some($name);
+                }
+            )?)*
+            None
+        }
+    };
+}
+
+diagnostic_codes! {
+    SYNTAX_ERROR = E0001,
+    UNRESOLVED_VALUE = E0002 for UnresolvedValue,
+    UNRESOLVED_TYPE = E0003 for UnresolvedType,
+    CYCLIC_TYPE = E0004 for CyclicType,
+    PRIVATE_ACCESS = E0005 for PrivateAccess,
+    EXPECTED_FUNCTION = E0006 for ExpectedFunction,
+    EXPORTED_PRIVATE = E0007 for ExportedPrivate,
+    PARAMETER_COUNT_MISMATCH = E0008 for ParameterCountMismatch,
+    MISMATCHED_TYPE = E0009 for MismatchedType,
+    INCOMPATIBLE_BRANCH = E0010 for IncompatibleBranch,
+    INVALID_LHS = E0011 for InvalidLhs,
+    MISSING_ELSE_BRANCH = E0012 for MissingElseBranch,
+    CANNOT_APPLY_BINARY_OP = E0013 for CannotApplyBinaryOp,
+    CANNOT_APPLY_UNARY_OP = E0014 for CannotApplyUnaryOp,
+    DUPLICATE_DEFINITION = E0015 for DuplicateDefinition,
+    RETURN_MISSING_EXPRESSION = E0016 for ReturnMissingExpression,
+    BREAK_OUTSIDE_LOOP = E0017 for BreakOutsideLoop,
+    BREAK_WITH_VALUE_OUTSIDE_LOOP = E0018 for BreakWithValueOutsideLoop,
+    ACCESS_UNKNOWN_FIELD = E0019 for AccessUnknownField,
+    FIELD_COUNT_MISMATCH = E0020 for FieldCountMismatch,
+    MISSING_FIELDS = E0021 for MissingFields,
+    MISMATCHED_STRUCT_LIT = E0022 for MismatchedStructLit,
+    NO_FIELDS = E0023 for NoFields,
+    NO_SUCH_FIELD = E0024 for NoSuchField,
+    POSSIBLY_UNINITIALIZED_VARIABLE = E0025 for PossiblyUninitializedVariable,
+    EXTERN_CANNOT_HAVE_BODY = E0026 for ExternCannotHaveBody,
+    EXTERN_NON_PRIMITIVE_PARAM = E0027 for ExternNonPrimitiveParam,
+    INT_LITERAL_TOO_LARGE = E0028 for IntLiteralTooLarge,
+    LITERAL_OUT_OF_RANGE = E0029 for LiteralOutOfRange,
+    INVALID_LITERAL_SUFFIX = E0030 for InvalidLiteralSuffix,
+    INVALID_FLOATING_POINT_LITERAL = E0031 for InvalidFloatingPointLiteral,
+    INVALID_LITERAL = E0032 for InvalidLiteral,
+    FREE_TYPE_ALIAS_WITHOUT_TYPE_REF = E0033 for FreeTypeAliasWithoutTypeRef,
+    UNRESOLVED_IMPORT = E0034 for UnresolvedImport,
+    IMPORT_DUPLICATE_DEFINITION = E0035 for ImportDuplicateDefinition,
+    PRIVATE_TYPE_ALIAS = E0036 for PrivateTypeAlias,
+    IMPL_FOR_FOREIGN_TYPE = E0037 for ImplForForeignType,
+    INVALID_SELF_TY_IMPL = E0038 for InvalidSelfTyImpl,
+    METHOD_NOT_IN_SCOPE = E0039 for MethodNotInScope,
+    METHOD_NOT_FOUND = E0040 for MethodNotFound,
+    UNKNOWN_ATTRIBUTE = E0041 for UnknownAttribute,
+    UNKNOWN_LINT = E0042 for UnknownLint,
+    CONST_EVAL_FAILURE = E0043 for ConstEvalFailure,
+    ARRAY_REPEAT_OF_REFERENCE = E0044 for ArrayRepeatOfReference,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DiagnosticCode;
+
+    #[test]
+    fn codes_are_unique_and_sorted() {
+        let codes = DiagnosticCode::all()
+            .iter()
+            .map(|code| code.as_str())
+            .collect::<Vec<_>>();
+        let mut sorted = codes.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(codes, sorted);
+    }
+
+    #[test]
+    fn find_code() {
+        assert_eq!(DiagnosticCode::find("e0009"), Some(super::MISMATCHED_TYPE));
+        assert_eq!(DiagnosticCode::find("E9999"), None);
+    }
+}
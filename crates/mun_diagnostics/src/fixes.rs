@@ -0,0 +1,377 @@
+//! Provides fixes for diagnostics reported by the compiler. A fix is a change
+//! to the source code that resolves the diagnostic it is attached to.
+
+use itertools::Itertools;
+use mun_hir::{
+    diagnostics::{
+        MethodNotInScope, MissingFields, PossiblyUninitializedVariable, UnresolvedImport,
+        UnresolvedType, UnresolvedValue,
+    },
+    method_resolution::{AssociationMode, MethodResolutionCtx},
+    semantics::{PathResolution, Semantics},
+    AssocItemId, Function, HasSource, HasVisibility, HirDatabase, Module, ModuleDef, Package,
+    Struct, StructKind, Ty, TyKind,
+};
+use mun_hir_input::FileId;
+use mun_syntax::{
+    ast::{self, ModuleItemOwner, TypeAscriptionOwner, VisibilityOwner},
+    AstNode, Direction, T,
+};
+use ra_ap_text_edit::TextEdit;
+
+use crate::SourceChange;
+
+/// A change to the source that resolves a diagnostic.
+#[derive(Debug)]
+pub struct Fix {
+    /// A short description of the change, e.g. "Fill struct fields"
+    pub label: String,
+
+    /// The edits that make up the fix
+    pub source_change: SourceChange,
+}
+
+/// Returns a fix for the specified diagnostic or `None` if no fix is available.
+pub fn fix(db: &dyn HirDatabase, diagnostic: &dyn mun_hir::Diagnostic) -> Option<Fix> {
+    if let Some(d) = diagnostic.downcast_ref::<MissingFields>() {
+        missing_fields(db, d)
+    } else if let Some(d) = diagnostic.downcast_ref::<UnresolvedImport>() {
+        unresolved_import(db, d)
+    } else if let Some(d) = diagnostic.downcast_ref::<UnresolvedType>() {
+        unresolved_type(db, d)
+    } else if let Some(d) = diagnostic.downcast_ref::<UnresolvedValue>() {
+        unresolved_value(db, d)
+    } else if let Some(d) = diagnostic.downcast_ref::<MethodNotInScope>() {
+        method_not_in_scope(db, d)
+    } else if let Some(d) = diagnostic.downcast_ref::<PossiblyUninitializedVariable>() {
+        possibly_uninitialized_variable(db, d)
+    } else {
+        None
+    }
+}
+
+/// Adds the missing fields to a struct literal.
+///
+/// ```mun
+/// let a = Foo { a: 1 };
+/// ```
+/// becomes
+/// ```mun
+/// let a = Foo { a: 1, b: 0 };
+/// ```
+fn missing_fields(db: &dyn HirDatabase, diag: &MissingFields) -> Option<Fix> {
+    let root = db.parse(diag.file).syntax_node();
+    let record_lit = ast::RecordLit::cast(diag.fields.to_node(&root))?;
+    let field_list = record_lit.record_field_list()?;
+    let strukt = diag.struct_ty.as_struct()?;
+
+    let new_fields = diag
+        .field_names
+        .iter()
+        .map(|name| {
+            let value = strukt.field(db, name).map_or_else(
+                || String::from("()"),
+                |field| default_value(db, &field.ty(db), &mut vec![strukt]),
+            );
+            format!("{name}: {value}")
+        })
+        .join(", ");
+
+    let edit = match field_list.fields().last() {
+        Some(last_field) => {
+            let trailing_comma = last_field
+                .syntax()
+                .siblings_with_tokens(Direction::Next)
+                .skip(1)
+                .find(|it| !it.kind().is_trivia())
+                .filter(|it| it.kind() == T![,]);
+            match trailing_comma {
+                Some(comma) => {
+                    TextEdit::insert(comma.text_range().end(), format!(" {new_fields},"))
+                }
+                None => TextEdit::insert(
+                    last_field.syntax().text_range().end(),
+                    format!(", {new_fields}"),
+                ),
+            }
+        }
+        None => TextEdit::replace(
+            field_list.syntax().text_range(),
+            format!("{{ {new_fields} }}"),
+        ),
+    };
+
+    Some(Fix {
+        label: String::from("Fill struct fields"),
+        source_change: SourceChange::from_text_edit(diag.file, edit),
+    })
+}
+
+/// Replaces the path of an unresolved import with the path of an item with the
+/// same name that is defined elsewhere in the package.
+///
+/// ```mun
+/// use package::foo::Bar; // `Bar` is actually defined in `package::baz`
+/// ```
+fn unresolved_import(db: &dyn HirDatabase, diag: &UnresolvedImport) -> Option<Fix> {
+    let file_id = diag.use_tree.file_id;
+    let root = db.parse(file_id).syntax_node();
+    let use_tree = diag.use_tree.value.to_node(&root);
+    let path = use_tree.path()?;
+    let name = path.segment()?.name_ref()?.text().to_string();
+    let module = Module::from_file(db, file_id)?;
+
+    let import_path = find_importable_items(db, module, &name, |_| true)
+        .into_iter()
+        .next()?;
+
+    Some(Fix {
+        label: format!("Change import to `{import_path}`"),
+        source_change: SourceChange::from_text_edit(
+            file_id,
+            TextEdit::replace(path.syntax().text_range(), import_path),
+        ),
+    })
+}
+
+/// Adds a `use` declaration for an unresolved type that is defined elsewhere
+/// in the package.
+fn unresolved_type(db: &dyn HirDatabase, diag: &UnresolvedType) -> Option<Fix> {
+    let root = db.parse(diag.file).syntax_node();
+    let path = match diag.type_ref.to_node(&root).kind() {
+        ast::TypeRefKind::PathType(path_type) => path_type.path()?,
+        _ => return None,
+    };
+    add_import_for_path(db, diag.file, &path, |def| {
+        matches!(def, ModuleDef::Struct(_) | ModuleDef::TypeAlias(_))
+    })
+}
+
+/// Adds a `use` declaration for an unresolved value that is defined elsewhere
+/// in the package.
+fn unresolved_value(db: &dyn HirDatabase, diag: &UnresolvedValue) -> Option<Fix> {
+    let root = db.parse(diag.file).syntax_node();
+    let path = match ast::Expr::cast(diag.expr.to_node(&root))?.kind() {
+        ast::ExprKind::PathExpr(path_expr) => path_expr.path()?,
+        _ => return None,
+    };
+    add_import_for_path(db, diag.file, &path, |def| match def {
+        ModuleDef::Function(_) | ModuleDef::Const(_) => true,
+        ModuleDef::Struct(s) => s.data(db).kind != StructKind::Record,
+        _ => false,
+    })
+}
+
+/// Makes a method public that is called from a scope in which it is not
+/// visible.
+fn method_not_in_scope(db: &dyn HirDatabase, diag: &MethodNotInScope) -> Option<Fix> {
+    let root = db.parse(diag.method_call.file_id).syntax_node();
+    let method_call = diag.method_call.value.to_node(&root);
+    let method_name = method_call.name_ref()?.text().to_string();
+
+    let function = MethodResolutionCtx::new(db, diag.receiver_ty.clone())
+        .with_association(AssociationMode::WithSelf)
+        .collect(|item, _| match item {
+            AssocItemId::FunctionId(f) => {
+                let function = Function::from(f);
+                (function.name(db).to_string() == method_name).then_some(function)
+            }
+        })?;
+
+    let source = function.source(db);
+    let edit = if let Some(visibility) = source.value.visibility() {
+        TextEdit::replace(visibility.syntax().text_range(), String::from("pub"))
+    } else {
+        let fn_kw = source
+            .value
+            .syntax()
+            .children_with_tokens()
+            .find(|it| it.kind() == T![fn])?;
+        TextEdit::insert(fn_kw.text_range().start(), String::from("pub "))
+    };
+
+    Some(Fix {
+        label: format!("Make `{method_name}` public"),
+        source_change: SourceChange::from_text_edit(source.file_id, edit),
+    })
+}
+
+/// Initializes a variable that is used before it is guaranteed to be
+/// initialized.
+///
+/// ```mun
+/// let a;
+/// ```
+/// becomes
+/// ```mun
+/// let a = 0;
+/// ```
+fn possibly_uninitialized_variable(
+    db: &dyn HirDatabase,
+    diag: &PossiblyUninitializedVariable,
+) -> Option<Fix> {
+    let sema = Semantics::new(db);
+    let root = sema.parse(diag.file);
+    let path = match ast::Expr::cast(diag.pat.to_node(root.syntax()))?.kind() {
+        ast::ExprKind::PathExpr(path_expr) => path_expr.path()?,
+        _ => return None,
+    };
+    let local = match sema.resolve_path(&path)? {
+        PathResolution::Local(local) => local,
+        _ => return None,
+    };
+
+    let pat = local.source(db)?;
+    let let_stmt = pat.value.syntax().parent().and_then(ast::LetStmt::cast)?;
+    if let_stmt.initializer().is_some() {
+        return None;
+    }
+
+    // Insert the initializer after the type ascription if there is one.
+    let insert_after = match let_stmt.ascribed_type() {
+        Some(type_ref) => type_ref.syntax().text_range(),
+        None => pat.value.syntax().text_range(),
+    };
+
+    let name = local.name(db)?;
+    let value = default_value(db, &local.ty(db), &mut Vec::new());
+    Some(Fix {
+        label: format!("Initialize `{name}`"),
+        source_change: SourceChange::from_text_edit(
+            pat.file_id,
+            TextEdit::insert(insert_after.end(), format!(" = {value}")),
+        ),
+    })
+}
+
+/// Adds a `use` declaration to the file for the single segment `path` if an
+/// item by that name is defined elsewhere in the package.
+fn add_import_for_path(
+    db: &dyn HirDatabase,
+    file_id: FileId,
+    path: &ast::Path,
+    filter: impl Fn(ModuleDef) -> bool,
+) -> Option<Fix> {
+    if path.qualifier().is_some() {
+        return None;
+    }
+
+    let name = path.segment()?.name_ref()?.text().to_string();
+    let module = Module::from_file(db, file_id)?;
+    let import_path = find_importable_items(db, module, &name, filter)
+        .into_iter()
+        .next()?;
+
+    let source_file = db.parse(file_id).tree();
+    let edit = match source_file
+        .items()
+        .filter_map(|item| match item.kind() {
+            ast::ModuleItemKind::Use(it) => Some(it),
+            _ => None,
+        })
+        .last()
+    {
+        Some(last_use) => TextEdit::insert(
+            last_use.syntax().text_range().end(),
+            format!("\nuse {import_path};"),
+        ),
+        None => TextEdit::insert(
+            source_file.syntax().text_range().start(),
+            format!("use {import_path};\n\n"),
+        ),
+    };
+
+    Some(Fix {
+        label: format!("Import `{import_path}`"),
+        source_change: SourceChange::from_text_edit(file_id, edit),
+    })
+}
+
+/// Finds all items with the specified `name` in the package of `from` that are
+/// visible from `from` and that are not defined in `from` itself. Returns the
+/// absolute paths through which these items can be imported.
+fn find_importable_items(
+    db: &dyn HirDatabase,
+    from: Module,
+    name: &str,
+    filter: impl Fn(ModuleDef) -> bool,
+) -> Vec<String> {
+    let package: Package = from.package();
+    package
+        .modules(db)
+        .into_iter()
+        .filter(|&module| module != from)
+        .flat_map(|module| {
+            module
+                .declarations(db)
+                .into_iter()
+                .map(move |def| (module, def))
+        })
+        .filter(|&(_, def)| filter(def))
+        .filter(|&(_, def)| {
+            let (def_name, is_visible) = match def {
+                ModuleDef::Function(f) => (f.name(db), f.is_visible_from(db, from)),
+                ModuleDef::Struct(s) => (s.name(db), s.is_visible_from(db, from)),
+                ModuleDef::TypeAlias(t) => (t.name(db), t.is_visible_from(db, from)),
+                ModuleDef::Const(c) => (c.name(db), c.is_visible_from(db, from)),
+                ModuleDef::Module(_) | ModuleDef::PrimitiveType(_) => return false,
+            };
+            is_visible && def_name.to_string() == name
+        })
+        .map(|(module, _)| {
+            let mut segments = module
+                .path_to_root(db)
+                .into_iter()
+                .rev()
+                .filter_map(|module| module.name(db))
+                .collect::<Vec<_>>();
+            segments.push(name.to_owned());
+            format!("package::{}", segments.join("::"))
+        })
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// Returns the source text of a value that can be used to initialize an
+/// instance of the specified type. For types without an obvious default value
+/// the unit value is returned.
+///
+/// `visited` contains all the structs that are currently being initialized,
+/// this is used to prevent infinite recursion for recursive types.
+fn default_value(db: &dyn HirDatabase, ty: &Ty, visited: &mut Vec<Struct>) -> String {
+    match ty.interned() {
+        TyKind::Bool => String::from("false"),
+        TyKind::Int(_) => String::from("0"),
+        TyKind::Float(_) => String::from("0.0"),
+        TyKind::Array(_) => String::from("[]"),
+        TyKind::TypeAlias(alias) => default_value(db, &alias.target_type(db), visited),
+        TyKind::Struct(s) if !visited.contains(s) => {
+            let strukt = *s;
+            visited.push(strukt);
+            let fields = strukt
+                .fields(db)
+                .into_iter()
+                .map(|field| (field.name(db), default_value(db, &field.ty(db), visited)))
+                .collect::<Vec<_>>();
+            visited.pop();
+
+            let name = strukt.name(db);
+            match strukt.data(db).kind {
+                StructKind::Record => format!(
+                    "{name} {{ {} }}",
+                    fields
+                        .iter()
+                        .map(|(name, value)| format!("{name}: {value}"))
+                        .join(", ")
+                ),
+                StructKind::Tuple => format!(
+                    "{name}({})",
+                    fields.iter().map(|(_, value)| value).join(", ")
+                ),
+                StructKind::Unit => name.to_string(),
+            }
+        }
+        _ => String::from("()"),
+    }
+}
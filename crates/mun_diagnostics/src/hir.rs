@@ -5,16 +5,17 @@ mod access_unknown_field;
 mod duplicate_definition_error;
 mod expected_function;
 mod exported_private;
+mod lint_violation;
 mod mismatched_type;
 mod missing_fields;
 mod possibly_unitialized_variable;
 mod unresolved_type;
 mod unresolved_value;
 
-use mun_hir::Diagnostic as HirDiagnostic;
+use mun_hir::{diagnostics::Severity, Diagnostic as HirDiagnostic};
 use mun_syntax::TextRange;
 
-use crate::{Diagnostic, DiagnosticForWith, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, DiagnosticForWith, SourceAnnotation};
 
 // Provides conversion of a mun_hir::Diagnostic to a crate::Diagnostic. This
 // requires a database for most operations.
@@ -42,6 +43,8 @@ impl<DB: mun_hir::HirDatabase> DiagnosticForWith<DB> for dyn mun_hir::Diagnostic
             f(&missing_fields::MissingFields::new(with, v))
         } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::ExportedPrivate>() {
             f(&exported_private::ExportedPrivate::new(with, v))
+        } else if let Some(v) = self.downcast_ref::<mun_hir::diagnostics::LintViolation>() {
+            f(&lint_violation::LintViolation::new(v))
         } else {
             f(&GenericHirDiagnostic { diagnostic: self })
         }
@@ -59,6 +62,10 @@ impl Diagnostic for GenericHirDiagnostic<'_> {
         self.diagnostic.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        codes::hir_diagnostic_code(self.diagnostic)
+    }
+
     fn title(&self) -> String {
         self.diagnostic.message()
     }
@@ -66,4 +73,8 @@ impl Diagnostic for GenericHirDiagnostic<'_> {
     fn primary_annotation(&self) -> Option<SourceAnnotation> {
         None
     }
+
+    fn severity(&self) -> Severity {
+        self.diagnostic.severity()
+    }
 }
@@ -2,7 +2,7 @@ use mun_hir::HirDisplay;
 use mun_syntax::{ast, AstNode, TextRange};
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when trying to access a field that doesn't exist.
 ///
@@ -27,6 +27,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for AccessUnknownField<'_, '_, DB> {
         self.location
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::ACCESS_UNKNOWN_FIELD)
+    }
+
     fn title(&self) -> String {
         format!(
             "no field `{}` on type `{}`",
@@ -1,7 +1,7 @@
 use mun_hir::InFile;
 use mun_syntax::{ast, AstNode, Parse, SourceFile, SyntaxKind, SyntaxNodePtr, TextRange};
 
-use crate::{Diagnostic, SecondaryAnnotation, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SecondaryAnnotation, SourceAnnotation};
 
 /// For a given node returns the signature range (if that is applicable for the
 /// type of node)
@@ -38,6 +38,10 @@ fn syntax_node_signature_range(
             ast::TypeAliasDef::cast(syntax_node_ptr.to_node(parse.tree().syntax()))
                 .map_or_else(|| syntax_node_ptr.range(), |s| s.signature_range())
         }
+        SyntaxKind::CONST_DEF => {
+            ast::ConstDef::cast(syntax_node_ptr.to_node(parse.tree().syntax()))
+                .map_or_else(|| syntax_node_ptr.range(), |c| c.signature_range())
+        }
         _ => syntax_node_ptr.range(),
     }
 }
@@ -65,13 +69,14 @@ fn syntax_node_identifier_range(
     parse: &Parse<SourceFile>,
 ) -> TextRange {
     match syntax_node_ptr.kind() {
-        SyntaxKind::FUNCTION_DEF | SyntaxKind::STRUCT_DEF | SyntaxKind::TYPE_ALIAS_DEF => {
-            syntax_node_ptr
-                .to_node(parse.tree().syntax())
-                .children()
-                .find(|n| n.kind() == SyntaxKind::NAME)
-                .map_or_else(|| syntax_node_ptr.range(), |name| name.text_range())
-        }
+        SyntaxKind::FUNCTION_DEF
+        | SyntaxKind::STRUCT_DEF
+        | SyntaxKind::TYPE_ALIAS_DEF
+        | SyntaxKind::CONST_DEF => syntax_node_ptr
+            .to_node(parse.tree().syntax())
+            .children()
+            .find(|n| n.kind() == SyntaxKind::NAME)
+            .map_or_else(|| syntax_node_ptr.range(), |name| name.text_range()),
         _ => syntax_node_ptr.range(),
     }
 }
@@ -100,6 +105,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for DuplicateDefinition<'_, '_, DB> {
         )
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::DUPLICATE_DEFINITION)
+    }
+
     fn title(&self) -> String {
         format!(
             "a {} named `{}` has already been defined in this module",
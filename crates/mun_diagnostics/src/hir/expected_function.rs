@@ -2,7 +2,7 @@ use mun_hir::HirDisplay;
 use mun_syntax::TextRange;
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when a function is expected but something else is
 /// encountered:
@@ -23,6 +23,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for ExpectedFunction<'_, '_, DB> {
         self.diag.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::EXPECTED_FUNCTION)
+    }
+
     fn title(&self) -> String {
         format!(
             "expected function, found `{}`",
@@ -1,7 +1,7 @@
 use mun_syntax::{AstNode, TextRange};
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when trying to leak a private type
 pub struct ExportedPrivate<'db, 'diag, DB: mun_hir::HirDatabase> {
@@ -15,6 +15,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for ExportedPrivate<'_, '_, DB> {
         self.diag.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::EXPORTED_PRIVATE)
+    }
+
     fn title(&self) -> String {
         format!("can't leak `{}`", self.value_name)
     }
@@ -0,0 +1,61 @@
+use mun_hir::{diagnostics::Severity, lint::Lint};
+use mun_syntax::TextRange;
+
+use super::HirDiagnostic;
+use crate::{Diagnostic, DiagnosticCode, SourceAnnotation};
+
+/// A warning or error that is emitted for a violation of a lint.
+pub struct LintViolation<'diag> {
+    diag: &'diag mun_hir::diagnostics::LintViolation,
+}
+
+impl Diagnostic for LintViolation<'_> {
+    fn range(&self) -> TextRange {
+        self.diag.highlight_range()
+    }
+
+    fn code(&self) -> Option<DiagnosticCode> {
+        None
+    }
+
+    fn title(&self) -> String {
+        self.diag.message()
+    }
+
+    fn primary_annotation(&self) -> Option<SourceAnnotation> {
+        let message = if *self.diag.lint == mun_hir::lint::UNUSED_VARIABLES {
+            "if this is intentional, prefix it with an underscore"
+        } else {
+            return None;
+        };
+
+        Some(SourceAnnotation {
+            range: self.diag.highlight_range(),
+            message: message.to_owned(),
+        })
+    }
+
+    fn footer(&self) -> Vec<String> {
+        let name = self.diag.lint.name;
+        vec![format!(
+            "`{name}` is set to `{}`, which can be changed with `#[allow({name})]` or in the \
+             `[lints]` section of `mun.toml`",
+            self.diag.level
+        )]
+    }
+
+    fn severity(&self) -> Severity {
+        self.diag.severity()
+    }
+
+    fn lint(&self) -> Option<&'static Lint> {
+        Some(self.diag.lint)
+    }
+}
+
+impl<'diag> LintViolation<'diag> {
+    /// Constructs a new instance of `LintViolation`
+    pub fn new(diag: &'diag mun_hir::diagnostics::LintViolation) -> Self {
+        LintViolation { diag }
+    }
+}
@@ -2,7 +2,7 @@ use mun_hir::HirDisplay;
 use mun_syntax::TextRange;
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when a different type was found than expected.
 ///
@@ -25,6 +25,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for MismatchedType<'_, '_, DB> {
         self.diag.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::MISMATCHED_TYPE)
+    }
+
     fn title(&self) -> String {
         format!(
             "expected `{}`, found `{}`",
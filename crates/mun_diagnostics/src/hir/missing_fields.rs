@@ -2,7 +2,7 @@ use mun_hir::HirDisplay;
 use mun_syntax::{ast, AstNode, TextRange};
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when a field is missing from a struct initializer.
 ///
@@ -27,6 +27,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for MissingFields<'_, '_, DB> {
         self.location
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::MISSING_FIELDS)
+    }
+
     fn title(&self) -> String {
         format!(
             "missing fields {} in initializer of `{}`",
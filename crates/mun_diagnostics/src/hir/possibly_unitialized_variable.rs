@@ -1,7 +1,7 @@
 use mun_syntax::TextRange;
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when trying to access a field that is potentially
 /// not yet initialized.
@@ -23,6 +23,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for PossiblyUninitializedVariable<'_,
         self.diag.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::POSSIBLY_UNINITIALIZED_VARIABLE)
+    }
+
     fn title(&self) -> String {
         format!("use of possibly-uninitialized `{}`", self.value_name)
     }
@@ -1,7 +1,7 @@
 use mun_syntax::{AstNode, TextRange};
 
 use super::HirDiagnostic;
-use crate::{Diagnostic, SourceAnnotation};
+use crate::{codes, Diagnostic, DiagnosticCode, SourceAnnotation};
 
 /// An error that is emitted when trying to use a type that doesnt exist within
 /// the scope.
@@ -22,6 +22,10 @@ impl<DB: mun_hir::HirDatabase> Diagnostic for UnresolvedType<'_, '_, DB> {
         self.diag.highlight_range()
     }
 
+    fn code(&self) -> Option<DiagnosticCode> {
+        Some(codes::UNRESOLVED_TYPE)
+    }
+
     fn title(&self) -> String {
         format!("cannot find type `{}` in this scope", self.value_name)
     }
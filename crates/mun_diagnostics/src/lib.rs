@@ -7,11 +7,20 @@
 //! reasons. This enables lazily querying the system for more information only
 //! when required.
 
+pub mod codes;
+mod fixes;
 mod hir;
+mod source_change;
 
-use mun_hir::InFile;
+use mun_hir::{diagnostics::Severity, lint::Lint, InFile};
 use mun_syntax::TextRange;
 
+pub use crate::{
+    codes::DiagnosticCode,
+    fixes::{fix, Fix},
+    source_change::{SourceChange, SourceFileEdit},
+};
+
 /// An annotation within the source code
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SourceAnnotation {
@@ -34,6 +43,9 @@ pub struct SecondaryAnnotation {
 
 /// The base trait for all diagnostics in this crate.
 pub trait Diagnostic {
+    /// Returns the stable code that identifies the kind of diagnostic.
+    fn code(&self) -> Option<DiagnosticCode>;
+
     /// Returns the primary message of the diagnostic.
     fn title(&self) -> String;
 
@@ -54,6 +66,16 @@ pub trait Diagnostic {
     fn footer(&self) -> Vec<String> {
         Vec::new()
     }
+
+    /// Returns whether the diagnostic is an error or a warning.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    /// Returns the lint that this diagnostic is a violation of, if any.
+    fn lint(&self) -> Option<&'static Lint> {
+        None
+    }
 }
 
 /// When implemented enables requesting `Diagnostic`s for the implementer.
@@ -0,0 +1,26 @@
+use mun_hir_input::FileId;
+use ra_ap_text_edit::TextEdit;
+
+/// A set of changes to one or more source files, for instance the result of
+/// applying a fix.
+#[derive(Debug, Default)]
+pub struct SourceChange {
+    pub source_file_edits: Vec<SourceFileEdit>,
+}
+
+/// A change to the text of a single source file.
+#[derive(Debug)]
+pub struct SourceFileEdit {
+    pub file_id: FileId,
+    pub edit: TextEdit,
+}
+
+impl SourceChange {
+    /// Constructs a `SourceChange` that consists of a single edit to a single
+    /// file.
+    pub fn from_text_edit(file_id: FileId, edit: TextEdit) -> Self {
+        SourceChange {
+            source_file_edits: vec![SourceFileEdit { file_id, edit }],
+        }
+    }
+}
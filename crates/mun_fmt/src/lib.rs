@@ -0,0 +1,390 @@
+//! A source code formatter for the Mun programming language.
+//!
+//! The formatter operates on the lossless syntax tree produced by
+//! [`mun_syntax`]. It walks over all the tokens in the tree and recomputes the
+//! whitespace in between them based on the syntactic context of the tokens.
+//! Because the syntax tree is lossless, comments are retained exactly where
+//! they were written. Empty lines that separate statements or items are kept
+//! (although multiple empty lines are collapsed into one) and so are line
+//! breaks that a user explicitly inserted into long expressions.
+
+#[cfg(test)]
+mod tests;
+
+use mun_syntax::{
+    ast, AstNode, SourceFile, SyntaxElement, SyntaxError,
+    SyntaxKind::{
+        self, ARG_LIST, ARRAY_EXPR, ASSOCIATED_ITEM_LIST, ATTR, BLOCK_EXPR, COMMENT, INDEX,
+        INDEX_EXPR, MEMORY_TYPE_SPECIFIER, PARAM_LIST, PREFIX_EXPR, RECORD_FIELD_DEF_LIST,
+        RECORD_FIELD_LIST, SOURCE_FILE, TOKEN_TREE, TUPLE_FIELD_DEF_LIST, USE_TREE_LIST,
+        VISIBILITY, WHITESPACE,
+    },
+    SyntaxNode, SyntaxToken, T,
+};
+
+/// The string used to indent a single level
+const INDENT: &str = "    ";
+
+/// An error that occurred while formatting source code.
+#[derive(Debug, thiserror::Error)]
+pub enum FormatError {
+    /// The source could not be formatted because it contains syntax errors.
+    #[error("the source contains {} syntax error(s)", .0.len())]
+    SyntaxErrors(Vec<SyntaxError>),
+}
+
+/// Parses the given `text` and returns the formatted source. Returns an error
+/// if the text could not be parsed without errors.
+pub fn format(text: &str) -> Result<String, FormatError> {
+    let parse = SourceFile::parse(text);
+    let errors = parse.errors();
+    if !errors.is_empty() {
+        return Err(FormatError::SyntaxErrors(errors.to_vec()));
+    }
+    Ok(format_source_file(&parse.tree()))
+}
+
+/// Formats an already parsed source file.
+pub fn format_source_file(file: &ast::SourceFile) -> String {
+    let mut formatter = Formatter::default();
+    let mut prev: Option<SyntaxToken> = None;
+    let mut newlines = 0;
+    for token in file
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+    {
+        if token.kind() == WHITESPACE {
+            newlines += token.text().matches('\n').count();
+            continue;
+        }
+
+        if is_redundant_trailing_comma(&token) {
+            continue;
+        }
+
+        if let Some(prev) = &prev {
+            let separator = separator(prev, &token, newlines);
+            formatter.push_separator(separator, indent_level(&token));
+        }
+        formatter.push_token(&token);
+        if needs_trailing_comma(&token) {
+            formatter.output.push(',');
+        }
+
+        prev = Some(token);
+        newlines = 0;
+    }
+    formatter.finish()
+}
+
+/// Describes the whitespace between two consecutive tokens.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Separator {
+    /// The tokens are directly adjacent to each other
+    None,
+
+    /// The tokens are separated by a single space
+    Space,
+
+    /// The second token starts on a new line, optionally preceded by an empty
+    /// line.
+    Newline { blank_line: bool },
+
+    /// The second token starts on a new line and is indented one additional
+    /// level to indicate that it continues the previous line.
+    Continuation,
+}
+
+/// Accumulates the formatted output.
+#[derive(Default)]
+struct Formatter {
+    output: String,
+}
+
+impl Formatter {
+    fn push_separator(&mut self, separator: Separator, indent_level: usize) {
+        match separator {
+            Separator::None => {}
+            Separator::Space => self.output.push(' '),
+            Separator::Newline { blank_line } => {
+                self.output.push('\n');
+                if blank_line {
+                    self.output.push('\n');
+                }
+                self.push_indent(indent_level);
+            }
+            Separator::Continuation => {
+                self.output.push('\n');
+                self.push_indent(indent_level + 1);
+            }
+        }
+    }
+
+    fn push_indent(&mut self, indent_level: usize) {
+        for _ in 0..indent_level {
+            self.output.push_str(INDENT);
+        }
+    }
+
+    fn push_token(&mut self, token: &SyntaxToken) {
+        if token.kind() == COMMENT {
+            // Line comments are not allowed to end with trailing whitespace.
+            self.output.push_str(token.text().trim_end());
+        } else {
+            self.output.push_str(token.text());
+        }
+    }
+
+    fn finish(mut self) -> String {
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.output
+    }
+}
+
+/// Computes the whitespace that should be placed between the tokens `prev` and
+/// `token`. `newlines` is the number of line breaks between the two tokens in
+/// the original source.
+fn separator(prev: &SyntaxToken, token: &SyntaxToken, newlines: usize) -> Separator {
+    let blank_line = newlines > 1 && !is_opening_delimiter(prev) && !is_closing_delimiter(token);
+
+    // A line comment always runs until the end of the line, and a line break
+    // after a block comment is kept without indenting the next line
+    if is_line_comment(prev) || (prev.kind() == COMMENT && newlines > 0) {
+        return Separator::Newline { blank_line };
+    }
+
+    // A block comment stays on the same line as the token that follows it,
+    // unless that token closes a vertical container
+    if prev.kind() == COMMENT && !(is_closing_delimiter(token) && starts_vertical_element(token)) {
+        return Separator::Space;
+    }
+
+    if starts_vertical_element(token) {
+        return Separator::Newline { blank_line };
+    }
+
+    // Comments stay on the line they were originally written on
+    if token.kind() == COMMENT {
+        return if newlines > 0 {
+            Separator::Newline { blank_line }
+        } else {
+            Separator::Space
+        };
+    }
+
+    // An attribute on its own line stays on its own line, instead of being
+    // treated as a continuation of the declaration it is attached to
+    if newlines > 0 && prev.kind() == T![']'] && prev.parent().is_some_and(|p| p.kind() == ATTR) {
+        return Separator::Newline { blank_line: false };
+    }
+
+    let may_break = !matches!(
+        token.kind(),
+        T![,] | T![;] | T!['{'] | T![else] | T![')'] | T![']'] | T!['}']
+    );
+    if newlines > 0 && may_break {
+        return Separator::Continuation;
+    }
+
+    inline_separator(prev, token)
+}
+
+/// Computes the separator between two tokens that are placed on the same line.
+fn inline_separator(prev: &SyntaxToken, token: &SyntaxToken) -> Separator {
+    let parent_kind = token.parent().map(|parent| parent.kind());
+
+    let no_space_before = match token.kind() {
+        T![')'] | T![']'] | T![,] | T![;] | T![.] | T![::] | T![:] | INDEX => true,
+        T!['('] => matches!(
+            parent_kind,
+            Some(
+                ARG_LIST
+                    | PARAM_LIST
+                    | TUPLE_FIELD_DEF_LIST
+                    | MEMORY_TYPE_SPECIFIER
+                    | VISIBILITY
+                    | TOKEN_TREE
+            )
+        ),
+        T!['['] => parent_kind == Some(INDEX_EXPR),
+        T!['}'] => prev.kind() == T!['{'] || parent_kind == Some(USE_TREE_LIST),
+        _ => false,
+    };
+
+    let no_space_after = match prev.kind() {
+        T!['('] | T!['['] | T![.] | T![::] | T![#] => true,
+        T!['{'] => prev.parent().map(|parent| parent.kind()) == Some(USE_TREE_LIST),
+        _ => prev.parent().map(|parent| parent.kind()) == Some(PREFIX_EXPR),
+    };
+
+    if no_space_before || no_space_after {
+        Separator::None
+    } else {
+        Separator::Space
+    }
+}
+
+/// Returns true if the specified token is a comment that runs until the end of
+/// the line.
+fn is_line_comment(token: &SyntaxToken) -> bool {
+    token.kind() == COMMENT && token.text().starts_with("//")
+}
+
+fn is_opening_delimiter(token: &SyntaxToken) -> bool {
+    matches!(token.kind(), T!['('] | T!['['] | T!['{'])
+}
+
+fn is_closing_delimiter(token: &SyntaxToken) -> bool {
+    is_closing_delimiter_kind(token.kind())
+}
+
+fn is_closing_delimiter_kind(kind: SyntaxKind) -> bool {
+    matches!(kind, T![')'] | T![']'] | T!['}'])
+}
+
+/// Returns true if the token must be placed on a new line because it is
+/// either the first token of an element of a vertically laid out container, or
+/// because it closes a non-empty vertical container.
+fn starts_vertical_element(token: &SyntaxToken) -> bool {
+    if is_closing_delimiter(token) {
+        return token
+            .parent()
+            .is_some_and(|parent| is_vertical(&parent) && !is_empty_container(&parent));
+    }
+
+    let mut node = token.parent();
+    while let Some(current) = node {
+        if first_significant_token(&current).as_ref() != Some(token) {
+            return false;
+        }
+        match current.parent() {
+            Some(parent) if is_vertical(&parent) => return true,
+            parent => node = parent,
+        }
+    }
+    false
+}
+
+/// Returns true if the specified token ends the last element of a vertically
+/// laid out, comma separated list that does not have a trailing comma.
+fn needs_trailing_comma(token: &SyntaxToken) -> bool {
+    let mut node = token.parent();
+    while let Some(current) = node {
+        if last_significant_token(&current).as_ref() != Some(token) {
+            return false;
+        }
+        match current.parent() {
+            Some(parent) if is_comma_separated(&parent) && is_vertical(&parent) => {
+                return next_significant_sibling(current.into())
+                    .is_some_and(|element| is_closing_delimiter_kind(element.kind()));
+            }
+            parent => node = parent,
+        }
+    }
+    false
+}
+
+/// Returns true if the token is a trailing comma of a list that is laid out on
+/// a single line.
+fn is_redundant_trailing_comma(token: &SyntaxToken) -> bool {
+    token.kind() == T![,]
+        && token
+            .parent()
+            .is_some_and(|parent| is_comma_separated(&parent) && !is_vertical(&parent))
+        && next_significant_sibling(token.clone().into())
+            .is_some_and(|element| is_closing_delimiter_kind(element.kind()))
+}
+
+/// Returns the next sibling of the element that is not trivia.
+fn next_significant_sibling(element: SyntaxElement) -> Option<SyntaxElement> {
+    std::iter::successors(element.next_sibling_or_token(), |element| {
+        element.next_sibling_or_token()
+    })
+    .find(|element| !element.kind().is_trivia())
+}
+
+/// Returns the number of levels the specified token should be indented with
+/// when it starts a new line.
+fn indent_level(token: &SyntaxToken) -> usize {
+    token
+        .parent_ancestors()
+        .filter(|node| node.kind() != SOURCE_FILE && is_vertical(node))
+        .filter(|node| {
+            // The delimiters of the container itself are not indented
+            let is_own_delimiter = token.parent().as_ref() == Some(node)
+                && (is_opening_delimiter(token) || is_closing_delimiter(token));
+            !is_own_delimiter
+        })
+        .count()
+}
+
+/// Returns true if the elements of the specified node are laid out on separate
+/// lines.
+fn is_vertical(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        SOURCE_FILE | BLOCK_EXPR | RECORD_FIELD_DEF_LIST | ASSOCIATED_ITEM_LIST => true,
+
+        // Lists are only laid out vertically if the user chose to break them
+        // over multiple lines.
+        ARG_LIST | PARAM_LIST | ARRAY_EXPR | RECORD_FIELD_LIST | USE_TREE_LIST
+        | TUPLE_FIELD_DEF_LIST => node
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .any(|token| {
+                token.kind() == WHITESPACE && token.text().contains('\n') || is_line_comment(&token)
+            }),
+        _ => false,
+    }
+}
+
+/// Returns true if the elements of the specified node are separated by commas.
+fn is_comma_separated(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        RECORD_FIELD_DEF_LIST
+        | ARG_LIST
+        | PARAM_LIST
+        | RECORD_FIELD_LIST
+        | USE_TREE_LIST
+        | TUPLE_FIELD_DEF_LIST => true,
+        // The initializer and length of `[0; 4]` are separated by a semicolon
+        ARRAY_EXPR => ast::ArrayExpr::cast(node.clone())
+            .is_some_and(|array| matches!(array.kind(), ast::ArrayExprKind::ElementList(_))),
+        _ => false,
+    }
+}
+
+/// Returns true if the container contains nothing but its delimiters.
+fn is_empty_container(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .all(|element| is_trivia_or_delimiter(element.kind()) && element.kind() != COMMENT)
+}
+
+fn is_trivia_or_delimiter(kind: SyntaxKind) -> bool {
+    kind.is_trivia()
+        || matches!(
+            kind,
+            T!['('] | T![')'] | T!['['] | T![']'] | T!['{'] | T!['}']
+        )
+}
+
+/// Returns the first token of a node that is not whitespace.
+fn first_significant_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .find(|token| token.kind() != WHITESPACE)
+}
+
+/// Returns the last token of a node that is not trivia.
+fn last_significant_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let mut token = node.last_token();
+    while let Some(current) = token {
+        if !current.kind().is_trivia() {
+            return Some(current);
+        }
+        token = current.prev_token();
+    }
+    None
+}
@@ -0,0 +1,283 @@
+use crate::{format, FormatError};
+
+fn check(text: &str) -> String {
+    let formatted = format(text).expect("source contains syntax errors");
+    let formatted_again = format(&formatted).expect("formatted source contains syntax errors");
+    assert_eq!(formatted, formatted_again, "formatting is not idempotent");
+    formatted
+}
+
+#[test]
+fn functions() {
+    insta::assert_snapshot!(check(r#"
+pub  fn   add(a:i32,b :i32)->i32{a+b}
+fn main(){let a=add(1,2);let b:i32=-a;
+    if a>b{a}else{b};
+}
+extern fn foo() -> never;
+"#), @r"
+    pub fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+    fn main() {
+        let a = add(1, 2);
+        let b: i32 = -a;
+        if a > b {
+            a
+        } else {
+            b
+        };
+    }
+    extern fn foo() -> never;
+    ");
+}
+
+#[test]
+fn structs() {
+    insta::assert_snapshot!(check(r#"
+struct(gc) Foo{a:i32,pub b:f64}
+pub(package) struct Bar(i32,f32);
+struct Baz;
+fn main(){let foo=Foo{a:1,b:2.0,};let bar=Bar(1,2.0);foo.a+bar.0;}
+"#), @r"
+    struct(gc) Foo {
+        a: i32,
+        pub b: f64,
+    }
+    pub(package) struct Bar(i32, f32);
+    struct Baz;
+    fn main() {
+        let foo = Foo { a: 1, b: 2.0 };
+        let bar = Bar(1, 2.0);
+        foo.a + bar.0;
+    }
+    ");
+}
+
+#[test]
+fn attributes() {
+    insta::assert_snapshot!(check(r#"
+#[ allow ( dead_code,unused_variables ) ]
+fn foo(#[allow(unused_variables)]a:i32){}
+struct Foo{#[allow(dead_code)]a:i32}
+#[allow(dead_code)] impl Foo{
+#[warn(dead_code)]
+fn bar(){}}
+"#), @r"
+    #[allow(dead_code, unused_variables)]
+    fn foo(#[allow(unused_variables)] a: i32) {}
+    struct Foo {
+        #[allow(dead_code)] a: i32,
+    }
+    #[allow(dead_code)] impl Foo {
+        #[warn(dead_code)]
+        fn bar() {}
+    }
+    ");
+}
+
+#[test]
+fn impls() {
+    insta::assert_snapshot!(check(r#"
+impl Foo{fn new()->Self{Self{a:1}}
+pub fn get(self)->i32{self.a}}
+"#), @r"
+    impl Foo {
+        fn new() -> Self {
+            Self { a: 1 }
+        }
+        pub fn get(self) -> i32 {
+            self.a
+        }
+    }
+    ");
+}
+
+#[test]
+fn uses() {
+    insta::assert_snapshot!(check(r#"
+use package::foo::{Foo,Bar as Baz};
+pub use super::*;
+"#), @r"
+    use package::foo::{Foo, Bar as Baz};
+    pub use super::*;
+    ");
+}
+
+#[test]
+fn constants() {
+    insta::assert_snapshot!(check(r#"
+pub  const   SIZE:usize=4*2;
+const ORIGIN:Point=Point{x:0.0,y:0.0};
+fn main(){let a=[0;SIZE];let b=[
+    ORIGIN;
+    SIZE
+];}
+"#), @r"
+    pub const SIZE: usize = 4 * 2;
+    const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+    fn main() {
+        let a = [0; SIZE];
+        let b = [
+            ORIGIN;
+            SIZE
+        ];
+    }
+    ");
+}
+
+#[test]
+fn expressions() {
+    insta::assert_snapshot!(check(r#"
+fn main(){
+    let a=[1,2,3];let b=a[0];
+    let i=0;
+    while i<10{i+=1;if i==5{break;}}
+    loop{return;}
+    let c=(1+2)*3;
+    let d=!true&&false;
+    foo.bar(1,2).baz()
+}
+"#), @r"
+    fn main() {
+        let a = [1, 2, 3];
+        let b = a[0];
+        let i = 0;
+        while i < 10 {
+            i += 1;
+            if i == 5 {
+                break;
+            }
+        }
+        loop {
+            return;
+        }
+        let c = (1 + 2) * 3;
+        let d = !true && false;
+        foo.bar(1, 2).baz()
+    }
+    ");
+}
+
+#[test]
+fn comments() {
+    insta::assert_snapshot!(check(r#"
+// A comment at the top of the file
+
+/// Documents foo
+fn foo(){ // trailing comment after brace
+    // leading comment
+    let a = 1;   // trailing comment
+
+    /* block */ let b = 2;
+    // comment at end of block
+}
+"#), @r"
+    // A comment at the top of the file
+
+    /// Documents foo
+    fn foo() { // trailing comment after brace
+        // leading comment
+        let a = 1; // trailing comment
+
+        /* block */ let b = 2;
+        // comment at end of block
+    }
+    ");
+}
+
+#[test]
+fn block_comments() {
+    insta::assert_snapshot!(check("/* c */\nfn f(){}"), @r"
+    /* c */
+    fn f() {}
+    ");
+    insta::assert_snapshot!(check("fn g(){}\n/* a\nb */\nfn f(){}"), @r"
+    fn g() {}
+    /* a
+    b */
+    fn f() {}
+    ");
+    insta::assert_snapshot!(check("fn g(){ let a = 1; /* x */ }"), @r"
+    fn g() {
+        let a = 1; /* x */
+    }
+    ");
+    insta::assert_snapshot!(check("fn g(){ /* x */ }"), @r"
+    fn g() { /* x */
+    }
+    ");
+}
+
+#[test]
+fn blank_lines() {
+    insta::assert_snapshot!(check(r#"
+
+
+fn foo() {
+
+    let a = 1;
+
+
+
+    let b = 2;
+
+}
+
+
+
+fn bar() {}
+"#), @r"
+    fn foo() {
+        let a = 1;
+
+        let b = 2;
+    }
+
+    fn bar() {}
+    ");
+}
+
+#[test]
+fn user_line_breaks() {
+    insta::assert_snapshot!(check(r#"
+fn foo() {
+    let a = some_long_function_name(1)
+        + another_long_function_name(2);
+    let b = call(
+        1,
+        2, // the second argument
+    );
+    let c = Foo {
+        a: 1,
+        b: 2 };
+    let d = [1,
+      2];
+}
+"#), @r"
+    fn foo() {
+        let a = some_long_function_name(1)
+            + another_long_function_name(2);
+        let b = call(
+            1,
+            2, // the second argument
+        );
+        let c = Foo {
+            a: 1,
+            b: 2,
+        };
+        let d = [
+            1,
+            2,
+        ];
+    }
+    ");
+}
+
+#[test]
+fn syntax_errors() {
+    assert!(matches!(
+        format("fn foo( {"),
+        Err(FormatError::SyntaxErrors(errors)) if !errors.is_empty()
+    ));
+}
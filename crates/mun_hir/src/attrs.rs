@@ -0,0 +1,174 @@
+//! Attributes attach additional information to declarations, struct fields and
+//! parameters, e.g. `#[allow(dead_code)]`.
+
+use std::sync::Arc;
+
+use mun_syntax::{ast, AstNode, SmolStr, SyntaxElement, SyntaxKind, T};
+
+use crate::{
+    item_tree::{ItemTreeId, ItemTreeNode},
+    lint::LintLevel,
+    DefDatabase,
+};
+
+/// The names of all attributes that are understood by the compiler.
+pub(crate) const KNOWN_ATTRIBUTES: &[&str] = &["allow", "warn", "deny", HOT_RELOAD_ATTRIBUTE];
+
+/// The name of the attribute that marks a function as a hot-reload boundary.
+/// Calls to such a function from other assemblies are always linked at
+/// runtime, even when link-time optimization is enabled.
+pub const HOT_RELOAD_ATTRIBUTE: &str = "hot_reload";
+
+/// A single attribute, e.g. `#[allow(dead_code, unused_variables)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attr {
+    name: SmolStr,
+    args: Option<Box<[SmolStr]>>,
+}
+
+impl Attr {
+    /// Lowers an attribute from the AST. Returns `None` if the attribute has no
+    /// name.
+    pub(crate) fn from_ast(attr: &ast::Attr) -> Option<Attr> {
+        let path = attr.path()?;
+        let name = path
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|token| !token.kind().is_trivia())
+            .map(|token| token.text().to_string())
+            .collect::<String>();
+
+        Some(Attr {
+            name: name.into(),
+            args: attr.token_tree().map(|tt| lower_args(&tt)),
+        })
+    }
+
+    /// Returns the name of the attribute, e.g. `allow`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the comma separated arguments of the attribute, e.g.
+    /// `["dead_code", "unused_variables"]`, or `None` if the attribute has no
+    /// argument list. Whitespace is removed from the arguments.
+    pub fn args(&self) -> Option<&[SmolStr]> {
+        self.args.as_deref()
+    }
+
+    /// Returns the lint level that this attribute configures, if it is one of
+    /// `allow`, `warn` or `deny`.
+    pub fn lint_level(&self) -> Option<LintLevel> {
+        match self.name() {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the contents of a token tree on its top-level commas.
+fn lower_args(tt: &ast::TokenTree) -> Box<[SmolStr]> {
+    let mut tokens = tt
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|token| !token.kind().is_trivia())
+        .peekable();
+
+    // Skip the opening parenthesis
+    tokens.next_if(|token| token.kind() == T!['(']);
+
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    for token in tokens {
+        match token.kind() {
+            T!['('] | T!['['] | T!['{'] => depth += 1,
+            T![')'] if depth == 0 => break,
+            T![')'] | T![']'] | T!['}'] => depth = depth.saturating_sub(1),
+            T![,] if depth == 0 => {
+                args.push(SmolStr::from(std::mem::take(&mut current)));
+                continue;
+            }
+            SyntaxKind::ERROR => continue,
+            _ => {}
+        }
+        current.push_str(token.text());
+    }
+
+    if !current.is_empty() {
+        args.push(SmolStr::from(current));
+    }
+
+    args.into_boxed_slice()
+}
+
+/// The attributes of a declaration, struct field or parameter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attrs(Option<Arc<[Attr]>>);
+
+pub(crate) static EMPTY_ATTRS: Attrs = Attrs(None);
+
+impl Attrs {
+    /// Lowers all attributes of an AST node.
+    pub fn from_ast(owner: &impl ast::AttrsOwner) -> Attrs {
+        owner
+            .attrs()
+            .filter_map(|attr| Attr::from_ast(&attr))
+            .collect()
+    }
+
+    /// Returns true if there are no attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns an iterator over all attributes.
+    pub fn iter(&self) -> impl Iterator<Item = &Attr> {
+        self.0.iter().flat_map(|attrs| attrs.iter())
+    }
+
+    /// Returns an iterator over all attributes with the specified name.
+    pub fn by_name<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Attr> {
+        self.iter().filter(move |attr| attr.name() == name)
+    }
+
+    /// Returns true if there is an attribute with the specified name.
+    pub fn has(&self, name: &str) -> bool {
+        self.by_name(name).next().is_some()
+    }
+
+    /// Returns the level that these attributes configure for the lint with the
+    /// specified name. If the lint is mentioned more than once, the last
+    /// attribute wins.
+    pub fn lint_level(&self, lint: &str) -> Option<LintLevel> {
+        self.iter()
+            .filter(|attr| {
+                attr.args()
+                    .is_some_and(|args| args.iter().any(|arg| arg == lint))
+            })
+            .filter_map(Attr::lint_level)
+            .last()
+    }
+}
+
+/// Returns the attributes of an item in the item tree.
+pub(crate) fn item_attrs<N: ItemTreeNode>(db: &dyn DefDatabase, id: ItemTreeId<N>) -> Attrs {
+    db.item_tree(id.file_id)
+        .attrs(N::id_to_mod_item(id.value).into())
+        .clone()
+}
+
+impl FromIterator<Attr> for Attrs {
+    fn from_iter<T: IntoIterator<Item = Attr>>(iter: T) -> Self {
+        let attrs = iter.into_iter().collect::<Vec<_>>();
+        if attrs.is_empty() {
+            Attrs(None)
+        } else {
+            Attrs(Some(attrs.into()))
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::{iter::once, sync::Arc};
+
+use mun_hir_input::FileId;
+use mun_syntax::ast::TypeAscriptionOwner;
+
+use super::Module;
+use crate::{
+    attrs::{self, Attrs},
+    consteval::{ConstEvalError, ConstValue},
+    diagnostics::ConstEvalFailure,
+    expr::{validator::ExprValidator, BodySourceMap},
+    has_module::HasModule,
+    ids::{ConstId, Lookup},
+    name_resolution::Namespace,
+    resolve::HasResolver,
+    type_ref::{LocalTypeRefId, TypeRefMap, TypeRefSourceMap},
+    visibility::RawVisibility,
+    Body, DefDatabase, DiagnosticSink, HasVisibility, HirDatabase, InferenceResult, Name, Ty,
+    Visibility,
+};
+
+/// A constant item, e.g. `const MAX_SPEED: f32 = 10.0;`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub struct Const {
+    pub(crate) id: ConstId,
+}
+
+impl From<ConstId> for Const {
+    fn from(id: ConstId) -> Self {
+        Const { id }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstData {
+    pub name: Name,
+    pub visibility: RawVisibility,
+    pub type_ref_id: LocalTypeRefId,
+    type_ref_map: TypeRefMap,
+    type_ref_source_map: TypeRefSourceMap,
+}
+
+impl ConstData {
+    pub(crate) fn const_data_query(db: &dyn DefDatabase, id: ConstId) -> Arc<ConstData> {
+        let loc = id.lookup(db);
+        let item_tree = db.item_tree(loc.id.file_id);
+        let konst = &item_tree[loc.id.value];
+        let src = item_tree.source(db, loc.id.value);
+        let mut type_ref_builder = TypeRefMap::builder();
+        let type_ref_id = type_ref_builder.alloc_from_node_opt(src.ascribed_type().as_ref());
+        let (type_ref_map, type_ref_source_map) = type_ref_builder.finish();
+        Arc::new(ConstData {
+            name: konst.name.clone(),
+            visibility: item_tree[konst.visibility].clone(),
+            type_ref_id,
+            type_ref_map,
+            type_ref_source_map,
+        })
+    }
+
+    pub fn type_ref_source_map(&self) -> &TypeRefSourceMap {
+        &self.type_ref_source_map
+    }
+
+    pub fn type_ref_map(&self) -> &TypeRefMap {
+        &self.type_ref_map
+    }
+}
+
+impl Const {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        self.id.module(db).into()
+    }
+
+    /// Returns the full name of the constant including all module specifiers
+    /// (e.g: `foo::BAR`).
+    pub fn full_name(self, db: &dyn HirDatabase) -> String {
+        itertools::Itertools::intersperse(
+            self.module(db)
+                .package()
+                .qualifier(db)
+                .into_iter()
+                .chain(
+                    self.module(db)
+                        .path_to_root(db)
+                        .into_iter()
+                        .rev()
+                        .filter_map(|module| module.name(db)),
+                )
+                .chain(once(self.name(db).to_string())),
+            String::from("::"),
+        )
+        .collect()
+    }
+
+    pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
+        self.id.lookup(db).id.file_id
+    }
+
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        self.data(db).name.clone()
+    }
+
+    pub fn data(self, db: &dyn DefDatabase) -> Arc<ConstData> {
+        db.const_data(self.id)
+    }
+
+    /// Returns the attributes of the constant.
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        attrs::item_attrs(db, self.id.lookup(db).id)
+    }
+
+    /// Returns the declared type of the constant.
+    pub fn ty(self, db: &dyn HirDatabase) -> Ty {
+        db.type_for_def(self.into(), Namespace::Values)
+    }
+
+    pub fn body(self, db: &dyn HirDatabase) -> Arc<Body> {
+        db.body(self.id.into())
+    }
+
+    pub fn infer(self, db: &dyn HirDatabase) -> Arc<InferenceResult> {
+        db.infer(self.id.into())
+    }
+
+    /// Evaluates the value of the constant.
+    pub fn eval(self, db: &dyn HirDatabase) -> Result<ConstValue, ConstEvalError> {
+        db.const_eval(self.id)
+    }
+
+    pub(crate) fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
+        db.body_with_source_map(self.id.into()).1
+    }
+
+    pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink<'_>) {
+        let body = self.body(db);
+        body.add_diagnostics(db, self.into(), sink);
+        let infer = self.infer(db);
+        infer.add_diagnostics(db, self.into(), sink);
+        let validator = ExprValidator::new(self.into(), db);
+        validator.validate_body(sink);
+
+        // Errors in the initializer are reported at the expression that could not be
+        // evaluated, a cycle is reported at the initializer itself.
+        let Err(error) = self.eval(db) else {
+            return;
+        };
+        let expr = match error {
+            ConstEvalError::Cycle => body.body_expr(),
+            _ => match error.expr() {
+                Some(expr) => expr,
+                None => return,
+            },
+        };
+        if let Some(ptr) = self.body_source_map(db).expr_syntax(expr) {
+            sink.push(ConstEvalFailure {
+                file: self.file_id(db),
+                expr: ptr
+                    .value
+                    .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                error,
+            });
+        }
+    }
+}
+
+impl HasVisibility for Const {
+    fn visibility(&self, db: &dyn HirDatabase) -> Visibility {
+        self.data(db).visibility.resolve(db, &self.id.resolver(db))
+    }
+}
@@ -1,20 +1,22 @@
 use std::{iter::once, sync::Arc};
 
 use mun_hir_input::FileId;
-use mun_syntax::{ast, ast::TypeAscriptionOwner};
+use mun_syntax::{ast, ast::TypeAscriptionOwner, TextRange};
 
 use super::Module;
 use crate::{
+    attrs::{self, Attrs},
     expr::{validator::ExprValidator, BodySourceMap},
     has_module::HasModule,
-    ids::{FunctionId, Lookup},
-    item_tree::FunctionFlags,
+    ids::{FunctionId, ItemContainerId, Lookup},
+    item_tree::{AttrOwner, FunctionFlags},
     name_resolution::Namespace,
     resolve::HasResolver,
+    semantics,
     type_ref::{LocalTypeRefId, TypeRefMap, TypeRefSourceMap},
     visibility::RawVisibility,
-    Body, DefDatabase, DiagnosticSink, HasSource, HasVisibility, HirDatabase, InFile,
-    InferenceResult, Name, Pat, Ty, Visibility,
+    Body, DefDatabase, DiagnosticSink, ExprId, HasSource, HasVisibility, HirDatabase, InFile,
+    InferenceResult, Name, Pat, PatId, Ty, Visibility,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -130,9 +132,16 @@ impl Function {
     pub fn full_name(self, db: &dyn HirDatabase) -> String {
         itertools::Itertools::intersperse(
             self.module(db)
-                .path_to_root(db)
+                .package()
+                .qualifier(db)
                 .into_iter()
-                .filter_map(|module| module.name(db))
+                .chain(
+                    self.module(db)
+                        .path_to_root(db)
+                        .into_iter()
+                        .rev()
+                        .filter_map(|module| module.name(db)),
+                )
                 .chain(once(self.name(db).to_string())),
             String::from("::"),
         )
@@ -143,6 +152,15 @@ impl Function {
         self.id.lookup(db).id.file_id
     }
 
+    /// Returns the `impl` block that contains this function, or `None` if the
+    /// function is defined directly in a module.
+    pub fn impl_container(self, db: &dyn HirDatabase) -> Option<semantics::Impl> {
+        match self.id.lookup(db).container {
+            ItemContainerId::ImplId(id) => Some(id.into()),
+            ItemContainerId::ModuleId(_) => None,
+        }
+    }
+
     pub fn name(self, db: &dyn HirDatabase) -> Name {
         self.data(db).name.clone()
     }
@@ -187,6 +205,34 @@ impl Function {
         db.fn_data(self.id).flags.is_extern()
     }
 
+    /// Returns the attributes of the function.
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        attrs::item_attrs(db, self.id.lookup(db).id)
+    }
+
+    /// Returns the range in the source file of an expression in the body of
+    /// the function, or `None` if the expression does not originate from the
+    /// source.
+    pub fn expr_range(self, db: &dyn HirDatabase, expr: ExprId) -> Option<TextRange> {
+        self.body_source_map(db).expr_syntax(expr).map(|ptr| {
+            ptr.value.either(
+                |it| it.syntax_node_ptr().range(),
+                |it| it.syntax_node_ptr().range(),
+            )
+        })
+    }
+
+    /// Returns the range in the source file of a pattern in the body of the
+    /// function, or `None` if the pattern does not originate from the source.
+    pub fn pat_range(self, db: &dyn HirDatabase, pat: PatId) -> Option<TextRange> {
+        self.body_source_map(db).pat_syntax(pat).map(|ptr| {
+            ptr.value.either(
+                |it| it.syntax_node_ptr().range(),
+                |it| it.syntax_node_ptr().range(),
+            )
+        })
+    }
+
     pub(crate) fn body_source_map(self, db: &dyn HirDatabase) -> Arc<BodySourceMap> {
         db.body_with_source_map(self.id.into()).1
     }
@@ -195,8 +241,8 @@ impl Function {
         let body = self.body(db);
         body.add_diagnostics(db, self.into(), sink);
         let infer = self.infer(db);
-        infer.add_diagnostics(db, self, sink);
-        let validator = ExprValidator::new(self, db);
+        infer.add_diagnostics(db, self.into(), sink);
+        let validator = ExprValidator::new(self.into(), db);
         validator.validate_body(sink);
     }
 }
@@ -226,6 +272,18 @@ impl Param {
         &self.ty
     }
 
+    /// Returns the attributes of the parameter.
+    pub fn attrs(&self, db: &dyn HirDatabase) -> Attrs {
+        let id = self.func.id.lookup(db).id;
+        let item_tree = db.item_tree(id.file_id);
+        item_tree[id.value]
+            .params
+            .clone()
+            .nth(self.idx)
+            .map(|param| item_tree.attrs(AttrOwner::Param(param)).clone())
+            .unwrap_or_default()
+    }
+
     /// Returns the source of the parameter.
     pub fn source(&self, db: &dyn HirDatabase) -> Option<InFile<ast::Param>> {
         let InFile { file_id, value } = self.func.source(db);
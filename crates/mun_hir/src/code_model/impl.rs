@@ -3,6 +3,7 @@ use std::sync::Arc;
 use mun_hir_input::FileId;
 
 use crate::{
+    attrs::{self, Attrs},
     has_module::HasModule,
     ids::{AssocItemId, FunctionLoc, ImplId, Intern, ItemContainerId, Lookup},
     item_tree::{AssociatedItem, ItemTreeId},
@@ -36,6 +37,11 @@ impl Impl {
         self.id.lookup(db).id.file_id
     }
 
+    /// Returns the attributes of the implementation
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        attrs::item_attrs(db, self.id.lookup(db).id)
+    }
+
     /// Returns the type for which this is an implementation
     pub fn self_ty(self, db: &dyn HirDatabase) -> Ty {
         let data = db.impl_data(self.id);
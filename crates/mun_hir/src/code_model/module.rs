@@ -1,7 +1,7 @@
 use mun_hir_input::{FileId, ModuleId};
 
-use super::{r#impl::Impl, AssocItem, Function, Package, PrimitiveType, Struct, TypeAlias};
-use crate::{ids::ItemDefinitionId, DiagnosticSink, HirDatabase};
+use super::{r#impl::Impl, AssocItem, Const, Function, Package, PrimitiveType, Struct, TypeAlias};
+use crate::{ids::ItemDefinitionId, lint, DiagnosticSink, HirDatabase};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct Module {
@@ -97,6 +97,7 @@ impl Module {
                 ModuleDef::Function(f) => f.diagnostics(db, sink),
                 ModuleDef::Struct(s) => s.diagnostics(db, sink),
                 ModuleDef::TypeAlias(t) => t.diagnostics(db, sink),
+                ModuleDef::Const(c) => c.diagnostics(db, sink),
                 _ => (),
             }
         }
@@ -111,6 +112,13 @@ impl Module {
         }
     }
 
+    /// Iterate over all lint violations in this `Module` by placing them in
+    /// the `sink`. In contrast to [`Module::diagnostics`], these do not
+    /// necessarily prevent compilation.
+    pub fn lints(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink<'_>) {
+        lint::check_module(db, self, sink);
+    }
+
     /// Returns all the child modules of this module
     pub fn children(self, db: &dyn HirDatabase) -> Vec<Module> {
         let module_tree = db.module_tree(self.id.package);
@@ -140,9 +148,12 @@ impl Module {
     /// Returns the name of this module including all parent modules
     pub fn full_name(self, db: &dyn HirDatabase) -> String {
         itertools::Itertools::intersperse(
-            self.path_to_root(db)
-                .iter()
-                .filter_map(|&module| module.name(db)),
+            self.package().qualifier(db).into_iter().chain(
+                self.path_to_root(db)
+                    .iter()
+                    .rev()
+                    .filter_map(|&module| module.name(db)),
+            ),
             String::from("::"),
         )
         .collect()
@@ -164,6 +175,7 @@ pub enum ModuleDef {
     PrimitiveType(PrimitiveType),
     Struct(Struct),
     TypeAlias(TypeAlias),
+    Const(Const),
 }
 
 impl From<Function> for ModuleDef {
@@ -190,6 +202,12 @@ impl From<TypeAlias> for ModuleDef {
     }
 }
 
+impl From<Const> for ModuleDef {
+    fn from(t: Const) -> Self {
+        ModuleDef::Const(t)
+    }
+}
+
 impl From<Module> for ModuleDef {
     fn from(m: Module) -> Self {
         ModuleDef::Module(m)
@@ -203,6 +221,7 @@ impl From<ItemDefinitionId> for ModuleDef {
             ItemDefinitionId::FunctionId(id) => Function { id }.into(),
             ItemDefinitionId::StructId(id) => Struct { id }.into(),
             ItemDefinitionId::TypeAliasId(id) => TypeAlias { id }.into(),
+            ItemDefinitionId::ConstId(id) => Const { id }.into(),
             ItemDefinitionId::PrimitiveType(ty) => PrimitiveType { inner: ty }.into(),
         }
     }
@@ -15,6 +15,11 @@ impl Package {
         db.packages().iter().map(|id| Package { id }).collect()
     }
 
+    /// Returns the id of the package in the `PackageSet`
+    pub fn id(self) -> PackageId {
+        self.id
+    }
+
     /// Returns the root module of the package (represented by the `mod.rs` in
     /// the source root)
     pub fn root_module(self, db: &dyn HirDatabase) -> Module {
@@ -27,6 +32,59 @@ impl Package {
         }
     }
 
+    /// Returns the packages this package depends on together with the names
+    /// with which they are referred to.
+    pub fn dependencies(self, db: &dyn HirDatabase) -> Vec<(String, Package)> {
+        db.packages()[self.id]
+            .dependencies
+            .iter()
+            .map(|dependency| {
+                (
+                    dependency.name.clone(),
+                    Package {
+                        id: dependency.package,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the name of the package as specified in its manifest. Packages
+    /// without a manifest are named after the name with which another package
+    /// refers to them, if any.
+    pub fn name(self, db: &dyn HirDatabase) -> Option<String> {
+        let packages = db.packages();
+        packages[self.id].name.clone().or_else(|| {
+            packages.iter().find_map(|package| {
+                packages[package]
+                    .dependencies
+                    .iter()
+                    .find(|dependency| dependency.package == self.id)
+                    .map(|dependency| dependency.name.clone())
+            })
+        })
+    }
+
+    /// Returns the name that qualifies the symbols of this package to ensure
+    /// they do not clash with the symbols of other packages, or `None` if no
+    /// other package depends on it. The symbols of the package that is loaded
+    /// by the host are not qualified, so they can be referred to by their path
+    /// within the package.
+    pub fn qualifier(self, db: &dyn HirDatabase) -> Option<String> {
+        let packages = db.packages();
+        let is_dependency = packages.iter().any(|package| {
+            packages[package]
+                .dependencies
+                .iter()
+                .any(|dependency| dependency.package == self.id)
+        });
+        if is_dependency {
+            self.name(db)
+        } else {
+            None
+        }
+    }
+
     /// Returns all the modules in the package
     pub fn modules(self, db: &dyn HirDatabase) -> Vec<Module> {
         let module_tree = db.module_tree(self.id);
@@ -10,8 +10,10 @@ use mun_syntax::{
 
 use super::Module;
 use crate::{
+    attrs::{self, Attrs},
     has_module::HasModule,
     ids::{Lookup, StructId},
+    item_tree::{AttrOwner, Fields},
     name::AsName,
     name_resolution::Namespace,
     resolve::HasResolver,
@@ -60,6 +62,20 @@ impl Field {
         self.id.into_raw().into()
     }
 
+    /// Returns the attributes of the field
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        let id = self.parent.id.lookup(db).id;
+        let item_tree = db.item_tree(id.file_id);
+        let mut fields = match &item_tree[id.value].fields {
+            Fields::Record(fields) | Fields::Tuple(fields) => fields.clone(),
+            Fields::Unit => return Attrs::default(),
+        };
+        fields
+            .nth(self.index(db) as usize)
+            .map(|field| item_tree.attrs(AttrOwner::Field(field)).clone())
+            .unwrap_or_default()
+    }
+
     /// Returns the ID of the field with relation to the parent struct
     pub(crate) fn id(self) -> LocalFieldId {
         self.id
@@ -79,6 +95,11 @@ impl Struct {
         db.struct_data(self.id)
     }
 
+    /// Returns the attributes of the struct.
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        attrs::item_attrs(db, self.id.lookup(db).id)
+    }
+
     /// Returns the name of the struct non including any module specifiers (e.g:
     /// `Bar`).
     pub fn name(self, db: &dyn HirDatabase) -> Name {
@@ -90,9 +111,16 @@ impl Struct {
     pub fn full_name(self, db: &dyn HirDatabase) -> String {
         itertools::Itertools::intersperse(
             self.module(db)
-                .path_to_root(db)
+                .package()
+                .qualifier(db)
                 .into_iter()
-                .filter_map(|module| module.name(db))
+                .chain(
+                    self.module(db)
+                        .path_to_root(db)
+                        .into_iter()
+                        .rev()
+                        .filter_map(|module| module.name(db)),
+                )
                 .chain(once(self.name(db).to_string())),
             String::from("::"),
         )
@@ -4,6 +4,7 @@ use mun_hir_input::FileId;
 
 use super::Module;
 use crate::{
+    attrs::{self, Attrs},
     expr::validator::TypeAliasValidator,
     has_module::HasModule,
     ids::{Lookup, TypeAliasId},
@@ -30,6 +31,11 @@ impl TypeAlias {
         self.id.module(db).into()
     }
 
+    /// Returns the attributes of the type alias.
+    pub fn attrs(self, db: &dyn HirDatabase) -> Attrs {
+        attrs::item_attrs(db, self.id.lookup(db).id)
+    }
+
     pub fn file_id(self, db: &dyn HirDatabase) -> FileId {
         self.id.lookup(db).id.file_id
     }
@@ -0,0 +1,625 @@
+//! Evaluation of constant expressions at compile time.
+//!
+//! The evaluator interprets the HIR of a body. It supports primitive values,
+//! value structs and SIMD vectors together with the operations that can be
+//! performed on them. Everything that would require the runtime, like
+//! function calls or heap allocations, is rejected.
+
+use std::{fmt, sync::Arc};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    code_model::StructMemoryKind,
+    expr::{ArithOp, BinaryOp, CmpOp, LogicOp, Ordering, Statement},
+    ids::{ConstId, DefWithBodyId},
+    resolve::{resolver_for_expr, ValueNs},
+    ty::{lower::CallableDef, ResolveBitness},
+    Body, Expr, ExprId, FloatBitness, FloatTy, HirDatabase, InferenceResult, IntBitness, IntTy,
+    Literal, Pat, PatId, Signedness, Struct, Ty, TyKind, UnaryOp,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The value of a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    Bool(bool),
+    /// The bits of an integer, truncated to the size of its type.
+    Int(u128),
+    Float(f64),
+    /// The fields of a value struct, in declaration order.
+    Struct(Vec<ConstValue>),
+    /// The lanes of a SIMD vector.
+    Vector(Vec<ConstValue>),
+}
+
+impl Eq for ConstValue {}
+
+/// The reason why an expression could not be evaluated at compile time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConstEvalError {
+    /// The expression cannot be evaluated at compile time.
+    NotConst(ExprId),
+    /// An arithmetic operation overflowed.
+    Overflow(ExprId),
+    /// A division or remainder with a zero divisor.
+    DivideByZero(ExprId),
+    /// The value of a constant depends on itself.
+    Cycle,
+    /// A constant that is used by the expression could not be evaluated. The
+    /// error is reported for the constant itself.
+    ErroneousConst(ConstId),
+    /// The expression contains type errors, which are reported by type
+    /// inference.
+    TypeError,
+}
+
+impl ConstEvalError {
+    /// Returns the expression that caused the error, if the error should be
+    /// reported at all.
+    pub fn expr(&self) -> Option<ExprId> {
+        match self {
+            ConstEvalError::NotConst(expr)
+            | ConstEvalError::Overflow(expr)
+            | ConstEvalError::DivideByZero(expr) => Some(*expr),
+            ConstEvalError::Cycle
+            | ConstEvalError::ErroneousConst(_)
+            | ConstEvalError::TypeError => None,
+        }
+    }
+}
+
+impl fmt::Display for ConstEvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstEvalError::NotConst(_) => {
+                f.write_str("expression cannot be evaluated at compile time")
+            }
+            ConstEvalError::Overflow(_) => f.write_str("arithmetic operation overflows"),
+            ConstEvalError::DivideByZero(_) => f.write_str("attempt to divide by zero"),
+            ConstEvalError::Cycle => f.write_str("the value of the constant depends on itself"),
+            ConstEvalError::ErroneousConst(_) => f.write_str("erroneous constant used"),
+            ConstEvalError::TypeError => f.write_str("the expression contains type errors"),
+        }
+    }
+}
+
+/// Evaluates the value of a constant.
+pub(crate) fn const_eval_query(
+    db: &dyn HirDatabase,
+    def: ConstId,
+) -> Result<ConstValue, ConstEvalError> {
+    let mut evaluator = ConstEvaluator::new(db, def.into());
+    evaluator.eval(evaluator.body.body_expr())
+}
+
+/// Called by salsa when the value of a constant depends on itself.
+pub(crate) fn const_eval_recover(
+    _db: &dyn HirDatabase,
+    _cycle: &[String],
+    _def: &ConstId,
+) -> Result<ConstValue, ConstEvalError> {
+    Err(ConstEvalError::Cycle)
+}
+
+/// Evaluates an expression in the body of `owner` at compile time, e.g. the
+/// length of an array.
+pub fn eval_const_expr(
+    db: &dyn HirDatabase,
+    owner: DefWithBodyId,
+    expr: ExprId,
+) -> Result<ConstValue, ConstEvalError> {
+    ConstEvaluator::new(db, owner).eval(expr)
+}
+
+struct ConstEvaluator<'a> {
+    db: &'a dyn HirDatabase,
+    owner: DefWithBodyId,
+    body: Arc<Body>,
+    infer: Arc<InferenceResult>,
+    locals: FxHashMap<PatId, ConstValue>,
+}
+
+impl<'a> ConstEvaluator<'a> {
+    fn new(db: &'a dyn HirDatabase, owner: DefWithBodyId) -> Self {
+        ConstEvaluator {
+            db,
+            owner,
+            body: db.body(owner),
+            infer: db.infer(owner),
+            locals: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the type of the specified expression with all type aliases
+    /// resolved.
+    fn type_of(&self, expr: ExprId) -> Result<Ty, ConstEvalError> {
+        let mut ty = self.infer[expr].clone();
+        while let &TyKind::TypeAlias(alias) = ty.interned() {
+            ty = alias.target_type(self.db);
+        }
+        if ty.is_unknown() {
+            Err(ConstEvalError::TypeError)
+        } else {
+            Ok(ty)
+        }
+    }
+
+    fn eval(&mut self, expr: ExprId) -> Result<ConstValue, ConstEvalError> {
+        let ty = self.type_of(expr)?;
+        let body = self.body.clone();
+        match &body[expr] {
+            Expr::Missing => Err(ConstEvalError::TypeError),
+            Expr::Literal(literal) => self.eval_literal(expr, literal, &ty),
+            Expr::Path(path) => {
+                let resolver = resolver_for_expr(self.db, self.owner, expr);
+                match resolver.resolve_path_as_value_fully(self.db, path) {
+                    Some((ValueNs::ConstId(id), _)) => self.eval_const(id),
+                    Some((ValueNs::LocalBinding(pat), _)) => self
+                        .locals
+                        .get(&pat)
+                        .cloned()
+                        .ok_or(ConstEvalError::NotConst(expr)),
+                    Some((ValueNs::StructId(_), _)) => match ty.interned() {
+                        // A unit struct
+                        TyKind::Struct(s) => {
+                            self.check_value_struct(expr, *s)?;
+                            Ok(ConstValue::Struct(Vec::new()))
+                        }
+                        _ => Err(ConstEvalError::NotConst(expr)),
+                    },
+                    Some(_) => Err(ConstEvalError::NotConst(expr)),
+                    None => Err(ConstEvalError::TypeError),
+                }
+            }
+            Expr::UnaryOp { expr: operand, op } => self.eval_unary_op(expr, *operand, *op, &ty),
+            Expr::BinaryOp {
+                lhs,
+                rhs,
+                op: Some(op),
+            } => self.eval_binary_op(expr, *lhs, *rhs, *op),
+            Expr::Block {
+                statements,
+                tail: Some(tail),
+            } => {
+                for statement in statements {
+                    match statement {
+                        Statement::Let {
+                            pat,
+                            initializer: Some(initializer),
+                            ..
+                        } => {
+                            let value = self.eval(*initializer)?;
+                            if let Pat::Bind { .. } = &body[*pat] {
+                                self.locals.insert(*pat, value);
+                            }
+                        }
+                        // A declaration without an initializer has to be assigned later on,
+                        // which is not supported.
+                        Statement::Let {
+                            initializer: None, ..
+                        } => return Err(ConstEvalError::NotConst(expr)),
+                        Statement::Expr(expr) => {
+                            self.eval(*expr)?;
+                        }
+                    }
+                }
+                self.eval(*tail)
+            }
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch: Some(else_branch),
+            } => match self.eval(*condition)? {
+                ConstValue::Bool(true) => self.eval(*then_branch),
+                ConstValue::Bool(false) => self.eval(*else_branch),
+                _ => Err(ConstEvalError::TypeError),
+            },
+            Expr::RecordLit { fields, spread, .. } => {
+                let s = ty.as_struct().ok_or(ConstEvalError::TypeError)?;
+                self.check_value_struct(expr, s)?;
+                let spread = spread.map(|spread| self.eval(spread)).transpose()?;
+                let mut values = Vec::new();
+                for (idx, field) in s.fields(self.db).into_iter().enumerate() {
+                    let name = field.name(self.db);
+                    let value = match fields.iter().find(|it| it.name == name) {
+                        Some(field) => self.eval(field.expr)?,
+                        None => match &spread {
+                            Some(ConstValue::Struct(values)) => {
+                                values.get(idx).cloned().ok_or(ConstEvalError::TypeError)?
+                            }
+                            _ => return Err(ConstEvalError::TypeError),
+                        },
+                    };
+                    values.push(value);
+                }
+                Ok(ConstValue::Struct(values))
+            }
+            Expr::Call { callee, args } => match self.type_of(*callee)?.interned() {
+                TyKind::FnDef(CallableDef::Struct(s), _) => {
+                    self.check_value_struct(expr, *s)?;
+                    let values = args
+                        .iter()
+                        .map(|arg| self.eval(*arg))
+                        .collect::<Result<_, _>>()?;
+                    Ok(ConstValue::Struct(values))
+                }
+                TyKind::FnDef(CallableDef::PrimitiveType(_), _) => {
+                    let values = args
+                        .iter()
+                        .map(|arg| self.eval(*arg))
+                        .collect::<Result<_, _>>()?;
+                    Ok(ConstValue::Vector(values))
+                }
+                _ => Err(ConstEvalError::NotConst(expr)),
+            },
+            Expr::Field {
+                expr: receiver,
+                name,
+            } => {
+                let receiver_ty = self.type_of(*receiver)?;
+                let idx = match receiver_ty.interned() {
+                    TyKind::Struct(s) => s
+                        .fields(self.db)
+                        .iter()
+                        .position(|field| field.name(self.db) == *name),
+                    TyKind::Vector(vector) => vector.lane_index(name).map(|idx| idx as usize),
+                    _ => None,
+                }
+                .ok_or(ConstEvalError::TypeError)?;
+                match self.eval(*receiver)? {
+                    ConstValue::Struct(values) | ConstValue::Vector(values) => {
+                        values.get(idx).cloned().ok_or(ConstEvalError::TypeError)
+                    }
+                    _ => Err(ConstEvalError::TypeError),
+                }
+            }
+            Expr::BinaryOp { op: None, .. }
+            | Expr::Block { tail: None, .. }
+            | Expr::If {
+                else_branch: None, ..
+            }
+            | Expr::MethodCall { .. }
+            | Expr::Index { .. }
+            | Expr::Return { .. }
+            | Expr::Break { .. }
+            | Expr::Loop { .. }
+            | Expr::While { .. }
+            | Expr::Array(_)
+            | Expr::ArrayRepeat { .. } => Err(ConstEvalError::NotConst(expr)),
+        }
+    }
+
+    /// Evaluates the value of another constant. The value is memoized by the
+    /// database, cycles are detected by salsa.
+    fn eval_const(&mut self, id: ConstId) -> Result<ConstValue, ConstEvalError> {
+        self.db.const_eval(id).map_err(|err| match err {
+            ConstEvalError::Cycle => ConstEvalError::Cycle,
+            _ => ConstEvalError::ErroneousConst(id),
+        })
+    }
+
+    /// Only value structs can be constructed at compile time, other structs
+    /// have to be allocated on the heap.
+    fn check_value_struct(&self, expr: ExprId, s: Struct) -> Result<(), ConstEvalError> {
+        match s.data(self.db).memory_kind {
+            StructMemoryKind::Value => Ok(()),
+            StructMemoryKind::Gc => Err(ConstEvalError::NotConst(expr)),
+        }
+    }
+
+    fn eval_literal(
+        &self,
+        expr: ExprId,
+        literal: &Literal,
+        ty: &Ty,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match (literal, ty.interned()) {
+            (Literal::Bool(value), _) => Ok(ConstValue::Bool(*value)),
+            (Literal::Int(literal), TyKind::Int(int_ty)) => {
+                let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                if literal.value > int_ty.max() {
+                    Err(ConstEvalError::Overflow(expr))
+                } else {
+                    Ok(ConstValue::Int(literal.value))
+                }
+            }
+            (Literal::Float(literal), TyKind::Float(float_ty)) => {
+                Ok(ConstValue::Float(round_float(literal.value, *float_ty)))
+            }
+            (Literal::String(_), _) => Err(ConstEvalError::NotConst(expr)),
+            _ => Err(ConstEvalError::TypeError),
+        }
+    }
+
+    fn eval_unary_op(
+        &mut self,
+        expr: ExprId,
+        operand: ExprId,
+        op: UnaryOp,
+        ty: &Ty,
+    ) -> Result<ConstValue, ConstEvalError> {
+        // The literal of the minimum value of a signed integer does not fit in its type
+        // until it is negated, e.g. `-128i8`.
+        if let (UnaryOp::Neg, Expr::Literal(Literal::Int(literal)), TyKind::Int(int_ty)) =
+            (op, &self.body[operand], ty.interned())
+        {
+            let int_ty = int_ty.resolve(&self.db.target_data_layout());
+            if int_ty.signedness == Signedness::Signed && literal.value == int_ty.max() + 1 {
+                return Ok(ConstValue::Int(truncate(literal.value, int_ty)));
+            }
+        }
+
+        let value = self.eval(operand)?;
+        match (value, ty.interned()) {
+            (ConstValue::Vector(lanes), TyKind::Vector(vector)) => lanes
+                .into_iter()
+                .map(|lane| self.eval_scalar_unary_op(expr, op, lane, &vector.element_ty()))
+                .collect::<Result<_, _>>()
+                .map(ConstValue::Vector),
+            (value, _) => self.eval_scalar_unary_op(expr, op, value, ty),
+        }
+    }
+
+    fn eval_scalar_unary_op(
+        &self,
+        expr: ExprId,
+        op: UnaryOp,
+        value: ConstValue,
+        ty: &Ty,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match (op, value, ty.interned()) {
+            (UnaryOp::Not, ConstValue::Bool(value), _) => Ok(ConstValue::Bool(!value)),
+            (UnaryOp::Not, ConstValue::Int(value), TyKind::Int(int_ty)) => {
+                let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                Ok(ConstValue::Int(truncate(!value, int_ty)))
+            }
+            (UnaryOp::Neg, ConstValue::Float(value), _) => Ok(ConstValue::Float(-value)),
+            (UnaryOp::Neg, ConstValue::Int(value), TyKind::Int(int_ty)) => {
+                let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                match int_ty.signedness {
+                    Signedness::Signed => {
+                        from_signed(sign_extend(value, int_ty).checked_neg(), int_ty)
+                    }
+                    Signedness::Unsigned => (value == 0).then_some(0),
+                }
+                .map(ConstValue::Int)
+                .ok_or(ConstEvalError::Overflow(expr))
+            }
+            _ => Err(ConstEvalError::TypeError),
+        }
+    }
+
+    fn eval_binary_op(
+        &mut self,
+        expr: ExprId,
+        lhs: ExprId,
+        rhs: ExprId,
+        op: BinaryOp,
+    ) -> Result<ConstValue, ConstEvalError> {
+        let op = match op {
+            BinaryOp::LogicOp(op) => {
+                // Logical operators short-circuit
+                let ConstValue::Bool(lhs) = self.eval(lhs)? else {
+                    return Err(ConstEvalError::TypeError);
+                };
+                return match (op, lhs) {
+                    (LogicOp::And, false) => Ok(ConstValue::Bool(false)),
+                    (LogicOp::Or, true) => Ok(ConstValue::Bool(true)),
+                    _ => self.eval(rhs),
+                };
+            }
+            BinaryOp::Assignment { .. } => return Err(ConstEvalError::NotConst(expr)),
+            BinaryOp::ArithOp(_) | BinaryOp::CmpOp(_) => op,
+        };
+
+        let ty = self.type_of(lhs)?;
+        let lhs = self.eval(lhs)?;
+        let rhs = self.eval(rhs)?;
+        match (lhs, rhs, ty.interned()) {
+            (ConstValue::Vector(lhs), ConstValue::Vector(rhs), TyKind::Vector(vector)) => lhs
+                .into_iter()
+                .zip(rhs)
+                .map(|(lhs, rhs)| {
+                    self.eval_scalar_binary_op(expr, op, lhs, rhs, &vector.element_ty())
+                })
+                .collect::<Result<_, _>>()
+                .map(ConstValue::Vector),
+            (lhs, rhs, _) => self.eval_scalar_binary_op(expr, op, lhs, rhs, &ty),
+        }
+    }
+
+    fn eval_scalar_binary_op(
+        &self,
+        expr: ExprId,
+        op: BinaryOp,
+        lhs: ConstValue,
+        rhs: ConstValue,
+        ty: &Ty,
+    ) -> Result<ConstValue, ConstEvalError> {
+        match (op, lhs, rhs, ty.interned()) {
+            (BinaryOp::CmpOp(op), lhs, rhs, ty) => {
+                let ordering = match (lhs, rhs, ty) {
+                    (ConstValue::Bool(lhs), ConstValue::Bool(rhs), _) => lhs.partial_cmp(&rhs),
+                    (ConstValue::Float(lhs), ConstValue::Float(rhs), _) => lhs.partial_cmp(&rhs),
+                    (ConstValue::Int(lhs), ConstValue::Int(rhs), TyKind::Int(int_ty)) => {
+                        let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                        match int_ty.signedness {
+                            Signedness::Signed => {
+                                sign_extend(lhs, int_ty).partial_cmp(&sign_extend(rhs, int_ty))
+                            }
+                            Signedness::Unsigned => lhs.partial_cmp(&rhs),
+                        }
+                    }
+                    _ => return Err(ConstEvalError::TypeError),
+                };
+                Ok(ConstValue::Bool(compare(op, ordering)))
+            }
+            (
+                BinaryOp::ArithOp(op),
+                ConstValue::Int(lhs),
+                ConstValue::Int(rhs),
+                TyKind::Int(int_ty),
+            ) => {
+                let int_ty = int_ty.resolve(&self.db.target_data_layout());
+                Self::eval_int_arith_op(expr, op, lhs, rhs, int_ty).map(ConstValue::Int)
+            }
+            (
+                BinaryOp::ArithOp(op),
+                ConstValue::Float(lhs),
+                ConstValue::Float(rhs),
+                TyKind::Float(float_ty),
+            ) => {
+                let value = match op {
+                    ArithOp::Add => lhs + rhs,
+                    ArithOp::Subtract => lhs - rhs,
+                    ArithOp::Multiply => lhs * rhs,
+                    ArithOp::Divide => lhs / rhs,
+                    ArithOp::Remainder => lhs % rhs,
+                    ArithOp::LeftShift
+                    | ArithOp::RightShift
+                    | ArithOp::BitAnd
+                    | ArithOp::BitOr
+                    | ArithOp::BitXor => return Err(ConstEvalError::TypeError),
+                };
+                Ok(ConstValue::Float(round_float(value, *float_ty)))
+            }
+            (BinaryOp::ArithOp(op), ConstValue::Bool(lhs), ConstValue::Bool(rhs), _) => match op {
+                ArithOp::BitAnd => Ok(ConstValue::Bool(lhs & rhs)),
+                ArithOp::BitOr => Ok(ConstValue::Bool(lhs | rhs)),
+                ArithOp::BitXor => Ok(ConstValue::Bool(lhs ^ rhs)),
+                _ => Err(ConstEvalError::NotConst(expr)),
+            },
+            _ => Err(ConstEvalError::TypeError),
+        }
+    }
+
+    /// Evaluates an arithmetic operation on two integers of the specified
+    /// type. Returns an error if the operation overflows.
+    fn eval_int_arith_op(
+        expr: ExprId,
+        op: ArithOp,
+        lhs: u128,
+        rhs: u128,
+        int_ty: IntTy,
+    ) -> Result<u128, ConstEvalError> {
+        let bits = int_bits(int_ty);
+        let overflow = ConstEvalError::Overflow(expr);
+        if matches!(op, ArithOp::Divide | ArithOp::Remainder) && rhs == 0 {
+            return Err(ConstEvalError::DivideByZero(expr));
+        }
+
+        match op {
+            ArithOp::BitAnd => Ok(lhs & rhs),
+            ArithOp::BitOr => Ok(lhs | rhs),
+            ArithOp::BitXor => Ok(lhs ^ rhs),
+            ArithOp::LeftShift | ArithOp::RightShift => {
+                let amount = match int_ty.signedness {
+                    Signedness::Signed => u32::try_from(sign_extend(rhs, int_ty)).ok(),
+                    Signedness::Unsigned => u32::try_from(rhs).ok(),
+                }
+                .filter(|amount| *amount < bits)
+                .ok_or(overflow)?;
+                Ok(match (op, int_ty.signedness) {
+                    (ArithOp::LeftShift, _) => truncate(lhs << amount, int_ty),
+                    (_, Signedness::Signed) => {
+                        truncate((sign_extend(lhs, int_ty) >> amount) as u128, int_ty)
+                    }
+                    (_, Signedness::Unsigned) => lhs >> amount,
+                })
+            }
+            ArithOp::Add
+            | ArithOp::Subtract
+            | ArithOp::Multiply
+            | ArithOp::Divide
+            | ArithOp::Remainder => match int_ty.signedness {
+                Signedness::Signed => {
+                    let (lhs, rhs) = (sign_extend(lhs, int_ty), sign_extend(rhs, int_ty));
+                    let value = match op {
+                        ArithOp::Add => lhs.checked_add(rhs),
+                        ArithOp::Subtract => lhs.checked_sub(rhs),
+                        ArithOp::Multiply => lhs.checked_mul(rhs),
+                        ArithOp::Divide => lhs.checked_div(rhs),
+                        // `MIN % -1` overflows just like `MIN / -1`
+                        _ => lhs.checked_div(rhs).and(lhs.checked_rem(rhs)),
+                    };
+                    from_signed(value, int_ty).ok_or(overflow)
+                }
+                Signedness::Unsigned => match op {
+                    ArithOp::Add => lhs.checked_add(rhs),
+                    ArithOp::Subtract => lhs.checked_sub(rhs),
+                    ArithOp::Multiply => lhs.checked_mul(rhs),
+                    ArithOp::Divide => lhs.checked_div(rhs),
+                    _ => lhs.checked_rem(rhs),
+                }
+                .filter(|value| *value <= int_ty.max())
+                .ok_or(overflow),
+            },
+        }
+    }
+}
+
+/// Returns the number of bits of an integer type with a resolved bitness.
+fn int_bits(int_ty: IntTy) -> u32 {
+    match int_ty.bitness {
+        IntBitness::X8 => 8,
+        IntBitness::X16 => 16,
+        IntBitness::X32 => 32,
+        IntBitness::X64 => 64,
+        IntBitness::X128 => 128,
+        IntBitness::Xsize => unreachable!("cannot determine size of variable bitness"),
+    }
+}
+
+/// Truncates the bits of an integer to the size of its type.
+fn truncate(value: u128, int_ty: IntTy) -> u128 {
+    let bits = int_bits(int_ty);
+    if bits == 128 {
+        value
+    } else {
+        value & ((1 << bits) - 1)
+    }
+}
+
+/// Interprets the bits of a signed integer.
+fn sign_extend(value: u128, int_ty: IntTy) -> i128 {
+    let shift = 128 - int_bits(int_ty);
+    ((value << shift) as i128) >> shift
+}
+
+/// Converts the result of a signed operation back into bits, if it fits in the
+/// integer type.
+fn from_signed(value: Option<i128>, int_ty: IntTy) -> Option<u128> {
+    let max = int_ty.max() as i128;
+    value
+        .filter(|value| (-max - 1..=max).contains(value))
+        .map(|value| truncate(value as u128, int_ty))
+}
+
+/// Rounds a floating point value to the precision of its type.
+fn round_float(value: f64, float_ty: FloatTy) -> f64 {
+    match float_ty.bitness {
+        FloatBitness::X32 => f64::from(value as f32),
+        FloatBitness::X64 => value,
+    }
+}
+
+/// Returns the result of a comparison given the ordering of its operands,
+/// which is `None` if they are not comparable like for NaN.
+fn compare(op: CmpOp, ordering: Option<std::cmp::Ordering>) -> bool {
+    use std::cmp::Ordering as CmpOrdering;
+    match op {
+        CmpOp::Eq { negated } => (ordering == Some(CmpOrdering::Equal)) != negated,
+        CmpOp::Ord {
+            ordering: expected,
+            strict,
+        } => match (ordering, expected) {
+            (Some(CmpOrdering::Less), Ordering::Less)
+            | (Some(CmpOrdering::Greater), Ordering::Greater) => true,
+            (Some(CmpOrdering::Equal), _) => !strict,
+            _ => false,
+        },
+    }
+}
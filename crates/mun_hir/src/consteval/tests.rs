@@ -0,0 +1,237 @@
+use std::fmt::Write;
+
+use mun_hir_input::WithFixture;
+
+use crate::{
+    mock::MockDatabase, ConstValue, HirDatabase, HirDisplay, ModuleDef, Package, ResolveBitness,
+    Signedness, Ty, TyKind,
+};
+
+#[test]
+fn primitives() {
+    insta::assert_snapshot!(eval(
+        r#"
+    const A: i32 = 5;
+    const B: i32 = -A * 3 + 1;
+    const C: u8 = 255;
+    const D: i8 = -127 - 1;
+    const E: f32 = 0.1;
+    const F: f64 = 1.0 / 4.0;
+    const G: bool = A > 3 && !(B == 0);
+    const H: u64 = 1 << 40;
+    const I: i16 = -7 % 3;
+    const J: i32 = -16 >> 2;
+    const K: u8 = !0 ^ 0x0f;
+    const L: usize = 3 * 4;
+    "#),
+    @r"
+        A: i32 = 5
+        B: i32 = -14
+        C: u8 = 255
+        D: i8 = -128
+        E: f32 = 0.10000000149011612
+        F: f64 = 0.25
+        G: bool = true
+        H: u64 = 1099511627776
+        I: i16 = -1
+        J: i32 = -4
+        K: u8 = 240
+        L: usize = 12
+        ");
+}
+
+#[test]
+fn blocks_and_branches() {
+    insta::assert_snapshot!(eval(
+        r#"
+    const DEBUG: bool = false;
+    const A: i32 = if DEBUG { 1 } else { 2 };
+    const B: i32 = {
+        let a = 3;
+        let b = a * a;
+        b + A
+    };
+    "#),
+    @r"
+        DEBUG: bool = false
+        A: i32 = 2
+        B: i32 = 11
+        ");
+}
+
+#[test]
+fn value_structs_and_vectors() {
+    insta::assert_snapshot!(eval(
+        r#"
+    struct(value) Point { x: f32, y: f32 }
+    struct(value) Pair(i32, i32);
+    struct(value) Unit;
+
+    const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+    const UP: Point = Point { y: 1.0, x: ORIGIN.x };
+    const PAIR: Pair = Pair(1, if UP.y > 0.0 { 2 } else { 3 });
+    const UNIT: Unit = Unit;
+    const V: f32x4 = f32x4(1.0, 2.0, 3.0, 4.0) * f32x4(2.0, 2.0, 2.0, 2.0);
+    const W: f32 = V.w;
+    "#),
+    @r"
+        ORIGIN: Point = Point(0.0, 0.0)
+        UP: Point = Point(0.0, 1.0)
+        PAIR: Pair = Pair(1, 2)
+        UNIT: Unit = Unit()
+        V: f32x4 = f32x4(2.0, 4.0, 6.0, 8.0)
+        W: f32 = 8.0
+        ");
+}
+
+#[test]
+fn errors() {
+    insta::assert_snapshot!(eval(
+        r#"
+    struct Foo { a: i32 }
+    fn one() -> i32 { 1 }
+
+    const A: i32 = one();
+    const B: u8 = 200 + 100;
+    const C: i32 = 1 / (A - A);
+    const D: i32 = 1 / 0;
+    const E: i32 = E + 1;
+    const F: i32 = G;
+    const G: i32 = F;
+    const H: Foo = Foo { a: 1 };
+    const I: i64 = D + 1;
+    const J: i8 = -128 / -1;
+    const K: u32 = 1 << 32;
+    "#),
+    @r"
+        A: NotConst(Idx::<Expr>(1))
+        B: Overflow(Idx::<Expr>(2))
+        C: ErroneousConst(ConstId(0))
+        D: DivideByZero(Idx::<Expr>(2))
+        E: Cycle
+        F: Cycle
+        G: Cycle
+        H: NotConst(Idx::<Expr>(1))
+        I: ErroneousConst(ConstId(3))
+        J: Overflow(Idx::<Expr>(4))
+        K: Overflow(Idx::<Expr>(2))
+        60..65: evaluation of constant value failed: expression cannot be evaluated at compile time
+        81..90: evaluation of constant value failed: arithmetic operation overflows
+        135..140: evaluation of constant value failed: attempt to divide by zero
+        157..162: evaluation of constant value failed: the value of the constant depends on itself
+        179..180: evaluation of constant value failed: the value of the constant depends on itself
+        197..198: evaluation of constant value failed: the value of the constant depends on itself
+        215..227: evaluation of constant value failed: expression cannot be evaluated at compile time
+        244..249: mismatched type
+        265..274: evaluation of constant value failed: arithmetic operation overflows
+        291..298: evaluation of constant value failed: arithmetic operation overflows
+        ");
+}
+
+#[test]
+fn array_length() {
+    insta::assert_snapshot!(eval(
+        r#"
+    const LEN: usize = 4;
+
+    fn main() {
+        let a = [0; LEN * 2];
+        let b = [0; a[0]];
+        let c = [0; 1 - 2];
+    }
+    "#),
+    @r"
+        LEN: usize = 4
+        77..81: evaluation of constant value failed: expression cannot be evaluated at compile time
+        100..105: evaluation of constant value failed: arithmetic operation overflows
+        ");
+}
+
+#[test]
+fn deep_chain_of_constants() {
+    // Every constant refers to the previous one twice, which takes exponential time
+    // unless the values of constants are memoized.
+    let mut content = String::from("const C0: u64 = 1;\n");
+    for idx in 1..64 {
+        writeln!(
+            content,
+            "const C{idx}: u64 = C{prev} + C{prev};",
+            prev = idx - 1
+        )
+        .unwrap();
+    }
+
+    let values = eval(&content);
+    assert_eq!(
+        values.lines().find(|line| line.starts_with("C63:")),
+        Some(format!("C63: u64 = {}", 1u64 << 63).as_str())
+    );
+}
+
+/// Evaluates all constants in the source and returns their values followed by
+/// the diagnostics.
+fn eval(content: &str) -> String {
+    let (db, _) = MockDatabase::with_single_file(content);
+
+    let mut acc = String::new();
+    for item in Package::all(&db)
+        .iter()
+        .flat_map(|pkg| pkg.modules(&db))
+        .flat_map(|module| module.declarations(&db))
+    {
+        if let ModuleDef::Const(konst) = item {
+            match konst.eval(&db) {
+                Ok(value) => writeln!(
+                    acc,
+                    "{}: {} = {}",
+                    konst.name(&db),
+                    konst.ty(&db).display(&db),
+                    display_value(&db, &value, &konst.ty(&db))
+                ),
+                Err(err) => writeln!(acc, "{}: {:?}", konst.name(&db), err),
+            }
+            .unwrap();
+        }
+    }
+
+    let diagnostics = crate::utils::tests::diagnostics(content);
+    [acc.trim_end(), diagnostics.as_str()]
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Displays a value with knowledge of its type, e.g. to show the sign of an
+/// integer.
+fn display_value(db: &MockDatabase, value: &ConstValue, ty: &Ty) -> String {
+    match (value, ty.interned()) {
+        (ConstValue::Bool(value), _) => value.to_string(),
+        (ConstValue::Int(value), TyKind::Int(int_ty)) => {
+            let int_ty = int_ty.resolve(&db.target_data_layout());
+            match int_ty.signedness {
+                Signedness::Signed => {
+                    let shift = int_ty.max().leading_zeros() - 1;
+                    (((*value << shift) as i128) >> shift).to_string()
+                }
+                Signedness::Unsigned => value.to_string(),
+            }
+        }
+        (ConstValue::Float(value), _) => format!("{value:?}"),
+        (ConstValue::Struct(fields), TyKind::Struct(s)) => {
+            let fields = fields
+                .iter()
+                .zip(s.fields(db))
+                .map(|(value, field)| display_value(db, value, &field.ty(db)))
+                .collect::<Vec<_>>();
+            format!("{}({})", s.name(db), fields.join(", "))
+        }
+        (ConstValue::Vector(lanes), TyKind::Vector(vector)) => {
+            let lanes = lanes
+                .iter()
+                .map(|lane| display_value(db, lane, &vector.element_ty()))
+                .collect::<Vec<_>>();
+            format!("{vector}({})", lanes.join(", "))
+        }
+        (value, _) => format!("{value:?}"),
+    }
+}
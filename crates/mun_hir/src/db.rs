@@ -6,18 +6,22 @@ use la_arena::ArenaMap;
 use mun_hir_input::{FileId, PackageId, SourceDatabase};
 use mun_syntax::{ast, Parse, SourceFile};
 use mun_target::{abi, spec::Target};
+use rustc_hash::FxHashSet;
 
 use crate::{
-    code_model::{r#struct::LocalFieldId, FunctionData, ImplData, StructData, TypeAliasData},
+    code_model::{
+        r#struct::LocalFieldId, ConstData, FunctionData, ImplData, StructData, TypeAliasData,
+    },
+    consteval::{ConstEvalError, ConstValue},
     expr::BodySourceMap,
     ids,
-    ids::{DefWithBodyId, FunctionId, ImplId, VariantId},
+    ids::{ConstId, DefWithBodyId, FunctionId, ImplId, VariantId},
     item_tree::{self, ItemTree},
     method_resolution::InherentImpls,
     name_resolution::Namespace,
     package_defs::PackageDefs,
     ty::{lower::LowerTyMap, CallableDef, FnSig, InferenceResult, Ty, TypableDef},
-    visibility, AstIdMap, Body, ExprScopes, Struct, TypeAlias, Visibility,
+    visibility, AstIdMap, Body, ExprScopes, Function, Package, Struct, TypeAlias, Visibility,
 };
 
 /// The `AstDatabase` provides queries that transform text from the
@@ -45,6 +49,8 @@ pub trait InternDatabase: SourceDatabase {
     #[salsa::interned]
     fn intern_type_alias(&self, loc: ids::TypeAliasLoc) -> ids::TypeAliasId;
     #[salsa::interned]
+    fn intern_const(&self, loc: ids::ConstLoc) -> ids::ConstId;
+    #[salsa::interned]
     fn intern_impl(self, loc: ids::ImplLoc) -> ids::ImplId;
 }
 
@@ -61,6 +67,9 @@ pub trait DefDatabase: InternDatabase + AstDatabase {
     #[salsa::invoke(TypeAliasData::type_alias_data_query)]
     fn type_alias_data(&self, id: ids::TypeAliasId) -> Arc<TypeAliasData>;
 
+    #[salsa::invoke(ConstData::const_data_query)]
+    fn const_data(&self, id: ConstId) -> Arc<ConstData>;
+
     #[salsa::invoke(crate::FunctionData::fn_data_query)]
     fn fn_data(&self, func: FunctionId) -> Arc<FunctionData>;
 
@@ -102,6 +111,11 @@ pub trait HirDatabase: DefDatabase {
     #[salsa::invoke(crate::ty::infer_query)]
     fn infer(&self, def: DefWithBodyId) -> Arc<InferenceResult>;
 
+    /// Evaluates the value of a constant at compile time.
+    #[salsa::invoke(crate::consteval::const_eval_query)]
+    #[salsa::cycle(crate::consteval::const_eval_recover)]
+    fn const_eval(&self, def: ConstId) -> Result<ConstValue, ConstEvalError>;
+
     #[salsa::invoke(crate::ty::lower::lower_struct_query)]
     fn lower_struct(&self, def: Struct) -> Arc<LowerTyMap>;
 
@@ -122,6 +136,11 @@ pub trait HirDatabase: DefDatabase {
 
     #[salsa::invoke(InherentImpls::inherent_impls_in_package_query)]
     fn inherent_impls_in_package(&self, package: PackageId) -> Arc<InherentImpls>;
+
+    /// Returns all functions that are referred to from the body of another
+    /// function in the package.
+    #[salsa::invoke(crate::lint::dead_code::used_functions_query)]
+    fn used_functions(&self, package: Package) -> Arc<FxHashSet<Function>>;
 }
 
 fn parse_query(db: &dyn AstDatabase, file_id: FileId) -> Parse<SourceFile> {
@@ -4,7 +4,12 @@ use mun_hir_input::FileId;
 use mun_syntax::{ast, AstPtr, SmolStr, SyntaxNode, SyntaxNodePtr, TextRange};
 
 use crate::{
-    code_model::StructKind, ids::FunctionId, in_file::InFile, HirDatabase, IntTy, Name, Ty,
+    code_model::StructKind,
+    consteval::ConstEvalError,
+    ids::FunctionId,
+    in_file::InFile,
+    lint::{Lint, LintLevel},
+    HirDatabase, IntTy, Name, Ty,
 };
 
 /// Diagnostic defines `mun_hir` API for errors and warnings.
@@ -23,9 +28,19 @@ pub trait Diagnostic: Any + Send + Sync + fmt::Debug + 'static {
     fn highlight_range(&self) -> TextRange {
         self.source().value.range()
     }
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
     fn as_any(&self) -> &(dyn Any + Send + 'static);
 }
 
+/// Determines whether a diagnostic prevents compilation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
 pub trait AstDiagnostic {
     type AST;
     fn ast(&self, db: &dyn HirDatabase) -> Self::AST;
@@ -913,3 +928,126 @@ impl Diagnostic for MethodNotFound {
         self
     }
 }
+
+/// A violation of a lint. Depending on the level of the lint this is either a
+/// warning or an error.
+#[derive(Debug)]
+pub struct LintViolation {
+    pub lint: &'static Lint,
+    pub level: LintLevel,
+    pub source: InFile<SyntaxNodePtr>,
+    pub message: String,
+}
+
+impl Diagnostic for LintViolation {
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.source.clone()
+    }
+
+    fn severity(&self) -> Severity {
+        match self.level {
+            LintLevel::Deny => Severity::Error,
+            LintLevel::Allow | LintLevel::Warn => Severity::Warning,
+        }
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted for an attribute that is not known to the compiler
+#[derive(Debug)]
+pub struct UnknownAttribute {
+    pub attr: InFile<AstPtr<ast::Attr>>,
+    pub name: String,
+}
+
+impl Diagnostic for UnknownAttribute {
+    fn message(&self) -> String {
+        format!("cannot find attribute `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.attr.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if a lint attribute refers to a lint that does not
+/// exist
+#[derive(Debug)]
+pub struct UnknownLint {
+    pub attr: InFile<AstPtr<ast::Attr>>,
+    pub name: String,
+}
+
+impl Diagnostic for UnknownLint {
+    fn message(&self) -> String {
+        format!("unknown lint: `{}`", self.name)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        self.attr.clone().map(Into::into)
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted if an expression that must be known at compile
+/// time, like the value of a constant or the length of an array, cannot be
+/// evaluated.
+#[derive(Debug)]
+pub struct ConstEvalFailure {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+    pub error: ConstEvalError,
+}
+
+impl Diagnostic for ConstEvalFailure {
+    fn message(&self) -> String {
+        format!("evaluation of constant value failed: {}", self.error)
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
+
+/// An error that is emitted when the initializer of an array repeat
+/// expression, e.g. `[Foo { a: 1 }; 4]`, is a reference type. Every element
+/// would refer to the same object.
+#[derive(Debug)]
+pub struct ArrayRepeatOfReference {
+    pub file: FileId,
+    pub expr: SyntaxNodePtr,
+}
+
+impl Diagnostic for ArrayRepeatOfReference {
+    fn message(&self) -> String {
+        "the initializer of an array repeat expression must be a value type, otherwise every \
+         element would refer to the same object"
+            .to_owned()
+    }
+
+    fn source(&self) -> InFile<SyntaxNodePtr> {
+        InFile::new(self.file, self.expr.clone())
+    }
+
+    fn as_any(&self) -> &(dyn Any + Send + 'static) {
+        self
+    }
+}
@@ -74,6 +74,12 @@ impl Body {
                 collector = ExprCollector::new(def, src.file_id, db);
                 collector.collect_fn_body(&src.value);
             }
+            DefWithBodyId::ConstId(c) => {
+                let c = c.lookup(db);
+                let src = c.source(db);
+                collector = ExprCollector::new(def, src.file_id, db);
+                collector.collect_const_body(&src.value);
+            }
         }
 
         let (body, source_map) = collector.finish();
@@ -336,6 +342,12 @@ pub enum Expr {
         name: Name,
     },
     Array(Vec<ExprId>),
+    /// An array with `repeat` copies of `initializer`, e.g. `[0; 4]`. The
+    /// length must be a constant expression.
+    ArrayRepeat {
+        initializer: ExprId,
+        repeat: ExprId,
+    },
     Literal(Literal),
 }
 
@@ -457,6 +469,13 @@ impl Expr {
                     f(*expr);
                 }
             }
+            Expr::ArrayRepeat {
+                initializer,
+                repeat,
+            } => {
+                f(*initializer);
+                f(*repeat);
+            }
         }
     }
 }
@@ -579,6 +598,16 @@ impl<'a> ExprCollector<'a> {
         self.ret_type = Some(ret_type);
     }
 
+    fn collect_const_body(&mut self, node: &ast::ConstDef) {
+        let body = self.collect_expr_opt(node.body());
+        self.body_expr = Some(body);
+
+        let ret_type = self
+            .type_ref_builder
+            .alloc_from_node_opt(node.ascribed_type().as_ref());
+        self.ret_type = Some(ret_type);
+    }
+
     fn collect_block_opt(&mut self, block: Option<ast::BlockExpr>) -> ExprId {
         if let Some(block) = block {
             self.collect_block(block)
@@ -906,10 +935,26 @@ impl<'a> ExprCollector<'a> {
                     syntax_ptr,
                 )
             }
-            ast::ExprKind::ArrayExpr(e) => {
-                let exprs = e.exprs().map(|expr| self.collect_expr(expr)).collect();
-                self.alloc_expr(Expr::Array(exprs), syntax_ptr)
-            }
+            ast::ExprKind::ArrayExpr(e) => match e.kind() {
+                ast::ArrayExprKind::ElementList(exprs) => {
+                    let exprs = exprs.map(|expr| self.collect_expr(expr)).collect();
+                    self.alloc_expr(Expr::Array(exprs), syntax_ptr)
+                }
+                ast::ArrayExprKind::Repeat {
+                    initializer,
+                    repeat,
+                } => {
+                    let initializer = self.collect_expr_opt(initializer);
+                    let repeat = self.collect_expr_opt(repeat);
+                    self.alloc_expr(
+                        Expr::ArrayRepeat {
+                            initializer,
+                            repeat,
+                        },
+                        syntax_ptr,
+                    )
+                }
+            },
             ast::ExprKind::IndexExpr(e) => {
                 let base = self.collect_expr_opt(e.base());
                 let index = self.collect_expr_opt(e.index());
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use mun_syntax::{AstNode, SyntaxNodePtr};
 
 use crate::{
-    code_model::src::HasSource,
+    code_model::{src::HasSource, DefWithBody},
     diagnostics::{
         CyclicType, DiagnosticSink, ExportedPrivate, ExternCannotHaveBody, ExternNonPrimitiveParam,
         FreeTypeAliasWithoutTypeRef, PrivateTypeAlias,
@@ -11,10 +11,12 @@ use crate::{
     expr::BodySourceMap,
     in_file::InFile,
     resolve::HasResolver,
-    Body, Expr, Function, HasVisibility, HirDatabase, InferenceResult, Ty, TyKind, TypeAlias,
+    type_ref::{LocalTypeRefId, TypeRefMap, TypeRefSourceMap},
+    Body, Expr, HasVisibility, HirDatabase, InferenceResult, Resolver, Ty, TyKind, TypeAlias,
     Visibility,
 };
 
+mod array_length;
 mod literal_out_of_range;
 mod uninitialized_access;
 
@@ -22,7 +24,7 @@ mod uninitialized_access;
 mod tests;
 
 pub struct ExprValidator<'a> {
-    func: Function,
+    owner: DefWithBody,
     infer: Arc<InferenceResult>,
     body: Arc<Body>,
     body_source_map: Arc<BodySourceMap>,
@@ -30,12 +32,12 @@ pub struct ExprValidator<'a> {
 }
 
 impl<'a> ExprValidator<'a> {
-    pub fn new(func: Function, db: &'a dyn HirDatabase) -> Self {
-        let (body, body_source_map) = db.body_with_source_map(func.id.into());
+    pub fn new(owner: DefWithBody, db: &'a dyn HirDatabase) -> Self {
+        let (body, body_source_map) = db.body_with_source_map(owner.into());
         ExprValidator {
-            func,
+            owner,
             db,
-            infer: db.infer(func.id.into()),
+            infer: db.infer(owner.into()),
             body,
             body_source_map,
         }
@@ -44,63 +46,93 @@ impl<'a> ExprValidator<'a> {
     pub fn validate_body(&self, sink: &mut DiagnosticSink<'_>) {
         self.validate_literal_ranges(sink);
         self.validate_uninitialized_access(sink);
+        self.validate_array_lengths(sink);
+        self.validate_array_repeat_initializers(sink);
         self.validate_extern(sink);
         self.validate_privacy(sink);
     }
 
     pub fn validate_privacy(&self, sink: &mut DiagnosticSink<'_>) {
-        let resolver = self.func.id.resolver(self.db);
-        let fn_data = self.func.data(self.db);
-        let ret_type_ref = fn_data.ret_type();
-        let param_types = fn_data
-            .params()
-            .iter()
-            .chain(std::iter::once(ret_type_ref))
-            .map(|type_ref| {
-                let (ty, _) = Ty::from_hir(self.db, &resolver, fn_data.type_ref_map(), *type_ref);
-                (ty, type_ref)
-            });
+        match self.owner {
+            DefWithBody::Function(func) => {
+                let fn_data = func.data(self.db);
+                self.validate_signature_privacy(
+                    func.visibility(self.db),
+                    &func.id.resolver(self.db),
+                    fn_data
+                        .params()
+                        .iter()
+                        .chain(std::iter::once(fn_data.ret_type())),
+                    fn_data.type_ref_map(),
+                    fn_data.type_ref_source_map(),
+                    sink,
+                );
+            }
+            DefWithBody::Const(konst) => {
+                let const_data = konst.data(self.db);
+                self.validate_signature_privacy(
+                    konst.visibility(self.db),
+                    &konst.id.resolver(self.db),
+                    std::iter::once(&const_data.type_ref_id),
+                    const_data.type_ref_map(),
+                    const_data.type_ref_source_map(),
+                    sink,
+                );
+            }
+        }
+    }
 
-        let fn_visibility = self.func.visibility(self.db);
-        let type_is_allowed = |ty: &Ty| match fn_visibility {
+    /// Validates that the types in the signature of an item are at least as
+    /// visible as the item itself.
+    fn validate_signature_privacy<'t>(
+        &self,
+        visibility: Visibility,
+        resolver: &Resolver,
+        type_refs: impl Iterator<Item = &'t LocalTypeRefId>,
+        type_ref_map: &TypeRefMap,
+        type_ref_source_map: &TypeRefSourceMap,
+        sink: &mut DiagnosticSink<'_>,
+    ) {
+        let type_is_allowed = |ty: &Ty| match visibility {
             Visibility::Module(module_id) => {
                 ty.visibility(self.db).is_visible_from(self.db, module_id)
             }
             Visibility::Public => ty.visibility(self.db).is_externally_visible(),
         };
 
-        let file_id = self.func.source(self.db).file_id;
-        param_types
+        let file_id = self.owner.file_id(self.db);
+        type_refs
+            .map(|type_ref| {
+                let (ty, _) = Ty::from_hir(self.db, resolver, type_ref_map, *type_ref);
+                (ty, type_ref)
+            })
             .filter(|(ty, _)| !type_is_allowed(ty))
             .for_each(|(_, type_ref)| {
                 sink.push(ExportedPrivate {
                     file: file_id,
-                    type_ref: fn_data
-                        .type_ref_source_map()
-                        .type_ref_syntax(*type_ref)
-                        .unwrap(),
+                    type_ref: type_ref_source_map.type_ref_syntax(*type_ref).unwrap(),
                 });
             });
     }
 
     pub fn validate_extern(&self, sink: &mut DiagnosticSink<'_>) {
-        if !self.func.is_extern(self.db) {
+        let DefWithBody::Function(func) = self.owner else {
+            return;
+        };
+        if !func.is_extern(self.db) {
             return;
         }
 
         // Validate that there is no body
-        match self.body[self.func.body(self.db).body_expr] {
+        match self.body[func.body(self.db).body_expr] {
             Expr::Missing => {}
             _ => sink.push(ExternCannotHaveBody {
-                func: self
-                    .func
-                    .source(self.db)
-                    .map(|f| SyntaxNodePtr::new(f.syntax())),
+                func: func.source(self.db).map(|f| SyntaxNodePtr::new(f.syntax())),
             }),
         }
 
-        if let Some(sig) = self.func.ty(self.db).callable_sig(self.db) {
-            let fn_data = self.func.data(self.db);
+        if let Some(sig) = func.ty(self.db).callable_sig(self.db) {
+            let fn_data = func.data(self.db);
             for (arg_ty, ty_ref) in sig.params().iter().zip(fn_data.params()) {
                 if arg_ty.as_struct().is_some() {
                     let arg_ptr = fn_data
@@ -109,7 +141,7 @@ impl<'a> ExprValidator<'a> {
                         .map(|ptr| ptr.syntax_node_ptr())
                         .unwrap();
                     sink.push(ExternNonPrimitiveParam {
-                        param: InFile::new(self.func.source(self.db).file_id, arg_ptr),
+                        param: InFile::new(func.source(self.db).file_id, arg_ptr),
                     });
                 }
             }
@@ -122,7 +154,7 @@ impl<'a> ExprValidator<'a> {
                     .map(|ptr| ptr.syntax_node_ptr())
                     .unwrap();
                 sink.push(ExternNonPrimitiveParam {
-                    param: InFile::new(self.func.source(self.db).file_id, arg_ptr),
+                    param: InFile::new(func.source(self.db).file_id, arg_ptr),
                 });
             }
         }
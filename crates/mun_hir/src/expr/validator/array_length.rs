@@ -0,0 +1,65 @@
+use super::ExprValidator;
+use crate::{
+    consteval::eval_const_expr,
+    diagnostics::{ArrayRepeatOfReference, ConstEvalFailure, DiagnosticSink},
+    Expr, StructMemoryKind,
+};
+
+impl ExprValidator<'_> {
+    /// Validates that the length of every array repeat expression, e.g. `[0;
+    /// LEN]`, can be evaluated at compile time.
+    pub(super) fn validate_array_lengths(&self, sink: &mut DiagnosticSink<'_>) {
+        for (_, expr) in self.body.exprs() {
+            let Expr::ArrayRepeat { repeat, .. } = expr else {
+                continue;
+            };
+
+            // Errors that are not caused by the expression itself are reported elsewhere
+            let Err(error) = eval_const_expr(self.db, self.owner.into(), *repeat) else {
+                continue;
+            };
+            let Some(failed_expr) = error.expr() else {
+                continue;
+            };
+
+            if let Some(ptr) = self.body_source_map.expr_syntax(failed_expr) {
+                sink.push(ConstEvalFailure {
+                    file: self.owner.file_id(self.db),
+                    expr: ptr
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                    error,
+                });
+            }
+        }
+    }
+
+    /// Validates that the initializer of every array repeat expression is a
+    /// value type. The initializer is evaluated only once, so repeating a gc
+    /// struct or an array would store the same reference in every element.
+    pub(super) fn validate_array_repeat_initializers(&self, sink: &mut DiagnosticSink<'_>) {
+        for (_, expr) in self.body.exprs() {
+            let Expr::ArrayRepeat { initializer, .. } = expr else {
+                continue;
+            };
+
+            let ty = &self.infer[*initializer];
+            let is_reference = ty.as_array().is_some()
+                || ty
+                    .as_struct()
+                    .is_some_and(|s| s.data(self.db).memory_kind == StructMemoryKind::Gc);
+            if !is_reference {
+                continue;
+            }
+
+            if let Some(ptr) = self.body_source_map.expr_syntax(*initializer) {
+                sink.push(ArrayRepeatOfReference {
+                    file: self.owner.file_id(self.db),
+                    expr: ptr
+                        .value
+                        .either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()),
+                });
+            }
+        }
+    }
+}
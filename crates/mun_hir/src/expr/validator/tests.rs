@@ -181,3 +181,23 @@ fn test_type_alias_with_private_type_alias() {
     "#,
     ), @"17..36: type alias `Foo` is private");
 }
+
+#[test]
+fn test_array_repeat_of_reference() {
+    insta::assert_snapshot!(diagnostics(
+        r#"
+    struct Foo { a: i32 }
+    struct(value) Bar { a: i32 }
+
+    fn foo() {
+        let a = [Foo { a: 1 }; 3];          // every element would be the same object
+        let b = [[0; 2]; 3];                // every element would be the same array
+        let c = [Bar { a: 1 }; 3];          // correct, `Bar` is copied
+        let d = [1.0; 3];                   // correct, `f64` is copied
+    }
+    "#,
+    ), @r###"
+    76..88: the initializer of an array repeat expression must be a value type, otherwise every element would refer to the same object
+    158..164: the initializer of an array repeat expression must be a value type, otherwise every element would refer to the same object
+    "###);
+}
@@ -194,6 +194,18 @@ impl ExprValidator<'_> {
                     self.validate_expr_access(sink, initialized_patterns, *expr, ExprKind::Normal);
                 }
             }
+            Expr::ArrayRepeat {
+                initializer,
+                repeat,
+            } => {
+                self.validate_expr_access(
+                    sink,
+                    initialized_patterns,
+                    *initializer,
+                    ExprKind::Normal,
+                );
+                self.validate_expr_access(sink, initialized_patterns, *repeat, ExprKind::Normal);
+            }
             Expr::Literal(_) | Expr::Missing => {}
         }
     }
@@ -215,10 +227,10 @@ impl ExprValidator<'_> {
         if expr_side == ExprKind::Normal || expr_side == ExprKind::Both {
             // Check if the binding has already been initialized
             if initialized_patterns.get(&pat).is_none() {
-                let (_, body_source_map) = self.db.body_with_source_map(self.func.id.into());
                 sink.push(PossiblyUninitializedVariable {
-                    file: self.func.file_id(self.db),
-                    pat: body_source_map
+                    file: self.owner.file_id(self.db),
+                    pat: self
+                        .body_source_map
                         .expr_syntax(expr)
                         .unwrap()
                         .value
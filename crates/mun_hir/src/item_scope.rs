@@ -66,10 +66,14 @@ pub(crate) static BUILTIN_SCOPE: Lazy<FxHashMap<Name, PerNs<(ItemDefinitionId, V
         PrimitiveType::ALL
             .iter()
             .map(|(name, ty)| {
-                (
-                    name.clone(),
-                    PerNs::types(((*ty).into(), Visibility::Public)),
-                )
+                let def = ((*ty).into(), Visibility::Public);
+                // Vectors can also be constructed by calling them like a function
+                let per_ns = if matches!(ty, PrimitiveType::Vector(_)) {
+                    PerNs::both(def, def)
+                } else {
+                    PerNs::types(def)
+                };
+                (name.clone(), per_ns)
             })
             .collect()
     });
@@ -226,7 +230,9 @@ impl PerNs<(ItemDefinitionId, Visibility)> {
         has_constructor: bool,
     ) -> PerNs<(ItemDefinitionId, Visibility)> {
         match def {
-            ItemDefinitionId::FunctionId(_) => PerNs::values((def, vis)),
+            ItemDefinitionId::FunctionId(_) | ItemDefinitionId::ConstId(_) => {
+                PerNs::values((def, vis))
+            }
             ItemDefinitionId::StructId(_) => {
                 if has_constructor {
                     PerNs::both((def, vis), (def, vis))
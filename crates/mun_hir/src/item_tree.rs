@@ -16,8 +16,10 @@ use std::{
 use la_arena::{Arena, Idx};
 use mun_hir_input::FileId;
 use mun_syntax::ast;
+use rustc_hash::FxHashMap;
 
 use crate::{
+    attrs::{Attrs, EMPTY_ATTRS},
     path::ImportAlias,
     source_id::{AstIdNode, FileAstId},
     type_ref::{LocalTypeRefId, TypeRefMap},
@@ -58,6 +60,7 @@ pub struct ItemTree {
     file_id: FileId,
     top_level: Vec<ModItem>,
     data: ItemTreeData,
+    attrs: FxHashMap<AttrOwner, Attrs>,
 
     pub diagnostics: Vec<diagnostics::ItemTreeDiagnostic>,
 }
@@ -76,6 +79,11 @@ impl ItemTree {
         &self.top_level
     }
 
+    /// Returns the attributes of the specified item, field or parameter.
+    pub fn attrs(&self, owner: AttrOwner) -> &Attrs {
+        self.attrs.get(&owner).unwrap_or(&EMPTY_ATTRS)
+    }
+
     /// Returns the source location of the specified item. Note that the
     /// `file_id` of the item must be the same `file_id` that was used to
     /// create this `ItemTree`.
@@ -117,6 +125,7 @@ struct ItemTreeData {
     structs: Arena<Struct>,
     fields: Arena<Field>,
     type_aliases: Arena<TypeAlias>,
+    consts: Arena<Const>,
     impls: Arena<Impl>,
 
     visibilities: ItemVisibilities,
@@ -228,6 +237,7 @@ mod_items! {
     Function in functions -> ast::FunctionDef,
     Struct in structs -> ast::StructDef,
     TypeAlias in type_aliases -> ast::TypeAliasDef,
+    Const in consts -> ast::ConstDef,
     Import in imports -> ast::Use,
     Impl in impls -> ast::Impl,
 }
@@ -248,6 +258,20 @@ macro_rules! impl_index {
 
 impl_index!(fields: Field, params: Param);
 
+/// Everything in an [`ItemTree`] that can have attributes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AttrOwner {
+    ModItem(ModItem),
+    Field(Idx<Field>),
+    Param(Idx<Param>),
+}
+
+impl From<ModItem> for AttrOwner {
+    fn from(item: ModItem) -> Self {
+        AttrOwner::ModItem(item)
+    }
+}
+
 static VIS_PUB: RawVisibility = RawVisibility::Public;
 static VIS_PRIV: RawVisibility = RawVisibility::This;
 static VIS_PUB_PACKAGE: RawVisibility = RawVisibility::Package;
@@ -374,6 +398,15 @@ pub struct TypeAlias {
     pub ast_id: FileAstId<ast::TypeAliasDef>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Const {
+    pub name: Name,
+    pub visibility: RawVisibilityId,
+    pub types: TypeRefMap,
+    pub type_ref: LocalTypeRefId,
+    pub ast_id: FileAstId<ast::ConstDef>,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum AssociatedItem {
     Function(LocalItemTreeId<Function>),
@@ -471,12 +504,13 @@ impl<T> PartialEq for IdRange<T> {
 impl<T> Eq for IdRange<T> {}
 
 mod diagnostics {
-    use mun_syntax::{AstNode, SyntaxNodePtr};
+    use mun_syntax::{ast, AstNode, SmolStr, SyntaxNodePtr};
 
     use super::{ItemTree, ModItem};
     use crate::{
-        diagnostics::DuplicateDefinition, DefDatabase, DiagnosticSink, HirDatabase, InFile, Name,
-        Path,
+        diagnostics::{DuplicateDefinition, UnknownAttribute, UnknownLint},
+        source_id::FileAstId,
+        DefDatabase, DiagnosticSink, HirDatabase, InFile, Name, Path,
     };
 
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -486,6 +520,14 @@ mod diagnostics {
             first: ModItem,
             second: ModItem,
         },
+        UnknownAttribute {
+            attr: FileAstId<ast::Attr>,
+            name: SmolStr,
+        },
+        UnknownLint {
+            attr: FileAstId<ast::Attr>,
+            name: SmolStr,
+        },
     }
 
     impl ItemTreeDiagnostic {
@@ -513,6 +555,10 @@ mod diagnostics {
                         item_tree.file_id,
                         SyntaxNodePtr::new(item_tree.source(db, item).syntax()),
                     ),
+                    ModItem::Const(item) => InFile::new(
+                        item_tree.file_id,
+                        SyntaxNodePtr::new(item_tree.source(db, item).syntax()),
+                    ),
                     ModItem::Import(it) => {
                         let import = &item_tree[it];
                         let import_src = item_tree.source(db, it);
@@ -533,6 +579,7 @@ mod diagnostics {
                 }
             }
 
+            let ast_id_map = db.ast_id_map(item_tree.file_id);
             match self {
                 ItemTreeDiagnostic::DuplicateDefinition {
                     name,
@@ -543,6 +590,16 @@ mod diagnostics {
                     first_definition: ast_ptr_from_mod(db, item_tree, *first),
                     definition: ast_ptr_from_mod(db, item_tree, *second),
                 }),
+                ItemTreeDiagnostic::UnknownAttribute { attr, name } => {
+                    sink.push(UnknownAttribute {
+                        attr: InFile::new(item_tree.file_id, ast_id_map.get(*attr)),
+                        name: name.to_string(),
+                    });
+                }
+                ItemTreeDiagnostic::UnknownLint { attr, name } => sink.push(UnknownLint {
+                    attr: InFile::new(item_tree.file_id, ast_id_map.get(*attr)),
+                    name: name.to_string(),
+                }),
             };
         }
     }
@@ -5,17 +5,20 @@ use std::{collections::HashMap, convert::TryInto, marker::PhantomData, sync::Arc
 use la_arena::{Idx, RawIdx};
 use mun_hir_input::FileId;
 use mun_syntax::ast::{
-    self, ExternOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner,
+    self, AttrsOwner, ExternOwner, ModuleItemOwner, NameOwner, StructKind, TypeAscriptionOwner,
 };
+use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 
 use super::{
-    diagnostics, AssociatedItem, Field, Fields, Function, FunctionFlags, IdRange, Impl, ItemTree,
-    ItemTreeData, ItemTreeNode, ItemVisibilities, LocalItemTreeId, ModItem, Param, ParamAstId,
-    RawVisibilityId, Struct, TypeAlias,
+    diagnostics, AssociatedItem, AttrOwner, Const, Field, Fields, Function, FunctionFlags, IdRange,
+    Impl, ItemTree, ItemTreeData, ItemTreeNode, ItemVisibilities, LocalItemTreeId, ModItem, Param,
+    ParamAstId, RawVisibilityId, Struct, TypeAlias,
 };
 use crate::{
+    attrs::{Attr, Attrs, KNOWN_ATTRIBUTES},
     item_tree::Import,
+    lint::Lint,
     name::AsName,
     source_id::AstIdMap,
     type_ref::{TypeRefMap, TypeRefMapBuilder},
@@ -47,6 +50,7 @@ pub(super) struct Context {
     file: FileId,
     source_ast_id_map: Arc<AstIdMap>,
     data: ItemTreeData,
+    attrs: FxHashMap<AttrOwner, Attrs>,
     diagnostics: Vec<diagnostics::ItemTreeDiagnostic>,
 }
 
@@ -57,6 +61,7 @@ impl Context {
             file,
             source_ast_id_map: db.ast_id_map(file),
             data: ItemTreeData::default(),
+            attrs: FxHashMap::default(),
             diagnostics: Vec::new(),
         }
     }
@@ -77,6 +82,7 @@ impl Context {
                 ModItem::Function(item) => Some(&self.data.functions[item.index].name),
                 ModItem::Struct(item) => Some(&self.data.structs[item.index].name),
                 ModItem::TypeAlias(item) => Some(&self.data.type_aliases[item.index].name),
+                ModItem::Const(item) => Some(&self.data.consts[item.index].name),
                 ModItem::Import(item) => {
                     let import = &self.data.imports[item.index];
                     if import.is_glob {
@@ -108,6 +114,7 @@ impl Context {
             file_id: self.file,
             top_level,
             data: self.data,
+            attrs: self.attrs,
             diagnostics: self.diagnostics,
         }
     }
@@ -118,6 +125,7 @@ impl Context {
             ast::ModuleItemKind::FunctionDef(ast) => self.lower_function(&ast).map(Into::into),
             ast::ModuleItemKind::StructDef(ast) => self.lower_struct(&ast).map(Into::into),
             ast::ModuleItemKind::TypeAliasDef(ast) => self.lower_type_alias(&ast).map(Into::into),
+            ast::ModuleItemKind::ConstDef(ast) => self.lower_const(&ast).map(Into::into),
             ast::ModuleItemKind::Use(ast) => Some(ModItems(
                 self.lower_use(&ast).into_iter().map(Into::into).collect(),
             )),
@@ -148,6 +156,11 @@ impl Context {
             );
         });
 
+        let attrs = self.lower_attrs(use_item);
+        for import in &imports {
+            self.add_attrs(ModItem::from(*import).into(), attrs.clone());
+        }
+
         imports
     }
 
@@ -155,6 +168,7 @@ impl Context {
     fn lower_function(&mut self, func: &ast::FunctionDef) -> Option<LocalItemTreeId<Function>> {
         let name = func.name()?.as_name();
         let visibility = lower_visibility(func);
+        let attrs = self.lower_attrs(func);
         let mut types = TypeRefMap::builder();
 
         // Lower all the params
@@ -177,10 +191,12 @@ impl Context {
             for param in param_list.params() {
                 let ast_id = self.source_ast_id_map.ast_id(&param);
                 let type_ref = types.alloc_from_node_opt(param.ascribed_type().as_ref());
-                self.data.params.alloc(Param {
+                let idx = self.data.params.alloc(Param {
                     type_ref,
                     ast_id: ParamAstId::Param(ast_id),
                 });
+                let attrs = self.lower_attrs(&param);
+                self.add_attrs(AttrOwner::Param(idx), attrs);
             }
         }
         let end_param_idx = self.next_param_idx();
@@ -216,7 +232,9 @@ impl Context {
             flags,
         };
 
-        Some(self.data.functions.alloc(res).into())
+        let id: LocalItemTreeId<Function> = self.data.functions.alloc(res).into();
+        self.add_attrs(ModItem::from(id).into(), attrs);
+        Some(id)
     }
 
     /// Lowers a struct
@@ -235,7 +253,10 @@ impl Context {
             fields,
             ast_id,
         };
-        Some(self.data.structs.alloc(res).into())
+        let id: LocalItemTreeId<Struct> = self.data.structs.alloc(res).into();
+        let attrs = self.lower_attrs(strukt);
+        self.add_attrs(ModItem::from(id).into(), attrs);
+        Some(id)
     }
 
     /// Lowers the fields of a struct or enum
@@ -266,7 +287,9 @@ impl Context {
         let start = self.next_field_idx();
         for field in fields.fields() {
             if let Some(data) = lower_record_field(&field, types) {
-                let _idx = self.data.fields.alloc(data);
+                let idx = self.data.fields.alloc(data);
+                let attrs = self.lower_attrs(&field);
+                self.add_attrs(AttrOwner::Field(idx), attrs);
             }
         }
         let end = self.next_field_idx();
@@ -282,7 +305,9 @@ impl Context {
         let start = self.next_field_idx();
         for (i, field) in fields.fields().enumerate() {
             let data = lower_tuple_field(i, &field, types);
-            let _idx = self.data.fields.alloc(data);
+            let idx = self.data.fields.alloc(data);
+            let attrs = self.lower_attrs(&field);
+            self.add_attrs(AttrOwner::Field(idx), attrs);
         }
         let end = self.next_field_idx();
         IdRange::new(start..end)
@@ -306,7 +331,31 @@ impl Context {
             type_ref,
             ast_id,
         };
-        Some(self.data.type_aliases.alloc(res).into())
+        let id: LocalItemTreeId<TypeAlias> = self.data.type_aliases.alloc(res).into();
+        let attrs = self.lower_attrs(type_alias);
+        self.add_attrs(ModItem::from(id).into(), attrs);
+        Some(id)
+    }
+
+    /// Lowers a constant (e.g. `const FOO: i32 = 5`)
+    fn lower_const(&mut self, konst: &ast::ConstDef) -> Option<LocalItemTreeId<Const>> {
+        let name = konst.name()?.as_name();
+        let visibility = lower_visibility(konst);
+        let mut types = TypeRefMap::builder();
+        let type_ref = types.alloc_from_node_opt(konst.ascribed_type().as_ref());
+        let ast_id = self.source_ast_id_map.ast_id(konst);
+        let (types, _types_source_map) = types.finish();
+        let res = Const {
+            name,
+            visibility,
+            types,
+            type_ref,
+            ast_id,
+        };
+        let id: LocalItemTreeId<Const> = self.data.consts.alloc(res).into();
+        let attrs = self.lower_attrs(konst);
+        self.add_attrs(ModItem::from(id).into(), attrs);
+        Some(id)
     }
 
     fn lower_impl(&mut self, impl_def: &ast::Impl) -> Option<LocalItemTreeId<Impl>> {
@@ -330,7 +379,10 @@ impl Context {
             ast_id,
         };
 
-        Some(self.data.impls.alloc(res).into())
+        let id: LocalItemTreeId<Impl> = self.data.impls.alloc(res).into();
+        let attrs = self.lower_attrs(impl_def);
+        self.add_attrs(ModItem::from(id).into(), attrs);
+        Some(id)
     }
 
     fn lower_associated_item(&mut self, item: &ast::AssociatedItem) -> Option<AssociatedItem> {
@@ -340,6 +392,46 @@ impl Context {
         Some(item)
     }
 
+    /// Lowers the attributes of a node and reports attributes and lints that
+    /// are not known.
+    fn lower_attrs(&mut self, owner: &impl AttrsOwner) -> Attrs {
+        let mut attrs = Vec::new();
+        for attr in owner.attrs() {
+            let Some(lowered) = Attr::from_ast(&attr) else {
+                continue;
+            };
+
+            if !KNOWN_ATTRIBUTES.contains(&lowered.name()) {
+                self.diagnostics
+                    .push(diagnostics::ItemTreeDiagnostic::UnknownAttribute {
+                        attr: self.source_ast_id_map.ast_id(&attr),
+                        name: lowered.name().into(),
+                    });
+            } else if lowered.lint_level().is_some() {
+                for lint in lowered.args().unwrap_or_default() {
+                    if Lint::find(lint).is_none() {
+                        self.diagnostics
+                            .push(diagnostics::ItemTreeDiagnostic::UnknownLint {
+                                attr: self.source_ast_id_map.ast_id(&attr),
+                                name: lint.clone(),
+                            });
+                    }
+                }
+            }
+
+            attrs.push(lowered);
+        }
+
+        attrs.into_iter().collect()
+    }
+
+    /// Associates attributes with an item, field or parameter.
+    fn add_attrs(&mut self, owner: AttrOwner, attrs: Attrs) {
+        if !attrs.is_empty() {
+            self.attrs.insert(owner, attrs);
+        }
+    }
+
     /// Returns the `Idx` of the next `Field`
     fn next_field_idx(&self) -> Idx<Field> {
         let idx: u32 = self.data.fields.len().try_into().expect("too many fields");
@@ -2,8 +2,8 @@ use std::{fmt, fmt::Write};
 
 use crate::{
     item_tree::{
-        Fields, Function, Impl, Import, ItemTree, LocalItemTreeId, ModItem, Param, RawVisibilityId,
-        Struct, TypeAlias,
+        AttrOwner, Const, Fields, Function, Impl, Import, ItemTree, LocalItemTreeId, ModItem,
+        Param, RawVisibilityId, Struct, TypeAlias,
     },
     path::ImportAlias,
     pretty::{print_path, print_type_ref},
@@ -64,10 +64,12 @@ impl Printer<'_> {
 
     /// Print a module item to the buffer.
     fn print_mod_item(&mut self, item: ModItem) -> fmt::Result {
+        self.print_attrs(item.into(), "\n")?;
         match item {
             ModItem::Function(it) => self.print_function(it),
             ModItem::Struct(it) => self.print_struct(it),
             ModItem::TypeAlias(it) => self.print_type_alias(it),
+            ModItem::Const(it) => self.print_const(it),
             ModItem::Import(it) => self.print_use(it),
             ModItem::Impl(it) => self.print_impl(it),
         }
@@ -115,6 +117,21 @@ impl Printer<'_> {
         writeln!(self, ";")
     }
 
+    /// Prints a constant to the buffer.
+    fn print_const(&mut self, it: LocalItemTreeId<Const>) -> fmt::Result {
+        let Const {
+            name,
+            visibility,
+            types,
+            type_ref,
+            ast_id: _,
+        } = &self.tree[it];
+        self.print_visibility(*visibility)?;
+        write!(self, "const {name}: ")?;
+        self.print_type_ref(*type_ref, types)?;
+        writeln!(self, " = _;")
+    }
+
     /// Prints a struct to the buffer.
     fn print_struct(&mut self, it: LocalItemTreeId<Struct>) -> fmt::Result {
         let Struct {
@@ -132,6 +149,7 @@ impl Printer<'_> {
                 write!(self, "{{")?;
                 self.indented(|this| {
                     for field in fields.clone() {
+                        this.print_attrs(AttrOwner::Field(field), " ")?;
                         let field = &this.tree[field];
                         write!(this, "{}: ", field.name)?;
                         this.print_type_ref(field.type_ref, types)?;
@@ -145,6 +163,7 @@ impl Printer<'_> {
                 write!(self, "(")?;
                 self.indented(|this| {
                     for field in fields.clone() {
+                        this.print_attrs(AttrOwner::Field(field), " ")?;
                         let field = &this.tree[field];
                         this.print_type_ref(field.type_ref, types)?;
                         writeln!(this, ",")?;
@@ -190,6 +209,7 @@ impl Printer<'_> {
                 }
 
                 for param in params {
+                    this.print_attrs(AttrOwner::Param(param), " ")?;
                     let Param {
                         type_ref,
                         ast_id: _,
@@ -205,6 +225,19 @@ impl Printer<'_> {
         writeln!(self, ";")
     }
 
+    /// Prints the attributes of an item, field or parameter to the buffer, each
+    /// followed by `separator`.
+    fn print_attrs(&mut self, owner: AttrOwner, separator: &str) -> fmt::Result {
+        for attr in self.tree.attrs(owner).iter() {
+            write!(self, "#[{}", attr.name())?;
+            if let Some(args) = attr.args() {
+                write!(self, "({})", args.join(", "))?;
+            }
+            write!(self, "]{separator}")?;
+        }
+        Ok(())
+    }
+
     /// Prints a [`RawVisibilityId`] to the buffer.
     fn print_visibility(&mut self, vis: RawVisibilityId) -> fmt::Result {
         match &self.tree[vis] {
@@ -238,7 +271,7 @@ impl Printer<'_> {
             }
             Ok(())
         })?;
-        write!(self, "}}")
+        writeln!(self, "}}")
     }
 }
 
@@ -93,3 +93,56 @@ fn test_duplicate_import() {
     )
     .unwrap());
 }
+
+#[test]
+fn test_attributes() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    #[allow(dead_code, unused_variables)]
+    fn foo(#[allow(unused_variables)] a: i32) {}
+
+    #[warn(dead_code)]
+    struct Foo {
+        #[allow(dead_code)]
+        a: i32,
+    }
+    struct Bar(#[deny(dead_code)] i32);
+
+    #[allow(dead_code)]
+    impl Foo {
+        #[deny(unused_variables)]
+        fn bar(self) {}
+    }
+
+    #[allow(unused_imports)]
+    use foo::{Baz, Qux};
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn test_unknown_attributes() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    #[inline]
+    #[allow(dead_code, unknown_lint)]
+    fn foo(#[foo(bar)] a: i32) {}
+    "#
+    )
+    .unwrap());
+}
+
+#[test]
+fn test_consts() {
+    insta::assert_snapshot!(print_item_tree(
+        r#"
+    const FOO: i32 = 5;
+    pub const BAR: f32 = 1.0 + 2.0;
+    #[allow(dead_code)]
+    pub(package) const BAZ: [u8] = [0; 4];
+    const MISSING = 3;
+    "#
+    )
+    .unwrap());
+}
@@ -10,10 +10,12 @@ pub use mun_hir_input::ModuleId;
 pub use salsa;
 
 pub use self::code_model::{
-    Field, Function, FunctionData, HasSource, Module, ModuleDef, Package, PrimitiveType, Struct,
-    StructMemoryKind, TypeAlias,
+    AssocItem, Const, Field, Function, FunctionData, HasSource, Module, ModuleDef, Package,
+    PrimitiveType, Struct, StructKind, StructMemoryKind, TypeAlias,
 };
 pub use crate::{
+    attrs::{Attr, Attrs, HOT_RELOAD_ATTRIBUTE},
+    consteval::{eval_const_expr, ConstEvalError, ConstValue},
     db::{
         AstDatabase, AstDatabaseStorage, DefDatabase, DefDatabaseStorage, HirDatabase,
         HirDatabaseStorage, InternDatabase, InternDatabaseStorage,
@@ -32,8 +34,8 @@ pub use crate::{
     primitive_type::{FloatBitness, IntBitness, Signedness},
     resolve::{resolver_for_expr, resolver_for_scope, Resolver, TypeNs, ValueNs},
     ty::{
-        lower::CallableDef, FloatTy, InferenceResult, IntTy, ResolveBitness, Substitution, Ty,
-        TyKind, TypableDef,
+        lower::CallableDef, FloatTy, InferenceResult, IntTy, ResolveBitness, ScalarTy,
+        Substitution, Ty, TyKind, TypableDef, VectorTy,
     },
     visibility::{HasVisibility, Visibility},
 };
@@ -41,7 +43,9 @@ use crate::{name::AsName, source_id::AstIdMap};
 
 #[macro_use]
 mod macros;
+mod attrs;
 mod code_model;
+mod consteval;
 mod db;
 pub mod diagnostics;
 mod display;
@@ -49,6 +53,7 @@ mod expr;
 mod ids;
 mod in_file;
 mod item_tree;
+pub mod lint;
 mod name;
 mod name_resolution;
 mod path;
@@ -0,0 +1,175 @@
+//! Lints are checks for code that is valid but most likely not what was
+//! intended, like a variable that is never used. In contrast to other
+//! diagnostics, lints do not prevent compilation by default. The level at which
+//! each lint is reported can be configured per package, and overridden for
+//! individual items with the `allow`, `warn` and `deny` attributes.
+
+pub(crate) mod dead_code;
+#[cfg(test)]
+mod tests;
+mod unreachable_code;
+mod unused_imports;
+mod unused_variables;
+
+pub use mun_hir_input::LintLevel;
+use mun_syntax::SyntaxNodePtr;
+
+use crate::{
+    attrs::{self, Attrs},
+    diagnostics::{DiagnosticSink, LintViolation},
+    ids::{ItemContainerId, Lookup},
+    AssocItem, Function, HirDatabase, InFile, Module, ModuleDef, Package,
+};
+
+/// Describes a single lint.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// The name with which the lint is configured, e.g. `unused_variables`
+    pub name: &'static str,
+
+    /// The level used if the lint is not configured otherwise
+    pub default_level: LintLevel,
+
+    /// A short description of what the lint checks
+    pub description: &'static str,
+}
+
+pub static UNUSED_VARIABLES: Lint = Lint {
+    name: "unused_variables",
+    default_level: LintLevel::Warn,
+    description: "detects variables that are never read",
+};
+
+pub static UNUSED_IMPORTS: Lint = Lint {
+    name: "unused_imports",
+    default_level: LintLevel::Warn,
+    description: "detects imports that are never used",
+};
+
+pub static DEAD_CODE: Lint = Lint {
+    name: "dead_code",
+    default_level: LintLevel::Warn,
+    description: "detects functions that are never called",
+};
+
+pub static UNREACHABLE_CODE: Lint = Lint {
+    name: "unreachable_code",
+    default_level: LintLevel::Warn,
+    description: "detects code that can never be executed",
+};
+
+static LINTS: &[&Lint] = &[
+    &UNUSED_VARIABLES,
+    &UNUSED_IMPORTS,
+    &DEAD_CODE,
+    &UNREACHABLE_CODE,
+];
+
+impl Lint {
+    /// Returns all known lints.
+    pub fn all() -> &'static [&'static Lint] {
+        LINTS
+    }
+
+    /// Finds the lint with the specified name.
+    pub fn find(name: &str) -> Option<&'static Lint> {
+        LINTS.iter().copied().find(|lint| lint.name == name)
+    }
+
+    /// Returns the level at which this lint is reported in the specified
+    /// package.
+    pub fn level(&self, db: &dyn HirDatabase, package: Package) -> LintLevel {
+        db.packages()[package.id()]
+            .lint_levels
+            .get(self.name)
+            .copied()
+            .unwrap_or(self.default_level)
+    }
+}
+
+/// Reports lint violations of a single package to a [`DiagnosticSink`],
+/// taking the configured levels into account.
+struct LintSink<'a, 'b> {
+    db: &'a dyn HirDatabase,
+    package: Package,
+    sink: &'a mut DiagnosticSink<'b>,
+}
+
+impl LintSink<'_, '_> {
+    /// Reports a violation of `lint`. The level of the lint is determined by
+    /// the first of `attrs` that configures it, ordered from the innermost
+    /// scope outwards, and falls back to the level of the package.
+    fn report(
+        &mut self,
+        lint: &'static Lint,
+        attrs: &[Attrs],
+        source: InFile<SyntaxNodePtr>,
+        message: String,
+    ) {
+        let level = attrs
+            .iter()
+            .find_map(|attrs| attrs.lint_level(lint.name))
+            .unwrap_or_else(|| lint.level(self.db, self.package));
+        if level != LintLevel::Allow {
+            self.sink.push(LintViolation {
+                lint,
+                level,
+                source,
+                message,
+            });
+        }
+    }
+}
+
+/// Checks all lints for the specified module and places the violations in the
+/// `sink`.
+pub(crate) fn check_module(db: &dyn HirDatabase, module: Module, sink: &mut DiagnosticSink<'_>) {
+    let mut sink = LintSink {
+        db,
+        package: module.package(),
+        sink,
+    };
+
+    unused_imports::check(db, module, &mut sink);
+    dead_code::check(db, module, &mut sink);
+
+    for function in module_functions(db, module) {
+        if function.is_extern(db) {
+            continue;
+        }
+
+        unused_variables::check(db, function, &mut sink);
+        unreachable_code::check(db, function, &mut sink);
+    }
+}
+
+/// Returns the attributes that apply to a function: those of the function
+/// itself followed by those of the `impl` that contains it.
+fn function_attrs(db: &dyn HirDatabase, function: Function) -> Vec<Attrs> {
+    let loc = function.id.lookup(db);
+    let mut result = vec![attrs::item_attrs(db, loc.id)];
+    if let ItemContainerId::ImplId(impl_id) = loc.container {
+        result.push(attrs::item_attrs(db, impl_id.lookup(db).id));
+    }
+    result
+}
+
+/// Returns all functions that are declared in the module, including the
+/// functions of impls.
+fn module_functions(db: &dyn HirDatabase, module: Module) -> Vec<Function> {
+    let declarations = module
+        .declarations(db)
+        .into_iter()
+        .filter_map(|decl| match decl {
+            ModuleDef::Function(function) => Some(function),
+            _ => None,
+        });
+
+    let associated_functions = module
+        .impls(db)
+        .into_iter()
+        .flat_map(|item| item.items(db))
+        .map(|AssocItem::Function(function)| function);
+
+    declarations.chain(associated_functions).collect()
+}
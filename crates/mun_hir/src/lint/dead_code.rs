@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use mun_syntax::{ast::NameOwner, AstNode, SyntaxNodePtr};
+use rustc_hash::FxHashSet;
+
+use super::{function_attrs, module_functions, LintSink, DEAD_CODE};
+use crate::{
+    CallableDef, Function, HasSource, HasVisibility, HirDatabase, InFile, Module, Package,
+};
+
+/// Reports functions of the module that are not visible outside of the package
+/// and that are never referred to from another function. Functions whose name
+/// starts with an underscore are ignored.
+pub(super) fn check(db: &dyn HirDatabase, module: Module, sink: &mut LintSink<'_, '_>) {
+    let candidates = module_functions(db, module)
+        .into_iter()
+        .filter(|function| {
+            !function.visibility(db).is_externally_visible()
+                && !function.name(db).to_string().starts_with('_')
+        })
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let used_functions = db.used_functions(module.package());
+    for function in candidates {
+        if used_functions.contains(&function) {
+            continue;
+        }
+
+        let source = function.source(db);
+        let ptr = source.value.name().map_or_else(
+            || SyntaxNodePtr::new(source.value.syntax()),
+            |name| SyntaxNodePtr::new(name.syntax()),
+        );
+        sink.report(
+            &DEAD_CODE,
+            &function_attrs(db, function),
+            InFile::new(source.file_id, ptr),
+            format!("function `{}` is never used", function.name(db)),
+        );
+    }
+}
+
+/// Returns all functions that are referred to from the body of another
+/// function in the package. This is computed once per package and shared by
+/// the checks of all its modules.
+pub(crate) fn used_functions_query(
+    db: &dyn HirDatabase,
+    package: Package,
+) -> Arc<FxHashSet<Function>> {
+    let mut used_functions = FxHashSet::default();
+    for module in package.modules(db) {
+        for caller in module_functions(db, module) {
+            if caller.is_extern(db) {
+                continue;
+            }
+
+            let body = caller.body(db);
+            let infer = caller.infer(db);
+            for (expr, _) in body.exprs() {
+                let callee = match infer[expr].as_callable_def() {
+                    Some(CallableDef::Function(callee)) => Some(callee),
+                    _ => infer.method_resolution(expr).map(Function::from),
+                };
+
+                if let Some(callee) = callee.filter(|&callee| callee != caller) {
+                    used_functions.insert(callee);
+                }
+            }
+        }
+    }
+    Arc::new(used_functions)
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use mun_hir_input::{PackageId, SourceDatabase, WithFixture};
+
+use super::LintLevel;
+use crate::{diagnostics::DiagnosticSink, mock::MockDatabase, Package};
+
+/// Returns all lint violations of the fixture, after configuring the specified
+/// lint levels for all packages.
+fn lints_with_levels(fixture: &str, levels: &[(&str, LintLevel)]) -> String {
+    let mut db = MockDatabase::with_files(fixture);
+
+    let mut packages = (*db.packages()).clone();
+    for package in packages.iter().collect::<Vec<PackageId>>() {
+        for (lint, level) in levels {
+            packages.set_lint_level(package, *lint, *level);
+        }
+    }
+    db.set_packages(Arc::new(packages));
+
+    let mut diags = Vec::new();
+    let mut diag_sink = DiagnosticSink::new(|diag| {
+        diags.push(format!(
+            "{:?} ({:?}): {}",
+            diag.highlight_range(),
+            diag.severity(),
+            diag.message()
+        ));
+    });
+
+    for module in Package::all(&db).iter().flat_map(|pkg| pkg.modules(&db)) {
+        module.lints(&db, &mut diag_sink);
+    }
+
+    drop(diag_sink);
+    diags.join("\n")
+}
+
+fn lints(fixture: &str) -> String {
+    lints_with_levels(fixture, &[])
+}
+
+#[test]
+fn unused_variables() {
+    insta::assert_snapshot!(lints(
+        r#"
+    pub fn foo(a: i32, b: i32, _c: i32) -> i32 {
+        let d = 3;
+        let _e = 4;
+        let f = a;
+        f
+    }
+
+    struct Bar;
+    impl Bar {
+        pub fn bar(self, a: i32) {}
+    }
+    "#
+    ), @r"
+        19..20 (Warning): unused variable: `b`
+        53..54 (Warning): unused variable: `d`
+        144..145 (Warning): unused variable: `a`
+        ");
+}
+
+#[test]
+fn unused_variables_shadowed() {
+    insta::assert_snapshot!(lints(
+        r#"
+    pub fn foo() -> i32 {
+        let a = 1;
+        let a = a + 1;
+        let b = 2;
+        let b = 3;
+        a + b
+    }
+    "#
+    ), @"64..65 (Warning): unused variable: `b`");
+}
+
+#[test]
+fn unused_imports() {
+    insta::assert_snapshot!(lints(
+        r#"
+    //- /mod.mun
+    pub struct Foo;
+    pub struct Bar;
+    pub struct Baz;
+
+    //- /foo.mun
+    use super::{Foo, Bar as Renamed};
+    use super::Baz;
+    use super::*;
+    pub(package) use super::Foo as Reexport;
+
+    pub fn foo(_foo: Foo) {}
+
+    //- /foo/bar.mun
+    pub fn bar(_baz: super::Baz) {}
+    "#
+    ), @"17..31 (Warning): unused import: `Renamed`");
+}
+
+#[test]
+fn dead_code() {
+    insta::assert_snapshot!(lints(
+        r#"
+    //- /mod.mun
+    pub fn main() {
+        used();
+        foo::used_from_parent();
+        Foo::new().method();
+    }
+
+    fn used() {}
+    fn unused() {}
+    fn _ignored() {}
+    fn recursive() { recursive() }
+
+    struct Foo;
+    impl Foo {
+        fn new() -> Self { Foo }
+        fn method(self) {}
+        fn unused_method(self) {}
+    }
+
+    //- /foo.mun
+    pub(super) fn used_from_parent() {}
+    pub(package) fn unused_in_package() {}
+    "#
+    ), @r"
+        101..107 (Warning): function `unused` is never used
+        133..142 (Warning): function `recursive` is never used
+        244..257 (Warning): function `unused_method` is never used
+        52..69 (Warning): function `unused_in_package` is never used
+        ");
+}
+
+#[test]
+fn unreachable_code() {
+    insta::assert_snapshot!(lints(
+        r#"
+    pub fn foo(a: i32) -> i32 {
+        if a > 3 {
+            return 5;
+            let b = 3;
+        }
+        loop {};
+        a
+    }
+
+    pub fn bar() -> i32 {
+        return 3;
+        4
+    }
+    "#
+    ), @r"
+        73..74 (Warning): unused variable: `b`
+        73..74 (Warning): unreachable code
+        103..104 (Warning): unreachable code
+        148..149 (Warning): unreachable code
+        ");
+}
+
+#[test]
+fn configured_levels() {
+    let fixture = r#"
+    pub fn foo() {
+        let a = 3;
+    }
+
+    fn bar() {}
+    "#;
+
+    insta::assert_snapshot!(lints_with_levels(fixture, &[
+        ("unused_variables", LintLevel::Deny),
+        ("dead_code", LintLevel::Allow),
+    ]), @"23..24 (Error): unused variable: `a`");
+}
+
+#[test]
+fn attribute_levels() {
+    let fixture = r#"
+    #[allow(unused_variables)]
+    pub fn foo() {
+        let a = 3;
+    }
+
+    pub fn bar(#[allow(unused_variables)] a: i32, #[deny(unused_variables)] b: i32) {}
+
+    #[allow(dead_code)]
+    fn baz() {}
+
+    struct Foo;
+
+    #[allow(dead_code)]
+    impl Foo {
+        fn unused() {}
+
+        #[warn(dead_code)]
+        fn warned() {}
+    }
+
+    #[warn(unused_variables)]
+    pub fn qux() {
+        let b = 3;
+    }
+
+    #[allow(unused_imports)]
+    use self::Foo as Bar;
+    "#;
+
+    insta::assert_snapshot!(lints_with_levels(fixture, &[
+        ("unused_variables", LintLevel::Allow),
+    ]), @r"
+        271..277 (Warning): function `warned` is never used
+        132..133 (Error): unused variable: `b`
+        335..336 (Warning): unused variable: `b`
+        ");
+}
@@ -0,0 +1,59 @@
+use super::{function_attrs, LintSink, UNREACHABLE_CODE};
+use crate::{Expr, Function, HirDatabase, Statement};
+
+/// Reports the first statement or tail expression of a block that follows a
+/// statement that never returns, e.g. a `return`.
+pub(super) fn check(db: &dyn HirDatabase, function: Function, sink: &mut LintSink<'_, '_>) {
+    let body = function.body(db);
+    let infer = function.infer(db);
+    let source_map = function.body_source_map(db);
+    let attrs = function_attrs(db, function);
+
+    for (_, expr) in body.exprs() {
+        let Expr::Block { statements, tail } = expr else {
+            continue;
+        };
+
+        let mut diverges = false;
+        let mut unreachable = None;
+        for statement in statements {
+            if diverges {
+                unreachable = Some(statement);
+                break;
+            }
+
+            diverges = match statement {
+                Statement::Let {
+                    initializer: Some(expr),
+                    ..
+                }
+                | Statement::Expr(expr) => infer[*expr].is_never(),
+                Statement::Let {
+                    initializer: None, ..
+                } => false,
+            };
+        }
+
+        let source = match (unreachable, tail) {
+            (Some(Statement::Let { pat, .. }), _) => source_map.pat_syntax(*pat).map(|ptr| {
+                ptr.map(|it| it.either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()))
+            }),
+            (Some(Statement::Expr(expr)), _) => source_map.expr_syntax(*expr).map(|ptr| {
+                ptr.map(|it| it.either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()))
+            }),
+            (None, Some(tail)) if diverges => source_map.expr_syntax(*tail).map(|ptr| {
+                ptr.map(|it| it.either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr()))
+            }),
+            (None, _) => None,
+        };
+
+        if let Some(source) = source {
+            sink.report(
+                &UNREACHABLE_CODE,
+                &attrs,
+                source,
+                "unreachable code".to_owned(),
+            );
+        }
+    }
+}
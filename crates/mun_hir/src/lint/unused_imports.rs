@@ -0,0 +1,97 @@
+use mun_syntax::{
+    ast::{self, NameOwner, VisibilityOwner},
+    AstNode, SyntaxNode, SyntaxNodePtr,
+};
+use rustc_hash::FxHashSet;
+
+use super::{LintSink, UNUSED_IMPORTS};
+use crate::{name::AsName, Attrs, HirDatabase, InFile, Module, Name};
+
+/// Reports private imports of the module whose name is never referred to.
+/// Re-exports and glob imports are ignored.
+///
+/// An import can only be referred to from the module itself or, through
+/// `super`, from one of its sub-modules which is why only those are checked
+/// for references.
+pub(super) fn check(db: &dyn HirDatabase, module: Module, sink: &mut LintSink<'_, '_>) {
+    let Some(file_id) = module.file_id(db) else {
+        return;
+    };
+    let source_file = db.parse(file_id).tree();
+
+    let mut used_names = FxHashSet::default();
+    collect_referenced_names(source_file.syntax(), true, &mut used_names);
+
+    let mut sub_modules = module.children(db);
+    while let Some(sub_module) = sub_modules.pop() {
+        if let Some(file_id) = sub_module.file_id(db) {
+            collect_referenced_names(db.parse(file_id).tree().syntax(), false, &mut used_names);
+        }
+        sub_modules.extend(sub_module.children(db));
+    }
+
+    for use_item in source_file.syntax().children().filter_map(ast::Use::cast) {
+        if use_item.visibility().is_some() {
+            continue;
+        }
+
+        let attrs = [Attrs::from_ast(&use_item)];
+        for use_tree in use_item
+            .syntax()
+            .descendants()
+            .filter_map(ast::UseTree::cast)
+        {
+            if use_tree.use_tree_list().is_some() || use_tree.has_star_token() {
+                continue;
+            }
+
+            let name = match use_tree.rename() {
+                Some(rename) => rename.name().map(|name| name.as_name()),
+                None => use_tree
+                    .path()
+                    .and_then(|path| path.segment())
+                    .and_then(|segment| segment.name_ref())
+                    .map(|name_ref| name_ref.as_name()),
+            };
+
+            let Some(name) = name else {
+                continue;
+            };
+
+            if !used_names.contains(&name) && !name.to_string().starts_with('_') {
+                sink.report(
+                    &UNUSED_IMPORTS,
+                    &attrs,
+                    InFile::new(file_id, SyntaxNodePtr::new(use_tree.syntax())),
+                    format!("unused import: `{name}`"),
+                );
+            }
+        }
+    }
+}
+
+/// Collects the names that are referred to by paths outside of `use` items.
+/// If `only_unqualified` is true only the first segment of every path is
+/// taken into account, otherwise every segment is.
+fn collect_referenced_names(
+    syntax: &SyntaxNode,
+    only_unqualified: bool,
+    names: &mut FxHashSet<Name>,
+) {
+    let paths = syntax
+        .descendants()
+        .filter_map(ast::Path::cast)
+        .filter(|path| !only_unqualified || path.qualifier().is_none())
+        .filter(|path| {
+            !path
+                .syntax()
+                .ancestors()
+                .any(|node| ast::Use::can_cast(node.kind()))
+        });
+
+    names.extend(
+        paths
+            .filter_map(|path| path.segment()?.name_ref())
+            .map(|name_ref| name_ref.as_name()),
+    );
+}
@@ -0,0 +1,61 @@
+use rustc_hash::FxHashSet;
+
+use super::{function_attrs, LintSink, UNUSED_VARIABLES};
+use crate::{
+    resolve::{resolver_for_expr, ValueNs},
+    Expr, Function, HirDatabase, Pat,
+};
+
+/// Reports bindings in the body of the function that are never read.
+/// Bindings whose name starts with an underscore are ignored. Attributes on a
+/// parameter take precedence over those of the function.
+pub(super) fn check(db: &dyn HirDatabase, function: Function, sink: &mut LintSink<'_, '_>) {
+    let body = function.body(db);
+    let source_map = function.body_source_map(db);
+
+    let mut used_patterns = FxHashSet::default();
+    for (expr_id, expr) in body.exprs() {
+        if let Expr::Path(path) = expr {
+            let resolver = resolver_for_expr(db, body.owner(), expr_id);
+            if let Some((ValueNs::LocalBinding(pat), _)) =
+                resolver.resolve_path_as_value_fully(db, path)
+            {
+                used_patterns.insert(pat);
+            }
+        }
+    }
+
+    let function_attrs = function_attrs(db, function);
+    let params = function.params(db);
+    let self_param = body.self_param().map(|(pat, _)| *pat);
+    for (pat_id, pat) in body.pats() {
+        let Pat::Bind { name } = pat else {
+            continue;
+        };
+
+        let name = name.to_string();
+        if Some(pat_id) == self_param || name.starts_with('_') || used_patterns.contains(&pat_id) {
+            continue;
+        }
+
+        let param_attrs = body
+            .params()
+            .iter()
+            .position(|(param, _)| *param == pat_id)
+            .and_then(|idx| params.get(idx + usize::from(self_param.is_some())))
+            .map(|param| param.attrs(db));
+        let attrs = param_attrs
+            .into_iter()
+            .chain(function_attrs.iter().cloned())
+            .collect::<Vec<_>>();
+
+        if let Some(source) = source_map.pat_syntax(pat_id) {
+            sink.report(
+                &UNUSED_VARIABLES,
+                &attrs,
+                source.map(|ptr| ptr.either(|it| it.syntax_node_ptr(), |it| it.syntax_node_ptr())),
+                format!("unused variable: `{name}`"),
+            );
+        }
+    }
+}
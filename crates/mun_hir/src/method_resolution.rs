@@ -14,8 +14,8 @@ use crate::{
     has_module::HasModule,
     ids::{AssocItemId, FunctionId, ImplId, Lookup, StructId},
     package_defs::PackageDefs,
-    ty::lower::LowerDiagnostic,
-    DefDatabase, DiagnosticSink, HasSource, InFile, Name, Ty, TyKind,
+    ty::{lower::LowerDiagnostic, InferTy},
+    ArithOp, DefDatabase, DiagnosticSink, HasSource, InFile, Name, Ty, TyKind,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -426,6 +426,60 @@ pub(crate) fn lookup_method(
         .ok_or(not_visible)
 }
 
+/// Describes how a builtin integer method handles arithmetic overflow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// The result wraps around at the boundary of the type, e.g.
+    /// `a.wrapping_add(b)`.
+    Wrapping,
+
+    /// Overflow causes a panic, regardless of whether overflow checks are
+    /// enabled, e.g. `a.checked_add(b)`. Unlike Rust's `checked_*` methods,
+    /// these do not return an optional value, because Mun has no `Option`
+    /// type.
+    Checked,
+
+    /// The result is clamped to the numeric bounds of the type, e.g.
+    /// `a.saturating_add(b)`.
+    Saturating,
+}
+
+/// A method that is built into the language instead of being defined in an
+/// `impl` block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BuiltinMethod {
+    /// An arithmetic operation between two integers of the same type with an
+    /// explicit overflow behavior.
+    IntArith { op: ArithOp, mode: OverflowMode },
+}
+
+impl BuiltinMethod {
+    /// Find the builtin method with the specified name on the specified type.
+    pub fn lookup(ty: &Ty, name: &Name) -> Option<Self> {
+        if !matches!(
+            ty.interned(),
+            TyKind::Int(_) | TyKind::InferenceVar(InferTy::Int(_))
+        ) {
+            return None;
+        }
+
+        let (mode, op) = name.as_str()?.split_once('_')?;
+        let mode = match mode {
+            "wrapping" => OverflowMode::Wrapping,
+            "checked" => OverflowMode::Checked,
+            "saturating" => OverflowMode::Saturating,
+            _ => return None,
+        };
+        let op = match op {
+            "add" => ArithOp::Add,
+            "sub" => ArithOp::Subtract,
+            "mul" => ArithOp::Multiply,
+            _ => return None,
+        };
+        Some(BuiltinMethod::IntArith { op, mode })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mun_hir_input::{SourceDatabase, WithFixture};
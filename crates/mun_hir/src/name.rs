@@ -118,7 +118,7 @@ pub mod known {
     known_names!(
         // Primitives
         int, isize, i8, i16, i32, i64, i128, uint, usize, u8, u16, u32, u64, u128, float, f32, f64,
-        bool,
+        bool, f32x2, f32x4, f64x2, i32x2, i32x4,
     );
 
     // self/Self cannot be used as an identifier
@@ -65,13 +65,40 @@ impl PackageDefs {
     /// Resolves the specified `name` from within the specified `module`
     fn resolve_name_in_module(
         &self,
-        _db: &dyn DefDatabase,
+        db: &dyn DefDatabase,
         module: PackageModuleId,
         name: &Name,
     ) -> PerNs<(ItemDefinitionId, Visibility)> {
         self[module]
             .get(name)
             .or(BUILTIN_SCOPE.get(name).copied().unwrap_or_else(PerNs::none))
+            .or(self.resolve_name_in_dependencies(db, name))
+    }
+
+    /// Resolves the specified `name` as the name of one of the packages this
+    /// package depends on. Returns the root module of the package.
+    fn resolve_name_in_dependencies(
+        &self,
+        db: &dyn DefDatabase,
+        name: &Name,
+    ) -> PerNs<(ItemDefinitionId, Visibility)> {
+        let Some(name) = name.as_str() else {
+            return PerNs::none();
+        };
+        db.packages()[self.id]
+            .dependencies
+            .iter()
+            .find(|dependency| dependency.name == name)
+            .map_or_else(PerNs::none, |dependency| {
+                PerNs::types((
+                    ModuleId {
+                        package: dependency.package,
+                        local_id: db.module_tree(dependency.package).root,
+                    }
+                    .into(),
+                    Visibility::Public,
+                ))
+            })
     }
 
     /// Resolves the specified `path` from within the specified `module`. Also
@@ -119,6 +146,7 @@ impl PackageDefs {
             )),
         };
 
+        let mut package = self.module_tree.package;
         for (i, segment) in segments {
             let (curr, vis) = match curr_per_ns.take_types() {
                 Some(r) => r,
@@ -128,24 +156,28 @@ impl PackageDefs {
             };
 
             curr_per_ns = match curr {
-                ItemDefinitionId::ModuleId(module) => self[module.local_id].get(segment),
+                ItemDefinitionId::ModuleId(module) if module.package == self.id => {
+                    self[module.local_id].get(segment)
+                }
+                ItemDefinitionId::ModuleId(module) => {
+                    // Only public items are accessible from other packages
+                    package = module.package;
+                    db.package_defs(module.package)[module.local_id]
+                        .get(segment)
+                        .and_then(|(item, vis)| vis.is_externally_visible().then_some((item, vis)))
+                }
                 // TODO: Enum variants
                 s => {
                     return ResolvePathResult::with(
                         PerNs::types((s, vis)),
                         ReachedFixedPoint::Yes,
                         Some(i),
-                        Some(self.module_tree.package),
+                        Some(package),
                     );
                 }
             };
         }
 
-        ResolvePathResult::with(
-            curr_per_ns,
-            ReachedFixedPoint::Yes,
-            None,
-            Some(self.module_tree.package),
-        )
+        ResolvePathResult::with(curr_per_ns, ReachedFixedPoint::Yes, None, Some(package))
     }
 }
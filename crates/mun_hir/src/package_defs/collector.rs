@@ -5,12 +5,13 @@ use rustc_hash::FxHashMap;
 use super::PackageDefs;
 use crate::{
     ids::{
-        FunctionLoc, ImplLoc, Intern, ItemContainerId, ItemDefinitionId, StructLoc, TypeAliasLoc,
+        ConstLoc, FunctionLoc, ImplLoc, Intern, ItemContainerId, ItemDefinitionId, StructLoc,
+        TypeAliasLoc,
     },
     item_scope::{ImportType, ItemScope, PerNsGlobImports},
     item_tree::{
-        self, Fields, Function, Impl, ItemTree, ItemTreeId, LocalItemTreeId, ModItem, Struct,
-        TypeAlias,
+        self, Const, Fields, Function, Impl, ItemTree, ItemTreeId, LocalItemTreeId, ModItem,
+        Struct, TypeAlias,
     },
     name_resolution::ReachedFixedPoint,
     package_defs::diagnostics::DefDiagnostic,
@@ -296,6 +297,30 @@ impl DefCollector<'_> {
         if import.is_glob {
             #[allow(clippy::match_same_arms)]
             match resolution.take_types() {
+                Some((ItemDefinitionId::ModuleId(m), _))
+                    if m.package != self.package_defs.module_tree.package =>
+                {
+                    // The definitions of other packages are complete, so there is no need to
+                    // record the glob import.
+                    let resolutions = self.db.package_defs(m.package)[m.local_id]
+                        .entries()
+                        .map(|(n, res)| ImportResolution {
+                            name: Some(n.clone()),
+                            resolution: res.and_then(|(item, vis)| {
+                                vis.is_externally_visible().then_some((item, vis))
+                            }),
+                        })
+                        .filter(|res| !res.resolution.is_none())
+                        .collect::<Vec<_>>();
+
+                    self.update(
+                        import_module_id,
+                        import_visibility,
+                        ImportType::Glob,
+                        import.source,
+                        &resolutions,
+                    );
+                }
                 Some((ItemDefinitionId::ModuleId(m), _)) => {
                     let scope = &self.package_defs[m.local_id];
 
@@ -517,6 +542,7 @@ impl<'a> ModCollectorContext<'a, '_> {
                 ModItem::Function(id) => self.collect_function(id),
                 ModItem::Struct(id) => self.collect_struct(id),
                 ModItem::TypeAlias(id) => self.collect_type_alias(id),
+                ModItem::Const(id) => self.collect_const(id),
                 ModItem::Import(id) => {
                     self.collect_import(id);
                     continue;
@@ -621,6 +647,25 @@ impl<'a> ModCollectorContext<'a, '_> {
             has_constructor: false,
         }
     }
+
+    /// Collects the definition data from a `Const`
+    fn collect_const(&self, id: LocalItemTreeId<Const>) -> DefData<'a> {
+        let konst = &self.item_tree[id];
+        DefData {
+            id: ConstLoc {
+                module: ModuleId {
+                    package: self.def_collector.package_id,
+                    local_id: self.module_id,
+                },
+                id: ItemTreeId::new(self.file_id, id),
+            }
+            .intern(self.def_collector.db)
+            .into(),
+            name: &konst.name,
+            visibility: &self.item_tree[konst.visibility],
+            has_constructor: false,
+        }
+    }
 }
 
 struct DefData<'a> {
@@ -2,7 +2,7 @@ use mun_hir_input::WithFixture;
 use rustc_hash::FxHashSet;
 
 use crate::{
-    db::DefDatabase, ids::ItemDefinitionId, mock::MockDatabase, package_defs::PackageDefs,
+    db::DefDatabase, ids::ItemDefinitionId, mock::MockDatabase, package_defs::PackageDefs, Const,
     DiagnosticSink, Function, HirDatabase, Module, Package, Struct, TypeAlias,
 };
 
@@ -217,6 +217,37 @@ fn use_() {
     "###);
 }
 
+#[test]
+fn use_dependency() {
+    insta::assert_snapshot!(resolve(
+        r#"
+    //- /mod.mun package:main deps:dep
+    use dep::foo::Bar;
+    use dep::foo::Private;
+    use dep::foo::*;
+    use unknown::Baz;
+
+    //- /mod.mun package:dep
+    //- /foo.mun
+    pub struct Bar;
+    pub struct Baz;
+    struct Private;
+    "#),
+    @r"
+        mod mod
+        +-- ERROR: 23..40: unresolved import
+        +-- ERROR: 63..75: unresolved import
+        +-- use struct dep::foo::Baz
+        '-- use struct dep::foo::Bar
+
+        mod mod
+        '-- mod foo
+            +-- struct Bar
+            +-- struct Baz
+            '-- struct Private
+        ");
+}
+
 fn resolve(content: &str) -> String {
     let db = MockDatabase::with_files(content);
 
@@ -324,6 +355,20 @@ fn tree_for_module(
                     node.push(format!("use type {fully_qualified_name}"));
                 }
             }
+            ItemDefinitionId::ConstId(konst) => {
+                let konst: Const = (*konst).into();
+                let name = konst.name(db);
+                if is_local {
+                    node.push(format!("const {name}"));
+                } else {
+                    let fully_qualified_name = format!(
+                        "{}::{}",
+                        fully_qualified_module_path(db, konst.module(db)),
+                        name
+                    );
+                    node.push(format!("use const {fully_qualified_name}"));
+                }
+            }
             ItemDefinitionId::PrimitiveType(_) => {}
         }
     }
@@ -336,15 +381,18 @@ fn tree_for_module(
     node
 }
 
-/// Returns a fully qualified path of a module e.g. `package::foo::bar::baz`
+/// Returns a fully qualified path of a module e.g. `package::foo::bar::baz`.
+/// Modules of dependencies are prefixed with the name of their package.
 fn fully_qualified_module_path(db: &dyn HirDatabase, module: Module) -> String {
     itertools::Itertools::intersperse(
         module
             .path_to_root(db)
             .into_iter()
             .map(|m| {
-                m.name(db)
-                    .map_or_else(|| "package".to_owned(), |name| name.clone())
+                m.name(db).map_or_else(
+                    || m.package().qualifier(db).unwrap_or_else(|| "package".to_owned()),
+                    |name| name.clone(),
+                )
             })
             .rev(),
         "::".to_string(),
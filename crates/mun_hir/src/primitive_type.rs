@@ -41,10 +41,25 @@ pub struct PrimitiveFloat {
     pub bitness: FloatBitness,
 }
 
+/// The scalar type of the lanes of a [`PrimitiveVector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveScalar {
+    Float(PrimitiveFloat),
+    Int(PrimitiveInt),
+}
+
+/// A SIMD vector with a fixed number of lanes, e.g. `f32x4`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrimitiveVector {
+    pub element: PrimitiveScalar,
+    pub lanes: u32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PrimitiveType {
     Float(PrimitiveFloat),
     Int(PrimitiveInt),
+    Vector(PrimitiveVector),
     Bool,
 }
 
@@ -69,6 +84,12 @@ impl PrimitiveType {
 
         (name![f32], PrimitiveType::Float(PrimitiveFloat::F32)),
         (name![f64], PrimitiveType::Float(PrimitiveFloat::F64)),
+
+        (name![f32x2], PrimitiveType::Vector(PrimitiveVector::F32X2)),
+        (name![f32x4], PrimitiveType::Vector(PrimitiveVector::F32X4)),
+        (name![f64x2], PrimitiveType::Vector(PrimitiveVector::F64X2)),
+        (name![i32x2], PrimitiveType::Vector(PrimitiveVector::I32X2)),
+        (name![i32x4], PrimitiveType::Vector(PrimitiveVector::I32X4)),
     ];
 }
 
@@ -104,6 +125,7 @@ impl PrimitiveType {
                 FloatBitness::X32 => "f32",
                 FloatBitness::X64 => "f64",
             },
+            PrimitiveType::Vector(vector) => vector.as_str(),
         }
     }
 }
@@ -153,6 +175,26 @@ impl PrimitiveInt {
     }
 }
 
+#[rustfmt::skip]
+impl PrimitiveVector {
+    pub const F32X2: PrimitiveVector = PrimitiveVector { element: PrimitiveScalar::Float(PrimitiveFloat::F32), lanes: 2 };
+    pub const F32X4: PrimitiveVector = PrimitiveVector { element: PrimitiveScalar::Float(PrimitiveFloat::F32), lanes: 4 };
+    pub const F64X2: PrimitiveVector = PrimitiveVector { element: PrimitiveScalar::Float(PrimitiveFloat::F64), lanes: 2 };
+    pub const I32X2: PrimitiveVector = PrimitiveVector { element: PrimitiveScalar::Int(PrimitiveInt::I32),     lanes: 2 };
+    pub const I32X4: PrimitiveVector = PrimitiveVector { element: PrimitiveScalar::Int(PrimitiveInt::I32),     lanes: 4 };
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::F32X2 => "f32x2",
+            Self::F32X4 => "f32x4",
+            Self::F64X2 => "f64x2",
+            Self::I32X2 => "i32x2",
+            Self::I32X4 => "i32x4",
+            _ => unreachable!("unsupported vector type"),
+        }
+    }
+}
+
 #[rustfmt::skip]
 impl PrimitiveFloat {
     pub const F32: PrimitiveFloat = PrimitiveFloat { bitness: FloatBitness::X32 };
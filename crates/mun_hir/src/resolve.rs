@@ -6,8 +6,8 @@ use crate::{
     expr::{scope::LocalScopeId, PatId},
     has_module::HasModule,
     ids::{
-        DefWithBodyId, FunctionId, ImplId, ItemContainerId, ItemDefinitionId, Lookup, StructId,
-        TypeAliasId,
+        ConstId, DefWithBodyId, FunctionId, ImplId, ItemContainerId, ItemDefinitionId, Lookup,
+        StructId, TypeAliasId,
     },
     item_scope::BUILTIN_SCOPE,
     name,
@@ -57,6 +57,8 @@ pub enum ValueNs {
     LocalBinding(PatId),
     FunctionId(FunctionId),
     StructId(StructId),
+    ConstId(ConstId),
+    PrimitiveType(PrimitiveType),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -173,12 +175,11 @@ impl Resolver {
             let (res, vis) = match per_ns.take_values()? {
                 (ItemDefinitionId::FunctionId(id), vis) => (ValueNs::FunctionId(id), vis),
                 (ItemDefinitionId::StructId(id), vis) => (ValueNs::StructId(id), vis),
-                (
-                    ItemDefinitionId::ModuleId(_)
-                    | ItemDefinitionId::TypeAliasId(_)
-                    | ItemDefinitionId::PrimitiveType(_),
-                    _,
-                ) => return None,
+                (ItemDefinitionId::ConstId(id), vis) => (ValueNs::ConstId(id), vis),
+                (ItemDefinitionId::PrimitiveType(id), vis) => (ValueNs::PrimitiveType(id), vis),
+                (ItemDefinitionId::ModuleId(_) | ItemDefinitionId::TypeAliasId(_), _) => {
+                    return None
+                }
             };
             Some((res, vis))
         }
@@ -236,7 +237,9 @@ impl Resolver {
                                     TypeNs::PrimitiveType(id)
                                 }
                                 (
-                                    ItemDefinitionId::ModuleId(_) | ItemDefinitionId::FunctionId(_),
+                                    ItemDefinitionId::ModuleId(_)
+                                    | ItemDefinitionId::FunctionId(_)
+                                    | ItemDefinitionId::ConstId(_),
                                     _,
                                 ) => return None,
                             };
@@ -277,7 +280,12 @@ impl Resolver {
                 (ItemDefinitionId::StructId(id), vis) => (TypeNs::StructId(id), vis),
                 (ItemDefinitionId::TypeAliasId(id), vis) => (TypeNs::TypeAliasId(id), vis),
                 (ItemDefinitionId::PrimitiveType(id), vis) => (TypeNs::PrimitiveType(id), vis),
-                (ItemDefinitionId::ModuleId(_) | ItemDefinitionId::FunctionId(_), _) => {
+                (
+                    ItemDefinitionId::ModuleId(_)
+                    | ItemDefinitionId::FunctionId(_)
+                    | ItemDefinitionId::ConstId(_),
+                    _,
+                ) => {
                     return None;
                 }
             };
@@ -433,10 +441,17 @@ impl HasResolver for TypeAliasId {
     }
 }
 
+impl HasResolver for ConstId {
+    fn resolver(self, db: &dyn DefDatabase) -> Resolver {
+        self.module(db).resolver(db)
+    }
+}
+
 impl HasResolver for DefWithBodyId {
     fn resolver(self, db: &dyn DefDatabase) -> Resolver {
         match self {
             DefWithBodyId::FunctionId(f) => f.resolver(db),
+            DefWithBodyId::ConstId(c) => c.resolver(db),
         }
     }
 }
@@ -24,7 +24,7 @@ use crate::{
     resolve::{self, HasResolver},
     semantics::source_to_def::{SourceToDefCache, SourceToDefContainer, SourceToDefContext},
     source_analyzer::SourceAnalyzer,
-    HirDatabase, InFile, ModuleDef, Name, PatId, PerNs, Resolver, Ty, Visibility,
+    Function, HirDatabase, InFile, ModuleDef, Name, Pat, PatId, PerNs, Resolver, Ty, Visibility,
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -162,6 +162,25 @@ impl<'db> Semantics<'db> {
     pub fn resolve_path(&self, path: &ast::Path) -> Option<PathResolution> {
         self.analyze(path.syntax()).resolve_path(self.db, path)
     }
+
+    /// Returns the function that is called by the specified call expression.
+    pub fn resolve_call(&self, call: &ast::CallExpr) -> Option<Function> {
+        self.analyze(call.syntax()).resolve_call(self.db, call)
+    }
+
+    /// Returns the function that is called by the specified method call
+    /// expression.
+    pub fn resolve_method_call(&self, call: &ast::MethodCallExpr) -> Option<Function> {
+        self.analyze(call.syntax())
+            .resolve_method_call(self.db, call)
+    }
+
+    /// Returns the function that is defined by the specified syntax node.
+    pub fn to_function_def(&self, src: &ast::FunctionDef) -> Option<Function> {
+        let src = self.find_file(src.syntax().clone()).with_value(src.clone());
+        self.with_source_to_def_context(|ctx| ctx.fn_to_def(src))
+            .map(Function::from)
+    }
 }
 
 /// Returns the root node of the specified node.
@@ -241,6 +260,27 @@ impl Local {
         let infer = db.infer(self.parent);
         infer[self.pat_id].clone()
     }
+
+    /// Returns the name of this local or `None` if the local is not bound to a
+    /// name.
+    pub fn name(self, db: &dyn HirDatabase) -> Option<Name> {
+        let body = db.body(self.parent);
+        match &body[self.pat_id] {
+            Pat::Bind { name } => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the pattern that introduces this local, or `None` if the local
+    /// refers to a `self` parameter.
+    pub fn source(self, db: &dyn HirDatabase) -> Option<InFile<ast::Pat>> {
+        let (_, source_map) = db.body_with_source_map(self.parent);
+        let src = source_map.pat_syntax(self.pat_id)?;
+        let root = src.file_syntax(db);
+        src.value
+            .left()
+            .map(|ptr| InFile::new(src.file_id, ptr.to_node(&root)))
+    }
 }
 
 impl SemanticsScope<'_> {
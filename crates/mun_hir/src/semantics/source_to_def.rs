@@ -54,7 +54,7 @@ impl SourceToDefContext<'_, '_> {
     }
 
     /// Find the `FunctionId` associated with the specified syntax tree node.
-    fn fn_to_def(&mut self, src: InFile<ast::FunctionDef>) -> Option<FunctionId> {
+    pub(super) fn fn_to_def(&mut self, src: InFile<ast::FunctionDef>) -> Option<FunctionId> {
         let container = self.find_container(src.as_ref().map(AstNode::syntax))?;
         let db = self.db;
         let def_map = &*self
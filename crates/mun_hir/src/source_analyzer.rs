@@ -7,9 +7,9 @@ use crate::{
     expr::{scope::LocalScopeId, BodySourceMap},
     ids::DefWithBodyId,
     resolver_for_scope,
-    semantics::PathResolution,
-    Body, ExprId, ExprScopes, HirDatabase, InFile, InferenceResult, Path, PrimitiveType, Resolver,
-    Struct, Ty, TypeAlias, TypeNs,
+    semantics::{Local, PathResolution},
+    Body, CallableDef, ExprId, ExprScopes, Function, HirDatabase, InFile, InferenceResult, Path,
+    PrimitiveType, Resolver, Struct, Ty, TypeAlias, TypeNs, ValueNs,
 };
 
 /// A `SourceAnalyzer` is a wrapper which exposes the HIR API in terms of the
@@ -83,6 +83,32 @@ impl SourceAnalyzer {
         sm.node_expr(expr)
     }
 
+    /// Returns the function that is called by the specified call expression.
+    pub(crate) fn resolve_call(
+        &self,
+        db: &dyn HirDatabase,
+        call: &ast::CallExpr,
+    ) -> Option<Function> {
+        match self.type_of_expr(db, &call.expr()?)?.as_callable_def()? {
+            CallableDef::Function(function) => Some(function),
+            CallableDef::Struct(_) | CallableDef::PrimitiveType(_) => None,
+        }
+    }
+
+    /// Returns the function that is called by the specified method call
+    /// expression.
+    pub(crate) fn resolve_method_call(
+        &self,
+        db: &dyn HirDatabase,
+        call: &ast::MethodCallExpr,
+    ) -> Option<Function> {
+        let expr_id = self.expr_id(db, &ast::Expr::cast(call.syntax().clone())?)?;
+        self.infer
+            .as_ref()?
+            .method_resolution(expr_id)
+            .map(Function::from)
+    }
+
     pub(crate) fn resolve_path(
         &self,
         db: &dyn HirDatabase,
@@ -96,6 +122,14 @@ impl SourceAnalyzer {
             return resolve_hir_path_qualifier(db, &self.resolver, &hir_path);
         }
 
+        // Case where the path refers to a local binding in the body.
+        if let Some((ValueNs::LocalBinding(pat_id), _)) =
+            self.resolver.resolve_path_as_value_fully(db, &hir_path)
+        {
+            let parent = self.resolver.body_owner()?;
+            return Some(PathResolution::Local(Local { parent, pat_id }));
+        }
+
         None
     }
 }
@@ -71,7 +71,9 @@ register_ast_id_node! {
         StructDef,
         Impl,
         TypeAliasDef,
-    Param, SelfParam
+        ConstDef,
+    Param, SelfParam,
+    Attr
 }
 
 /// Maps items' `SyntaxNode`s to `ErasedFileAstId`s and back.
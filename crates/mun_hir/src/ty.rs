@@ -6,19 +6,19 @@ mod resolve;
 
 use std::{fmt, iter::FromIterator, mem, ops::Deref, sync::Arc};
 
-pub(crate) use infer::infer_query;
 pub use infer::InferenceResult;
+pub(crate) use infer::{infer_query, InferTy};
 pub use lower::TypableDef;
 pub(crate) use lower::{
     callable_item_sig, fn_sig_for_fn, type_for_def, type_for_impl_self, CallableDef,
 };
-pub use primitives::{FloatTy, IntTy};
+pub use primitives::{FloatTy, IntTy, ScalarTy, VectorTy};
 pub use resolve::ResolveBitness;
 use smallvec::SmallVec;
 
 use crate::{
     display::{HirDisplay, HirFormatter},
-    ty::{infer::InferTy, lower::fn_sig_for_struct_constructor},
+    ty::lower::{fn_sig_for_primitive_constructor, fn_sig_for_struct_constructor},
     HasVisibility, HirDatabase, Struct, StructMemoryKind, TypeAlias, Visibility,
 };
 
@@ -41,6 +41,9 @@ pub enum TyKind {
     /// The primitive boolean type. Written as `bool`.
     Bool,
 
+    /// A primitive SIMD vector type. For example `f32x4`.
+    Vector(VectorTy),
+
     /// A tuple type. For example `(f32, f64, bool)`.
     Tuple(usize, Substitution),
 
@@ -161,6 +164,14 @@ impl Ty {
         }
     }
 
+    /// If this type represents a vector type, returns the type of the vector.
+    pub fn as_vector(&self) -> Option<VectorTy> {
+        match self.interned() {
+            TyKind::Vector(ty) => Some(*ty),
+            _ => None,
+        }
+    }
+
     /// Returns true if this type represents the empty tuple type
     pub fn is_empty(&self) -> bool {
         matches!(self.interned(), TyKind::Tuple(0, _))
@@ -221,6 +232,7 @@ impl Ty {
             TyKind::Bool => Some("core::bool".to_string()),
             TyKind::Float(ty) => Some(format!("core::{}", ty.as_str())),
             TyKind::Int(ty) => Some(format!("core::{}", ty.as_str())),
+            TyKind::Vector(ty) => Some(format!("core::{ty}")),
             TyKind::Array(ty) => Some(format!("[{}]", ty.display(db))),
             _ => None,
         }
@@ -264,6 +276,7 @@ impl Ty {
             (TyKind::Array(_), TyKind::Array(_)) | (TyKind::Bool, TyKind::Bool) => true,
             (TyKind::Float(f1), TyKind::Float(f2)) => f1 == f2,
             (TyKind::Int(i1), TyKind::Int(i2)) => i1 == i2,
+            (TyKind::Vector(v1), TyKind::Vector(v2)) => v1 == v2,
             (TyKind::FnDef(def, _), TyKind::FnDef(def2, _)) => def == def2,
             _ => false,
         }
@@ -371,6 +384,7 @@ impl HirDisplay for Ty {
             TyKind::Struct(s) => write!(f, "{}", s.name(f.db)),
             TyKind::Float(ty) => write!(f, "{ty}"),
             TyKind::Int(ty) => write!(f, "{ty}"),
+            TyKind::Vector(ty) => write!(f, "{ty}"),
             TyKind::Bool => write!(f, "bool"),
             TyKind::Tuple(_, elems) => {
                 write!(f, "(")?;
@@ -403,6 +417,13 @@ impl HirDisplay for Ty {
                 f.write_joined(sig.params(), ", ")?;
                 write!(f, ") -> {}", sig.ret().display(f.db))
             }
+            &TyKind::FnDef(CallableDef::PrimitiveType(def), _) => {
+                let sig = fn_sig_for_primitive_constructor(def);
+                write!(f, "ctor {}", def.name())?;
+                write!(f, "(")?;
+                f.write_joined(sig.params(), ", ")?;
+                write!(f, ") -> {}", sig.ret().display(f.db))
+            }
             TyKind::Array(elem_ty) => write!(f, "[{}]", elem_ty.display(f.db)),
             TyKind::Unknown => write!(f, "{{unknown}}"),
         }
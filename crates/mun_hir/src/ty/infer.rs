@@ -5,7 +5,7 @@ use mun_hir_input::ModuleId;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-    code_model::{Struct, StructKind},
+    code_model::{DefWithBody, Struct, StructKind},
     diagnostics::DiagnosticSink,
     expr::{Body, Expr, ExprId, Literal, Pat, PatId, RecordLitField, Statement, UnaryOp},
     name_resolution::Namespace,
@@ -27,7 +27,7 @@ use crate::{
     expr::{LiteralFloat, LiteralFloatKind, LiteralInt, LiteralIntKind},
     has_module::HasModule,
     ids::{DefWithBodyId, FunctionId},
-    method_resolution::{lookup_method, AssociationMode},
+    method_resolution::{lookup_method, AssociationMode, BuiltinMethod},
     resolve::{resolver_for_expr, HasResolver, ResolveValueResult},
     ty::{
         primitives::{FloatTy, IntTy},
@@ -62,6 +62,10 @@ pub struct InferenceResult {
     /// For each method call expression, records the function it resolves to.
     pub(crate) method_resolutions: FxHashMap<ExprId, FunctionId>,
 
+    /// For each method call expression that calls a builtin method, records
+    /// the builtin method it resolves to.
+    pub(crate) builtin_method_resolutions: FxHashMap<ExprId, BuiltinMethod>,
+
     /// Interned Unknown to return references to.
     standard_types: InternedStandardTypes,
 }
@@ -91,12 +95,18 @@ impl InferenceResult {
         self.method_resolutions.get(&expr).cloned()
     }
 
+    /// Find the builtin method resolution for the given expression. Returns
+    /// `None` if the expression is not a call to a builtin method.
+    pub fn builtin_method_resolution(&self, expr: ExprId) -> Option<BuiltinMethod> {
+        self.builtin_method_resolutions.get(&expr).copied()
+    }
+
     /// Adds all the `InferenceDiagnostic`s of the result to the
     /// `DiagnosticSink`.
     pub(crate) fn add_diagnostics(
         &self,
         db: &dyn HirDatabase,
-        owner: Function,
+        owner: DefWithBody,
         sink: &mut DiagnosticSink<'_>,
     ) {
         self.diagnostics
@@ -114,7 +124,7 @@ pub fn infer_query(db: &dyn HirDatabase, def: DefWithBodyId) -> Arc<InferenceRes
     let mut ctx = InferenceResultBuilder::new(db, &body, resolver);
 
     match def {
-        DefWithBodyId::FunctionId(_) => ctx.infer_signature(),
+        DefWithBodyId::FunctionId(_) | DefWithBodyId::ConstId(_) => ctx.infer_signature(),
     }
 
     ctx.infer_body();
@@ -180,6 +190,9 @@ struct InferenceResultBuilder<'a> {
 
     /// Stores the resolution of method calls
     method_resolution: FxHashMap<ExprId, FunctionId>,
+
+    /// Stores the resolution of method calls to builtin methods
+    builtin_method_resolution: FxHashMap<ExprId, BuiltinMethod>,
 }
 
 impl<'a> InferenceResultBuilder<'a> {
@@ -197,6 +210,7 @@ impl<'a> InferenceResultBuilder<'a> {
             resolver,
             return_ty: TyKind::Unknown.intern(), // set in collect_fn_signature
             method_resolution: FxHashMap::default(),
+            builtin_method_resolution: FxHashMap::default(),
         }
     }
 
@@ -204,6 +218,7 @@ impl<'a> InferenceResultBuilder<'a> {
     pub fn module(&self) -> ModuleId {
         match self.body.owner() {
             DefWithBodyId::FunctionId(func) => func.module(self.db),
+            DefWithBodyId::ConstId(konst) => konst.module(self.db),
         }
     }
 
@@ -473,6 +488,18 @@ impl InferenceResultBuilder<'_> {
                             });
                     }
                     field_ty
+                } else if matches!(
+                    receiver_ty.interned(),
+                    TyKind::Bool
+                        | TyKind::Int(_)
+                        | TyKind::Float(_)
+                        | TyKind::InferenceVar(InferTy::Int(_) | InferTy::Float(_))
+                ) {
+                    self.diagnostics.push(InferenceDiagnostic::NoFields {
+                        id: *expr,
+                        found: receiver_ty,
+                    });
+                    error_type()
                 } else {
                     self.diagnostics
                         .push(InferenceDiagnostic::AccessUnknownField {
@@ -491,6 +518,7 @@ impl InferenceResultBuilder<'_> {
                         TyKind::Bool | TyKind::Int(_) | TyKind::InferenceVar(InferTy::Int(_)) => {
                             inner_ty
                         }
+                        TyKind::Vector(vector) if !vector.is_float() => inner_ty,
                         _ => {
                             self.diagnostics
                                 .push(InferenceDiagnostic::CannotApplyUnaryOp {
@@ -503,6 +531,7 @@ impl InferenceResultBuilder<'_> {
                     UnaryOp::Neg => match inner_ty.interned() {
                         TyKind::Float(_)
                         | TyKind::Int(_)
+                        | TyKind::Vector(_)
                         | TyKind::InferenceVar(InferTy::Int(_) | InferTy::Float(_)) => inner_ty,
                         _ => {
                             self.diagnostics
@@ -527,6 +556,23 @@ impl InferenceResultBuilder<'_> {
 
                 TyKind::Array(elem_ty).intern()
             }
+            Expr::ArrayRepeat {
+                initializer,
+                repeat,
+            } => {
+                let elem_ty = match expected.ty.interned() {
+                    TyKind::Array(elem_ty) => elem_ty.clone(),
+                    _ => self.type_variables.new_type_var(),
+                };
+
+                self.infer_expr_coerce(*initializer, &Expectation::has_type(elem_ty.clone()));
+                self.infer_expr(
+                    *repeat,
+                    &Expectation::has_type(TyKind::Int(IntTy::usize()).intern()),
+                );
+
+                TyKind::Array(elem_ty).intern()
+            }
             Expr::Index { base, index } => {
                 let elem_ty = if expected.ty.is_unknown() {
                     self.type_variables.new_type_var()
@@ -597,6 +643,10 @@ impl InferenceResultBuilder<'_> {
                 let field_ty = subs.interned().get(idx)?.clone();
                 Some((field_ty, true))
             }
+            TyKind::Vector(vector) => {
+                vector.lane_index(field_name)?;
+                Some((vector.element_ty(), true))
+            }
             TyKind::Struct(s) => {
                 let struct_data = self.db.struct_data(s.id);
                 let local_field_idx = struct_data.find_field(field_name)?;
@@ -628,6 +678,13 @@ impl InferenceResultBuilder<'_> {
             return error_type();
         }
 
+        // Builtin methods take precedence, inherent impls cannot be defined for
+        // primitive types anyway.
+        if let Some(builtin) = BuiltinMethod::lookup(&receiver_ty, method_name) {
+            self.builtin_method_resolution.insert(tgt_expr, builtin);
+            return self.infer_builtin_method_call(tgt_expr, receiver_ty, args, builtin);
+        }
+
         // Resolve the method on the receiver type.
         let resolved_function = match lookup_method(
             self.db,
@@ -683,6 +740,33 @@ impl InferenceResultBuilder<'_> {
         )
     }
 
+    /// Infers the arguments of a call to a builtin method and returns the type
+    /// of the result.
+    fn infer_builtin_method_call(
+        &mut self,
+        tgt_expr: ExprId,
+        receiver_ty: Ty,
+        args: &[ExprId],
+        builtin: BuiltinMethod,
+    ) -> Ty {
+        match builtin {
+            BuiltinMethod::IntArith { .. } => {
+                if args.len() != 1 {
+                    self.diagnostics
+                        .push(InferenceDiagnostic::ParameterCountMismatch {
+                            id: tgt_expr,
+                            found: args.len(),
+                            expected: 1,
+                        });
+                }
+                for &arg in args {
+                    self.infer_expr_coerce(arg, &Expectation::has_type(receiver_ty.clone()));
+                }
+                self.resolve_ty_as_far_as_possible(receiver_ty)
+            }
+        }
+    }
+
     fn infer_call_arguments_and_return(
         &mut self,
         tgt_expr: ExprId,
@@ -945,6 +1029,12 @@ impl InferenceResultBuilder<'_> {
                         .type_for_def(TypableDef::Function(f.into()), Namespace::Values);
                     Some(ty)
                 }
+                ValueNs::ConstId(c) => {
+                    let ty = self
+                        .db
+                        .type_for_def(TypableDef::Const(c.into()), Namespace::Values);
+                    Some(ty)
+                }
                 ValueNs::StructId(s) => {
                     if check_params.is_unit_struct {
                         self.check_unit_struct_lit(id, s.into());
@@ -954,6 +1044,12 @@ impl InferenceResultBuilder<'_> {
                         .type_for_def(TypableDef::Struct(s.into()), Namespace::Values);
                     Some(ty)
                 }
+                ValueNs::PrimitiveType(t) => {
+                    let ty = self
+                        .db
+                        .type_for_def(TypableDef::PrimitiveType(t.into()), Namespace::Values);
+                    Some(ty)
+                }
             }
         } else {
             // If no value was found, try to resolve the path as a type. This will always
@@ -1028,6 +1124,7 @@ impl InferenceResultBuilder<'_> {
             diagnostics: self.diagnostics,
             standard_types: InternedStandardTypes::default(),
             method_resolutions: self.method_resolution,
+            builtin_method_resolutions: self.builtin_method_resolution,
         }
     }
 
@@ -1250,7 +1347,7 @@ impl From<PatId> for ExprOrPatId {
 
 mod diagnostics {
     use crate::{
-        code_model::{src::HasSource, StructKind},
+        code_model::{DefWithBody, StructKind},
         diagnostics::{
             AccessUnknownField, BreakOutsideLoop, BreakWithValueOutsideLoop, CannotApplyBinaryOp,
             CannotApplyUnaryOp, CyclicType, DiagnosticSink, ExpectedFunction, FieldCountMismatch,
@@ -1262,7 +1359,7 @@ mod diagnostics {
         ids::FunctionId,
         ty::infer::ExprOrPatId,
         type_ref::LocalTypeRefId,
-        ExprId, Function, HirDatabase, IntTy, Name, Ty,
+        ExprId, HirDatabase, IntTy, Name, Ty,
     };
 
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -1381,10 +1478,10 @@ mod diagnostics {
         pub(crate) fn add_to(
             &self,
             db: &dyn HirDatabase,
-            owner: Function,
+            owner: DefWithBody,
             sink: &mut DiagnosticSink<'_>,
         ) {
-            let file = owner.source(db).file_id;
+            let file = owner.file_id(db);
             let body = owner.body_source_map(db);
             match self {
                 InferenceDiagnostic::UnresolvedValue { id } => {
@@ -16,7 +16,14 @@ impl InferenceResultBuilder<'_> {
     fn check_place_path(&mut self, resolver: &Resolver, path: &Path) -> bool {
         match resolver.resolve_path_as_value_fully(self.db, path) {
             Some((ValueNs::ImplSelf(_) | ValueNs::LocalBinding(_), _)) => true,
-            Some((ValueNs::FunctionId(_) | ValueNs::StructId(_), _)) | None => false,
+            Some((
+                ValueNs::FunctionId(_)
+                | ValueNs::StructId(_)
+                | ValueNs::ConstId(_)
+                | ValueNs::PrimitiveType(_),
+                _,
+            ))
+            | None => false,
         }
     }
 }
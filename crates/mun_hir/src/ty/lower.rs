@@ -14,7 +14,7 @@ use crate::{
     resolve::{HasResolver, Resolver, TypeNs},
     ty::{FnSig, Substitution, Ty, TyKind},
     type_ref::{LocalTypeRefId, TypeRef, TypeRefMap, TypeRefSourceMap},
-    Function, HasVisibility, HirDatabase, ModuleDef, Path, PrimitiveType, Struct, TypeAlias,
+    Const, Function, HasVisibility, HirDatabase, ModuleDef, Path, PrimitiveType, Struct, TypeAlias,
     Visibility,
 };
 
@@ -175,6 +175,7 @@ pub enum TypableDef {
     PrimitiveType(PrimitiveType),
     Struct(Struct),
     TypeAlias(TypeAlias),
+    Const(Const),
 }
 
 impl From<Function> for TypableDef {
@@ -195,6 +196,12 @@ impl From<Struct> for TypableDef {
     }
 }
 
+impl From<Const> for TypableDef {
+    fn from(f: Const) -> Self {
+        TypableDef::Const(f)
+    }
+}
+
 impl From<ModuleDef> for Option<TypableDef> {
     fn from(d: ModuleDef) -> Self {
         match d {
@@ -202,6 +209,7 @@ impl From<ModuleDef> for Option<TypableDef> {
             ModuleDef::PrimitiveType(t) => Some(TypableDef::PrimitiveType(t)),
             ModuleDef::Struct(t) => Some(TypableDef::Struct(t)),
             ModuleDef::TypeAlias(t) => Some(TypableDef::TypeAlias(t)),
+            ModuleDef::Const(c) => Some(TypableDef::Const(c)),
             ModuleDef::Module(_) => None,
         }
     }
@@ -211,8 +219,9 @@ impl From<ModuleDef> for Option<TypableDef> {
 pub enum CallableDef {
     Function(Function),
     Struct(Struct),
+    PrimitiveType(PrimitiveType),
 }
-impl_froms!(CallableDef: Function, Struct);
+impl_froms!(CallableDef: Function, Struct, PrimitiveType);
 
 impl CallableDef {
     pub fn is_function(self) -> bool {
@@ -229,6 +238,7 @@ impl HasVisibility for CallableDef {
         match self {
             CallableDef::Struct(strukt) => strukt.visibility(db),
             CallableDef::Function(function) => function.visibility(db),
+            CallableDef::PrimitiveType(_) => Visibility::Public,
         }
     }
 }
@@ -241,15 +251,15 @@ pub(crate) fn type_for_def(db: &dyn HirDatabase, def: TypableDef, ns: Namespace)
     match (def, ns) {
         (TypableDef::Function(f), Namespace::Values) => type_for_fn(db, f),
         (TypableDef::PrimitiveType(t), Namespace::Types) => type_for_primitive(t),
+        (TypableDef::PrimitiveType(t), Namespace::Values) => type_for_primitive_constructor(t),
         (TypableDef::Struct(s), Namespace::Values) => type_for_struct_constructor(db, s),
         (TypableDef::Struct(s), Namespace::Types) => type_for_struct(db, s),
         (TypableDef::TypeAlias(t), Namespace::Types) => type_for_type_alias(db, t),
+        (TypableDef::Const(c), Namespace::Values) => type_for_const(db, c),
 
         // 'error' cases:
-        (TypableDef::Function(_), Namespace::Types)
-        | (TypableDef::PrimitiveType(_) | TypableDef::TypeAlias(_), Namespace::Values) => {
-            TyKind::Unknown.intern()
-        }
+        (TypableDef::Function(_) | TypableDef::Const(_), Namespace::Types)
+        | (TypableDef::TypeAlias(_), Namespace::Values) => TyKind::Unknown.intern(),
     }
 }
 
@@ -264,11 +274,24 @@ pub(crate) fn type_for_primitive(def: PrimitiveType) -> Ty {
     match def.inner {
         crate::primitive_type::PrimitiveType::Float(f) => TyKind::Float(f.into()),
         crate::primitive_type::PrimitiveType::Int(i) => TyKind::Int(i.into()),
+        crate::primitive_type::PrimitiveType::Vector(v) => TyKind::Vector(v.into()),
         crate::primitive_type::PrimitiveType::Bool => TyKind::Bool,
     }
     .intern()
 }
 
+/// Build the type of a primitive constructor. Only vectors can be constructed,
+/// e.g. `f32x4(1.0, 2.0, 3.0, 4.0)`.
+fn type_for_primitive_constructor(def: PrimitiveType) -> Ty {
+    match def.inner {
+        crate::primitive_type::PrimitiveType::Vector(_) => {
+            TyKind::FnDef(def.into(), Substitution::empty())
+        }
+        _ => TyKind::Unknown,
+    }
+    .intern()
+}
+
 /// Build the declared type of a function. This should not need to look at the
 /// function body.
 fn type_for_fn(_db: &dyn HirDatabase, def: Function) -> Ty {
@@ -279,6 +302,7 @@ pub(crate) fn callable_item_sig(db: &dyn HirDatabase, def: CallableDef) -> FnSig
     match def {
         CallableDef::Function(f) => fn_sig_for_fn(db, f),
         CallableDef::Struct(s) => fn_sig_for_struct_constructor(db, s),
+        CallableDef::PrimitiveType(t) => fn_sig_for_primitive_constructor(t),
     }
 }
 
@@ -306,6 +330,17 @@ pub(crate) fn fn_sig_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -
     FnSig::from_params_and_return(params, ret)
 }
 
+/// Returns the signature of the constructor of a vector: one parameter for
+/// every lane.
+pub(crate) fn fn_sig_for_primitive_constructor(def: PrimitiveType) -> FnSig {
+    let ret = type_for_primitive(def);
+    let params = match ret.interned() {
+        TyKind::Vector(vector) => vec![vector.element_ty(); vector.lanes as usize],
+        _ => Vec::new(),
+    };
+    FnSig::from_params_and_return(params, ret)
+}
+
 /// Build the type of a struct constructor.
 fn type_for_struct_constructor(db: &dyn HirDatabase, def: Struct) -> Ty {
     let struct_data = db.struct_data(def.id);
@@ -324,6 +359,18 @@ fn type_for_type_alias(_db: &dyn HirDatabase, def: TypeAlias) -> Ty {
     TyKind::TypeAlias(def).intern()
 }
 
+/// Returns the declared type of a constant.
+fn type_for_const(db: &dyn HirDatabase, def: Const) -> Ty {
+    let data = def.data(db);
+    Ty::from_hir(
+        db,
+        &def.id.resolver(db),
+        data.type_ref_map(),
+        data.type_ref_id,
+    )
+    .0
+}
+
 pub(crate) fn lower_impl_query(db: &dyn HirDatabase, impl_id: ImplId) -> Arc<LowerTyMap> {
     let impl_data = db.impl_data(impl_id);
     let resolver = impl_id.resolver(db);
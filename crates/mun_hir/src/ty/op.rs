@@ -1,6 +1,6 @@
 use crate::{
     ty::{infer::InferTy, TyKind},
-    ArithOp, BinaryOp, Ty,
+    ArithOp, BinaryOp, CmpOp, Ty,
 };
 
 /// Given a binary operation and the type on the left of that operation, returns
@@ -10,12 +10,14 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
     match op {
         BinaryOp::LogicOp(..) => TyKind::Bool.intern(),
 
-        // Compare operations are allowed for all scalar types
-        BinaryOp::CmpOp(..) => match lhs_ty.interned() {
+        // Compare operations are allowed for all scalar types. Vectors can only be
+        // compared for equality, which holds if all lanes are equal.
+        BinaryOp::CmpOp(op) => match lhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
             | TyKind::Bool
             | TyKind::InferenceVar(InferTy::Float(_) | InferTy::Int(_)) => lhs_ty,
+            TyKind::Vector(_) if matches!(op, CmpOp::Eq { .. }) => lhs_ty,
             _ => TyKind::Unknown.intern(),
         },
 
@@ -23,6 +25,7 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
             TyKind::Int(_)
             | TyKind::Float(_)
             | TyKind::Bool
+            | TyKind::Vector(_)
             | TyKind::Struct(_)
             | TyKind::Array(_)
             | TyKind::InferenceVar(InferTy::Float(_) | InferTy::Int(_)) => lhs_ty,
@@ -46,13 +49,16 @@ pub(super) fn binary_op_rhs_expectation(op: BinaryOp, lhs_ty: Ty) -> Ty {
             | ArithOp::BitXor,
         ) => match lhs_ty.interned() {
             TyKind::Int(_) | TyKind::Bool | TyKind::InferenceVar(InferTy::Int(_)) => lhs_ty,
+            TyKind::Vector(vector) if !vector.is_float() => lhs_ty,
             _ => TyKind::Unknown.intern(),
         },
 
-        // Arithmetic operations are supported only on number types
+        // Arithmetic operations are supported only on number types, they are applied
+        // lane-wise to vectors.
         BinaryOp::Assignment { op: Some(_) } | BinaryOp::ArithOp(_) => match lhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
+            | TyKind::Vector(_)
             | TyKind::InferenceVar(InferTy::Float(_) | InferTy::Int(_)) => lhs_ty,
             _ => TyKind::Unknown.intern(),
         },
@@ -66,6 +72,7 @@ pub(super) fn binary_op_return_ty(op: BinaryOp, rhs_ty: Ty) -> Ty {
         BinaryOp::ArithOp(_) => match rhs_ty.interned() {
             TyKind::Int(_)
             | TyKind::Float(_)
+            | TyKind::Vector(_)
             | TyKind::InferenceVar(InferTy::Float(_) | InferTy::Int(_)) => rhs_ty,
             _ => TyKind::Unknown.intern(),
         },
@@ -2,7 +2,14 @@ use std::fmt::{self};
 
 use mun_target::{abi, abi::Integer};
 
-use crate::primitive_type::{FloatBitness, IntBitness, PrimitiveFloat, PrimitiveInt, Signedness};
+use crate::{
+    name::Name,
+    primitive_type::{
+        FloatBitness, IntBitness, PrimitiveFloat, PrimitiveInt, PrimitiveScalar, PrimitiveVector,
+        Signedness,
+    },
+    ty::{Ty, TyKind},
+};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct IntTy {
@@ -211,3 +218,87 @@ impl From<PrimitiveFloat> for FloatTy {
         FloatTy { bitness: t.bitness }
     }
 }
+
+/// The type of the lanes of a [`VectorTy`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScalarTy {
+    Float(FloatTy),
+    Int(IntTy),
+}
+
+impl fmt::Debug for ScalarTy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for ScalarTy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScalarTy::Float(ty) => fmt::Display::fmt(ty, f),
+            ScalarTy::Int(ty) => fmt::Display::fmt(ty, f),
+        }
+    }
+}
+
+/// A SIMD vector type, e.g. `f32x4`. The lanes of a vector can be accessed by
+/// name: `x`, `y`, `z` and `w`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VectorTy {
+    pub element: ScalarTy,
+    pub lanes: u32,
+}
+
+impl fmt::Debug for VectorTy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for VectorTy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.element, self.lanes)
+    }
+}
+
+impl VectorTy {
+    /// The names of the lanes of a vector, in order.
+    const LANE_NAMES: [&'static str; 4] = ["x", "y", "z", "w"];
+
+    /// Returns the type of a single lane of the vector
+    pub fn element_ty(self) -> Ty {
+        match self.element {
+            ScalarTy::Float(ty) => TyKind::Float(ty),
+            ScalarTy::Int(ty) => TyKind::Int(ty),
+        }
+        .intern()
+    }
+
+    /// Returns true if the lanes of the vector are floating point numbers
+    pub fn is_float(self) -> bool {
+        matches!(self.element, ScalarTy::Float(_))
+    }
+
+    /// Returns the index of the lane with the specified name, if the vector has
+    /// such a lane.
+    pub fn lane_index(self, name: &Name) -> Option<u32> {
+        let name = name.as_str()?;
+        Self::LANE_NAMES
+            .iter()
+            .take(self.lanes as usize)
+            .position(|lane| *lane == name)
+            .map(|index| index as u32)
+    }
+}
+
+impl From<PrimitiveVector> for VectorTy {
+    fn from(t: PrimitiveVector) -> Self {
+        VectorTy {
+            element: match t.element {
+                PrimitiveScalar::Float(ty) => ScalarTy::Float(ty.into()),
+                PrimitiveScalar::Int(ty) => ScalarTy::Int(ty.into()),
+            },
+            lanes: t.lanes,
+        }
+    }
+}
@@ -33,6 +33,35 @@ fn issue_354() {
     "###);
 }
 
+#[test]
+fn infer_dependency_path() {
+    insta::assert_snapshot!(infer(
+        r#"
+    //- /mod.mun package:main deps:dep
+    fn main() -> i32 {
+        dep::foo::bar() + dep::foo::private()
+    }
+
+    //- /mod.mun package:dep
+    //- /foo.mun
+    pub fn bar() -> i32 { 1 }
+    fn private() -> i32 { 2 }
+    "#),
+    @r"
+        41..58: undefined value
+        17..62 '{     ...te() }': i32
+        23..36 'dep::foo::bar': function bar() -> i32
+        23..38 'dep::foo::bar()': i32
+        23..60 'dep::f...vate()': {unknown}
+        41..58 'dep::f...rivate': {unknown}
+        41..60 'dep::f...vate()': {unknown}
+        20..25 '{ 1 }': i32
+        22..23 '1': i32
+        46..51 '{ 2 }': i32
+        48..49 '2': i32
+        ");
+}
+
 #[test]
 fn array_element_assignment() {
     insta::assert_snapshot!(infer(
@@ -150,6 +179,67 @@ fn infer_array() {
     "###);
 }
 
+#[test]
+fn infer_array_repeat() {
+    insta::assert_snapshot!(infer(
+        r"
+    const LEN: usize = 2;
+
+    fn main() -> u8 {
+        let a = [5; LEN * 2];
+        let b: [f32] = [1.0; 3];
+        a[3]
+    }",
+    ), @r"
+        19..20 '2': usize
+        39..106 '{     ...a[3] }': u8
+        49..50 'a': [u8]
+        53..65 '[5; LEN * 2]': [u8]
+        54..55 '5': u8
+        57..60 'LEN': usize
+        57..64 'LEN * 2': usize
+        63..64 '2': usize
+        75..76 'b': [f32]
+        86..94 '[1.0; 3]': [f32]
+        87..90 '1.0': f32
+        92..93 '3': usize
+        100..101 'a': [u8]
+        100..104 'a[3]': u8
+        102..103 '3': i32
+        ");
+}
+
+#[test]
+fn infer_const() {
+    insta::assert_snapshot!(infer(
+        r"
+    struct(value) Point { x: f32, y: f32 }
+
+    const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+    pub const FOO: i64 = 4;
+    const BAR: u8 = FOO;
+
+    fn main() -> f32 {
+        let a = FOO + 1;
+        ORIGIN.x
+    }",
+    ), @r"
+        128..131: mismatched type
+        62..86 'Point ... 0.0 }': Point
+        73..76 '0.0': f32
+        81..84 '0.0': f32
+        109..110 '4': i64
+        128..131 'FOO': i64
+        151..188 '{     ...IN.x }': f32
+        161..162 'a': i64
+        165..168 'FOO': i64
+        165..172 'FOO + 1': i64
+        171..172 '1': i64
+        178..184 'ORIGIN': Point
+        178..186 'ORIGIN.x': f32
+        ");
+}
+
 #[test]
 fn private_access() {
     insta::assert_snapshot!(infer(
@@ -678,6 +768,44 @@ fn infer_call_method() {
     ));
 }
 
+#[test]
+fn infer_builtin_int_methods() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn main(a: u8, b: i64) {
+        let c = a.wrapping_add(2);
+        let d = b.checked_mul(b);
+        let e = 5.saturating_sub(3);
+        let f = a.saturating_mul(b);
+        a.wrapping_add();
+        a.wrapping_div(1);
+        1.5.wrapping_add(1.0);
+    }
+    "#
+    ));
+}
+
+#[test]
+fn infer_vector() {
+    insta::assert_snapshot!(infer(
+        r#"
+    fn main(a: f32x4, b: i32x2) -> f32 {
+        let c = f32x4(1.0, 2.0, 3.0, 4.0);
+        let d = a * c + -a;
+        d.w = 5.0;
+        let e = b & i32x2(1, 2) ^ !b;
+        let f = b.z;
+        let g = a & c;
+        let h = f32x2(1.0);
+        let i = b / b % b;
+        let j = a == c;
+        let k = a < c;
+        d.x + d.y
+    }
+    "#
+    ));
+}
+
 #[test]
 fn infer_call_method_not_in_scope() {
     insta::assert_snapshot!(infer(
@@ -1345,15 +1473,15 @@ fn struct_field_index() {
         let baz = Baz;
         baz.a // error: attempted to access a non-existent field in a struct.
         let f = 1.0
-        f.0; // error: attempted to access a non-existent field in a struct.
+        f.0; // error: attempted to access a field on a primitive type.
     }
     "#),
     @r###"
     146..151: attempted to access a non-existent field in a struct.
     268..273: attempted to access a non-existent field in a struct.
     361..366: attempted to access a non-existent field in a struct.
-    451..454: attempted to access a non-existent field in a struct.
-    83..521 '{     ...uct. }': ()
+    451..452: attempted to access a field on a primitive type.
+    83..516 '{     ...ype. }': ()
     93..96 'foo': Foo
     99..120 'Foo { ...b: 4 }': Foo
     108..112 '1.23': f64
@@ -1568,10 +1696,18 @@ fn infer(content: &str) -> String {
         .flat_map(|pkg| pkg.modules(&db))
         .flat_map(|module| module.declarations(&db))
     {
-        if let ModuleDef::Function(fun) = item {
-            let source_map = fun.body_source_map(&db);
-            let infer_result = fun.infer(&db);
-            infer_def(infer_result, source_map);
+        match item {
+            ModuleDef::Function(fun) => {
+                let source_map = fun.body_source_map(&db);
+                let infer_result = fun.infer(&db);
+                infer_def(infer_result, source_map);
+            }
+            ModuleDef::Const(konst) => {
+                let source_map = konst.body_source_map(&db);
+                let infer_result = konst.infer(&db);
+                infer_def(infer_result, source_map);
+            }
+            _ => {}
         }
     }
 
@@ -19,6 +19,13 @@ pub struct Fixture {
 
     /// The text of the file
     pub text: String,
+
+    /// The name of the package the file belongs to. Files without a package
+    /// belong to the package of the previous file, or to the default package.
+    pub package: Option<String>,
+
+    /// The names of the packages the package of this file depends on
+    pub dependencies: Vec<String>,
 }
 
 impl Fixture {
@@ -36,6 +43,10 @@ impl Fixture {
     ///
     /// into two separate `Fixture`s one with `relative_path` 'foo.mun' and one
     /// with 'bar.mun'.
+    ///
+    /// A meta line can optionally specify the package a file belongs to and
+    /// the packages that package depends on, e.g.
+    /// `//- /mod.mun package:foo deps:bar,baz`.
     pub fn parse(text: impl AsRef<str>) -> Vec<Fixture> {
         let text = trim_raw_string_literal(text);
         let mut result: Vec<Fixture> = Vec::new();
@@ -64,7 +75,10 @@ impl Fixture {
             }
 
             if line.starts_with(META_LINE) {
-                let meta = Fixture::parse_meta_line(line);
+                let mut meta = Fixture::parse_meta_line(line);
+                if meta.package.is_none() {
+                    meta.package = result.last().and_then(|prev| prev.package.clone());
+                }
                 result.push(meta);
             } else if let Some(entry) = result.last_mut() {
                 entry.text.push_str(line);
@@ -77,7 +91,7 @@ impl Fixture {
 
     /// Parses a fixture meta line like:
     /// ```
-    /// //- /main.mun
+    /// //- /main.mun package:foo deps:bar
     /// ```
     fn parse_meta_line(line: impl AsRef<str>) -> Fixture {
         let line = line.as_ref();
@@ -90,9 +104,24 @@ impl Fixture {
         assert!(path.starts_with('/'));
         let relative_path = RelativePathBuf::from(&path[1..]);
 
+        let mut package = None;
+        let mut dependencies = Vec::new();
+        for component in &components[1..] {
+            let (key, value) = component
+                .split_once(':')
+                .unwrap_or_else(|| panic!("invalid fixture meta data: {component}"));
+            match key {
+                "package" => package = Some(value.to_owned()),
+                "deps" => dependencies.extend(value.split(',').map(ToOwned::to_owned)),
+                _ => panic!("unknown fixture meta data: {key}"),
+            }
+        }
+
         Fixture {
             relative_path,
             text: String::new(),
+            package,
+            dependencies,
         }
     }
 }
@@ -168,7 +197,9 @@ mod test {
             Fixture::parse(""),
             vec![Fixture {
                 relative_path: RelativePathBuf::from(DEFAULT_FILE_NAME),
-                text: "".to_owned()
+                text: "".to_owned(),
+                package: None,
+                dependencies: Vec::new(),
             }]
         );
     }
@@ -179,7 +210,9 @@ mod test {
             Fixture::parse(format!("{META_LINE} /foo.mun\nfn hello_world() {{}}")),
             vec![Fixture {
                 relative_path: RelativePathBuf::from("foo.mun"),
-                text: "fn hello_world() {}\n".to_owned()
+                text: "fn hello_world() {}\n".to_owned(),
+                package: None,
+                dependencies: Vec::new(),
             }]
         );
     }
@@ -201,11 +234,48 @@ mod test {
             vec![
                 Fixture {
                     relative_path: RelativePathBuf::from("foo.mun"),
-                    text: "fn hello_world() {\n}\n\n".to_owned()
+                    text: "fn hello_world() {\n}\n\n".to_owned(),
+                    package: None,
+                    dependencies: Vec::new(),
                 },
                 Fixture {
                     relative_path: RelativePathBuf::from("bar.mun"),
-                    text: "fn baz() {\n}\n".to_owned()
+                    text: "fn baz() {\n}\n".to_owned(),
+                    package: None,
+                    dependencies: Vec::new(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_packages() {
+        assert_eq!(
+            Fixture::parse(
+                r#"
+                //- /mod.mun package:foo deps:bar,baz
+                //- /foo.mun
+                //- /mod.mun package:bar
+            "#
+            ),
+            vec![
+                Fixture {
+                    relative_path: RelativePathBuf::from("mod.mun"),
+                    text: String::new(),
+                    package: Some("foo".to_owned()),
+                    dependencies: vec!["bar".to_owned(), "baz".to_owned()],
+                },
+                Fixture {
+                    relative_path: RelativePathBuf::from("foo.mun"),
+                    text: String::new(),
+                    package: Some("foo".to_owned()),
+                    dependencies: Vec::new(),
+                },
+                Fixture {
+                    relative_path: RelativePathBuf::from("mod.mun"),
+                    text: String::new(),
+                    package: Some("bar".to_owned()),
+                    dependencies: Vec::new(),
                 }
             ]
         );
@@ -23,26 +23,64 @@ pub trait WithFixture: Default + SourceDatabase + 'static {
     }
 }
 
-/// Fills the specified database with all the files from the specified `fixture`
+/// Fills the specified database with all the files from the specified
+/// `fixture`. Every package in the fixture is stored in its own source root.
 fn with_files(db: &mut dyn SourceDatabase, fixture: &str) -> Vec<FileId> {
     let fixture = Fixture::parse(fixture);
 
-    let mut source_root = SourceRoot::default();
-    let source_root_id = SourceRootId(0);
+    let mut package_names: Vec<Option<String>> = Vec::new();
+    let mut source_roots: Vec<SourceRoot> = Vec::new();
+    let mut dependencies: Vec<Vec<String>> = Vec::new();
     let mut files = Vec::new();
 
     for (idx, entry) in fixture.into_iter().enumerate() {
+        let package_idx = package_names
+            .iter()
+            .position(|name| *name == entry.package)
+            .unwrap_or_else(|| {
+                package_names.push(entry.package.clone());
+                source_roots.push(SourceRoot::default());
+                dependencies.push(Vec::new());
+                package_names.len() - 1
+            });
+        let source_root_id = SourceRootId(package_idx.try_into().expect("too many packages"));
+
         let file_id = FileId(idx.try_into().expect("too many files"));
         db.set_file_text(file_id, Arc::from(entry.text));
         db.set_file_source_root(file_id, source_root_id);
-        source_root.insert_file(file_id, entry.relative_path);
+        source_roots[package_idx].insert_file(file_id, entry.relative_path);
+        dependencies[package_idx].extend(entry.dependencies);
         files.push(file_id);
     }
 
-    db.set_source_root(source_root_id, Arc::new(source_root));
-
     let mut packages = PackageSet::default();
-    packages.add_package(source_root_id);
+    let package_ids = source_roots
+        .into_iter()
+        .enumerate()
+        .map(|(idx, source_root)| {
+            let source_root_id = SourceRootId(idx.try_into().expect("too many packages"));
+            db.set_source_root(source_root_id, Arc::new(source_root));
+            let package_id = packages.add_package(source_root_id);
+            if let Some(name) = &package_names[idx] {
+                packages.set_name(package_id, name);
+            }
+            package_id
+        })
+        .collect::<Vec<_>>();
+
+    for (package_idx, dependencies) in dependencies.into_iter().enumerate() {
+        for dependency in dependencies {
+            let dependency_idx = package_names
+                .iter()
+                .position(|name| name.as_deref() == Some(dependency.as_str()))
+                .unwrap_or_else(|| panic!("unknown package in fixture: {dependency}"));
+            packages.add_dependency(
+                package_ids[package_idx],
+                package_ids[dependency_idx],
+                dependency,
+            );
+        }
+    }
     db.set_packages(Arc::new(packages));
 
     files
@@ -1,4 +1,4 @@
-use std::ops::Index;
+use std::{fmt, ops::Index, path::PathBuf};
 
 use rustc_hash::FxHashMap;
 
@@ -20,6 +20,56 @@ pub struct PackageId(pub u32);
 pub struct PackageData {
     /// The source root which groups together all the source files of a package.
     pub source_root: SourceRootId,
+
+    /// The name of the package as specified in its manifest, if the package
+    /// has one.
+    pub name: Option<String>,
+
+    /// The packages this package depends on.
+    pub dependencies: Vec<Dependency>,
+
+    /// The levels of lints that are configured for this package, by lint
+    /// name. Lints that are not in this map use their default level.
+    pub lint_levels: FxHashMap<String, LintLevel>,
+
+    /// The directory that contains the source files of the package, if the
+    /// package was loaded from disk. This is used to refer to the source files
+    /// from debug information.
+    pub source_directory: Option<PathBuf>,
+}
+
+/// Determines how a violation of a lint is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// Violations are not reported
+    Allow,
+
+    /// Violations are reported as warnings
+    Warn,
+
+    /// Violations are reported as errors
+    Deny,
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        })
+    }
+}
+
+/// A dependency of a package on another package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    /// The package that is depended upon
+    pub package: PackageId,
+
+    /// The name with which the package is referred to from the dependent
+    /// package
+    pub name: String,
 }
 
 /// Contains information about all the packages in the project.
@@ -32,12 +82,62 @@ impl PackageSet {
     /// Adds a new package to the package set with the source files located add
     /// the specified root. Returns the `PackageId` associated with the package.
     pub fn add_package(&mut self, source_root: SourceRootId) -> PackageId {
-        let data = PackageData { source_root };
+        let data = PackageData {
+            source_root,
+            name: None,
+            dependencies: Vec::new(),
+            lint_levels: FxHashMap::default(),
+            source_directory: None,
+        };
         let package_id = PackageId(self.arena.len() as u32);
         self.arena.insert(package_id, data);
         package_id
     }
 
+    /// Records that the package `from` depends on the package `to`, which it
+    /// refers to by `name`.
+    pub fn add_dependency(&mut self, from: PackageId, to: PackageId, name: impl Into<String>) {
+        let dependency = Dependency {
+            package: to,
+            name: name.into(),
+        };
+        self.arena
+            .get_mut(&from)
+            .expect("the dependent package must be part of the package set")
+            .dependencies
+            .push(dependency);
+    }
+
+    /// Overrides the level of the lint with the specified name for a package.
+    pub fn set_lint_level(
+        &mut self,
+        package: PackageId,
+        lint: impl Into<String>,
+        level: LintLevel,
+    ) {
+        self.arena
+            .get_mut(&package)
+            .expect("the package must be part of the package set")
+            .lint_levels
+            .insert(lint.into(), level);
+    }
+
+    /// Sets the name of a package as specified in its manifest.
+    pub fn set_name(&mut self, package: PackageId, name: impl Into<String>) {
+        self.arena
+            .get_mut(&package)
+            .expect("the package must be part of the package set")
+            .name = Some(name.into());
+    }
+
+    /// Sets the directory that contains the source files of a package.
+    pub fn set_source_directory(&mut self, package: PackageId, directory: impl Into<PathBuf>) {
+        self.arena
+            .get_mut(&package)
+            .expect("the package must be part of the package set")
+            .source_directory = Some(directory.into());
+    }
+
     /// Iterates over all packages
     pub fn iter(&self) -> impl Iterator<Item = PackageId> + '_ {
         self.arena.keys().copied()
@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use mun_hir::AstDatabase;
 use mun_hir_input::{FileId, LineIndex, PackageId, SourceDatabase};
-use mun_syntax::SourceFile;
+use mun_syntax::{SourceFile, TextRange, TextSize};
+use ra_ap_text_edit::TextEdit;
 use salsa::{ParallelDatabase, Snapshot};
 
 use crate::{
-    cancelation::Canceled, change::AnalysisChange, completion, db::AnalysisDatabase, diagnostics,
-    diagnostics::Diagnostic, file_structure, FilePosition,
+    call_hierarchy, call_hierarchy::CallItem, cancelation::Canceled, change::AnalysisChange,
+    completion, db::AnalysisDatabase, diagnostics, diagnostics::Diagnostic, file_structure,
+    folding_ranges, folding_ranges::Fold, navigation_target::NavigationTarget, runnables,
+    runnables::Runnable, selection_range, workspace_symbols, FilePosition,
 };
 
 /// Result of an operation that can be canceled.
@@ -85,6 +88,60 @@ impl AnalysisSnapshot {
         self.with_db(|db| file_structure::file_structure(&db.parse(file_id).tree()))
     }
 
+    /// Returns the regions of a file that can be folded.
+    pub fn folding_ranges(&self, file_id: FileId) -> Cancelable<Vec<Fold>> {
+        self.with_db(|db| folding_ranges::folding_ranges(&db.parse(file_id).tree()))
+    }
+
+    /// Returns the ranges to select when expanding the selection from the
+    /// given position, ordered from the innermost to the outermost range.
+    pub fn selection_range(&self, position: FilePosition) -> Cancelable<Vec<TextRange>> {
+        self.with_db(|db| {
+            selection_range::selection_range(&db.parse(position.file_id).tree(), position.offset)
+        })
+    }
+
+    /// Returns the functions in a file that can be run from the editor.
+    pub fn runnables(&self, file_id: FileId) -> Cancelable<Vec<Runnable>> {
+        self.with_db(|db| runnables::runnables(db, file_id))
+    }
+
+    /// Returns the symbols in all packages whose name fuzzy matches `query`.
+    pub fn workspace_symbols(&self, query: &str) -> Cancelable<Vec<NavigationTarget>> {
+        self.with_db(|db| workspace_symbols::workspace_symbols(db, query))
+    }
+
+    /// Returns the function at the given position for which a call hierarchy
+    /// can be computed.
+    pub fn call_hierarchy(
+        &self,
+        position: FilePosition,
+    ) -> Cancelable<Option<Vec<NavigationTarget>>> {
+        self.with_db(|db| call_hierarchy::call_hierarchy(db, position))
+    }
+
+    /// Computes the functions that call the function at the given position.
+    pub fn incoming_calls(&self, position: FilePosition) -> Cancelable<Option<Vec<CallItem>>> {
+        self.with_db(|db| call_hierarchy::incoming_calls(db, position))
+    }
+
+    /// Computes the functions that are called by the function at the given
+    /// position.
+    pub fn outgoing_calls(&self, position: FilePosition) -> Cancelable<Option<Vec<CallItem>>> {
+        self.with_db(|db| call_hierarchy::outgoing_calls(db, position))
+    }
+
+    /// Computes the edit required to format the given file. Returns `None` if
+    /// the file contains syntax errors.
+    pub fn format(&self, file_id: FileId) -> Cancelable<Option<TextEdit>> {
+        self.with_db(|db| {
+            let text = db.file_text(file_id);
+            let formatted = mun_fmt::format(&text).ok()?;
+            let range = TextRange::up_to(TextSize::of(text.as_ref()));
+            Some(TextEdit::replace(range, formatted))
+        })
+    }
+
     /// Computes completions at the given position
     pub fn completions(
         &self,
@@ -0,0 +1,327 @@
+//! Computes the call hierarchy of functions. The call hierarchy consists of the
+//! functions that call a function (incoming calls) and the functions that are
+//! called by a function (outgoing calls).
+
+use mun_hir::{semantics::Semantics, AssocItem, HasSource, ModuleDef, Package};
+use mun_syntax::{ast, ast::NameOwner, match_ast, AstNode, SyntaxKind, SyntaxNode, TextRange, T};
+
+use crate::{db::AnalysisDatabase, navigation_target::NavigationTarget, FilePosition};
+
+/// Describes a function that calls, or is called by, another function.
+#[derive(Debug, Clone)]
+pub struct CallItem {
+    /// The function that calls or is called
+    pub target: NavigationTarget,
+
+    /// The ranges of the calls in the calling function
+    pub ranges: Vec<TextRange>,
+}
+
+/// Returns the function at the specified position. The position must either
+/// point to the name of a function definition or to the name of a function
+/// that is called.
+pub(crate) fn call_hierarchy(
+    db: &AnalysisDatabase,
+    position: FilePosition,
+) -> Option<Vec<NavigationTarget>> {
+    let sema = Semantics::new(db);
+    let function = function_at_position(&sema, position)?;
+    Some(vec![NavigationTarget::from_function(db, function)])
+}
+
+/// Returns all the functions that call the function at the specified position.
+pub(crate) fn incoming_calls(
+    db: &AnalysisDatabase,
+    position: FilePosition,
+) -> Option<Vec<CallItem>> {
+    let sema = Semantics::new(db);
+    let function = function_at_position(&sema, position)?;
+
+    let calls = all_functions(db)
+        .into_iter()
+        .filter_map(|caller| {
+            let (_, ranges) = calls_in_function(db, &sema, caller)
+                .into_iter()
+                .find(|(callee, _)| *callee == function)?;
+            Some(CallItem {
+                target: NavigationTarget::from_function(db, caller),
+                ranges,
+            })
+        })
+        .collect();
+
+    Some(calls)
+}
+
+/// Returns all the functions that are called by the function at the specified
+/// position.
+pub(crate) fn outgoing_calls(
+    db: &AnalysisDatabase,
+    position: FilePosition,
+) -> Option<Vec<CallItem>> {
+    let sema = Semantics::new(db);
+    let function = function_at_position(&sema, position)?;
+
+    let calls = calls_in_function(db, &sema, function)
+        .into_iter()
+        .map(|(callee, ranges)| CallItem {
+            target: NavigationTarget::from_function(db, callee),
+            ranges,
+        })
+        .collect();
+
+    Some(calls)
+}
+
+/// Finds the function that is either defined or called at the specified
+/// position.
+fn function_at_position(sema: &Semantics<'_>, position: FilePosition) -> Option<mun_hir::Function> {
+    let file = sema.parse(position.file_id);
+    let token = file
+        .syntax()
+        .token_at_offset(position.offset)
+        .find(|token| token.kind() == SyntaxKind::IDENT || token.kind() == T![self])?;
+
+    for node in token.parent_ancestors() {
+        match_ast! {
+            match node {
+                ast::FunctionDef(it) => {
+                    let name = it.name()?;
+                    return if name.syntax().text_range().contains_range(token.text_range()) {
+                        sema.to_function_def(&it)
+                    } else {
+                        None
+                    };
+                },
+                ast::CallExpr(it) => return sema.resolve_call(&it),
+                ast::MethodCallExpr(it) => return sema.resolve_method_call(&it),
+                ast::ArgList(_it) => return None,
+                _ => {},
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns all the functions that are called from the body of the specified
+/// `function`, together with the ranges of the calls. The callees are returned
+/// in the order in which they are first called.
+fn calls_in_function(
+    db: &AnalysisDatabase,
+    sema: &Semantics<'_>,
+    function: mun_hir::Function,
+) -> Vec<(mun_hir::Function, Vec<TextRange>)> {
+    let src = function.source(db);
+    let file = sema.parse(src.file_id);
+    let Some(function_def) = file
+        .syntax()
+        .covering_element(src.value.syntax().text_range())
+        .into_node()
+        .and_then(ast::FunctionDef::cast)
+    else {
+        return Vec::new();
+    };
+    let Some(body) = function_def.body() else {
+        return Vec::new();
+    };
+
+    let mut calls: Vec<(mun_hir::Function, Vec<TextRange>)> = Vec::new();
+    for node in body.syntax().descendants() {
+        let call = match_ast! {
+            match node {
+                ast::CallExpr(it) => sema
+                    .resolve_call(&it)
+                    .zip(it.expr().map(|callee| call_range(callee.syntax()))),
+                ast::MethodCallExpr(it) => sema
+                    .resolve_method_call(&it)
+                    .zip(it.name_ref().map(|name_ref| name_ref.syntax().text_range())),
+                _ => None,
+            }
+        };
+
+        if let Some((callee, range)) = call {
+            match calls.iter_mut().find(|(function, _)| *function == callee) {
+                Some((_, ranges)) => ranges.push(range),
+                None => calls.push((callee, vec![range])),
+            }
+        }
+    }
+
+    calls
+}
+
+/// Returns the range of the name of the function that is called by a callee
+/// expression. For a path like `foo::bar` this is the range of `bar`.
+fn call_range(callee: &SyntaxNode) -> TextRange {
+    callee
+        .descendants()
+        .filter_map(ast::NameRef::cast)
+        .last()
+        .map_or_else(
+            || callee.text_range(),
+            |name_ref| name_ref.syntax().text_range(),
+        )
+}
+
+/// Returns all functions in all packages
+fn all_functions(db: &AnalysisDatabase) -> Vec<mun_hir::Function> {
+    let mut functions = Vec::new();
+    for package in Package::all(db) {
+        for module in package.modules(db) {
+            functions.extend(module.declarations(db).into_iter().filter_map(|def| {
+                if let ModuleDef::Function(function) = def {
+                    Some(function)
+                } else {
+                    None
+                }
+            }));
+            for imp in module.impls(db) {
+                functions.extend(imp.items(db).into_iter().map(|item| {
+                    let AssocItem::Function(function) = item;
+                    function
+                }));
+            }
+        }
+    }
+    functions
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use mun_syntax::TextRange;
+
+    use super::{call_hierarchy, incoming_calls, outgoing_calls, CallItem};
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase, FilePosition};
+
+    fn with_position(fixture: &str) -> (AnalysisDatabase, FilePosition) {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+        let (file_id, range_or_offset) = change_fixture
+            .file_position
+            .expect("expected a marker ($0)");
+        let offset = TextRange::from(range_or_offset).start();
+        (db, FilePosition { file_id, offset })
+    }
+
+    fn format_calls(calls: Vec<CallItem>) -> String {
+        calls
+            .into_iter()
+            .map(|call| {
+                format!(
+                    "{} ({}) {:?}",
+                    call.target.name,
+                    call.target.container_name.as_deref().unwrap_or("<root>"),
+                    call.ranges
+                )
+            })
+            .join("\n")
+    }
+
+    fn prepare(fixture: &str) -> String {
+        let (db, position) = with_position(fixture);
+        call_hierarchy(&db, position)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|target| format!("{:?} {} {:?}", target.kind, target.name, target.focus_range))
+            .join("\n")
+    }
+
+    fn incoming(fixture: &str) -> String {
+        let (db, position) = with_position(fixture);
+        format_calls(incoming_calls(&db, position).unwrap_or_default())
+    }
+
+    fn outgoing(fixture: &str) -> String {
+        let (db, position) = with_position(fixture);
+        format_calls(outgoing_calls(&db, position).unwrap_or_default())
+    }
+
+    #[test]
+    fn test_prepare_on_definition() {
+        insta::assert_snapshot!(prepare(
+            r#"
+        fn fo$0o() {}
+        "#
+        ), @"Function foo 3..6");
+    }
+
+    #[test]
+    fn test_prepare_on_call() {
+        insta::assert_snapshot!(prepare(
+            r#"
+        struct Foo;
+        impl Foo {
+            fn bar(self) {}
+        }
+        fn main() {
+            Foo.ba$0r();
+        }
+        "#
+        ), @"Method bar 30..33");
+    }
+
+    #[test]
+    fn test_prepare_outside_function_name() {
+        insta::assert_snapshot!(prepare(
+            r#"
+        fn foo() {
+            let a$0 = 1;
+        }
+        "#
+        ), @"");
+    }
+
+    #[test]
+    fn test_incoming_calls() {
+        insta::assert_snapshot!(incoming(
+            r#"
+        //- /mod.mun
+        pub fn fo$0o() {}
+        fn bar() {
+            foo();
+            foo();
+        }
+        fn baz() {
+            bar();
+        }
+
+        //- /qux.mun
+        struct Qux;
+        impl Qux {
+            fn qux(self) {
+                super::foo();
+            }
+        }
+        "#
+        ), @r"
+            bar (<root>) [31..34, 42..45]
+            qux (Qux) [57..60]
+            ");
+    }
+
+    #[test]
+    fn test_outgoing_calls() {
+        insta::assert_snapshot!(outgoing(
+            r#"
+        struct Foo;
+        impl Foo {
+            fn new() -> Self { Foo }
+            fn bar(self) {}
+        }
+        fn baz() -> i32 { 0 }
+        fn ma$0in() {
+            let foo = Foo::new();
+            foo.bar();
+            baz() + baz();
+        }
+        "#
+        ), @r"
+            new (Foo) [127..130]
+            bar (Foo) [142..145]
+            baz (<root>) [153..156, 161..164]
+            ");
+    }
+}
@@ -57,7 +57,7 @@ impl<'a> Render<'a> {
         local_name: String,
         resolution: &ScopeDef,
     ) -> Option<CompletionItem> {
-        use mun_hir::ModuleDef::{Function, Module, PrimitiveType, Struct, TypeAlias};
+        use mun_hir::ModuleDef::{Const, Function, Module, PrimitiveType, Struct, TypeAlias};
 
         let kind = match resolution {
             ScopeDef::ModuleDef(Module(_)) => CompletionItemKind::SymbolKind(SymbolKind::Module),
@@ -69,6 +69,7 @@ impl<'a> Render<'a> {
             ScopeDef::ModuleDef(TypeAlias(_)) => {
                 CompletionItemKind::SymbolKind(SymbolKind::TypeAlias)
             }
+            ScopeDef::ModuleDef(Const(_)) => CompletionItemKind::SymbolKind(SymbolKind::Const),
             ScopeDef::ImplSelfType(_) => CompletionItemKind::SymbolKind(SymbolKind::SelfParam),
             ScopeDef::Local(_) => CompletionItemKind::SymbolKind(SymbolKind::Local),
             ScopeDef::Unknown => {
@@ -94,6 +95,9 @@ impl<'a> Render<'a> {
         match resolution {
             ScopeDef::Local(local) => set_item_relevance(local.ty(self.ctx.db())),
             ScopeDef::ModuleDef(ModuleDef::Struct(st)) => set_item_relevance(st.ty(self.ctx.db())),
+            ScopeDef::ModuleDef(ModuleDef::Const(konst)) => {
+                set_item_relevance(konst.ty(self.ctx.db()));
+            }
             ScopeDef::ModuleDef(ModuleDef::PrimitiveType(pt)) => {
                 set_item_relevance(pt.ty(self.ctx.db()));
             }
@@ -1,7 +1,11 @@
+#[cfg(test)]
+mod tests;
+
 use std::cell::RefCell;
 
-use mun_diagnostics::DiagnosticForWith;
-use mun_hir::{AstDatabase, InFile, Module};
+pub use mun_diagnostics::Fix;
+use mun_diagnostics::{DiagnosticCode, DiagnosticForWith};
+use mun_hir::{diagnostics::Severity, AstDatabase, InFile, Module};
 use mun_hir_input::{FileId, ModuleId, PackageId, SourceDatabase};
 use mun_syntax::{Location, TextRange};
 
@@ -15,11 +19,12 @@ pub struct SourceAnnotation {
 
 #[derive(Debug)]
 pub struct Diagnostic {
+    pub code: Option<DiagnosticCode>,
     pub message: String,
     pub range: TextRange,
     pub additional_annotations: Vec<SourceAnnotation>,
-    // pub fix: Option<SourceChange>,
-    // pub severity: Severity,
+    pub fix: Option<Fix>,
+    pub severity: Severity,
 }
 
 /// Converts a location to a a range for use in diagnostics
@@ -37,16 +42,21 @@ pub(crate) fn diagnostics(db: &AnalysisDatabase, file_id: FileId) -> Vec<Diagnos
     // Add all syntax errors
     let parse = db.parse(file_id);
     result.extend(parse.errors().iter().map(|err| Diagnostic {
+        code: Some(mun_diagnostics::codes::SYNTAX_ERROR),
         message: format!("parse error: {err}"),
         range: location_to_range(err.location()),
         additional_annotations: vec![],
+        fix: None,
+        severity: Severity::Error,
     }));
 
-    // Add all HIR diagnostics
+    // Add all HIR diagnostics and lint violations
     let result = RefCell::new(result);
     let mut sink = mun_hir::diagnostics::DiagnosticSink::new(|d| {
+        let mut fix = mun_diagnostics::fix(db, d);
         result.borrow_mut().push(d.with_diagnostic(db, |d| {
             Diagnostic {
+                code: d.code(),
                 message: format!("{}\n{}", d.title(), d.footer().join("\n"))
                     .trim()
                     .to_owned(),
@@ -59,6 +69,8 @@ pub(crate) fn diagnostics(db: &AnalysisDatabase, file_id: FileId) -> Vec<Diagnos
                         range: annotation.range,
                     })
                     .collect(),
+                fix: fix.take(),
+                severity: d.severity(),
             }
         }));
     });
@@ -70,7 +82,9 @@ pub(crate) fn diagnostics(db: &AnalysisDatabase, file_id: FileId) -> Vec<Diagnos
             package: package_id,
             local_id,
         };
-        Module::from(module_id).diagnostics(db, &mut sink);
+        let module = Module::from(module_id);
+        module.diagnostics(db, &mut sink);
+        module.lints(db, &mut sink);
     }
     drop(sink);
 
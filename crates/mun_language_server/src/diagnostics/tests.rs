@@ -0,0 +1,222 @@
+mod fixes {
+    use mun_hir_input::SourceDatabase;
+
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase, diagnostics::diagnostics};
+
+    /// Applies the fix of the diagnostic at the position of the cursor (`$0`)
+    /// and returns the text of the changed file(s).
+    fn apply_fix(fixture: &str) -> String {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+        let (file_id, range_or_offset) = change_fixture
+            .file_position
+            .expect("expected a marker ($0)");
+        let cursor = mun_syntax::TextRange::from(range_or_offset);
+
+        let fix = diagnostics(&db, file_id)
+            .into_iter()
+            .filter(|d| d.range.contains_range(cursor))
+            .find_map(|d| d.fix)
+            .expect("no fix found at the cursor position");
+
+        let mut result = format!("// {}", fix.label);
+        for file_edit in fix.source_change.source_file_edits {
+            let mut text = db.file_text(file_edit.file_id).to_string();
+            file_edit.edit.apply(&mut text);
+            result.push('\n');
+            result.push_str(&text);
+        }
+        result
+    }
+
+    #[test]
+    fn test_fill_missing_fields() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        struct Bar(f32, bool);
+        struct Foo { a: i32, b: Bar, c: f64 }
+
+        fn main() {
+            let a = Foo$0 { a: 1 };
+        }
+        "#,
+        ), @r###"
+        // Fill struct fields
+        struct Bar(f32, bool);
+        struct Foo { a: i32, b: Bar, c: f64 }
+
+        fn main() {
+            let a = Foo { a: 1, b: Bar(0.0, false), c: 0.0 };
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_fill_missing_fields_empty() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        struct Foo { a: i32, b: bool }
+
+        fn main() {
+            let a = Foo$0 {};
+        }
+        "#,
+        ), @r###"
+        // Fill struct fields
+        struct Foo { a: i32, b: bool }
+
+        fn main() {
+            let a = Foo { a: 0, b: false };
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_fill_missing_fields_trailing_comma() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        struct Foo { a: i32, b: bool }
+
+        fn main() {
+            let a = Foo$0 { a: 3, };
+        }
+        "#,
+        ), @r###"
+        // Fill struct fields
+        struct Foo { a: i32, b: bool }
+
+        fn main() {
+            let a = Foo { a: 3, b: false, };
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_add_use_for_unresolved_type() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        //- /mod.mun
+        fn main(a: Fo$0o) {}
+
+        //- /foo/bar.mun
+        pub struct Foo;
+        "#,
+        ), @r###"
+        // Import `package::foo::bar::Foo`
+        use package::foo::bar::Foo;
+
+        fn main(a: Foo) {}
+        "###);
+    }
+
+    #[test]
+    fn test_add_use_for_unresolved_value() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        //- /mod.mun
+        use package::bar::Bar;
+
+        fn main() {
+            f$0oo();
+        }
+
+        //- /foo.mun
+        pub fn foo() {}
+
+        //- /bar.mun
+        pub struct Bar;
+        "#,
+        ), @r###"
+        // Import `package::foo::foo`
+        use package::bar::Bar;
+        use package::foo::foo;
+
+        fn main() {
+            foo();
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_no_use_for_private_item() {
+        let change_fixture = ChangeFixture::parse(
+            r#"
+        //- /mod.mun
+        fn main() {
+            foo();
+        }
+
+        //- /foo.mun
+        fn foo() {}
+        "#,
+        );
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+        assert!(diagnostics(&db, mun_hir_input::FileId(0))
+            .into_iter()
+            .all(|d| d.fix.is_none()));
+    }
+
+    #[test]
+    fn test_fix_unresolved_import() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        //- /mod.mun
+        use package::foo::B$0ar;
+
+        //- /foo.mun
+        pub struct Foo;
+
+        //- /baz.mun
+        pub struct Bar;
+        "#,
+        ), @r###"
+        // Change import to `package::baz::Bar`
+        use package::baz::Bar;
+        "###);
+    }
+
+    #[test]
+    fn test_make_method_public() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        //- /mod.mun
+        fn main() {
+            let a = foo::Foo { a: 1 };
+            a.ba$0r();
+        }
+
+        //- /foo.mun
+        pub struct Foo { a: i32 }
+        impl Foo {
+            fn bar(self) -> i32 { self.a }
+        }
+        "#,
+        ), @r###"
+        // Make `bar` public
+        pub struct Foo { a: i32 }
+        impl Foo {
+            pub fn bar(self) -> i32 { self.a }
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_initialize_variable() {
+        insta::assert_snapshot!(apply_fix(
+            r#"
+        fn main() {
+            let a: i32;
+            let b = a$0 + 1;
+        }
+        "#,
+        ), @r###"
+        // Initialize `a`
+        fn main() {
+            let a: i32 = 0;
+            let b = a + 1;
+        }
+        "###);
+    }
+}
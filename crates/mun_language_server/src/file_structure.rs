@@ -79,25 +79,6 @@ fn try_convert_to_structure_node(node: &SyntaxNode) -> Option<StructureNode> {
         })
     }
 
-    /// Given a `SyntaxNode` get the text without any whitespaces
-    fn collapse_whitespaces(node: &SyntaxNode, output: &mut String) {
-        let mut can_insert_ws = false;
-        node.text().for_each_chunk(|chunk| {
-            for line in chunk.lines() {
-                let line = line.trim();
-                if line.is_empty() {
-                    if can_insert_ws {
-                        output.push(' ');
-                        can_insert_ws = false;
-                    }
-                } else {
-                    output.push_str(line);
-                    can_insert_ws = true;
-                }
-            }
-        });
-    }
-
     /// Given a `SyntaxNode` construct a `StructureNode` by referring to the
     /// type of a node.
     fn decl_with_type_ref<N: NameOwner>(
@@ -116,22 +97,12 @@ fn try_convert_to_structure_node(node: &SyntaxNode) -> Option<StructureNode> {
     match_ast! {
         match node {
             ast::FunctionDef(it) => {
-                let mut detail = String::from("fn");
-                let has_self_param = if let Some(param_list) = it.param_list() {
-                    collapse_whitespaces(param_list.syntax(), &mut detail);
-                    param_list.self_param().is_some()
-                } else {
-                    false
-                };
-                if let Some(ret_type) = it.ret_type() {
-                    detail.push(' ');
-                    collapse_whitespaces(ret_type.syntax(), &mut detail);
-                }
-
-                decl_with_detail(&it, Some(detail), if has_self_param { SymbolKind::Method } else { SymbolKind::Function })
+                let has_self_param = it.param_list().is_some_and(|param_list| param_list.self_param().is_some());
+                decl_with_detail(&it, Some(function_detail(&it)), if has_self_param { SymbolKind::Method } else { SymbolKind::Function })
             },
             ast::StructDef(it) => decl(it, SymbolKind::Struct),
             ast::TypeAliasDef(it) => decl_with_type_ref(&it, it.type_ref(), SymbolKind::TypeAlias),
+            ast::ConstDef(it) => decl_with_type_ref(&it, it.ascribed_type(), SymbolKind::Const),
             ast::RecordFieldDef(it) => decl_with_type_ref(&it, it.ascribed_type(), SymbolKind::Field),
             ast::Impl(it) => {
                 let target_type = it.type_ref()?;
@@ -151,3 +122,35 @@ fn try_convert_to_structure_node(node: &SyntaxNode) -> Option<StructureNode> {
         }
     }
 }
+
+/// Returns the signature of a function, e.g. `fn(a: i32) -> i32`.
+pub(crate) fn function_detail(function: &ast::FunctionDef) -> String {
+    let mut detail = String::from("fn");
+    if let Some(param_list) = function.param_list() {
+        collapse_whitespaces(param_list.syntax(), &mut detail);
+    }
+    if let Some(ret_type) = function.ret_type() {
+        detail.push(' ');
+        collapse_whitespaces(ret_type.syntax(), &mut detail);
+    }
+    detail
+}
+
+/// Given a `SyntaxNode` get the text without any whitespaces
+fn collapse_whitespaces(node: &SyntaxNode, output: &mut String) {
+    let mut can_insert_ws = false;
+    node.text().for_each_chunk(|chunk| {
+        for line in chunk.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                if can_insert_ws {
+                    output.push(' ');
+                    can_insert_ws = false;
+                }
+            } else {
+                output.push_str(line);
+                can_insert_ws = true;
+            }
+        }
+    });
+}
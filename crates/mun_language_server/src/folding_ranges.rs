@@ -0,0 +1,249 @@
+//! Computes the regions of a source file that can be folded (collapsed) by an
+//! editor. Folding ranges are computed purely from the syntax tree.
+
+use mun_syntax::{
+    AstNode, Direction, SourceFile, SyntaxElement,
+    SyntaxKind::{
+        ASSOCIATED_ITEM_LIST, BLOCK_EXPR, COMMENT, RECORD_FIELD_DEF_LIST, USE, USE_TREE_LIST,
+        WHITESPACE,
+    },
+    SyntaxNode, SyntaxToken, TextRange,
+};
+use rustc_hash::FxHashSet;
+
+/// The type of region that can be folded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FoldKind {
+    /// A run of consecutive line comments
+    Comment,
+
+    /// A group of consecutive `use` declarations or a multi-line use tree
+    Imports,
+
+    /// A delimited block like a function body, the items of an `impl` or the
+    /// fields of a struct
+    Block,
+}
+
+/// A region of a source file that can be folded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fold {
+    /// The range of text that can be folded
+    pub range: TextRange,
+
+    /// The type of region
+    pub kind: FoldKind,
+}
+
+/// Returns all regions of the specified file that can be folded. Only regions
+/// that span multiple lines are returned.
+pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
+    let mut result = Vec::new();
+    let mut visited_comments = FxHashSet::default();
+    let mut visited_uses = FxHashSet::default();
+
+    for element in file.syntax().descendants_with_tokens() {
+        match element {
+            SyntaxElement::Token(token) => {
+                if token.kind() == COMMENT && !visited_comments.contains(&token) {
+                    if let Some(range) = contiguous_comments(&token, &mut visited_comments) {
+                        result.push(Fold {
+                            range,
+                            kind: FoldKind::Comment,
+                        });
+                    }
+                }
+            }
+            SyntaxElement::Node(node) => {
+                let kind = match node.kind() {
+                    BLOCK_EXPR | ASSOCIATED_ITEM_LIST | RECORD_FIELD_DEF_LIST => FoldKind::Block,
+                    USE_TREE_LIST => FoldKind::Imports,
+                    USE => {
+                        if !visited_uses.contains(&node) {
+                            if let Some(range) = contiguous_uses(&node, &mut visited_uses) {
+                                result.push(Fold {
+                                    range,
+                                    kind: FoldKind::Imports,
+                                });
+                            }
+                        }
+                        continue;
+                    }
+                    _ => continue,
+                };
+
+                if is_multiline(&node) {
+                    result.push(Fold {
+                        range: node.text_range(),
+                        kind,
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn is_multiline(node: &SyntaxNode) -> bool {
+    node.text().contains_char('\n')
+}
+
+/// Returns the range of the run of line comments that starts with `first`, if
+/// the run consists of more than one comment. All comments in the run are
+/// added to `visited`.
+fn contiguous_comments(
+    first: &SyntaxToken,
+    visited: &mut FxHashSet<SyntaxToken>,
+) -> Option<TextRange> {
+    if !first.text().starts_with("//") {
+        return None;
+    }
+
+    visited.insert(first.clone());
+    let mut last = first.clone();
+    for element in first.siblings_with_tokens(Direction::Next).skip(1) {
+        let Some(token) = element.into_token() else {
+            break;
+        };
+        match token.kind() {
+            // Comments separated by an empty line are not part of the same run
+            WHITESPACE if token.text().matches('\n').count() <= 1 => {}
+            COMMENT if token.text().starts_with("//") => {
+                visited.insert(token.clone());
+                last = token;
+            }
+            _ => break,
+        }
+    }
+
+    (last != *first).then(|| TextRange::new(first.text_range().start(), last.text_range().end()))
+}
+
+/// Returns the range of the group of consecutive `use` declarations that
+/// starts with `first`, if the group contains more than one declaration. All
+/// declarations in the group are added to `visited`.
+fn contiguous_uses(first: &SyntaxNode, visited: &mut FxHashSet<SyntaxNode>) -> Option<TextRange> {
+    visited.insert(first.clone());
+    let mut last = first.clone();
+    for element in first.siblings_with_tokens(Direction::Next).skip(1) {
+        match element {
+            SyntaxElement::Token(token) if token.kind().is_trivia() => {}
+            SyntaxElement::Node(node) if node.kind() == USE => {
+                visited.insert(node.clone());
+                last = node;
+            }
+            _ => break,
+        }
+    }
+
+    (last != *first).then(|| TextRange::new(first.text_range().start(), last.text_range().end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use mun_hir::AstDatabase;
+    use mun_hir_input::SourceDatabase;
+
+    use super::folding_ranges;
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase};
+
+    fn folds(fixture: &str) -> String {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let file_id = change_fixture.files[0];
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+
+        let text = db.file_text(file_id);
+        let file = db.parse(file_id).tree();
+        folding_ranges(&file)
+            .into_iter()
+            .map(|fold| format!("{:?}: {}", fold.kind, &text[fold.range]))
+            .join("\n---\n")
+    }
+
+    #[test]
+    fn test_blocks() {
+        insta::assert_snapshot!(folds(
+            r#"
+        struct Foo {
+            a: i32,
+            b: f32,
+        }
+        struct Bar { a: i32 }
+        impl Foo {
+            fn new() -> Self {
+                Foo { a: 1, b: 2.0 }
+            }
+            fn get(self) -> i32 { self.a }
+        }
+        "#
+        ), @r"
+            Block: {
+                a: i32,
+                b: f32,
+            }
+            ---
+            Block: {
+                fn new() -> Self {
+                    Foo { a: 1, b: 2.0 }
+                }
+                fn get(self) -> i32 { self.a }
+            }
+            ---
+            Block: {
+                    Foo { a: 1, b: 2.0 }
+                }
+            ");
+    }
+
+    #[test]
+    fn test_comments() {
+        insta::assert_snapshot!(folds(
+            r#"
+        // first
+        // second
+
+        // separate run
+        // of two comments
+        fn foo() {} // single
+        /* block */
+        "#
+        ), @r"
+            Comment: // first
+            // second
+            ---
+            Comment: // separate run
+            // of two comments
+            ");
+    }
+
+    #[test]
+    fn test_imports() {
+        insta::assert_snapshot!(folds(
+            r#"
+        use foo::Bar;
+        use foo::{
+            Baz,
+            Qux,
+        };
+
+        fn main() {}
+
+        use bar::Foo;
+        "#
+        ), @r"
+            Imports: use foo::Bar;
+            use foo::{
+                Baz,
+                Qux,
+            };
+            ---
+            Imports: {
+                Baz,
+                Qux,
+            }
+            ");
+    }
+}
@@ -1,8 +1,13 @@
-use lsp_types::{CompletionContext, DocumentSymbol};
+use lsp_types::{CodeActionKind, CompletionContext, DocumentSymbol};
 use mun_syntax::{AstNode, TextSize};
 
 use crate::{
-    from_lsp, state::LanguageServerSnapshot, to_lsp, to_lsp::completion_items, FilePosition,
+    from_json, from_lsp, run,
+    run::{RunFunctionArgs, RUN_FUNCTION_COMMAND},
+    state::LanguageServerSnapshot,
+    to_json, to_lsp,
+    to_lsp::completion_items,
+    FilePosition,
 };
 
 /// Computes the document symbols for a specific document. Converts the LSP
@@ -86,6 +91,232 @@ pub(crate) fn handle_completion(
     Ok(Some(completion_items(items).into()))
 }
 
+/// Computes the code actions that are available for a range in a document.
+/// Returns the fixes of all diagnostics that overlap with the requested range
+/// as quick fixes.
+pub(crate) fn handle_code_action(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CodeActionParams,
+) -> anyhow::Result<Option<lsp_types::CodeActionResponse>> {
+    // If the client is only interested in specific kinds of code actions, make sure
+    // quick fixes are one of them.
+    if let Some(only) = &params.context.only {
+        let quick_fix = CodeActionKind::QUICKFIX;
+        if !only
+            .iter()
+            .any(|kind| quick_fix.as_str().starts_with(kind.as_str()))
+        {
+            return Ok(None);
+        }
+    }
+
+    let file_id = from_lsp::file_id(&snapshot, &params.text_document.uri)?;
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    let range = from_lsp::text_range(&line_index, params.range);
+
+    let mut actions = Vec::new();
+    for mut diagnostic in snapshot.analysis.diagnostics(file_id)? {
+        let Some(fix) = diagnostic.fix.take() else {
+            continue;
+        };
+        if diagnostic.range.intersect(range).is_none() {
+            continue;
+        }
+
+        let lsp_diagnostic = to_lsp::diagnostic(&snapshot, diagnostic, &line_index)?;
+        actions.push(to_lsp::code_action(&snapshot, &fix, lsp_diagnostic)?.into());
+    }
+
+    Ok(Some(actions))
+}
+
+/// Formats a document. Returns `None` if the document contains syntax errors
+/// and can therefore not be formatted.
+pub(crate) fn handle_formatting(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::DocumentFormattingParams,
+) -> anyhow::Result<Option<Vec<lsp_types::TextEdit>>> {
+    let file_id = from_lsp::file_id(&snapshot, &params.text_document.uri)?;
+    let Some(edit) = snapshot.analysis.format(file_id)? else {
+        return Ok(None);
+    };
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    Ok(Some(to_lsp::text_edit_vec(&edit, &line_index)))
+}
+
+/// Computes the regions of a document that can be folded.
+pub(crate) fn handle_folding_range(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::FoldingRangeParams,
+) -> anyhow::Result<Option<Vec<lsp_types::FoldingRange>>> {
+    let file_id = from_lsp::file_id(&snapshot, &params.text_document.uri)?;
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    let folds = snapshot.analysis.folding_ranges(file_id)?;
+    Ok(Some(
+        folds
+            .iter()
+            .map(|fold| to_lsp::folding_range(fold, &line_index))
+            .collect(),
+    ))
+}
+
+/// Computes the ranges to select when the user expands the selection from
+/// each of the requested positions.
+pub(crate) fn handle_selection_range(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::SelectionRangeParams,
+) -> anyhow::Result<Option<Vec<lsp_types::SelectionRange>>> {
+    let file_id = from_lsp::file_id(&snapshot, &params.text_document.uri)?;
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    let mut result = Vec::with_capacity(params.positions.len());
+    for position in params.positions {
+        let offset = from_lsp::offset(&line_index, position);
+        let ranges = snapshot
+            .analysis
+            .selection_range(FilePosition { file_id, offset })?;
+        let Some(selection_range) = to_lsp::selection_range(&ranges, &line_index) else {
+            return Ok(None);
+        };
+        result.push(selection_range);
+    }
+    Ok(Some(result))
+}
+
+/// Returns a code lens above every function in a document that can be run
+/// from the editor.
+pub(crate) fn handle_code_lens(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CodeLensParams,
+) -> anyhow::Result<Option<Vec<lsp_types::CodeLens>>> {
+    let file_id = from_lsp::file_id(&snapshot, &params.text_document.uri)?;
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    snapshot
+        .analysis
+        .runnables(file_id)?
+        .into_iter()
+        .map(|runnable| {
+            let args = RunFunctionArgs {
+                text_document: params.text_document.clone(),
+                function_name: runnable.function_name,
+            };
+            Ok(lsp_types::CodeLens {
+                range: to_lsp::range(runnable.range, &line_index),
+                command: Some(lsp_types::Command {
+                    title: String::from("▶\u{fe0e} Run"),
+                    command: String::from(RUN_FUNCTION_COMMAND),
+                    arguments: Some(vec![to_json(args)?]),
+                }),
+                data: None,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Executes one of the commands supported by the server.
+pub(crate) fn handle_execute_command(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::ExecuteCommandParams,
+) -> anyhow::Result<Option<serde_json::Value>> {
+    match params.command.as_str() {
+        RUN_FUNCTION_COMMAND => {
+            let [args] =
+                <[serde_json::Value; 1]>::try_from(params.arguments).map_err(|arguments| {
+                    anyhow::anyhow!(
+                        "{RUN_FUNCTION_COMMAND} expects a single argument, found {}",
+                        arguments.len()
+                    )
+                })?;
+            let args: RunFunctionArgs = from_json(RUN_FUNCTION_COMMAND, args)?;
+            let result = run::run_function(&snapshot, &args)?;
+            Ok(Some(to_json(result)?))
+        }
+        command => Err(anyhow::anyhow!("unknown command: {command}")),
+    }
+}
+
+/// Searches for symbols in all packages of the workspace whose name fuzzy
+/// matches the query.
+pub(crate) fn handle_workspace_symbol(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::WorkspaceSymbolParams,
+) -> anyhow::Result<Option<lsp_types::WorkspaceSymbolResponse>> {
+    let symbols = snapshot
+        .analysis
+        .workspace_symbols(&params.query)?
+        .into_iter()
+        .map(|symbol| to_lsp::symbol_information(&snapshot, symbol))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Some(lsp_types::WorkspaceSymbolResponse::Flat(symbols)))
+}
+
+/// Returns the function at the requested position for which the client can
+/// request incoming and outgoing calls.
+pub(crate) fn handle_call_hierarchy_prepare(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CallHierarchyPrepareParams,
+) -> anyhow::Result<Option<Vec<lsp_types::CallHierarchyItem>>> {
+    let position = from_lsp::file_position(&snapshot, params.text_document_position_params)?;
+    let Some(targets) = snapshot.analysis.call_hierarchy(position)? else {
+        return Ok(None);
+    };
+    targets
+        .into_iter()
+        .map(|target| to_lsp::call_hierarchy_item(&snapshot, target))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Computes the functions that call the function described by the requested
+/// call hierarchy item.
+pub(crate) fn handle_call_hierarchy_incoming(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CallHierarchyIncomingCallsParams,
+) -> anyhow::Result<Option<Vec<lsp_types::CallHierarchyIncomingCall>>> {
+    let position = call_hierarchy_item_position(&snapshot, params.item)?;
+    let Some(calls) = snapshot.analysis.incoming_calls(position)? else {
+        return Ok(None);
+    };
+    calls
+        .into_iter()
+        .map(|call| to_lsp::call_hierarchy_incoming_call(&snapshot, call))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Computes the functions that are called by the function described by the
+/// requested call hierarchy item.
+pub(crate) fn handle_call_hierarchy_outgoing(
+    snapshot: LanguageServerSnapshot,
+    params: lsp_types::CallHierarchyOutgoingCallsParams,
+) -> anyhow::Result<Option<Vec<lsp_types::CallHierarchyOutgoingCall>>> {
+    let position = call_hierarchy_item_position(&snapshot, params.item)?;
+    let Some(calls) = snapshot.analysis.outgoing_calls(position)? else {
+        return Ok(None);
+    };
+    let line_index = snapshot.analysis.file_line_index(position.file_id)?;
+    calls
+        .into_iter()
+        .map(|call| to_lsp::call_hierarchy_outgoing_call(&snapshot, call, &line_index))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Returns the position of the name of the function described by a call
+/// hierarchy item.
+fn call_hierarchy_item_position(
+    snapshot: &LanguageServerSnapshot,
+    item: lsp_types::CallHierarchyItem,
+) -> anyhow::Result<FilePosition> {
+    from_lsp::file_position(
+        snapshot,
+        lsp_types::TextDocumentPositionParams {
+            text_document: lsp_types::TextDocumentIdentifier { uri: item.uri },
+            position: item.selection_range.start,
+        },
+    )
+}
+
 /// Constructs a hierarchy of `DocumentSymbols` for a list of symbols that
 /// specify which index is the parent of a symbol. The parent index must always
 /// be smaller than the current index.
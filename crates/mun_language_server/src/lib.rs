@@ -11,6 +11,7 @@ pub(crate) use state::LanguageServerState;
 pub(crate) use symbol_kind::SymbolKind;
 
 mod analysis;
+mod call_hierarchy;
 mod cancelation;
 mod capabilities;
 mod change;
@@ -21,13 +22,19 @@ mod config;
 mod db;
 mod diagnostics;
 mod file_structure;
+mod folding_ranges;
 mod from_lsp;
 mod handlers;
 mod lsp_utils;
 mod main_loop;
+mod navigation_target;
+mod run;
+mod runnables;
+mod selection_range;
 mod state;
 mod symbol_kind;
 mod to_lsp;
+mod workspace_symbols;
 
 /// Represents a position in a file
 #[derive(Clone, Copy, Debug)]
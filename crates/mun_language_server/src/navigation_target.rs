@@ -0,0 +1,136 @@
+use mun_hir::{HasSource, HirDisplay, Module};
+use mun_hir_input::FileId;
+use mun_syntax::{ast::NameOwner, AstNode, TextRange};
+
+use crate::{db::AnalysisDatabase, file_structure::function_detail, SymbolKind};
+
+/// A symbol in the source that the user can navigate to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationTarget {
+    /// The file in which the symbol is defined
+    pub file_id: FileId,
+
+    /// The name of the symbol
+    pub name: String,
+
+    /// The type of symbol
+    pub kind: SymbolKind,
+
+    /// The entire range of the symbol in the file
+    pub full_range: TextRange,
+
+    /// The range to navigate to if selected, usually the name of the symbol
+    pub focus_range: TextRange,
+
+    /// The name of the container of the symbol (e.g. the module or the type
+    /// of an `impl`)
+    pub container_name: Option<String>,
+
+    /// Optional detailed information
+    pub detail: Option<String>,
+}
+
+impl NavigationTarget {
+    /// Constructs a `NavigationTarget` for the specified function.
+    pub(crate) fn from_function(db: &AnalysisDatabase, function: mun_hir::Function) -> Self {
+        let src = function.source(db);
+        let kind = if function.data(db).has_self_param() {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        };
+        let detail = Some(function_detail(&src.value));
+        Self::from_named(
+            src.file_id,
+            &src.value,
+            kind,
+            container_name(db, function),
+            detail,
+        )
+    }
+
+    /// Constructs a `NavigationTarget` for the specified struct.
+    pub(crate) fn from_struct(db: &AnalysisDatabase, strukt: mun_hir::Struct) -> Self {
+        let src = strukt.source(db);
+        let container_name = module_container_name(db, strukt.module(db));
+        Self::from_named(
+            src.file_id,
+            &src.value,
+            SymbolKind::Struct,
+            container_name,
+            None,
+        )
+    }
+
+    /// Constructs a `NavigationTarget` for the specified type alias.
+    pub(crate) fn from_type_alias(db: &AnalysisDatabase, alias: mun_hir::TypeAlias) -> Self {
+        let src = alias.source(db);
+        let container_name = module_container_name(db, alias.module(db));
+        Self::from_named(
+            src.file_id,
+            &src.value,
+            SymbolKind::TypeAlias,
+            container_name,
+            None,
+        )
+    }
+
+    /// Constructs a `NavigationTarget` for the specified constant.
+    pub(crate) fn from_const(db: &AnalysisDatabase, konst: mun_hir::Const) -> Self {
+        let src = konst.source(db);
+        let container_name = module_container_name(db, konst.module(db));
+        Self::from_named(
+            src.file_id,
+            &src.value,
+            SymbolKind::Const,
+            container_name,
+            None,
+        )
+    }
+
+    fn from_named(
+        file_id: FileId,
+        node: &impl NameOwner,
+        kind: SymbolKind,
+        container_name: Option<String>,
+        detail: Option<String>,
+    ) -> Self {
+        let full_range = node.syntax().text_range();
+        let (name, focus_range) = match node.name() {
+            Some(name) => (name.text().to_string(), name.syntax().text_range()),
+            None => (String::from("<missing>"), full_range),
+        };
+        NavigationTarget {
+            file_id,
+            name,
+            kind,
+            full_range,
+            focus_range,
+            container_name,
+            detail,
+        }
+    }
+}
+
+/// Returns the name of the container of a function. This is either the type of
+/// the `impl` the function is defined in, or the module that contains the
+/// function.
+fn container_name(db: &AnalysisDatabase, function: mun_hir::Function) -> Option<String> {
+    match function.impl_container(db) {
+        Some(imp) => Some(imp.self_ty(db).display(db).to_string()),
+        None => module_container_name(db, function.module(db)),
+    }
+}
+
+/// Returns the name of a module to display as the container of a symbol, or
+/// `None` for the root module of a package.
+fn module_container_name(db: &AnalysisDatabase, module: Module) -> Option<String> {
+    let name = module
+        .path_to_root(db)
+        .into_iter()
+        .rev()
+        .filter_map(|module| module.name(db))
+        .collect::<Vec<_>>()
+        .join("::");
+    (!name.is_empty()).then_some(name)
+}
@@ -0,0 +1,329 @@
+//! Compiles a package and invokes one of its functions through the Mun
+//! runtime. This powers the [`RUN_FUNCTION_COMMAND`] that editors execute when
+//! the user clicks the code lens above a runnable function.
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use mun_compiler::{compute_source_relative_path, Config, DisplayColor, Driver};
+use mun_project::DEV_PROFILE;
+use mun_runtime::{Runtime, StructRef, Type};
+use parking_lot::Mutex;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{from_lsp, state::LanguageServerSnapshot};
+
+/// The name of the command that compiles a package and runs a function
+pub const RUN_FUNCTION_COMMAND: &str = "mun.runFunction";
+
+/// The maximum amount of time a function is allowed to run before the
+/// [`RUN_FUNCTION_COMMAND`] gives up on it
+const RUN_FUNCTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The arguments of the [`RUN_FUNCTION_COMMAND`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunFunctionArgs {
+    /// The document in which the function is defined
+    pub text_document: lsp_types::TextDocumentIdentifier,
+
+    /// The fully qualified name of the function to invoke
+    pub function_name: String,
+}
+
+/// The result of the [`RUN_FUNCTION_COMMAND`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RunFunctionResult {
+    /// The function returned successfully.
+    #[serde(rename_all = "camelCase")]
+    Value {
+        /// A textual representation of the returned value
+        value: String,
+
+        /// The name of the type of the returned value
+        return_type: String,
+    },
+
+    /// The package could not be compiled.
+    CompileError {
+        /// The diagnostics emitted by the compiler
+        message: String,
+    },
+
+    /// The function could not be invoked or panicked while it was running.
+    Panic {
+        /// A description of the failure
+        message: String,
+    },
+}
+
+/// Keeps track of the function that is currently being run, if any. A function
+/// that does not return cannot be stopped, so only a single function is run at
+/// a time.
+#[derive(Clone, Default)]
+pub(crate) struct RunningFunction(Arc<Mutex<Option<String>>>);
+
+impl RunningFunction {
+    /// Marks the function with the specified name as running until the
+    /// returned guard is dropped. Returns an error if another function is
+    /// still running.
+    fn start(&self, function_name: &str) -> anyhow::Result<RunningFunctionGuard> {
+        let mut running = self.0.lock();
+        if let Some(running) = running.as_ref() {
+            anyhow::bail!(
+                "cannot run function `{function_name}` while function `{running}` is still running"
+            );
+        }
+        *running = Some(function_name.to_owned());
+        Ok(RunningFunctionGuard(self.clone()))
+    }
+}
+
+/// Marks a function as running until it is dropped.
+struct RunningFunctionGuard(RunningFunction);
+
+impl Drop for RunningFunctionGuard {
+    fn drop(&mut self) {
+        *self.0 .0.lock() = None;
+    }
+}
+
+/// Compiles the package that contains the specified document and invokes the
+/// function described by `args`. The contents of documents that are open in
+/// the editor are used instead of the contents on disk.
+///
+/// The package is compiled with its `dev` profile into a temporary directory,
+/// so the build output of the package itself is left untouched. The function
+/// is invoked on a dedicated thread; if it does not return within
+/// [`RUN_FUNCTION_TIMEOUT`] an error is returned. Until the function returns,
+/// requests to run another function are refused.
+pub(crate) fn run_function(
+    snapshot: &LanguageServerSnapshot,
+    args: &RunFunctionArgs,
+) -> anyhow::Result<RunFunctionResult> {
+    let running_guard = snapshot.running_function.start(&args.function_name)?;
+
+    let path = from_lsp::abs_path(&args.text_document.uri)?;
+    let package = snapshot
+        .packages
+        .iter()
+        .find(|package| path.starts_with(package.source_directory()))
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not part of a package", path.display()))?;
+    let source_directory = package.source_directory();
+
+    let profile = package
+        .manifest()
+        .profile(DEV_PROFILE)
+        .ok_or_else(|| anyhow::anyhow!("profile `{}` is not defined", DEV_PROFILE))?;
+    let out_dir = tempfile::tempdir()?;
+    let config = Config {
+        out_dir: Some(out_dir.path().to_path_buf()),
+        ..Config::from_profile(profile, package.root())
+    };
+
+    let (_package, mut driver) = Driver::with_package_path(package.manifest_path(), config)?;
+
+    // Overwrite the contents on disk with the contents known to the language server
+    {
+        let vfs = snapshot.vfs.read();
+        for (file_id, file_path) in vfs.iter() {
+            let Ok(relative_path) = compute_source_relative_path(&source_directory, file_path)
+            else {
+                continue;
+            };
+            let Some(contents) = vfs.file_contents(file_id) else {
+                continue;
+            };
+            let contents = String::from_utf8_lossy(contents).into_owned();
+            if driver.get_file_id_for_path(&relative_path).is_some() {
+                driver.update_file(&relative_path, contents);
+            } else {
+                driver.add_file(&relative_path, contents);
+            }
+        }
+    }
+
+    if let Some(message) = driver.emit_diagnostics_to_string(DisplayColor::Disable)? {
+        return Ok(RunFunctionResult::CompileError { message });
+    }
+    driver.write_all_assemblies(false)?;
+
+    let relative_path = compute_source_relative_path(&source_directory, &path)?;
+    let file_id = driver
+        .get_file_id_for_path(&relative_path)
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a source file", path.display()))?;
+    let assembly_path = driver.assembly_output_path_from_file(file_id);
+
+    // The thread cannot be stopped if the function does not return, so it takes
+    // ownership of the temporary directory which is removed once it finishes, and
+    // of the guard that prevents other functions from running in the meantime.
+    let (sender, receiver) = crossbeam_channel::bounded(1);
+    let function_name = args.function_name.clone();
+    std::thread::Builder::new()
+        .name(String::from("run function"))
+        .spawn(move || {
+            let result = run_assembly(assembly_path, &function_name);
+            drop(out_dir);
+            drop(running_guard);
+            // The receiver is gone if the function timed out
+            let _ = sender.send(result);
+        })?;
+
+    match receiver.recv_timeout(RUN_FUNCTION_TIMEOUT) {
+        Ok(result) => result,
+        Err(crossbeam_channel::RecvTimeoutError::Timeout) => Err(anyhow::anyhow!(
+            "function `{}` did not return within {} seconds; no other function can be run until it returns",
+            args.function_name,
+            RUN_FUNCTION_TIMEOUT.as_secs()
+        )),
+        Err(crossbeam_channel::RecvTimeoutError::Disconnected) => Err(anyhow::anyhow!(
+            "function `{}` stopped unexpectedly",
+            args.function_name
+        )),
+    }
+}
+
+/// Loads the assembly at the specified path and invokes the function with the
+/// specified name.
+fn run_assembly(assembly_path: PathBuf, function_name: &str) -> anyhow::Result<RunFunctionResult> {
+    // Safety: we assume that the library we just compiled is safe
+    let runtime = unsafe { Runtime::builder(assembly_path).finish() }?;
+
+    let result = catch_unwind(AssertUnwindSafe(|| invoke(&runtime, function_name))).unwrap_or_else(
+        |payload| {
+            Err(payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("the function panicked")))
+        },
+    );
+
+    Ok(match result {
+        Ok((value, return_type)) => RunFunctionResult::Value { value, return_type },
+        Err(message) => RunFunctionResult::Panic { message },
+    })
+}
+
+/// Invokes the function with the specified name without arguments. Returns the
+/// formatted return value and the name of its type.
+fn invoke(runtime: &Runtime, function_name: &str) -> Result<(String, String), String> {
+    let definition = runtime
+        .get_function_definition(function_name)
+        .ok_or_else(|| format!("failed to obtain function '{function_name}'"))?;
+    let return_type = definition.prototype.signature.return_type.clone();
+
+    macro_rules! invoke_primitive {
+        ($($ty:ty),+) => {
+            $(
+                if return_type.equals::<$ty>() {
+                    let value: $ty = runtime
+                        .invoke(function_name, ())
+                        .map_err(|e| e.to_string())?;
+                    return Ok((format!("{value:?}"), return_type.name().to_owned()));
+                }
+            )+
+        };
+    }
+    invoke_primitive!(
+        bool,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        f32,
+        f64,
+        ()
+    );
+
+    if return_type.is_struct() {
+        let value: StructRef<'_> = runtime
+            .invoke(function_name, ())
+            .map_err(|e| e.to_string())?;
+        return Ok((format_struct(&value), return_type.name().to_owned()));
+    }
+
+    Err(format!(
+        "functions that return a `{}` cannot be run",
+        return_type.name()
+    ))
+}
+
+/// Formats the fields of a struct, e.g. `Foo { a: 1, b: 2.0 }`.
+fn format_struct(value: &StructRef<'_>) -> String {
+    let ty = value.type_info();
+    let fields = ty
+        .as_struct()
+        .map(|s| {
+            s.fields()
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}: {}",
+                        field.name(),
+                        format_field(value, field.name(), &field.ty())
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if fields.is_empty() {
+        ty.name().to_owned()
+    } else {
+        format!("{} {{ {} }}", ty.name(), fields.join(", "))
+    }
+}
+
+/// Formats the value of a single field of a struct.
+fn format_field(value: &StructRef<'_>, field_name: &str, ty: &Type) -> String {
+    macro_rules! format_primitive {
+        ($($ty:ty),+) => {
+            $(
+                if ty.equals::<$ty>() {
+                    return value
+                        .get::<$ty>(field_name)
+                        .map_or_else(|e| e, |value| format!("{value:?}"));
+                }
+            )+
+        };
+    }
+    format_primitive!(bool, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+
+    if ty.is_struct() {
+        return value
+            .get::<StructRef<'_>>(field_name)
+            .map_or_else(|e| e, |value| format_struct(&value));
+    }
+
+    format!("<{}>", ty.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RunningFunction;
+
+    #[test]
+    fn refuses_to_run_while_another_function_runs() {
+        let running_function = RunningFunction::default();
+        let guard = running_function.start("foo").unwrap();
+        assert_eq!(
+            running_function.start("bar").err().unwrap().to_string(),
+            "cannot run function `bar` while function `foo` is still running"
+        );
+
+        drop(guard);
+        assert!(running_function.start("bar").is_ok());
+    }
+}
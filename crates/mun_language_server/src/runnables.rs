@@ -0,0 +1,97 @@
+//! Finds the functions in a file that can be run from the editor. A function
+//! is runnable if it is exported from its assembly and can be invoked without
+//! any arguments.
+
+use mun_hir::{HasSource, HasVisibility, Module, ModuleDef, Visibility};
+use mun_hir_input::FileId;
+use mun_syntax::{ast::NameOwner, AstNode, TextRange};
+
+use crate::db::AnalysisDatabase;
+
+/// A function that can be invoked through the runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Runnable {
+    /// The range of the function definition
+    pub range: TextRange,
+
+    /// The fully qualified name with which the function can be invoked through
+    /// the runtime
+    pub function_name: String,
+}
+
+/// Returns all the runnable functions defined in the specified file.
+pub(crate) fn runnables(db: &AnalysisDatabase, file_id: FileId) -> Vec<Runnable> {
+    let Some(module) = Module::from_file(db, file_id) else {
+        return Vec::new();
+    };
+
+    module
+        .declarations(db)
+        .into_iter()
+        .filter_map(|def| match def {
+            ModuleDef::Function(function) => Some(function),
+            _ => None,
+        })
+        .filter(|function| {
+            function.visibility(db) == Visibility::Public
+                && !function.is_extern(db)
+                && function.params(db).is_empty()
+        })
+        .map(|function| {
+            let src = function.source(db);
+            let range = src.value.name().map_or_else(
+                || src.value.syntax().text_range(),
+                |name| name.syntax().text_range(),
+            );
+            Runnable {
+                range,
+                function_name: function.full_name(db),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::runnables;
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase};
+
+    fn check(fixture: &str) -> String {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+
+        change_fixture
+            .files
+            .iter()
+            .flat_map(|&file_id| runnables(&db, file_id))
+            .map(|runnable| format!("{} {:?}", runnable.function_name, runnable.range))
+            .join("\n")
+    }
+
+    #[test]
+    fn test_runnables() {
+        insta::assert_snapshot!(check(
+            r#"
+        //- /mod.mun
+        pub fn main() -> i32 { 0 }
+        pub fn add(a: i32, b: i32) -> i32 { a + b }
+        fn private() {}
+        extern fn external();
+
+        struct Foo;
+        impl Foo {
+            pub fn new() -> Self { Foo }
+        }
+
+        //- /foo/bar.mun
+        pub fn baz() -> bool { true }
+        "#
+        ), @r"
+            main 7..11
+            foo::bar::baz 7..10
+            ");
+    }
+}
@@ -0,0 +1,136 @@
+//! Computes the ranges that an editor selects when the user repeatedly expands
+//! the selection, starting from a cursor position. The ranges are computed
+//! purely from the syntax tree by walking up the ancestors of the token at the
+//! cursor.
+
+use mun_syntax::{
+    AstNode, SourceFile, SyntaxElement, SyntaxKind::WHITESPACE, SyntaxNode, TextRange, TextSize,
+};
+
+/// Returns the ranges that enclose the specified `offset`, ordered from the
+/// innermost range to the range of the entire file. Every range strictly
+/// contains the range that precedes it.
+pub(crate) fn selection_range(file: &SourceFile, offset: TextSize) -> Vec<TextRange> {
+    let root = file.syntax();
+    let Some(token) = root
+        .token_at_offset(offset)
+        .max_by_key(|token| token.kind() != WHITESPACE)
+    else {
+        return vec![root.text_range()];
+    };
+
+    let mut ranges = vec![token.text_range()];
+    for node in token.parent_ancestors() {
+        let range = trimmed_range(&node);
+        if ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    }
+    ranges
+}
+
+/// Returns the range of the node without any leading or trailing whitespace.
+fn trimmed_range(node: &SyntaxNode) -> TextRange {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|token| token.kind() != WHITESPACE);
+    let Some(first) = tokens.next() else {
+        return node.text_range();
+    };
+    let end = tokens
+        .last()
+        .unwrap_or_else(|| first.clone())
+        .text_range()
+        .end();
+    TextRange::new(first.text_range().start(), end)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use mun_hir::AstDatabase;
+    use mun_hir_input::SourceDatabase;
+    use mun_syntax::TextRange;
+
+    use super::selection_range;
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase};
+
+    fn selections(fixture: &str) -> String {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+        let (file_id, range_or_offset) = change_fixture
+            .file_position
+            .expect("expected a marker ($0)");
+        let offset = TextRange::from(range_or_offset).start();
+
+        let text = db.file_text(file_id);
+        let file = db.parse(file_id).tree();
+        selection_range(&file, offset)
+            .into_iter()
+            .map(|range| text[range].to_string())
+            .join("\n---\n")
+    }
+
+    #[test]
+    fn test_expression() {
+        insta::assert_snapshot!(selections(
+            r#"
+        fn foo(a: i32) -> i32 {
+            let b = a * (a +$0 1);
+            b
+        }
+        "#
+        ), @r"
+            +
+            ---
+            a + 1
+            ---
+            (a + 1)
+            ---
+            a * (a + 1)
+            ---
+            let b = a * (a + 1);
+            ---
+            {
+                let b = a * (a + 1);
+                b
+            }
+            ---
+            fn foo(a: i32) -> i32 {
+                let b = a * (a + 1);
+                b
+            }
+            ");
+    }
+
+    #[test]
+    fn test_identifier_in_impl() {
+        insta::assert_snapshot!(selections(
+            r#"
+        struct Foo;
+        impl Foo {
+            fn b$0ar(self) {}
+        }
+        "#
+        ), @r"
+            bar
+            ---
+            fn bar(self) {}
+            ---
+            {
+                fn bar(self) {}
+            }
+            ---
+            impl Foo {
+                fn bar(self) {}
+            }
+            ---
+            struct Foo;
+            impl Foo {
+                fn bar(self) {}
+            }
+            ");
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
     analysis::{Analysis, AnalysisSnapshot},
     change::AnalysisChange,
     config::Config,
+    run::RunningFunction,
     state::utils::Progress,
     to_json, to_lsp,
 };
@@ -80,6 +81,9 @@ pub(crate) struct LanguageServerState {
     /// All the packages known to the server
     pub packages: Arc<Vec<mun_project::Package>>,
 
+    /// The function that is currently run from a code lens, if any
+    pub running_function: RunningFunction,
+
     /// True if the client requested that we shut down
     pub shutdown_requested: bool,
 }
@@ -94,6 +98,9 @@ pub(crate) struct LanguageServerSnapshot {
 
     /// All the packages known to the server
     pub packages: Arc<Vec<mun_project::Package>>,
+
+    /// The function that is currently run from a code lens, if any
+    pub running_function: RunningFunction,
 }
 
 impl LanguageServerState {
@@ -131,6 +138,7 @@ impl LanguageServerState {
             task_receiver,
             analysis,
             packages: Arc::new(Vec::new()),
+            running_function: RunningFunction::default(),
             shutdown_requested: false,
         }
     }
@@ -259,45 +267,10 @@ fn handle_diagnostics(state: LanguageServerSnapshot, sender: Sender<Task>) -> an
             let uri = to_lsp::url(&state, file)?;
             let diagnostics = state.analysis.diagnostics(file)?;
 
-            let diagnostics = {
-                let mut lsp_diagnostics = Vec::with_capacity(diagnostics.len());
-                for d in diagnostics {
-                    lsp_diagnostics.push(lsp_types::Diagnostic {
-                        range: to_lsp::range(d.range, &line_index),
-                        severity: Some(lsp_types::DiagnosticSeverity::ERROR),
-                        code: None,
-                        code_description: None,
-                        source: Some("mun".to_string()),
-                        message: d.message,
-                        related_information: {
-                            let mut annotations =
-                                Vec::with_capacity(d.additional_annotations.len());
-                            for annotation in d.additional_annotations {
-                                annotations.push(lsp_types::DiagnosticRelatedInformation {
-                                    location: lsp_types::Location {
-                                        uri: to_lsp::url(&state, annotation.range.file_id)?,
-                                        range: to_lsp::range(
-                                            annotation.range.value,
-                                            &*state
-                                                .analysis
-                                                .file_line_index(annotation.range.file_id)?,
-                                        ),
-                                    },
-                                    message: annotation.message,
-                                });
-                            }
-                            if annotations.is_empty() {
-                                None
-                            } else {
-                                Some(annotations)
-                            }
-                        },
-                        tags: None,
-                        data: None,
-                    });
-                }
-                lsp_diagnostics
-            };
+            let diagnostics = diagnostics
+                .into_iter()
+                .map(|d| to_lsp::diagnostic(&state, d, &line_index))
+                .collect::<anyhow::Result<Vec<_>>>()?;
 
             sender
                 .send(Task::Notify(lsp_server::Notification {
@@ -322,6 +295,7 @@ impl LanguageServerState {
             vfs: self.vfs.clone(),
             analysis: self.analysis.snapshot(),
             packages: self.packages.clone(),
+            running_function: self.running_function.clone(),
         }
     }
 
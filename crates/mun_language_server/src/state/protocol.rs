@@ -96,6 +96,22 @@ impl LanguageServerState {
             })?
             .on::<lsp_types::request::DocumentSymbolRequest>(handlers::handle_document_symbol)?
             .on::<lsp_types::request::Completion>(handlers::handle_completion)?
+            .on::<lsp_types::request::CodeActionRequest>(handlers::handle_code_action)?
+            .on::<lsp_types::request::Formatting>(handlers::handle_formatting)?
+            .on::<lsp_types::request::FoldingRangeRequest>(handlers::handle_folding_range)?
+            .on::<lsp_types::request::SelectionRangeRequest>(handlers::handle_selection_range)?
+            .on::<lsp_types::request::CodeLensRequest>(handlers::handle_code_lens)?
+            .on::<lsp_types::request::ExecuteCommand>(handlers::handle_execute_command)?
+            .on::<lsp_types::request::WorkspaceSymbolRequest>(handlers::handle_workspace_symbol)?
+            .on::<lsp_types::request::CallHierarchyPrepare>(
+                handlers::handle_call_hierarchy_prepare,
+            )?
+            .on::<lsp_types::request::CallHierarchyIncomingCalls>(
+                handlers::handle_call_hierarchy_incoming,
+            )?
+            .on::<lsp_types::request::CallHierarchyOutgoingCalls>(
+                handlers::handle_call_hierarchy_outgoing,
+            )?
             .finish();
 
         Ok(())
@@ -1,9 +1,10 @@
 use std::{
     convert::{TryFrom, TryInto},
+    path::Path,
     sync::Arc,
 };
 
-use mun_hir_input::{FileId, PackageSet, SourceRoot, SourceRootId};
+use mun_hir_input::{FileId, LintLevel, PackageSet, SourceRoot, SourceRootId};
 use mun_paths::{AbsPathBuf, RelativePath};
 
 use super::LanguageServerState;
@@ -19,20 +20,21 @@ impl LanguageServerState {
             .clone()
             .into_iter()
             .flatten()
-            .filter_map(
-                |project| match mun_project::Package::from_file(project.path) {
-                    Ok(package) => Some(package),
-                    Err(err) => {
-                        self.show_message(
-                            lsp_types::MessageType::ERROR,
-                            format!("mun failed to load package: {err:#}"),
-                        );
-                        None
-                    }
-                },
-            )
+            .flat_map(|project| match load_project(&project.path) {
+                Ok(packages) => packages,
+                Err(err) => {
+                    self.show_message(
+                        lsp_types::MessageType::ERROR,
+                        format!("mun failed to load package: {err:#}"),
+                    );
+                    Vec::new()
+                }
+            })
             .collect::<Vec<_>>();
 
+        // Load the dependencies of all packages
+        let (packages, dependencies) = self.load_dependencies(packages);
+
         // If these packages are the same as the ones we already had, there is little to
         // do.
         if *self.packages == packages {
@@ -96,8 +98,22 @@ impl LanguageServerState {
 
         // Create the set of packages
         let mut package_set = PackageSet::default();
-        for (idx, _package) in packages.iter().enumerate() {
-            package_set.add_package(SourceRootId(idx as u32));
+        let package_ids = (0..packages.len())
+            .map(|idx| package_set.add_package(SourceRootId(idx as u32)))
+            .collect::<Vec<_>>();
+        for (from, to, name) in dependencies {
+            package_set.add_dependency(package_ids[from], package_ids[to], name);
+        }
+        for (package, &package_id) in packages.iter().zip(&package_ids) {
+            package_set.set_name(package_id, package.name());
+            for (lint, level) in package.manifest().lints() {
+                let level = match level {
+                    mun_project::LintLevel::Allow => LintLevel::Allow,
+                    mun_project::LintLevel::Warn => LintLevel::Warn,
+                    mun_project::LintLevel::Deny => LintLevel::Deny,
+                };
+                package_set.set_lint_level(package_id, lint.clone(), level);
+            }
         }
         change.set_packages(package_set);
 
@@ -109,6 +125,64 @@ impl LanguageServerState {
         self.analysis.apply_change(change);
     }
 
+    /// Adds the dependencies of the specified packages, and recursively their
+    /// dependencies, to the set of packages. Returns the packages together
+    /// with the dependency edges between them as `(from, to, name)` indices
+    /// into the returned packages.
+    fn load_dependencies(
+        &mut self,
+        mut packages: Vec<mun_project::Package>,
+    ) -> (Vec<mun_project::Package>, Vec<(usize, usize, String)>) {
+        let mut manifest_paths = packages
+            .iter()
+            .map(|package| {
+                package
+                    .manifest_path()
+                    .canonicalize()
+                    .unwrap_or_else(|_err| package.manifest_path().to_path_buf())
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = Vec::new();
+        let mut idx = 0;
+        while idx < packages.len() {
+            match packages[idx].dependencies() {
+                Ok(dependencies) => {
+                    for (name, dependency) in dependencies {
+                        let to = manifest_paths
+                            .iter()
+                            .position(|path| path == dependency.manifest_path())
+                            .unwrap_or_else(|| {
+                                manifest_paths.push(dependency.manifest_path().to_path_buf());
+                                packages.push(dependency);
+                                packages.len() - 1
+                            });
+                        if depends_on(&edges, to, idx) {
+                            self.show_message(
+                                lsp_types::MessageType::ERROR,
+                                format!(
+                                    "cyclic dependency detected: package `{}` depends on `{}`, which in turn depends on `{}`",
+                                    packages[idx].name(),
+                                    packages[to].name(),
+                                    packages[idx].name()
+                                ),
+                            );
+                        } else {
+                            edges.push((idx, to, name));
+                        }
+                    }
+                }
+                Err(err) => self.show_message(
+                    lsp_types::MessageType::ERROR,
+                    format!("mun failed to load dependencies: {err:#}"),
+                ),
+            }
+            idx += 1;
+        }
+
+        (packages, edges)
+    }
+
     /// Recomputes all the source roots based on the `packages`
     pub(crate) fn recompute_source_roots(&self) -> Vec<SourceRoot> {
         // Iterate over all sources and see to which package they belong
@@ -146,3 +220,37 @@ impl LanguageServerState {
         source_roots
     }
 }
+
+/// Loads the packages described by the manifest at the specified path. If the
+/// manifest describes a workspace, all its members are loaded.
+fn load_project(manifest_path: &Path) -> anyhow::Result<Vec<mun_project::Package>> {
+    if mun_project::Workspace::is_workspace_manifest(manifest_path)? {
+        Ok(mun_project::Workspace::from_file(manifest_path)?
+            .members()
+            .to_vec())
+    } else {
+        Ok(vec![mun_project::Package::from_file(manifest_path)?])
+    }
+}
+
+/// Returns true if the package `from` directly or indirectly depends on the
+/// package `to` given the dependency `edges`.
+fn depends_on(edges: &[(usize, usize, String)], from: usize, to: usize) -> bool {
+    let mut visited = vec![from];
+    let mut stack = vec![from];
+    while let Some(package) = stack.pop() {
+        if package == to {
+            return true;
+        }
+        for &(_, dependency, _) in edges
+            .iter()
+            .filter(|(dependent, _, _)| *dependent == package)
+        {
+            if !visited.contains(&dependency) {
+                visited.push(dependency);
+                stack.push(dependency);
+            }
+        }
+    }
+    false
+}
@@ -1,14 +1,22 @@
 use std::{
+    collections::HashMap,
     path::{Component, Path, Prefix},
     str::FromStr,
 };
 
 use lsp_types::Url;
+use mun_diagnostics::SourceChange;
+use mun_hir::diagnostics::Severity;
 use mun_hir_input::{FileId, LineIndex};
 use mun_syntax::{TextRange, TextSize};
+use ra_ap_text_edit::{Indel, TextEdit};
 
 use crate::{
+    call_hierarchy::CallItem,
     completion::{CompletionItem, CompletionItemKind},
+    diagnostics::{Diagnostic, Fix},
+    folding_ranges::{Fold, FoldKind},
+    navigation_target::NavigationTarget,
     state::LanguageServerSnapshot,
     symbol_kind::SymbolKind,
 };
@@ -70,9 +78,107 @@ pub(crate) fn position(range: TextSize, line_index: &LineIndex) -> lsp_types::Po
     }
 }
 
+/// Converts an insertion or deletion of text to an LSP `TextEdit`.
+pub(crate) fn text_edit(indel: &Indel, line_index: &LineIndex) -> lsp_types::TextEdit {
+    lsp_types::TextEdit {
+        range: range(indel.delete, line_index),
+        new_text: indel.insert.clone(),
+    }
+}
+
+/// Converts a `TextEdit` to a list of LSP `TextEdit`s.
+pub(crate) fn text_edit_vec(edit: &TextEdit, line_index: &LineIndex) -> Vec<lsp_types::TextEdit> {
+    edit.iter()
+        .map(|indel| text_edit(indel, line_index))
+        .collect()
+}
+
+/// Converts a `SourceChange` to an LSP `WorkspaceEdit`.
+pub(crate) fn workspace_edit(
+    snapshot: &LanguageServerSnapshot,
+    source_change: &SourceChange,
+) -> anyhow::Result<lsp_types::WorkspaceEdit> {
+    let mut changes = HashMap::new();
+    for file_edit in &source_change.source_file_edits {
+        let line_index = snapshot.analysis.file_line_index(file_edit.file_id)?;
+        let edits: &mut Vec<_> = changes
+            .entry(url(snapshot, file_edit.file_id)?)
+            .or_default();
+        edits.extend(text_edit_vec(&file_edit.edit, &line_index));
+    }
+    Ok(lsp_types::WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    })
+}
+
+/// Converts a diagnostic to an LSP `Diagnostic`.
+pub(crate) fn diagnostic(
+    snapshot: &LanguageServerSnapshot,
+    diagnostic: Diagnostic,
+    line_index: &LineIndex,
+) -> anyhow::Result<lsp_types::Diagnostic> {
+    let mut related_information = Vec::with_capacity(diagnostic.additional_annotations.len());
+    for annotation in diagnostic.additional_annotations {
+        related_information.push(lsp_types::DiagnosticRelatedInformation {
+            location: lsp_types::Location {
+                uri: url(snapshot, annotation.range.file_id)?,
+                range: range(
+                    annotation.range.value,
+                    &*snapshot
+                        .analysis
+                        .file_line_index(annotation.range.file_id)?,
+                ),
+            },
+            message: annotation.message,
+        });
+    }
+
+    Ok(lsp_types::Diagnostic {
+        range: range(diagnostic.range, line_index),
+        severity: Some(match diagnostic.severity {
+            Severity::Error => lsp_types::DiagnosticSeverity::ERROR,
+            Severity::Warning => lsp_types::DiagnosticSeverity::WARNING,
+        }),
+        code: diagnostic
+            .code
+            .map(|code| lsp_types::NumberOrString::String(code.to_string())),
+        code_description: None,
+        source: Some("mun".to_string()),
+        message: diagnostic.message,
+        related_information: if related_information.is_empty() {
+            None
+        } else {
+            Some(related_information)
+        },
+        tags: None,
+        data: None,
+    })
+}
+
+/// Converts a `Fix` to an LSP quick fix `CodeAction` that resolves the
+/// specified diagnostic.
+pub(crate) fn code_action(
+    snapshot: &LanguageServerSnapshot,
+    fix: &Fix,
+    diagnostic: lsp_types::Diagnostic,
+) -> anyhow::Result<lsp_types::CodeAction> {
+    Ok(lsp_types::CodeAction {
+        title: fix.label.clone(),
+        kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(workspace_edit(snapshot, &fix.source_change)?),
+        command: None,
+        is_preferred: Some(true),
+        disabled: None,
+        data: None,
+    })
+}
+
 /// Converts a symbol kind from this crate to one for the LSP protocol.
 pub(crate) fn symbol_kind(symbol_kind: SymbolKind) -> lsp_types::SymbolKind {
     match symbol_kind {
+        SymbolKind::Const => lsp_types::SymbolKind::CONSTANT,
         SymbolKind::Function => lsp_types::SymbolKind::FUNCTION,
         SymbolKind::Struct => lsp_types::SymbolKind::STRUCT,
         SymbolKind::TypeAlias | SymbolKind::SelfType => lsp_types::SymbolKind::TYPE_PARAMETER,
@@ -92,6 +198,120 @@ pub(crate) fn url(snapshot: &LanguageServerSnapshot, file_id: FileId) -> anyhow:
     Ok(url)
 }
 
+/// Converts a `Fold` to an LSP `FoldingRange`. Only the lines of the range are
+/// specified. The closing delimiter of a block is kept visible when the block
+/// is folded.
+pub(crate) fn folding_range(fold: &Fold, line_index: &LineIndex) -> lsp_types::FoldingRange {
+    let range = range(fold.range, line_index);
+    let (end_line, kind) = match fold.kind {
+        FoldKind::Comment => (range.end.line, Some(lsp_types::FoldingRangeKind::Comment)),
+        FoldKind::Imports => (range.end.line, Some(lsp_types::FoldingRangeKind::Imports)),
+        FoldKind::Block => (range.end.line.saturating_sub(1).max(range.start.line), None),
+    };
+    lsp_types::FoldingRange {
+        start_line: range.start.line,
+        start_character: None,
+        end_line,
+        end_character: None,
+        kind,
+        collapsed_text: None,
+    }
+}
+
+/// Converts a list of ranges, ordered from the innermost to the outermost
+/// range, to a nested LSP `SelectionRange`.
+pub(crate) fn selection_range(
+    ranges: &[TextRange],
+    line_index: &LineIndex,
+) -> Option<lsp_types::SelectionRange> {
+    ranges.iter().rev().fold(None, |parent, &text_range| {
+        Some(lsp_types::SelectionRange {
+            range: range(text_range, line_index),
+            parent: parent.map(Box::new),
+        })
+    })
+}
+
+/// Returns the LSP `Location` of a range in a file.
+pub(crate) fn location(
+    snapshot: &LanguageServerSnapshot,
+    file_id: FileId,
+    range: TextRange,
+) -> anyhow::Result<lsp_types::Location> {
+    let line_index = snapshot.analysis.file_line_index(file_id)?;
+    Ok(lsp_types::Location {
+        uri: url(snapshot, file_id)?,
+        range: self::range(range, &line_index),
+    })
+}
+
+/// Converts a `NavigationTarget` to an LSP `SymbolInformation`.
+pub(crate) fn symbol_information(
+    snapshot: &LanguageServerSnapshot,
+    target: NavigationTarget,
+) -> anyhow::Result<lsp_types::SymbolInformation> {
+    #[allow(deprecated)]
+    Ok(lsp_types::SymbolInformation {
+        location: location(snapshot, target.file_id, target.focus_range)?,
+        name: target.name,
+        kind: symbol_kind(target.kind),
+        tags: None,
+        deprecated: None,
+        container_name: target.container_name,
+    })
+}
+
+/// Converts a `NavigationTarget` to an LSP `CallHierarchyItem`.
+pub(crate) fn call_hierarchy_item(
+    snapshot: &LanguageServerSnapshot,
+    target: NavigationTarget,
+) -> anyhow::Result<lsp_types::CallHierarchyItem> {
+    let line_index = snapshot.analysis.file_line_index(target.file_id)?;
+    Ok(lsp_types::CallHierarchyItem {
+        name: target.name,
+        kind: symbol_kind(target.kind),
+        tags: None,
+        detail: target.detail,
+        uri: url(snapshot, target.file_id)?,
+        range: range(target.full_range, &line_index),
+        selection_range: range(target.focus_range, &line_index),
+        data: None,
+    })
+}
+
+/// Converts a `CallItem` to an LSP `CallHierarchyIncomingCall`.
+pub(crate) fn call_hierarchy_incoming_call(
+    snapshot: &LanguageServerSnapshot,
+    call: CallItem,
+) -> anyhow::Result<lsp_types::CallHierarchyIncomingCall> {
+    let line_index = snapshot.analysis.file_line_index(call.target.file_id)?;
+    Ok(lsp_types::CallHierarchyIncomingCall {
+        from_ranges: call
+            .ranges
+            .into_iter()
+            .map(|it| range(it, &line_index))
+            .collect(),
+        from: call_hierarchy_item(snapshot, call.target)?,
+    })
+}
+
+/// Converts a `CallItem` to an LSP `CallHierarchyOutgoingCall`. The
+/// `line_index` must be the index of the file that contains the caller.
+pub(crate) fn call_hierarchy_outgoing_call(
+    snapshot: &LanguageServerSnapshot,
+    call: CallItem,
+    line_index: &LineIndex,
+) -> anyhow::Result<lsp_types::CallHierarchyOutgoingCall> {
+    Ok(lsp_types::CallHierarchyOutgoingCall {
+        from_ranges: call
+            .ranges
+            .into_iter()
+            .map(|it| range(it, line_index))
+            .collect(),
+        to: call_hierarchy_item(snapshot, call.target)?,
+    })
+}
+
 /// Converts from a list of our `CompletionItem` to an LSP `CompletionItem`
 pub(crate) fn completion_items(
     completion_items: Vec<CompletionItem>,
@@ -151,6 +371,7 @@ pub(crate) fn completion_item_kind(
         CompletionItemKind::Snippet => lsp_types::CompletionItemKind::SNIPPET,
         CompletionItemKind::UnresolvedReference => lsp_types::CompletionItemKind::REFERENCE,
         CompletionItemKind::SymbolKind(symbol) => match symbol {
+            SymbolKind::Const => lsp_types::CompletionItemKind::CONSTANT,
             SymbolKind::Field => lsp_types::CompletionItemKind::FIELD,
             SymbolKind::Function => lsp_types::CompletionItemKind::FUNCTION,
             SymbolKind::Local => lsp_types::CompletionItemKind::VARIABLE,
@@ -0,0 +1,110 @@
+//! Provides a fuzzy search over the symbols defined in all packages of the
+//! workspace. The [`workspace_symbols`] function is the main entry point.
+
+use mun_hir::{AssocItem, ModuleDef, Package};
+
+use crate::{db::AnalysisDatabase, navigation_target::NavigationTarget};
+
+/// The maximum number of symbols returned by a single query
+const MAX_SYMBOLS: usize = 128;
+
+/// Returns all functions, structs, type aliases and constants in the workspace
+/// whose name fuzzy matches the specified `query`. The results are sorted such
+/// that the best matches come first.
+pub(crate) fn workspace_symbols(db: &AnalysisDatabase, query: &str) -> Vec<NavigationTarget> {
+    let mut symbols = Vec::new();
+    for package in Package::all(db) {
+        for module in package.modules(db) {
+            for def in module.declarations(db) {
+                let symbol = match def {
+                    ModuleDef::Function(function) => NavigationTarget::from_function(db, function),
+                    ModuleDef::Struct(strukt) => NavigationTarget::from_struct(db, strukt),
+                    ModuleDef::TypeAlias(alias) => NavigationTarget::from_type_alias(db, alias),
+                    ModuleDef::Const(konst) => NavigationTarget::from_const(db, konst),
+                    ModuleDef::Module(_) | ModuleDef::PrimitiveType(_) => continue,
+                };
+                symbols.push(symbol);
+            }
+
+            for imp in module.impls(db) {
+                for item in imp.items(db) {
+                    let AssocItem::Function(function) = item;
+                    symbols.push(NavigationTarget::from_function(db, function));
+                }
+            }
+        }
+    }
+
+    let query = query.to_lowercase();
+    symbols.retain(|symbol| fuzzy_match(&query, &symbol.name));
+    symbols.sort_by_cached_key(|symbol| {
+        let name = symbol.name.to_lowercase();
+        (name != query, !name.starts_with(&query), name.len(), name)
+    });
+    symbols.truncate(MAX_SYMBOLS);
+    symbols
+}
+
+/// Returns true if all characters of the lowercase `query` appear in `name` in
+/// the same order, ignoring case.
+fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::workspace_symbols;
+    use crate::{change_fixture::ChangeFixture, db::AnalysisDatabase};
+
+    fn symbols(fixture: &str, query: &str) -> String {
+        let change_fixture = ChangeFixture::parse(fixture);
+        let mut db = AnalysisDatabase::default();
+        db.apply_change(change_fixture.change);
+
+        workspace_symbols(&db, query)
+            .into_iter()
+            .map(|symbol| {
+                format!(
+                    "{:?} {} ({})",
+                    symbol.kind,
+                    symbol.name,
+                    symbol.container_name.as_deref().unwrap_or("<root>")
+                )
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn test_workspace_symbols() {
+        let fixture = r#"
+        //- /mod.mun
+        struct Foo;
+        type FooAlias = Foo;
+        fn foo_bar() {}
+
+        //- /baz.mun
+        struct Baz;
+        impl Baz {
+            fn new_foo() -> Self { Baz }
+            fn bar(self) {}
+        }
+        "#;
+
+        insta::assert_snapshot!(symbols(fixture, "foo"), @r"
+            Struct Foo (<root>)
+            Function foo_bar (<root>)
+            TypeAlias FooAlias (<root>)
+            Function new_foo (Baz)
+            ");
+        insta::assert_snapshot!(symbols(fixture, "fb"), @"Function foo_bar (<root>)");
+        insta::assert_snapshot!(symbols(fixture, "BAR"), @r"
+            Method bar (Baz)
+            Function foo_bar (<root>)
+            ");
+    }
+}
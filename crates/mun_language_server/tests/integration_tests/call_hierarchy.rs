@@ -0,0 +1,55 @@
+use lsp_types::{
+    CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, PartialResultParams, Position,
+    TextDocumentPositionParams, WorkDoneProgressParams,
+};
+
+use crate::Project;
+
+#[test]
+fn test_outgoing_calls() {
+    let server = Project::with_fixture(
+        r#"
+    //- /mun.toml
+    [package]
+    name = "foo"
+    version = "0.0.0"
+
+    //- /src/mod.mun
+    fn foo() -> i32 { 1 }
+    fn main() -> i32 { foo() + foo() }
+    "#,
+    )
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let items = server
+        .send_request::<lsp_types::request::CallHierarchyPrepare>(CallHierarchyPrepareParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: server.doc_id("src/mod.mun"),
+                position: Position::new(1, 4),
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .expect("expected a call hierarchy item");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "main");
+
+    let calls = server
+        .send_request::<lsp_types::request::CallHierarchyOutgoingCalls>(
+            CallHierarchyOutgoingCallsParams {
+                item: items[0].clone(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .expect("expected outgoing calls");
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].to.name, "foo");
+    assert_eq!(
+        calls[0].from_ranges,
+        vec![
+            lsp_types::Range::new(Position::new(1, 19), Position::new(1, 22)),
+            lsp_types::Range::new(Position::new(1, 27), Position::new(1, 30)),
+        ]
+    );
+}
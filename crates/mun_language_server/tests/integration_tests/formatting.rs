@@ -0,0 +1,40 @@
+use lsp_types::{FormattingOptions, WorkDoneProgressParams};
+
+use crate::Project;
+
+#[test]
+fn test_formatting() {
+    let server = Project::with_fixture(
+        r#"
+    //- /mun.toml
+    [package]
+    name = "foo"
+    version = "0.0.0"
+
+    //- /src/mod.mun
+    struct Foo{a:i32}
+    fn main()->i32{let foo=Foo{a:1}; foo.a}
+    "#,
+    )
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let edits = server
+        .send_request::<lsp_types::request::Formatting>(lsp_types::DocumentFormattingParams {
+            text_document: server.doc_id("src/mod.mun"),
+            options: FormattingOptions::default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .expect("expected formatting edits");
+
+    assert_eq!(edits.len(), 1);
+    insta::assert_snapshot!(edits[0].new_text, @r"
+        struct Foo {
+            a: i32,
+        }
+        fn main() -> i32 {
+            let foo = Foo { a: 1 };
+            foo.a
+        }
+        ");
+}
@@ -1,5 +1,8 @@
+mod call_hierarchy;
 mod document_symbols;
+mod formatting;
 mod initialization;
+mod run;
 mod support;
 
 pub use support::Project;
@@ -0,0 +1,63 @@
+use lsp_types::{
+    CodeLensParams, ExecuteCommandParams, PartialResultParams, WorkDoneProgressParams,
+};
+
+use crate::Project;
+
+#[test]
+fn test_run_function_from_code_lens() {
+    let server = Project::with_fixture(
+        r#"
+    //- /mun.toml
+    [package]
+    name = "foo"
+    version = "0.0.0"
+
+    //- /src/mod.mun
+    pub struct Foo { a: i32, b: bool }
+    pub fn main() -> i32 { 5 + 37 }
+    pub fn foo() -> Foo { Foo { a: 1, b: true } }
+    pub fn add(a: i32, b: i32) -> i32 { a + b }
+    "#,
+    )
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let lenses = server
+        .send_request::<lsp_types::request::CodeLensRequest>(CodeLensParams {
+            text_document: server.doc_id("src/mod.mun"),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        })
+        .expect("expected code lenses");
+    assert_eq!(lenses.len(), 2);
+
+    let results = lenses
+        .into_iter()
+        .map(|lens| {
+            let command = lens.command.expect("expected a command");
+            server
+                .send_request::<lsp_types::request::ExecuteCommand>(ExecuteCommandParams {
+                    command: command.command,
+                    arguments: command.arguments.unwrap_or_default(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                })
+                .expect("expected a result")
+        })
+        .collect::<Vec<_>>();
+
+    insta::assert_snapshot!(serde_json::to_string_pretty(&results).unwrap(), @r#"
+        [
+          {
+            "kind": "value",
+            "returnType": "core::i32",
+            "value": "42"
+          },
+          {
+            "kind": "value",
+            "returnType": "Foo",
+            "value": "Foo { a: 1, b: true }"
+          }
+        ]
+        "#);
+}
@@ -1263,6 +1263,11 @@ impl_primitive_type!(
     f32,
     f64,
     bool,
+    abi::F32x2,
+    abi::F32x4,
+    abi::F64x2,
+    abi::I32x2,
+    abi::I32x4,
     (),
     std::ffi::c_void
 );
@@ -111,6 +111,11 @@ impl Default for TypeTable {
         type_table.insert_static_type::<f32>();
         type_table.insert_static_type::<f64>();
         type_table.insert_static_type::<bool>();
+        type_table.insert_static_type::<abi::F32x2>();
+        type_table.insert_static_type::<abi::F32x4>();
+        type_table.insert_static_type::<abi::F64x2>();
+        type_table.insert_static_type::<abi::I32x2>();
+        type_table.insert_static_type::<abi::I32x4>();
         type_table.insert_static_type::<()>();
         type_table.insert_static_type::<std::ffi::c_void>();
 
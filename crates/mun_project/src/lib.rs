@@ -1,10 +1,14 @@
-pub use manifest::{Manifest, ManifestMetadata, PackageId};
+pub use manifest::{Dependency, LintLevel, Manifest, ManifestMetadata, PackageId};
 pub use package::Package;
+pub use profile::{AssemblyPartition, Profile, DEV_PROFILE, RELEASE_PROFILE};
 pub use project_manifest::ProjectManifest;
+pub use workspace::Workspace;
 
 mod manifest;
 mod package;
+mod profile;
 mod project_manifest;
+mod workspace;
 
 pub const MANIFEST_FILENAME: &str = "mun.toml";
 pub const LOCKFILE_NAME: &str = ".munlock";
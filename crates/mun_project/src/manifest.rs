@@ -1,6 +1,15 @@
-use std::{fmt, path::Path, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-mod toml;
+pub(crate) mod toml;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Profile;
 
 /// Contains all information of a package. Usually this information is read from
 /// a mun.toml file.
@@ -8,6 +17,9 @@ mod toml;
 pub struct Manifest {
     package_id: PackageId,
     metadata: ManifestMetadata,
+    dependencies: Vec<Dependency>,
+    profiles: BTreeMap<String, Profile>,
+    lints: BTreeMap<String, LintLevel>,
 }
 
 /// General metadata for a package.
@@ -16,6 +28,22 @@ pub struct ManifestMetadata {
     pub authors: Vec<String>,
 }
 
+/// A dependency of a package on another package on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dependency {
+    name: String,
+    path: PathBuf,
+}
+
+/// The level of a lint as configured in the `[lints]` section of a manifest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
 /// Unique identifier of a package and version
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PackageId {
@@ -52,6 +80,36 @@ impl Manifest {
     pub fn metadata(&self) -> &ManifestMetadata {
         &self.metadata
     }
+
+    /// Returns the packages this package depends on
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// Returns the profile with the specified name, or `None` if no such
+    /// profile exists.
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
+
+    /// Returns the levels of the lints that are configured for this package,
+    /// by lint name.
+    pub fn lints(&self) -> &BTreeMap<String, LintLevel> {
+        &self.lints
+    }
+}
+
+impl Dependency {
+    /// Returns the name with which the dependency is referred to from source
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the root of the dependency, relative to the root of
+    /// the depending package
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
 }
 
 impl PackageId {
@@ -85,9 +143,9 @@ impl std::str::FromStr for Manifest {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{path::Path, str::FromStr};
 
-    use crate::Manifest;
+    use crate::{AssemblyPartition, LintLevel, Manifest};
 
     #[test]
     fn parse() {
@@ -108,5 +166,172 @@ mod tests {
         );
         assert_eq!(manifest.metadata().authors, vec!["Mun Team"]);
         assert_eq!(format!("{}", manifest.package_id()), "test v0.2.0");
+        assert!(manifest.dependencies().is_empty());
+        assert!(manifest.lints().is_empty());
+    }
+
+    #[test]
+    fn parse_lints() {
+        let manifest = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [lints]
+        unused_variables = "allow"
+        dead_code = "deny"
+        "#,
+        )
+        .unwrap();
+
+        let lints = manifest
+            .lints()
+            .iter()
+            .map(|(name, level)| (name.as_str(), *level))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lints,
+            vec![
+                ("dead_code", LintLevel::Deny),
+                ("unused_variables", LintLevel::Allow)
+            ]
+        );
+
+        assert!(Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [lints]
+        unused_variables = "ignore"
+        "#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_dependencies() {
+        let manifest = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [dependencies]
+        foo = { path = "../foo" }
+        bar = { path = "libs/bar" }
+        "#,
+        )
+        .unwrap();
+
+        let dependencies = manifest
+            .dependencies()
+            .iter()
+            .map(|dep| (dep.name(), dep.path().to_str().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(dependencies, vec![("bar", "libs/bar"), ("foo", "../foo")]);
+    }
+
+    #[test]
+    fn parse_profiles() {
+        let manifest = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [profile.dev]
+        opt-level = 1
+
+        [profile.release]
+        debug = true
+        out-dir = "dist"
+
+        [profile.bench]
+        inherits = "release"
+        overflow-checks = true
+        runtime-checks = false
+        lto = true
+        partition = "single"
+        "#,
+        )
+        .unwrap();
+
+        let dev = manifest.profile("dev").unwrap();
+        assert_eq!(dev.opt_level, 1);
+        assert!(!dev.debug);
+        assert!(!dev.overflow_checks);
+        assert_eq!(dev.output_directory(), Path::new("target"));
+        assert_eq!(dev.partition, AssemblyPartition::PerModule);
+
+        let release = manifest.profile("release").unwrap();
+        assert_eq!(release.opt_level, 3);
+        assert!(release.debug);
+        assert!(!release.overflow_checks);
+        assert!(release.runtime_checks);
+        assert!(!release.lto);
+        assert_eq!(release.output_directory(), Path::new("dist"));
+
+        let bench = manifest.profile("bench").unwrap();
+        assert_eq!(bench.name, "bench");
+        assert_eq!(bench.opt_level, 3);
+        assert!(bench.debug);
+        assert!(bench.overflow_checks);
+        assert!(!bench.runtime_checks);
+        assert!(bench.lto);
+        assert_eq!(bench.output_directory(), Path::new("dist"));
+        assert_eq!(bench.partition, AssemblyPartition::Single);
+
+        assert!(manifest.profile("unknown").is_none());
+    }
+
+    #[test]
+    fn parse_invalid_profiles() {
+        for (profiles, error) in [
+            (
+                "[profile.dev]\nopt-level = 4",
+                "invalid opt-level 4 in profile `dev`: only optimization levels 0-3 are supported",
+            ),
+            (
+                "[profile.custom]\nopt-level = 1",
+                "profile `custom` must specify the profile it inherits from",
+            ),
+            (
+                "[profile.custom]\ninherits = \"unknown\"",
+                "profile `unknown` is not defined",
+            ),
+            (
+                "[profile.a]\ninherits = \"b\"\n[profile.b]\ninherits = \"a\"",
+                "profile `a` inherits from itself",
+            ),
+        ] {
+            let err = Manifest::from_str(&format!(
+                "[package]\nname=\"test\"\nversion=\"0.2.0\"\n{profiles}"
+            ))
+            .unwrap_err();
+            assert_eq!(err.to_string(), error);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_dependency_name() {
+        let err = Manifest::from_str(
+            r#"
+        [package]
+        name="test"
+        version="0.2.0"
+
+        [dependencies]
+        foo-bar = { path = "../foo" }
+        "#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid dependency name `foo-bar`: dependency names must be valid identifiers"
+        );
     }
 }
@@ -1,12 +1,19 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
 use serde_derive::{Deserialize, Serialize};
 
-use super::{Manifest, ManifestMetadata, PackageId};
+use super::{Dependency, LintLevel, Manifest, ManifestMetadata, PackageId};
+use crate::{AssemblyPartition, Profile, DEV_PROFILE, RELEASE_PROFILE};
 
 /// A manifest as specified in a mun.toml file.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct TomlManifest {
-    package: TomlProject,
+    package: Option<TomlProject>,
+    dependencies: Option<BTreeMap<String, TomlDependency>>,
+    workspace: Option<TomlWorkspace>,
+    profile: Option<BTreeMap<String, TomlProfile>>,
+    lints: Option<BTreeMap<String, LintLevel>>,
 }
 
 /// Represents the `package` section of a mun.toml file.
@@ -17,22 +24,172 @@ pub struct TomlProject {
     authors: Option<Vec<String>>,
 }
 
+/// Represents a single entry of the `dependencies` section of a mun.toml file.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TomlDependency {
+    path: PathBuf,
+}
+
+/// Represents the `workspace` section of a mun.toml file.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TomlWorkspace {
+    members: Vec<PathBuf>,
+}
+
+/// Represents a single `profile` section of a mun.toml file.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct TomlProfile {
+    inherits: Option<String>,
+    opt_level: Option<u8>,
+    debug: Option<bool>,
+    overflow_checks: Option<bool>,
+    runtime_checks: Option<bool>,
+    lto: Option<bool>,
+    out_dir: Option<PathBuf>,
+    partition: Option<AssemblyPartition>,
+}
+
 impl TomlManifest {
+    /// Resolves all profiles defined in the manifest, together with the
+    /// built-in `dev` and `release` profiles.
+    pub fn profiles(&self) -> Result<BTreeMap<String, Profile>, anyhow::Error> {
+        let definitions = self.profile.clone().unwrap_or_default();
+        let mut profiles = BTreeMap::new();
+        for name in [DEV_PROFILE, RELEASE_PROFILE]
+            .into_iter()
+            .chain(definitions.keys().map(String::as_str))
+        {
+            let profile = resolve_profile(&definitions, name, &mut Vec::new())?;
+            profiles.insert(name.to_owned(), profile);
+        }
+        Ok(profiles)
+    }
+
+    /// Returns the paths of the members of the workspace, relative to the
+    /// manifest, or `None` if this manifest does not describe a workspace.
+    pub fn workspace_members(&self) -> Option<&[PathBuf]> {
+        self.workspace
+            .as_ref()
+            .map(|workspace| workspace.members.as_slice())
+    }
+
+    /// Returns true if this manifest describes a package.
+    pub fn has_package(&self) -> bool {
+        self.package.is_some()
+    }
+
     /// Convert this toml manifest into a "real" manifest.
     pub fn into_real_manifest(self) -> Result<Manifest, anyhow::Error> {
-        let name = self.package.name.trim();
+        let profiles = self.profiles()?;
+        let Some(package) = self.package else {
+            anyhow::bail!("manifest is missing a `[package]` section");
+        };
+        let name = package.name.trim();
         if name.is_empty() {
             anyhow::bail!("package name cannot be an empty string");
         }
 
+        let dependencies = self
+            .dependencies
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, dependency)| {
+                if !is_identifier(&name) {
+                    anyhow::bail!(
+                        "invalid dependency name `{name}`: dependency names must be valid identifiers"
+                    );
+                }
+                Ok(Dependency {
+                    name,
+                    path: dependency.path,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
         Ok(Manifest {
             package_id: PackageId {
                 name: name.to_owned(),
-                version: self.package.version,
+                version: package.version,
             },
             metadata: ManifestMetadata {
-                authors: self.package.authors.unwrap_or_default(),
+                authors: package.authors.unwrap_or_default(),
             },
+            dependencies,
+            profiles,
+            lints: self.lints.unwrap_or_default(),
         })
     }
 }
+
+/// Resolves the settings of the profile with the specified `name` by applying
+/// its definition on top of the profile it inherits from. `stack` contains the
+/// profiles that are currently being resolved to detect cyclic inheritance.
+fn resolve_profile<'a>(
+    definitions: &'a BTreeMap<String, TomlProfile>,
+    name: &'a str,
+    stack: &mut Vec<&'a str>,
+) -> Result<Profile, anyhow::Error> {
+    if stack.contains(&name) {
+        anyhow::bail!("profile `{name}` inherits from itself");
+    }
+
+    let definition = definitions.get(name);
+    let mut profile = match name {
+        DEV_PROFILE => Profile::dev(),
+        RELEASE_PROFILE => Profile::release(),
+        _ => {
+            let Some(definition) = definition else {
+                anyhow::bail!("profile `{name}` is not defined");
+            };
+            let Some(inherits) = definition.inherits.as_deref() else {
+                anyhow::bail!("profile `{name}` must specify the profile it inherits from");
+            };
+            stack.push(name);
+            let mut profile = resolve_profile(definitions, inherits, stack)?;
+            stack.pop();
+            profile.name = name.to_owned();
+            profile
+        }
+    };
+
+    if let Some(definition) = definition {
+        if let Some(opt_level) = definition.opt_level {
+            if opt_level > 3 {
+                anyhow::bail!("invalid opt-level {opt_level} in profile `{name}`: only optimization levels 0-3 are supported");
+            }
+            profile.opt_level = opt_level;
+        }
+        if let Some(debug) = definition.debug {
+            profile.debug = debug;
+        }
+        if let Some(overflow_checks) = definition.overflow_checks {
+            profile.overflow_checks = overflow_checks;
+        }
+        if let Some(runtime_checks) = definition.runtime_checks {
+            profile.runtime_checks = runtime_checks;
+        }
+        if let Some(lto) = definition.lto {
+            profile.lto = lto;
+        }
+        if let Some(out_dir) = &definition.out_dir {
+            profile.out_dir = Some(out_dir.clone());
+        }
+        if let Some(partition) = definition.partition {
+            profile.partition = partition;
+        }
+    }
+
+    Ok(profile)
+}
+
+/// Returns true if `name` can be used to refer to a dependency from source.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
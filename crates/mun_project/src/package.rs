@@ -5,7 +5,7 @@ use std::{
 
 use semver::Version;
 
-use crate::{Manifest, PackageId};
+use crate::{Manifest, PackageId, MANIFEST_FILENAME};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Package {
@@ -65,6 +65,32 @@ impl Package {
     pub fn source_directory(&self) -> PathBuf {
         self.root().join("src")
     }
+
+    /// Loads the packages this package directly depends on, together with the
+    /// name with which they are referred to. The manifest paths of the
+    /// returned packages are canonicalized.
+    pub fn dependencies(&self) -> anyhow::Result<Vec<(String, Package)>> {
+        self.manifest()
+            .dependencies()
+            .iter()
+            .map(|dependency| {
+                let manifest_path = self.root().join(dependency.path()).join(MANIFEST_FILENAME);
+                let package = manifest_path
+                    .canonicalize()
+                    .map_err(anyhow::Error::from)
+                    .and_then(Package::from_file)
+                    .map_err(|e| {
+                        anyhow::anyhow!(
+                            "failed to load dependency `{}` of package `{}`: {}",
+                            dependency.name(),
+                            self.name(),
+                            e
+                        )
+                    })?;
+                Ok((dependency.name().to_owned(), package))
+            })
+            .collect()
+    }
 }
 
 impl fmt::Display for Package {
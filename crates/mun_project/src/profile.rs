@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// The name of the profile that is used when no profile is specified
+pub const DEV_PROFILE: &str = "dev";
+
+/// The name of the profile that is used for optimized builds
+pub const RELEASE_PROFILE: &str = "release";
+
+/// A set of settings that control how a package is compiled. Profiles are
+/// defined in the `[profile.<name>]` sections of a mun.toml file. The `dev`
+/// and `release` profiles always exist, other profiles have to inherit from an
+/// existing profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    /// The name of the profile
+    pub name: String,
+
+    /// The optimization level [0,3]
+    pub opt_level: u8,
+
+    /// Whether or not to emit debug information
+    pub debug: bool,
+
+    /// Whether or not to emit runtime checks for arithmetic overflow
+    pub overflow_checks: bool,
+
+    /// Whether or not to emit runtime checks for out-of-bounds array accesses
+    /// and integer division by zero
+    pub runtime_checks: bool,
+
+    /// Whether or not to optimize the assemblies with link-time optimization.
+    /// This allows calls to functions in other module groups of the package
+    /// to be inlined, at the cost of having to recompile the callers when
+    /// the called functions change.
+    pub lto: bool,
+
+    /// The directory to store the compiled assemblies in, relative to the root
+    /// of the package. If no directory is specified, `target` is used for the
+    /// `dev` profile and `target/<name>` for all other profiles.
+    pub out_dir: Option<PathBuf>,
+
+    /// How the modules of a package are grouped into assemblies
+    pub partition: AssemblyPartition,
+}
+
+/// Describes how the modules of a package are grouped into assemblies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssemblyPartition {
+    /// Every module is compiled into its own assembly
+    #[default]
+    PerModule,
+
+    /// All modules whose source files are located in the same directory are
+    /// compiled into a single assembly
+    PerDirectory,
+
+    /// All modules of a package are compiled into a single assembly
+    Single,
+}
+
+impl Profile {
+    /// Returns the default settings of the `dev` profile. These match the
+    /// settings that `mun build` used before profiles existed: optimization
+    /// level 2 without debug information or overflow checks.
+    pub fn dev() -> Self {
+        Self {
+            name: DEV_PROFILE.to_owned(),
+            opt_level: 2,
+            debug: false,
+            overflow_checks: false,
+            runtime_checks: true,
+            lto: false,
+            out_dir: None,
+            partition: AssemblyPartition::PerModule,
+        }
+    }
+
+    /// Returns the default settings of the `release` profile, which favors
+    /// runtime performance.
+    pub fn release() -> Self {
+        Self {
+            name: RELEASE_PROFILE.to_owned(),
+            opt_level: 3,
+            debug: false,
+            overflow_checks: false,
+            runtime_checks: true,
+            lto: false,
+            out_dir: None,
+            partition: AssemblyPartition::PerModule,
+        }
+    }
+
+    /// Returns the directory to store the compiled assemblies in, relative to
+    /// the root of the package.
+    pub fn output_directory(&self) -> PathBuf {
+        self.out_dir.clone().unwrap_or_else(|| {
+            if self.name == DEV_PROFILE {
+                PathBuf::from("target")
+            } else {
+                PathBuf::from("target").join(&self.name)
+            }
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::{manifest::toml::TomlManifest, Manifest, Package, Profile, MANIFEST_FILENAME};
+
+/// A set of packages that are built together and share an output directory. A
+/// workspace is described by a manifest with a `[workspace]` section. If the
+/// manifest also contains a `[package]` section, that package is a member of
+/// the workspace as well.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Workspace {
+    // The location of the manifest which marks the root of the workspace
+    manifest_path: PathBuf,
+    // The packages that are part of the workspace
+    members: Vec<Package>,
+    // The profiles of the workspace, which apply to all members
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Workspace {
+    /// Creates a workspace by loading the manifest at the specified path and
+    /// the manifests of all its members.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file_contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read manifest file: {}", e))?;
+        let manifest = ::toml::from_str::<TomlManifest>(&file_contents)
+            .map_err(|e| anyhow::anyhow!("could not parse manifest: {}", e))?;
+
+        let Some(member_paths) = manifest.workspace_members() else {
+            anyhow::bail!(
+                "manifest '{}' does not contain a `[workspace]` section",
+                path.display()
+            );
+        };
+        let root = path.parent().expect("a manifest path always has a parent");
+        let member_manifest_paths = member_paths
+            .iter()
+            .map(|member_path| {
+                let manifest_path = root.join(member_path).join(MANIFEST_FILENAME);
+                manifest_path.canonicalize().map_err(|e| {
+                    anyhow::anyhow!(
+                        "could not find workspace member '{}': {}",
+                        member_path.display(),
+                        e
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let profiles = manifest.profiles()?;
+        let mut members = Vec::with_capacity(member_manifest_paths.len() + 1);
+        if manifest.has_package() {
+            members.push(Package::new(manifest.into_real_manifest()?, path));
+        }
+        for manifest_path in member_manifest_paths {
+            let manifest = Manifest::from_file(&manifest_path).map_err(|e| {
+                anyhow::anyhow!(
+                    "failed to load workspace member '{}': {}",
+                    manifest_path.display(),
+                    e
+                )
+            })?;
+            members.push(Package::new(manifest, &manifest_path));
+        }
+
+        // Every member is written to a directory named after the member
+        for (idx, member) in members.iter().enumerate() {
+            if members[..idx]
+                .iter()
+                .any(|other| other.name() == member.name())
+            {
+                anyhow::bail!("multiple workspace members are named `{}`", member.name());
+            }
+        }
+
+        Ok(Self {
+            manifest_path: path.to_path_buf(),
+            members,
+            profiles,
+        })
+    }
+
+    /// Returns true if the manifest at the specified path describes a
+    /// workspace.
+    pub fn is_workspace_manifest<P: AsRef<Path>>(path: P) -> anyhow::Result<bool> {
+        let file_contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read manifest file: {}", e))?;
+        let manifest = ::toml::from_str::<TomlManifest>(&file_contents)
+            .map_err(|e| anyhow::anyhow!("could not parse manifest: {}", e))?;
+        Ok(manifest.workspace_members().is_some())
+    }
+
+    /// Returns the path of the manifest
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+
+    /// Returns the root folder of the workspace
+    pub fn root(&self) -> &Path {
+        self.manifest_path().parent().unwrap()
+    }
+
+    /// Returns the packages that are part of the workspace
+    pub fn members(&self) -> &[Package] {
+        &self.members
+    }
+
+    /// Returns the profile with the specified name, or `None` if no such
+    /// profile exists. The profiles of a workspace override the profiles of
+    /// its members.
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
+
+    /// Returns the path to the output directory that is shared by all members
+    /// of the workspace
+    pub fn target_directory(&self) -> PathBuf {
+        self.root().join("target")
+    }
+}
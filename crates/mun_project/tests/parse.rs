@@ -1,6 +1,6 @@
 use std::{path::Path, str::FromStr};
 
-use mun_project::{Manifest, Package};
+use mun_project::{Manifest, Package, Workspace};
 use semver::Version;
 
 #[test]
@@ -26,3 +26,32 @@ fn package_from_file() {
     let source_dir = package.source_directory();
     assert_eq!(source_dir, manifest_path.parent().unwrap().join("src"));
 }
+
+#[test]
+fn workspace_from_file() {
+    let manifest_path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/resources/workspace/mun.toml");
+    let workspace = Workspace::from_file(&manifest_path).expect("could not load workspace");
+    assert_eq!(workspace.manifest_path(), &manifest_path);
+    assert_eq!(
+        workspace.target_directory(),
+        manifest_path.parent().unwrap().join("target")
+    );
+
+    let member_names = workspace
+        .members()
+        .iter()
+        .map(Package::name)
+        .collect::<Vec<_>>();
+    assert_eq!(member_names, vec!["app", "lib"]);
+
+    assert!(Workspace::is_workspace_manifest(&manifest_path).unwrap());
+    assert!(Package::from_file(&manifest_path).is_err());
+}
+
+#[test]
+fn package_is_not_a_workspace() {
+    let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/resources/mun.toml");
+    assert!(!Workspace::is_workspace_manifest(&manifest_path).unwrap());
+    assert!(Workspace::from_file(&manifest_path).is_err());
+}
@@ -89,11 +89,32 @@ pub enum LinkFunctionsError {
 /// Mun modules.
 pub struct Assembly {
     library_path: PathBuf,
-    library: TempLibrary,
+    library: Option<TempLibrary>,
     info: abi::AssemblyInfo<'static>,
     allocator: Arc<GarbageCollector>,
 }
 
+/// The entry points of an assembly that is statically linked into the host
+/// binary. Statically linked assemblies are never hot reloaded.
+///
+/// A static library emitted by the compiler exports the entry points of every
+/// assembly under names that are prefixed with its package and module group,
+/// e.g. `app__get_info` for the `get_info` function of the root module of the
+/// package `app`, or `app__foo__get_info` for that of its module `foo`.
+///
+/// Because the state of a statically linked assembly is global, an assembly
+/// should only be registered with a single [`Runtime`](crate::Runtime) at a
+/// time.
+#[derive(Clone, Copy, Debug)]
+pub struct StaticAssembly {
+    /// Returns the ABI version of the assembly
+    pub get_version: unsafe extern "C" fn() -> u32,
+    /// Returns the information of the assembly
+    pub get_info: unsafe extern "C" fn() -> abi::AssemblyInfo<'static>,
+    /// Stores the allocator handle inside the assembly
+    pub set_allocator_handle: unsafe extern "C" fn(*mut c_void),
+}
+
 impl Assembly {
     /// Loads an assembly and its information for the shared library at
     /// `library_path`. The resulting `Assembly` is ensured to be linkable.
@@ -129,13 +150,45 @@ impl Assembly {
         let assembly = Assembly {
             info: library.get_info(),
             library_path: library_path.to_path_buf(),
-            library: library.into_inner(),
+            library: Some(library.into_inner()),
             allocator: gc,
         };
 
         Ok(assembly)
     }
 
+    /// Loads the information of an assembly that is statically linked into the
+    /// host binary. The path of the resulting `Assembly` is the path of its
+    /// root module.
+    ///
+    /// # Safety
+    ///
+    /// The entry points of `assembly` must be the functions exported by a
+    /// static library that was emitted by the Mun compiler.
+    pub unsafe fn load_static(
+        assembly: StaticAssembly,
+        gc: Arc<GarbageCollector>,
+    ) -> Result<Self, LoadError> {
+        let version = (assembly.get_version)();
+        if abi::ABI_VERSION != version {
+            return Err(LoadError::MismatchedAbiVersions {
+                expected: abi::ABI_VERSION,
+                actual: version,
+            });
+        }
+
+        let allocator_ptr = Arc::into_raw(gc.clone()) as *mut std::ffi::c_void;
+        (assembly.set_allocator_handle)(allocator_ptr);
+
+        let info = (assembly.get_info)();
+        Ok(Assembly {
+            library_path: PathBuf::from(info.symbols.path()),
+            library: None,
+            info,
+            allocator: gc,
+        })
+    }
+
     /// On failure, returns debug names of all missing types.
     fn link_all_types<'abi>(
         type_table: &TypeTable,
@@ -461,14 +514,15 @@ impl Assembly {
         unsafe { std::mem::transmute(&mut self.info) }
     }
 
-    /// Returns the path corresponding to the assembly's library.
+    /// Returns the path corresponding to the assembly's library, or the path
+    /// of its root module if the assembly is statically linked.
     pub fn library_path(&self) -> &Path {
         self.library_path.as_path()
     }
 
     /// Converts the `Assembly` into a `TempLibrary`, consuming the input in the
-    /// process.
-    pub fn into_library(self) -> TempLibrary {
+    /// process. Returns `None` if the assembly is statically linked.
+    pub fn into_library(self) -> Option<TempLibrary> {
         self.library
     }
 }
@@ -113,11 +113,11 @@ pub trait IntoFunctionDefinition {
 
 macro_rules! into_function_info_impl {
     ($(
-        extern "C" fn($($T:ident),*) -> $R:ident;
+        extern $abi:literal fn($($T:ident),*) -> $R:ident;
     )+) => {
         $(
             impl<$R: mun_memory::HasStaticType, $($T: mun_memory::HasStaticType,)*> IntoFunctionDefinition
-            for extern "C" fn($($T),*) -> $R
+            for extern $abi fn($($T),*) -> $R
             {
                 fn into<S: Into<String>>(self, name: S) -> FunctionDefinition {
                     FunctionDefinition {
@@ -148,6 +148,17 @@ into_function_info_impl! {
     extern "C" fn(A, B, C, D, E, F, G, H) -> R;
     extern "C" fn(A, B, C, D, E, F, G, H, I) -> R;
     extern "C" fn(A, B, C, D, E, F, G, H, I, J) -> R;
+    extern "C-unwind" fn() -> R;
+    extern "C-unwind" fn(A) -> R;
+    extern "C-unwind" fn(A, B) -> R;
+    extern "C-unwind" fn(A, B, C) -> R;
+    extern "C-unwind" fn(A, B, C, D) -> R;
+    extern "C-unwind" fn(A, B, C, D, E) -> R;
+    extern "C-unwind" fn(A, B, C, D, E, F) -> R;
+    extern "C-unwind" fn(A, B, C, D, E, F, G) -> R;
+    extern "C-unwind" fn(A, B, C, D, E, F, G, H) -> R;
+    extern "C-unwind" fn(A, B, C, D, E, F, G, H, I) -> R;
+    extern "C-unwind" fn(A, B, C, D, E, F, G, H, I, J) -> R;
 }
 
 /// A helper struct to ergonomically build functions.
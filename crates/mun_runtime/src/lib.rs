@@ -12,6 +12,7 @@ mod array;
 mod dispatch_table;
 mod function_info;
 mod marshal;
+mod panic;
 mod reflection;
 mod utils;
 
@@ -35,11 +36,12 @@ use dispatch_table::DispatchTable;
 use garbage_collector::GarbageCollector;
 use log::{debug, error, info};
 use mun_abi as abi;
+// Re-export some useful types so crates dont have to depend on mun_memory as well.
+pub use mun_abi::{F32x2, F32x4, F64x2, I32x2, I32x4};
 use mun_memory::{
     gc::{self, Array, GcRuntime},
     type_table::TypeTable,
 };
-// Re-export some useful types so crates dont have to depend on mun_memory as well.
 pub use mun_memory::{Field, FieldData, HasStaticType, PointerType, StructType, Type};
 use mun_project::LOCKFILE_NAME;
 use notify::{event::ModifyKind, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
@@ -47,18 +49,21 @@ use notify::{event::ModifyKind, Event, EventKind, RecommendedWatcher, RecursiveM
 pub use crate::{
     adt::{RootedStruct, StructRef},
     array::{ArrayRef, RawArray, RootedArray},
-    assembly::{Assembly, LinkError, LinkFunctionsError},
+    assembly::{Assembly, LinkError, LinkFunctionsError, StaticAssembly},
     function_info::{
         FunctionDefinition, FunctionPrototype, FunctionSignature, IntoFunctionDefinition,
     },
     marshal::Marshal,
+    panic::{catch_panic, Panic, StackFrame},
     reflection::{ArgumentReflection, ReturnTypeReflection},
 };
 
 /// Options for the construction of a [`Runtime`].
 pub struct RuntimeOptions {
-    /// Path to the entry point library
-    pub library_path: PathBuf,
+    /// Path to the entry point library, if any
+    pub library_path: Option<PathBuf>,
+    /// Assemblies that are statically linked into the host binary
+    pub static_assemblies: Vec<StaticAssembly>,
     /// Custom type table used for the runtime
     pub type_table: TypeTable,
     /// Custom user injected functions
@@ -133,11 +138,12 @@ pub struct RuntimeBuilder {
 
 impl RuntimeBuilder {
     /// Constructs a new `RuntimeBuilder` for the shared library at
-    /// `library_path`.
-    fn new<P: Into<PathBuf>>(library_path: P) -> Self {
+    /// `library_path`, if any.
+    fn new(library_path: Option<PathBuf>) -> Self {
         Self {
             options: RuntimeOptions {
-                library_path: library_path.into(),
+                library_path,
+                static_assemblies: Vec::new(),
                 type_table: TypeTable::default(),
                 user_functions: Vec::default(),
             },
@@ -154,6 +160,13 @@ impl RuntimeBuilder {
         self
     }
 
+    /// Registers an assembly that is statically linked into the host binary.
+    /// All assemblies that it depends on must be registered as well.
+    pub fn add_static_assembly(mut self, assembly: StaticAssembly) -> Self {
+        self.options.static_assemblies.push(assembly);
+        self
+    }
+
     /// Constructs a [`Runtime`] with the builder's options.
     ///
     /// # Safety
@@ -213,12 +226,21 @@ impl Runtime {
     /// Constructs a new [`RuntimeBuilder`] to construct a new [`Runtime`]
     /// instance.
     pub fn builder<P: Into<PathBuf>>(library_path: P) -> RuntimeBuilder {
-        RuntimeBuilder::new(library_path)
+        RuntimeBuilder::new(Some(library_path.into()))
+    }
+
+    /// Constructs a new [`RuntimeBuilder`] for a [`Runtime`] that only
+    /// contains assemblies that are statically linked into the host binary.
+    /// Assemblies are registered with
+    /// [`RuntimeBuilder::add_static_assembly`] and are never hot reloaded.
+    pub fn static_builder() -> RuntimeBuilder {
+        RuntimeBuilder::new(None)
     }
 
-    /// Constructs a new `Runtime` that loads the library at `library_path` and
-    /// its dependencies. The `Runtime` contains a file watcher that is
-    /// triggered with an interval of `dur`.
+    /// Constructs a new `Runtime` that links the statically linked assemblies
+    /// and loads the library at `library_path` and its dependencies. The
+    /// `Runtime` contains a file watcher that is triggered with an interval of
+    /// `dur`.
     ///
     /// # Safety
     ///
@@ -256,6 +278,21 @@ impl Runtime {
             "new_array",
         ));
 
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic::panic as extern "C-unwind" fn(*const u8, *const u8, u32),
+            "panic",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic::push_frame as extern "C" fn(*mut ffi::c_void),
+            "push_frame",
+        ));
+
+        options.user_functions.push(IntoFunctionDefinition::into(
+            panic::pop_frame as extern "C" fn(*mut ffi::c_void),
+            "pop_frame",
+        ));
+
         options.user_functions.into_iter().for_each(|fn_def| {
             dispatch_table.insert_fn(fn_def.prototype.name.clone(), Arc::new(fn_def));
         });
@@ -274,10 +311,40 @@ impl Runtime {
             gc: Arc::new(self::garbage_collector::GarbageCollector::default()),
         };
 
-        runtime.add_assembly(&options.library_path)?;
+        runtime.add_static_assemblies(options.static_assemblies)?;
+        if let Some(library_path) = &options.library_path {
+            runtime.add_assembly(library_path)?;
+        }
         Ok(runtime)
     }
 
+    /// Adds assemblies that are statically linked into the host binary. These
+    /// assemblies are not watched for changes.
+    ///
+    /// # Safety
+    ///
+    /// See [`Assembly::load_static`] for more information.
+    unsafe fn add_static_assemblies(
+        &mut self,
+        static_assemblies: Vec<StaticAssembly>,
+    ) -> Result<(), LinkError> {
+        let mut loaded = HashMap::new();
+        for static_assembly in static_assemblies {
+            let assembly = Assembly::load_static(static_assembly, self.gc.clone())?;
+            let path = assembly.library_path().to_path_buf();
+            if self.assemblies.contains_key(&path) || loaded.contains_key(&path) {
+                return Err(LoadError::AlreadyExists.into());
+            }
+            loaded.insert(path, assembly);
+        }
+
+        (self.dispatch_table, self.type_table) =
+            Assembly::link_all(loaded.values_mut(), &self.dispatch_table, &self.type_table)?;
+        self.assemblies.extend(loaded);
+
+        Ok(())
+    }
+
     /// Adds an assembly corresponding to the library at `library_path`.
     ///
     /// # Safety
@@ -378,7 +445,10 @@ impl Runtime {
                     None
                 }
             })
-            .min_by(|(_, dist1), (_, dist2)| dist1.cmp(dist2));
+            // Ties are broken by name, because `fn_names` iterates the dispatch
+            // table in hash order, which changes whenever the runtime or the
+            // host registers another function.
+            .min_by(|(name1, dist1), (name2, dist2)| dist1.cmp(dist2).then(name1.cmp(name2)));
         found_match.map(|(closest_name, _)| closest_name)
     }
 
@@ -394,6 +464,41 @@ impl Runtime {
         self.type_table.find_type_info_by_id(type_id)
     }
 
+    /// Retrieves the value of the public constant called `constant_name`. The
+    /// value was evaluated when the assembly that defines the constant was
+    /// compiled.
+    pub fn get_constant<'runtime, 'ret, T: ReturnTypeReflection + Marshal<'ret> + 'ret>(
+        &'runtime self,
+        constant_name: &str,
+    ) -> Result<T, String>
+    where
+        'runtime: 'ret,
+    {
+        let constant = self
+            .assemblies
+            .values()
+            .flat_map(|assembly| assembly.info().symbols.constants())
+            .find(|constant| constant.name() == constant_name)
+            .ok_or_else(|| {
+                format!("failed to obtain constant '{constant_name}', no such constant exists.")
+            })?;
+
+        let type_info = self
+            .get_type_info_by_id(&constant.type_id)
+            .ok_or_else(|| format!("the type of constant '{constant_name}' is unknown."))?;
+        if !T::accepts_type(&type_info) {
+            return Err(format!(
+                "unexpected constant type, got '{}', expected '{}'",
+                type_info.name(),
+                T::type_hint()
+            ));
+        }
+
+        let value = NonNull::new(constant.value.cast_mut().cast::<T::MunType>())
+            .expect("the value of a constant cannot be null");
+        Ok(T::marshal_from_ptr(value, self, &type_info))
+    }
+
     /// Updates the state of the runtime. This includes checking for file
     /// changes, and reloading compiled assemblies.
     /// # Safety
@@ -655,7 +760,10 @@ impl Runtime {
 pub struct InvokeErr<'name, T> {
     msg: String,
     function_name: &'name str,
-    arguments: T,
+    /// The arguments of the invocation, or `None` if they were consumed by an
+    /// invocation that panicked.
+    arguments: Option<T>,
+    panic: Option<Panic>,
 }
 
 impl<T> Debug for InvokeErr<'_, T> {
@@ -670,9 +778,20 @@ impl<T> Display for InvokeErr<'_, T> {
     }
 }
 
+impl<T> InvokeErr<'_, T> {
+    /// Returns the panic that aborted the function, or `None` if the function
+    /// could not be invoked.
+    pub fn panic(&self) -> Option<&Panic> {
+        self.panic.as_ref()
+    }
+}
+
 impl<T: InvokeArgs> InvokeErr<'_, T> {
     /// Retries a function invocation once, resulting in a potentially
     /// successful invocation.
+    ///
+    /// The arguments of an invocation that panicked were consumed, so such an
+    /// invocation cannot be retried and the error is returned immediately.
     // FIXME: `unwrap_or_else` does not compile for `StructRef`, due to
     // https://doc.rust-lang.org/nomicon/lifetime-mismatch.html#improperly-reduced-borrows
     pub fn retry<'r, 'o, Output>(self, runtime: &'r mut Runtime) -> Result<Output, Self>
@@ -687,16 +806,46 @@ impl<T: InvokeArgs> InvokeErr<'_, T> {
 
     /// Retries the function invocation until it succeeds, resulting in an
     /// output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the invocation panicked, since its arguments were consumed and
+    /// it cannot be retried. Use [`InvokeErr::try_wait`] to handle such an
+    /// invocation instead.
+    // FIXME: `unwrap_or_else` does not compile for `StructRef`, due to
+    // https://doc.rust-lang.org/nomicon/lifetime-mismatch.html#improperly-reduced-borrows
+    pub fn wait<'r, 'o, Output>(self, runtime: &'r mut Runtime) -> Output
+    where
+        Output: 'o + ReturnTypeReflection + Marshal<'o>,
+        'r: 'o,
+    {
+        match self.try_wait(runtime) {
+            Ok(output) => output,
+            Err(e) => panic!("{}", e.msg),
+        }
+    }
+
+    /// Retries the function invocation until it succeeds or panics, resulting
+    /// in an output.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of an invocation that panicked. Its arguments were
+    /// consumed by the call, so it cannot be retried.
     // FIXME: `unwrap_or_else` does not compile for `StructRef`, due to
     // https://doc.rust-lang.org/nomicon/lifetime-mismatch.html#improperly-reduced-borrows
-    pub fn wait<'r, 'o, Output>(mut self, runtime: &'r mut Runtime) -> Output
+    pub fn try_wait<'r, 'o, Output>(mut self, runtime: &'r mut Runtime) -> Result<Output, Self>
     where
         Output: 'o + ReturnTypeReflection + Marshal<'o>,
         'r: 'o,
     {
         loop {
+            if self.panic.is_some() {
+                return Err(self);
+            }
+
             self = match unsafe { self.retry_impl(runtime) } {
-                Ok(output) => return output,
+                Ok(output) => return Ok(output),
                 Err(e) => e,
             };
         }
@@ -715,6 +864,10 @@ impl<T: InvokeArgs> InvokeErr<'_, T> {
     where
         Output: 'o + ReturnTypeReflection + Marshal<'o>,
     {
+        let Some(arguments) = self.arguments else {
+            return Err(self);
+        };
+
         // Safety: Guaranteed by the caller to be valid to dereference.
         let runtime = &mut *runtime;
 
@@ -723,7 +876,7 @@ impl<T: InvokeArgs> InvokeErr<'_, T> {
             // Wait until there has been an update that might fix the error
         }
 
-        runtime.invoke(self.function_name, self.arguments)
+        runtime.invoke(self.function_name, arguments)
     }
 }
 
@@ -816,7 +969,8 @@ impl Runtime {
                 return Err(InvokeErr {
                     msg: suggested_message,
                     function_name,
-                    arguments,
+                    arguments: Some(arguments),
+                    panic: None,
                 });
             }
         };
@@ -828,7 +982,8 @@ impl Runtime {
                 return Err(InvokeErr {
                     msg,
                     function_name,
-                    arguments,
+                    arguments: Some(arguments),
+                    panic: None,
                 })
             }
         };
@@ -842,11 +997,21 @@ impl Runtime {
                     ReturnType::type_hint()
                 ),
                 function_name,
-                arguments,
+                arguments: Some(arguments),
+                panic: None,
             });
         }
 
-        let result: ReturnType::MunType = unsafe { arguments.invoke(function_info.fn_ptr) };
-        Ok(Marshal::marshal_from(result, self))
+        // A panic in Mun code unwinds the stack up to here. The arguments are
+        // consumed by the invocation, so they cannot be returned with the panic.
+        match catch_panic(|| unsafe { arguments.invoke(function_info.fn_ptr) }) {
+            Ok(result) => Ok(Marshal::marshal_from(result, self)),
+            Err(panic) => Err(InvokeErr {
+                msg: format!("function `{function_name}` {panic}"),
+                function_name,
+                arguments: None,
+                panic: Some(panic),
+            }),
+        }
     }
 }
@@ -0,0 +1,212 @@
+use std::{
+    cell::Cell,
+    ffi::{c_char, c_void, CStr},
+    fmt,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+/// Describes why the execution of Mun code was aborted, e.g. because an array
+/// was indexed out of bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    message: String,
+    file: String,
+    line: u32,
+    stack_trace: Vec<StackFrame>,
+}
+
+/// A call to a Mun function that was active when a [`Panic`] occurred.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StackFrame {
+    function: String,
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+impl StackFrame {
+    /// Returns the full name of the function, e.g. `foo::bar`.
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    /// Returns the path of the source file that contains the function,
+    /// relative to the source directory of its package.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the one-based line number of the expression that was being
+    /// executed in this frame.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the one-based column number of the expression that was being
+    /// executed in this frame.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}:{}:{})",
+            self.function, self.file, self.line, self.column
+        )
+    }
+}
+
+/// The record of a call to a Mun function. Mun code that is compiled with
+/// debug information allocates a record on its stack for every call and links
+/// it into the call stack of the current thread.
+///
+/// The layout of this struct must match the layout generated by the compiler.
+#[repr(C)]
+struct CallFrame {
+    parent: *const CallFrame,
+    function: *const c_char,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+}
+
+thread_local! {
+    /// The innermost frame of the Mun call stack of the current thread.
+    static CALL_STACK: Cell<*const CallFrame> = const { Cell::new(ptr::null()) };
+}
+
+/// The `push_frame` intrinsic that is called by Mun code when it enters a
+/// function.
+pub(crate) extern "C" fn push_frame(frame: *mut c_void) {
+    let frame = frame.cast::<CallFrame>();
+    CALL_STACK.with(|stack| {
+        // SAFETY: The Mun compiler always passes a valid frame record.
+        unsafe { (*frame).parent = stack.get() };
+        stack.set(frame);
+    });
+}
+
+/// The `pop_frame` intrinsic that is called by Mun code when it returns from
+/// a function.
+pub(crate) extern "C" fn pop_frame(frame: *mut c_void) {
+    let frame = frame.cast::<CallFrame>();
+    // SAFETY: The Mun compiler always passes the frame record that it pushed.
+    CALL_STACK.with(|stack| stack.set(unsafe { (*frame).parent }));
+}
+
+/// Collects the frames of the Mun call stack of the current thread, starting
+/// with the innermost frame.
+fn capture_stack_trace() -> Vec<StackFrame> {
+    let mut stack_trace = Vec::new();
+    let mut frame = CALL_STACK.with(Cell::get);
+    // SAFETY: Frames are only linked while the Mun function that owns them is
+    // executing and the compiler always passes null-terminated strings.
+    while let Some(CallFrame {
+        parent,
+        function,
+        file,
+        line,
+        column,
+    }) = unsafe { frame.as_ref() }
+    {
+        stack_trace.push(StackFrame {
+            function: unsafe { CStr::from_ptr(*function) }
+                .to_string_lossy()
+                .into_owned(),
+            file: unsafe { CStr::from_ptr(*file) }
+                .to_string_lossy()
+                .into_owned(),
+            line: *line,
+            column: *column,
+        });
+        frame = *parent;
+    }
+    stack_trace
+}
+
+impl Panic {
+    /// Returns the reason of the panic, e.g. `index out of bounds`.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the path of the source file that caused the panic, relative to
+    /// the source directory of its package.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the one-based line number in the source file that caused the
+    /// panic, or `0` if the line is unknown.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the Mun call stack at the time of the panic, starting with the
+    /// innermost frame. The stack trace is empty if the code was compiled
+    /// without debug information.
+    pub fn stack_trace(&self) -> &[StackFrame] {
+        &self.stack_trace
+    }
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "panicked at {}:{}: {}",
+            self.file, self.line, self.message
+        )?;
+        if !self.stack_trace.is_empty() {
+            write!(f, "\nstack backtrace:")?;
+            for (index, frame) in self.stack_trace.iter().enumerate() {
+                write!(f, "\n{index:>4}: {frame}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The `panic` intrinsic that is called by Mun code when a runtime check
+/// fails. It unwinds the stack up to the nearest [`catch_panic`].
+pub(crate) extern "C-unwind" fn panic(message: *const u8, file: *const u8, line: u32) {
+    // SAFETY: The Mun compiler always passes null-terminated strings.
+    let (message, file) = unsafe {
+        (
+            CStr::from_ptr(message.cast())
+                .to_string_lossy()
+                .into_owned(),
+            CStr::from_ptr(file.cast()).to_string_lossy().into_owned(),
+        )
+    };
+
+    // Using `resume_unwind` instead of `panic!` bypasses the panic hook, which
+    // would otherwise print the panic as if it occurred in the runtime.
+    resume_unwind(Box::new(Panic {
+        message,
+        file,
+        line,
+        stack_trace: capture_stack_trace(),
+    }))
+}
+
+/// Calls `f` and returns its result. If Mun code that is called from `f`
+/// panics, the stack is unwound up to this function and the [`Panic`] is
+/// returned instead. Panics that do not originate from Mun code are
+/// propagated.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    // The frames of Mun functions that are unwound are never popped, so the call
+    // stack is restored afterwards.
+    let call_stack = CALL_STACK.with(Cell::get);
+    let result = catch_unwind(AssertUnwindSafe(f));
+    CALL_STACK.with(|stack| stack.set(call_stack));
+
+    result.map_err(|payload| match payload.downcast::<Panic>() {
+        Ok(panic) => *panic,
+        Err(payload) => resume_unwind(payload),
+    })
+}
@@ -94,6 +94,11 @@ impl_primitive_type!(
     f32,
     f64,
     bool,
+    mun_abi::F32x2,
+    mun_abi::F32x4,
+    mun_abi::F64x2,
+    mun_abi::I32x2,
+    mun_abi::I32x4,
     ()
 );
 
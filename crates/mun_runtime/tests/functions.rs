@@ -117,7 +117,43 @@ fn multiple_match_exists_function() {
     assert_eq!(
         err.to_string(),
         format!(
-            "failed to obtain function '{EXPECTED_FN_NAME}', no such function exists. There is a function with a similar name: foobar_b"
+            "failed to obtain function '{EXPECTED_FN_NAME}', no such function exists. There is a function with a similar name: foobar_a"
         )
     );
 }
+
+#[test]
+fn multiple_match_exists_function_independent_of_registered_functions() {
+    const EXPECTED_FN_NAME: &str = "foobar";
+
+    extern "C" fn host_fn() -> i32 {
+        3
+    }
+
+    // Registering functions changes the iteration order of the dispatch table,
+    // but must not change which of the equally similar names is suggested.
+    for num_host_fns in [0, 1, 5, 20] {
+        let driver = CompileAndRunTestDriver::new(
+            r"
+    pub fn foobar_b() -> i32 { 4 }
+    pub fn foobar_a() -> i32 { 4 }
+    ",
+            |builder| {
+                (0..num_host_fns).fold(builder, |builder, idx| {
+                    builder.insert_fn(format!("host_fn_{idx}"), host_fn as extern "C" fn() -> i32)
+                })
+            },
+        )
+        .expect("Failed to build test driver");
+
+        let result: Result<i32, _> = driver.runtime.invoke(EXPECTED_FN_NAME, ());
+        let err = result.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "failed to obtain function '{EXPECTED_FN_NAME}', no such function exists. There is a function with a similar name: foobar_a"
+            )
+        );
+    }
+}
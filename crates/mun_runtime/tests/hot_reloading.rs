@@ -1,6 +1,7 @@
 #[macro_use]
 mod util;
 
+use mun_compiler::Config;
 use mun_runtime::StructRef;
 use mun_test::CompileAndRunTestDriver;
 
@@ -54,6 +55,47 @@ fn reloadable_function_multi_file() {
     assert_invoke_eq!(i32, 10, driver, "main");
 }
 
+#[test]
+fn reloadable_function_multi_file_lto() {
+    let mut driver = CompileAndRunTestDriver::from_fixture_with_compiler_config(
+        r#"
+    //- /mun.toml
+    [package]
+    name="foo"
+    version="0.0.0"
+
+    //- /src/mod.mun
+    use package::foo::{bar, baz};
+    pub fn main() -> i32 { bar() + baz() }
+
+    //- /src/foo.mun
+    pub fn bar() -> i32 { 5 }
+
+    #[hot_reload]
+    pub fn baz() -> i32 { 1 }
+    "#,
+        |config| Config {
+            lto: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+    assert_invoke_eq!(i32, 6, driver, "main");
+
+    // The inlined function is updated because its callers are recompiled as well
+    driver.update_file(
+        "foo.mun",
+        r#"
+    pub fn bar() -> i32 { 10 }
+
+    #[hot_reload]
+    pub fn baz() -> i32 { 2 }
+    "#,
+    );
+    assert_invoke_eq!(i32, 12, driver, "main");
+}
+
 #[test]
 fn reloadable_struct_decl_single_file() {
     let mut driver = CompileAndRunTestDriver::new(
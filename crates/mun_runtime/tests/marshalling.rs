@@ -1,4 +1,4 @@
-use mun_runtime::{ArgumentReflection, Marshal, ReturnTypeReflection, StructRef};
+use mun_runtime::{ArgumentReflection, F32x4, I32x2, Marshal, ReturnTypeReflection, StructRef};
 use mun_test::CompileAndRunTestDriver;
 
 #[macro_use]
@@ -735,3 +735,101 @@ fn signed_and_unsigned_rem() {
     assert_invoke_eq!(i32, -2, driver, "signed");
     assert_invoke_eq!(i32, 2, driver, "unsigned");
 }
+
+#[test]
+fn vectors() {
+    // Vectors are passed as 128-bit integers, the same way Mun passes them
+    #[allow(improper_ctypes_definitions)]
+    extern "C" fn splat(value: f32) -> F32x4 {
+        F32x4::from_array([value; 4])
+    }
+
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    extern fn splat(value: f32) -> f32x4;
+
+    pub struct Particle { position: f32x4 }
+
+    pub fn scale(v: f32x4, s: f32) -> f32x4 {
+        v * splat(s)
+    }
+
+    pub fn sum(v: f32x4) -> f32 {
+        v.x + v.y + v.z + v.w
+    }
+
+    pub fn swap(v: i32x2) -> i32x2 {
+        let swapped = i32x2(v.y, v.x);
+        swapped.x = swapped.x ^ 1;
+        swapped
+    }
+
+    pub fn particle_new(x: f32) -> Particle {
+        Particle { position: scale(f32x4(x, 1.0, 2.0, 3.0), 2.0) }
+    }
+
+    pub fn div_rem(a: i32x2, b: i32x2) -> i32x2 {
+        a / b + a % b
+    }
+
+    pub fn eq(a: f32x4, b: f32x4) -> bool {
+        a == b
+    }
+
+    pub fn neq(a: i32x2, b: i32x2) -> bool {
+        a != b
+    }
+    "#,
+        |builder| builder.insert_fn("splat", splat as extern "C" fn(f32) -> F32x4),
+    )
+    .expect("Failed to build test driver");
+
+    let v = F32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    assert_invoke_eq!(
+        F32x4,
+        F32x4::from_array([2.5, 5.0, 7.5, 10.0]),
+        driver,
+        "scale",
+        v,
+        2.5f32
+    );
+    assert_invoke_eq!(f32, 10.0, driver, "sum", v);
+    assert_invoke_eq!(
+        I32x2,
+        I32x2::from_array([3, -7]),
+        driver,
+        "swap",
+        I32x2::from_array([-7, 2])
+    );
+    assert_invoke_eq!(
+        I32x2,
+        I32x2::from_array([3 + 1, -3 - 1]),
+        driver,
+        "div_rem",
+        I32x2::from_array([7, -7]),
+        I32x2::from_array([2, 2])
+    );
+    assert_invoke_eq!(bool, true, driver, "eq", v, v);
+    assert_invoke_eq!(
+        bool,
+        false,
+        driver,
+        "eq",
+        v,
+        F32x4::from_array([1.0, 2.0, 3.0, 5.0])
+    );
+    let w = I32x2::from_array([1, 2]);
+    assert_invoke_eq!(bool, false, driver, "neq", w, w);
+    assert_invoke_eq!(bool, true, driver, "neq", w, I32x2::from_array([1, 3]));
+
+    let mut particle: StructRef<'_> = driver.runtime.invoke("particle_new", (0.5f32,)).unwrap();
+    assert_eq!(
+        particle.get::<F32x4>("position").unwrap(),
+        F32x4::from_array([1.0, 2.0, 4.0, 6.0])
+    );
+    particle
+        .set("position", F32x4::from_array([0.0, 0.0, 0.0, 1.0]))
+        .unwrap();
+    let position = particle.get::<F32x4>("position").unwrap();
+    assert_invoke_eq!(f32, 1.0, driver, "sum", position);
+}
@@ -0,0 +1,604 @@
+use mun_compiler::Config;
+use mun_runtime::{I32x2, I32x4};
+use mun_test::CompileAndRunTestDriver;
+
+#[test]
+fn index_out_of_bounds() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn get(index: i64) -> i32 {
+        let array = [1, 2, 3];
+        array[index]
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("get", (2i64,)).unwrap();
+    assert_eq!(result, 3);
+
+    for index in [3i64, -1, i64::MAX] {
+        let err = driver
+            .runtime
+            .invoke::<i32, _>("get", (index,))
+            .expect_err("indexing out of bounds must fail");
+        let panic = err.panic().expect("expected a panic");
+        assert_eq!(panic.message(), "index out of bounds");
+        assert_eq!(panic.file(), "mod.mun");
+        assert_eq!(panic.line(), 4);
+    }
+}
+
+#[test]
+fn division_by_zero() {
+    let mut driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn div(a: u32, b: u32) -> u32 { a / b }
+    pub fn rem(a: i8, b: i8) -> i8 { a % b }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: u32 = driver.runtime.invoke("div", (7u32, 2u32)).unwrap();
+    assert_eq!(result, 3);
+
+    let err = driver
+        .runtime
+        .invoke::<u32, _>("div", (7u32, 0u32))
+        .unwrap_err();
+    assert_eq!(err.panic().unwrap().message(), "attempt to divide by zero");
+    assert_eq!(
+        err.to_string(),
+        "function `div` panicked at mod.mun:2: attempt to divide by zero"
+    );
+
+    // The arguments were consumed by the invocation, so it cannot be retried
+    let err = err.retry::<u32>(&mut driver.runtime).unwrap_err();
+    assert_eq!(err.panic().unwrap().message(), "attempt to divide by zero");
+
+    // Waiting for a fix returns the panic instead of panicking the host
+    let err = err.try_wait::<u32>(&mut driver.runtime).unwrap_err();
+    assert_eq!(err.panic().unwrap().message(), "attempt to divide by zero");
+
+    let err = driver
+        .runtime
+        .invoke::<i8, _>("rem", (7i8, 0i8))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to calculate the remainder with a divisor of zero"
+    );
+}
+
+#[test]
+fn division_overflow() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn div(a: i32, b: i32) -> i32 { a / b }
+    pub fn rem(a: i8, b: i8) -> i8 { a % b }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("div", (i32::MIN, 1i32)).unwrap();
+    assert_eq!(result, i32::MIN);
+    let result: i32 = driver.runtime.invoke("div", (i32::MAX, -1i32)).unwrap();
+    assert_eq!(result, -i32::MAX);
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("div", (i32::MIN, -1i32))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "function `div` panicked at mod.mun:2: attempt to divide with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<i8, _>("rem", (i8::MIN, -1i8))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to calculate the remainder with overflow"
+    );
+}
+
+#[test]
+fn arithmetic_overflow() {
+    let source = r"
+    pub fn add(a: u8, b: u8) -> u8 { a + b }
+    pub fn sub(a: i32, b: i32) -> i32 { a - b }
+    pub fn mul(a: i64, b: i64) -> i64 {
+        let c = a;
+        c *= b;
+        c
+    }
+    ";
+
+    // Without overflow checks arithmetic wraps around
+    let driver = CompileAndRunTestDriver::new(source, |builder| builder)
+        .expect("Failed to build test driver");
+    let result: u8 = driver.runtime.invoke("add", (255u8, 1u8)).unwrap();
+    assert_eq!(result, 0);
+
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        source,
+        |config| Config {
+            overflow_checks: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: u8 = driver.runtime.invoke("add", (254u8, 1u8)).unwrap();
+    assert_eq!(result, 255);
+
+    let err = driver
+        .runtime
+        .invoke::<u8, _>("add", (255u8, 1u8))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "function `add` panicked at mod.mun:2: attempt to add with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("sub", (i32::MIN, 1i32))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to subtract with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<i64, _>("mul", (i64::MAX, 2i64))
+        .unwrap_err();
+    let panic = err.panic().unwrap();
+    assert_eq!(panic.message(), "attempt to multiply with overflow");
+    assert_eq!(panic.line(), 6);
+}
+
+#[test]
+fn vector_division() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn div(a: i32x2, b: i32x2) -> i32x2 { a / b }
+    pub fn rem(a: i32x4, b: i32x4) -> i32x4 {
+        let c = a;
+        c %= b;
+        c
+    }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let err = driver
+        .runtime
+        .invoke::<I32x2, _>(
+            "div",
+            (I32x2::from_array([4, 6]), I32x2::from_array([2, 0])),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "function `div` panicked at mod.mun:2: attempt to divide by zero"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<I32x2, _>(
+            "div",
+            (I32x2::from_array([4, i32::MIN]), I32x2::from_array([2, -1])),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to divide with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<I32x4, _>(
+            "rem",
+            (
+                I32x4::from_array([1, 2, 3, 4]),
+                I32x4::from_array([1, 1, 0, 1]),
+            ),
+        )
+        .unwrap_err();
+    let panic = err.panic().unwrap();
+    assert_eq!(
+        panic.message(),
+        "attempt to calculate the remainder with a divisor of zero"
+    );
+    assert_eq!(panic.line(), 5);
+}
+
+#[test]
+fn negation_shift_and_division_overflow() {
+    let source = r"
+    pub fn neg(a: i32) -> i32 { -a }
+    pub fn neg_vector(x: i32, y: i32) -> i32 {
+        let v = -i32x2(x, y);
+        v.x + v.y
+    }
+    pub fn shl(a: u8, b: u8) -> u8 { a << b }
+    pub fn shr(a: i64, b: i64) -> i64 {
+        let c = a;
+        c >>= b;
+        c
+    }
+    pub fn div(a: i16, b: i16) -> i16 { a / b }
+    ";
+
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        source,
+        |config| Config {
+            overflow_checks: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("neg", (i32::MAX,)).unwrap();
+    assert_eq!(result, -i32::MAX);
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("neg", (i32::MIN,))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "function `neg` panicked at mod.mun:2: attempt to negate with overflow"
+    );
+
+    let result: i32 = driver.runtime.invoke("neg_vector", (1i32, 2i32)).unwrap();
+    assert_eq!(result, -3);
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("neg_vector", (1i32, i32::MIN))
+        .unwrap_err();
+    let panic = err.panic().unwrap();
+    assert_eq!(panic.message(), "attempt to negate with overflow");
+    assert_eq!(panic.line(), 4);
+
+    let result: u8 = driver.runtime.invoke("shl", (1u8, 7u8)).unwrap();
+    assert_eq!(result, 128);
+    let err = driver
+        .runtime
+        .invoke::<u8, _>("shl", (1u8, 8u8))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to shift left with overflow"
+    );
+
+    let result: i64 = driver.runtime.invoke("shr", (-8i64, 2i64)).unwrap();
+    assert_eq!(result, -2);
+    for amount in [64i64, -1] {
+        let err = driver
+            .runtime
+            .invoke::<i64, _>("shr", (8i64, amount))
+            .unwrap_err();
+        let panic = err.panic().unwrap();
+        assert_eq!(panic.message(), "attempt to shift right with overflow");
+        assert_eq!(panic.line(), 10);
+    }
+
+    let err = driver
+        .runtime
+        .invoke::<i16, _>("div", (i16::MIN, -1i16))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to divide with overflow"
+    );
+}
+
+#[test]
+fn vector_overflow_and_shift() {
+    let source = r"
+    pub fn add(x: i32, y: i32) -> i32 {
+        let v = i32x2(x, y) + i32x2(1, 1);
+        v.x + v.y
+    }
+    pub fn mul(x: i32, y: i32) -> i32 {
+        let v = i32x4(x, y, 1, 1) * i32x4(2, 2, 2, 2);
+        v.x + v.y + v.z + v.w
+    }
+    pub fn shl(x: i32, y: i32) -> i32 {
+        let v = i32x2(1, 1) << i32x2(x, y);
+        v.x + v.y
+    }
+    ";
+
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        source,
+        |config| Config {
+            overflow_checks: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("add", (1i32, 2i32)).unwrap();
+    assert_eq!(result, 5);
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("add", (1i32, i32::MAX))
+        .unwrap_err();
+    let panic = err.panic().unwrap();
+    assert_eq!(panic.message(), "attempt to add with overflow");
+    assert_eq!(panic.line(), 3);
+
+    let result: i32 = driver.runtime.invoke("mul", (1i32, 2i32)).unwrap();
+    assert_eq!(result, 10);
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("mul", (i32::MAX, 2i32))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to multiply with overflow"
+    );
+
+    let result: i32 = driver.runtime.invoke("shl", (1i32, 2i32)).unwrap();
+    assert_eq!(result, 6);
+    for amount in [32i32, -1] {
+        let err = driver
+            .runtime
+            .invoke::<i32, _>("shl", (1i32, amount))
+            .unwrap_err();
+        let panic = err.panic().unwrap();
+        assert_eq!(panic.message(), "attempt to shift left with overflow");
+        assert_eq!(panic.line(), 11);
+    }
+}
+
+#[test]
+fn builtin_int_methods() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn wrapping_add(a: u8, b: u8) -> u8 { a.wrapping_add(b) }
+    pub fn wrapping_mul(a: i8, b: i8) -> i8 { a.wrapping_mul(b) }
+    pub fn checked_sub(a: u32, b: u32) -> u32 { a.checked_sub(b) }
+    pub fn saturating_add(a: i16, b: i16) -> i16 { a.saturating_add(b) }
+    pub fn saturating_mul(a: i32, b: i32) -> i32 { a.saturating_mul(b) }
+    pub fn saturating_mul_unsigned(a: u16, b: u16) -> u16 { a.saturating_mul(b) }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: u8 = driver.runtime.invoke("wrapping_add", (255u8, 2u8)).unwrap();
+    assert_eq!(result, 1);
+    let result: i8 = driver.runtime.invoke("wrapping_mul", (100i8, 2i8)).unwrap();
+    assert_eq!(result, 100i8.wrapping_mul(2));
+
+    let result: u32 = driver.runtime.invoke("checked_sub", (3u32, 2u32)).unwrap();
+    assert_eq!(result, 1);
+    let err = driver
+        .runtime
+        .invoke::<u32, _>("checked_sub", (2u32, 3u32))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "function `checked_sub` panicked at mod.mun:4: attempt to subtract with overflow"
+    );
+
+    let result: i16 = driver
+        .runtime
+        .invoke("saturating_add", (i16::MAX, 1i16))
+        .unwrap();
+    assert_eq!(result, i16::MAX);
+    let result: i16 = driver
+        .runtime
+        .invoke("saturating_add", (i16::MIN, -1i16))
+        .unwrap();
+    assert_eq!(result, i16::MIN);
+
+    for (a, b) in [(i32::MAX, 2), (i32::MIN, 2), (i32::MAX, -2), (1000, -1000)] {
+        let result: i32 = driver.runtime.invoke("saturating_mul", (a, b)).unwrap();
+        assert_eq!(result, a.saturating_mul(b));
+    }
+    let result: u16 = driver
+        .runtime
+        .invoke("saturating_mul_unsigned", (1000u16, 1000u16))
+        .unwrap();
+    assert_eq!(result, u16::MAX);
+}
+
+#[test]
+fn panic_unwinds_nested_calls() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    fn inner(array: [i32], index: usize) -> i32 { array[index] }
+    fn middle(index: usize) -> i32 { inner([1, 2], index) + 1 }
+    pub fn outer(index: usize) -> i32 { middle(index) * 2 }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("outer", (5usize,))
+        .unwrap_err();
+    assert_eq!(err.panic().unwrap().line(), 2);
+
+    // The runtime remains usable after a panic
+    let result: i32 = driver.runtime.invoke("outer", (1usize,)).unwrap();
+    assert_eq!(result, 6);
+}
+
+#[test]
+fn panic_stack_trace() {
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        r"
+    fn inner(array: [i32], index: usize) -> i32 { array[index] }
+    fn middle(index: usize) -> i32 {
+        let array = [1, 2];
+        inner(array, index) + 1
+    }
+    pub fn outer(index: usize) -> i32 { middle(index) * 2 }
+    ",
+        |config| Config {
+            debug_info: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let result: i32 = driver.runtime.invoke("outer", (1usize,)).unwrap();
+    assert_eq!(result, 6);
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("outer", (5usize,))
+        .unwrap_err();
+    let frames: Vec<_> = err
+        .panic()
+        .unwrap()
+        .stack_trace()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        frames,
+        [
+            "inner (mod.mun:2:51)",
+            "middle (mod.mun:5:9)",
+            "outer (mod.mun:7:41)"
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "function `outer` panicked at mod.mun:2: index out of bounds
+stack backtrace:
+   0: inner (mod.mun:2:51)
+   1: middle (mod.mun:5:9)
+   2: outer (mod.mun:7:41)"
+    );
+
+    // Frames of unwound functions do not leak into later panics
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("outer", (2usize,))
+        .unwrap_err();
+    assert_eq!(err.panic().unwrap().stack_trace().len(), 3);
+}
+
+#[test]
+fn panic_stack_trace_in_nested_module() {
+    let driver = CompileAndRunTestDriver::from_fixture_with_compiler_config(
+        r#"
+    //- /mun.toml
+    [package]
+    name="foo"
+    version="0.0.0"
+
+    //- /src/mod.mun
+    pub fn main(index: usize) -> i32 { package::foo::bar::get(index) }
+
+    //- /src/foo.mun
+    pub fn value() -> i32 { 1 }
+
+    //- /src/foo/bar.mun
+    pub fn get(index: usize) -> i32 { [1, 2][index] }
+    "#,
+        |config| Config {
+            debug_info: true,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("main", (5usize,))
+        .unwrap_err();
+    let panic = err.panic().unwrap();
+    assert_eq!(panic.file(), "foo/bar.mun");
+    let frames: Vec<_> = panic
+        .stack_trace()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        frames,
+        ["foo::bar::get (foo/bar.mun:1:35)", "main (mod.mun:1:36)"]
+    );
+}
+
+#[test]
+fn panic_without_debug_info_has_no_stack_trace() {
+    let driver = CompileAndRunTestDriver::new(
+        r"
+    pub fn get(index: usize) -> i32 { [1, 2][index] }
+    ",
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("get", (5usize,))
+        .unwrap_err();
+    assert!(err.panic().unwrap().stack_trace().is_empty());
+}
+
+#[test]
+fn overflow_checks_without_runtime_checks() {
+    let driver = CompileAndRunTestDriver::with_compiler_config(
+        r"
+    pub fn neg(a: i32) -> i32 { -a }
+    pub fn shl(a: u8, b: u8) -> u8 { a << b }
+    pub fn div(a: i8, b: i8) -> i8 { a / b }
+    ",
+        |config| Config {
+            overflow_checks: true,
+            runtime_checks: false,
+            ..config
+        },
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let err = driver
+        .runtime
+        .invoke::<i32, _>("neg", (i32::MIN,))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to negate with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<u8, _>("shl", (1u8, 8u8))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to shift left with overflow"
+    );
+
+    let err = driver
+        .runtime
+        .invoke::<i8, _>("div", (i8::MIN, -1i8))
+        .unwrap_err();
+    assert_eq!(
+        err.panic().unwrap().message(),
+        "attempt to divide with overflow"
+    );
+}
@@ -1,4 +1,4 @@
-use mun_runtime::LinkFunctionsError;
+use mun_runtime::{LinkFunctionsError, StructRef};
 use mun_test::CompileAndRunTestDriver;
 
 #[macro_use]
@@ -231,3 +231,45 @@ fn cyclic_struct() {
         .ty();
     assert_eq!(foo_foo_ty, foo_ty);
 }
+
+#[test]
+fn constants() {
+    let driver = CompileAndRunTestDriver::new(
+        r#"
+    pub struct(value) Point { x: f32, y: f32 }
+
+    pub const MAX_SPEED: f32 = 10.0 * 2.0;
+    pub const LIVES: i32 = { let a = 2; a + 1 };
+    pub const DEBUG: bool = LIVES > 5;
+    pub const ORIGIN: Point = Point { x: 1.0, y: -1.0 };
+    const SECRET: i32 = 42;
+
+    pub fn secret() -> i32 { SECRET }
+        "#,
+        |builder| builder,
+    )
+    .expect("Failed to build test driver");
+
+    let max_speed: f32 = driver.runtime.get_constant("MAX_SPEED").unwrap();
+    assert_eq!(max_speed, 20.0);
+    let lives: i32 = driver.runtime.get_constant("LIVES").unwrap();
+    assert_eq!(lives, 3);
+    let debug: bool = driver.runtime.get_constant("DEBUG").unwrap();
+    assert!(!debug);
+
+    let origin: StructRef<'_> = driver.runtime.get_constant("ORIGIN").unwrap();
+    assert_eq!(origin.get::<f32>("x").unwrap(), 1.0);
+    assert_eq!(origin.get::<f32>("y").unwrap(), -1.0);
+
+    // Private constants are only used by the code itself
+    assert!(driver.runtime.get_constant::<i32>("SECRET").is_err());
+    let secret: i32 = driver.runtime.invoke("secret", ()).unwrap();
+    assert_eq!(secret, 42);
+
+    // The type of the constant must match
+    let err = driver.runtime.get_constant::<i64>("LIVES").unwrap_err();
+    assert_eq!(
+        err,
+        "unexpected constant type, got 'core::i32', expected 'core::i64'"
+    );
+}
@@ -186,7 +186,8 @@ pub unsafe extern "C" fn mun_runtime_create(
         .collect::<Result<_, _>>());
 
     let runtime_options = mun_runtime::RuntimeOptions {
-        library_path: library_path.into(),
+        library_path: Some(library_path.into()),
+        static_assemblies: Vec::new(),
         user_functions,
         type_table,
     };
@@ -350,6 +351,34 @@ pub unsafe extern "C" fn mun_runtime_update(runtime: Runtime, updated: *mut bool
     ErrorHandle::default()
 }
 
+/// Calls `callback` with `user_data`. If Mun code that is invoked from within
+/// `callback` panics (e.g. because an array was indexed out of bounds), the
+/// stack is unwound up to this function and a non-zero error handle that
+/// describes the panic is returned. If the Mun code was compiled with debug
+/// information, the description includes a stack trace of the Mun functions
+/// that were active when the panic occurred.
+///
+/// If a non-zero error handle is returned, it must be manually destructed using
+/// [`mun_error_destroy`].
+///
+/// # Safety
+///
+/// This function receives raw pointers as parameters. If `callback` is a null
+/// pointer, an error will be returned. All stack frames between this function
+/// and the panicking Mun function must support unwinding, e.g. C++ code or C
+/// code that is compiled with `-fexceptions`.
+#[no_mangle]
+pub unsafe extern "C" fn mun_runtime_catch_panic(
+    callback: Option<unsafe extern "C-unwind" fn(*mut c_void)>,
+    user_data: *mut c_void,
+) -> ErrorHandle {
+    let callback = mun_error_try!(callback.ok_or("invalid argument 'callback': null pointer"));
+    match mun_runtime::catch_panic(|| callback(user_data)) {
+        Ok(()) => ErrorHandle::default(),
+        Err(panic) => ErrorHandle::new(panic.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{ffi::CString, mem::MaybeUninit, ptr};
@@ -358,6 +387,7 @@ mod tests {
         assert_error_snapshot, assert_getter1, assert_getter2, assert_getter3,
         error::mun_error_destroy,
     };
+    use mun_compiler::Config;
     use mun_memory::HasStaticType;
 
     use super::*;
@@ -931,4 +961,63 @@ mod tests {
 
         assert_getter1!(mun_runtime_update(driver.runtime, _updated));
     }
+
+    #[test]
+    fn test_runtime_catch_panic_invalid_callback() {
+        assert_error_snapshot!(
+            unsafe { mun_runtime_catch_panic(None, ptr::null_mut()) },
+            @r#""invalid argument \'callback\': null pointer""#
+        );
+    }
+
+    #[test]
+    fn test_runtime_catch_panic() {
+        unsafe extern "C-unwind" fn call_div(user_data: *mut c_void) {
+            let runtime = &*user_data.cast::<mun_runtime::Runtime>();
+            let div = runtime
+                .get_function_definition("div")
+                .expect("function `div` must exist");
+            let div: extern "C-unwind" fn(i32, i32) -> i32 = std::mem::transmute(div.fn_ptr);
+            div(1, 0);
+        }
+
+        let driver = TestDriver::new(
+            r#"
+        pub fn div(a: i32, b: i32) -> i32 { a / b }
+    "#,
+        );
+
+        assert_error_snapshot!(
+            unsafe { mun_runtime_catch_panic(Some(call_div), driver.runtime.0) },
+            @r#""panicked at mod.mun:2: attempt to divide by zero""#
+        );
+    }
+
+    #[test]
+    fn test_runtime_catch_panic_stack_trace() {
+        unsafe extern "C-unwind" fn call_outer(user_data: *mut c_void) {
+            let runtime = &*user_data.cast::<mun_runtime::Runtime>();
+            let outer = runtime
+                .get_function_definition("outer")
+                .expect("function `outer` must exist");
+            let outer: extern "C-unwind" fn(i32) -> i32 = std::mem::transmute(outer.fn_ptr);
+            outer(0);
+        }
+
+        let driver = TestDriver::with_config(
+            r#"
+        fn divide(a: i32, b: i32) -> i32 { a / b }
+        pub fn outer(b: i32) -> i32 { divide(1, b) }
+    "#,
+            |config| Config {
+                debug_info: true,
+                ..config
+            },
+        );
+
+        assert_error_snapshot!(
+            unsafe { mun_runtime_catch_panic(Some(call_outer), driver.runtime.0) },
+            @r#""panicked at mod.mun:2: attempt to divide by zero\nstack backtrace:\n   0: divide (mod.mun:2:44)\n   1: outer (mod.mun:3:39)""#
+        );
+    }
 }
@@ -14,11 +14,17 @@ pub(crate) struct TestDriver {
 impl TestDriver {
     /// Constructs a new `TestDriver` from Mun source
     pub fn new(text: &str) -> Self {
+        Self::with_config(text, |config| config)
+    }
+
+    /// Constructs a new `TestDriver` from Mun source and a `config_fn` that
+    /// allows modification of the compiler [`Config`].
+    pub fn with_config(text: &str, config_fn: impl FnOnce(Config) -> Config) -> Self {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let config = Config {
+        let config = config_fn(Config {
             out_dir: Some(temp_dir.path().to_path_buf()),
             ..Config::default()
-        };
+        });
         let input = PathOrInline::Inline {
             rel_path: RelativePathBuf::from("mod.mun"),
             contents: text.to_owned(),
@@ -265,3 +265,33 @@ impl ast::IndexExpr {
         children(self).nth(1)
     }
 }
+
+#[derive(Debug)]
+pub enum ArrayExprKind {
+    /// An array with explicit elements, e.g. `[1, 2, 3]`
+    ElementList(AstChildren<ast::Expr>),
+    /// An array with a repeated initializer, e.g. `[0; 4]`
+    Repeat {
+        initializer: Option<ast::Expr>,
+        repeat: Option<ast::Expr>,
+    },
+}
+
+impl ast::ArrayExpr {
+    pub fn kind(&self) -> ArrayExprKind {
+        if self.is_repeat() {
+            ArrayExprKind::Repeat {
+                initializer: children(self).next(),
+                repeat: children(self).nth(1),
+            }
+        } else {
+            ArrayExprKind::ElementList(children(self))
+        }
+    }
+
+    fn is_repeat(&self) -> bool {
+        self.syntax()
+            .children_with_tokens()
+            .any(|it| it.kind() == T![;])
+    }
+}
@@ -5,7 +5,7 @@ use rowan::{GreenNodeData, GreenTokenData, NodeOrToken};
 use text_size::TextRange;
 
 use crate::{
-    ast::{self, child_opt, AstNode, NameOwner},
+    ast::{self, child_opt, AstNode, NameOwner, TypeAscriptionOwner},
     SyntaxKind, SyntaxNode, TokenText, T,
 };
 
@@ -262,3 +262,31 @@ impl ast::TypeAliasDef {
         TextRange::new(start, end)
     }
 }
+
+impl ast::ConstDef {
+    /// Returns the signature range.
+    ///
+    /// ```rust, ignore
+    /// const FOO: i32 = 5;
+    /// ^^^^^^^^^^^^^^___ this part
+    /// ```
+    pub fn signature_range(&self) -> TextRange {
+        let const_kw = self
+            .syntax()
+            .children_with_tokens()
+            .find(|p| p.kind() == T![const])
+            .map(|kw| kw.text_range());
+        let name = self.name().map(|n| n.syntax.text_range());
+        let ty = self.ascribed_type().map(|ty| ty.syntax().text_range());
+
+        let start =
+            const_kw.map_or_else(|| self.syntax.text_range().start(), rowan::TextRange::start);
+
+        let end = ty
+            .or(name)
+            .or(const_kw)
+            .map_or_else(|| self.syntax().text_range().end(), rowan::TextRange::end);
+
+        TextRange::new(start, end)
+    }
+}
@@ -173,6 +173,38 @@ impl AssociatedItemList {
     }
 }
 
+// Attr
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attr {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for Attr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, ATTR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Attr { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl Attr {
+    pub fn path(&self) -> Option<Path> {
+        super::child_opt(self)
+    }
+
+    pub fn token_tree(&self) -> Option<TokenTree> {
+        super::child_opt(self)
+    }
+}
+
 // BinExpr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -347,6 +379,39 @@ impl Condition {
     }
 }
 
+// ConstDef
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstDef {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for ConstDef {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CONST_DEF)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ConstDef { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl ast::AttrsOwner for ConstDef {}
+impl ast::NameOwner for ConstDef {}
+impl ast::VisibilityOwner for ConstDef {}
+impl ast::DocCommentsOwner for ConstDef {}
+impl ast::TypeAscriptionOwner for ConstDef {}
+impl ConstDef {
+    pub fn body(&self) -> Option<Expr> {
+        super::child_opt(self)
+    }
+}
+
 // Expr
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -605,6 +670,7 @@ impl AstNode for FunctionDef {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for FunctionDef {}
 impl ast::NameOwner for FunctionDef {}
 impl ast::VisibilityOwner for FunctionDef {}
 impl ast::DocCommentsOwner for FunctionDef {}
@@ -673,6 +739,7 @@ impl AstNode for Impl {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for Impl {}
 impl ast::VisibilityOwner for Impl {}
 impl ast::DocCommentsOwner for Impl {}
 impl Impl {
@@ -859,7 +926,7 @@ impl AstNode for ModuleItem {
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            USE | FUNCTION_DEF | STRUCT_DEF | TYPE_ALIAS_DEF | IMPL
+            USE | FUNCTION_DEF | STRUCT_DEF | TYPE_ALIAS_DEF | CONST_DEF | IMPL
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -879,6 +946,7 @@ pub enum ModuleItemKind {
     FunctionDef(FunctionDef),
     StructDef(StructDef),
     TypeAliasDef(TypeAliasDef),
+    ConstDef(ConstDef),
     Impl(Impl),
 }
 impl From<Use> for ModuleItem {
@@ -901,6 +969,11 @@ impl From<TypeAliasDef> for ModuleItem {
         ModuleItem { syntax: n.syntax }
     }
 }
+impl From<ConstDef> for ModuleItem {
+    fn from(n: ConstDef) -> ModuleItem {
+        ModuleItem { syntax: n.syntax }
+    }
+}
 impl From<Impl> for ModuleItem {
     fn from(n: Impl) -> ModuleItem {
         ModuleItem { syntax: n.syntax }
@@ -918,6 +991,7 @@ impl ModuleItem {
             TYPE_ALIAS_DEF => {
                 ModuleItemKind::TypeAliasDef(TypeAliasDef::cast(self.syntax.clone()).unwrap())
             }
+            CONST_DEF => ModuleItemKind::ConstDef(ConstDef::cast(self.syntax.clone()).unwrap()),
             IMPL => ModuleItemKind::Impl(Impl::cast(self.syntax.clone()).unwrap()),
             _ => unreachable!(),
         }
@@ -1020,6 +1094,7 @@ impl AstNode for Param {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for Param {}
 impl ast::TypeAscriptionOwner for Param {}
 impl Param {
     pub fn pat(&self) -> Option<Pat> {
@@ -1361,6 +1436,7 @@ impl AstNode for RecordFieldDef {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for RecordFieldDef {}
 impl ast::NameOwner for RecordFieldDef {}
 impl ast::VisibilityOwner for RecordFieldDef {}
 impl ast::DocCommentsOwner for RecordFieldDef {}
@@ -1663,6 +1739,7 @@ impl AstNode for StructDef {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for StructDef {}
 impl ast::NameOwner for StructDef {}
 impl ast::VisibilityOwner for StructDef {}
 impl ast::DocCommentsOwner for StructDef {}
@@ -1672,6 +1749,30 @@ impl StructDef {
     }
 }
 
+// TokenTree
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenTree {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl AstNode for TokenTree {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, TOKEN_TREE)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TokenTree { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl TokenTree {}
+
 // TupleFieldDef
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1694,6 +1795,7 @@ impl AstNode for TupleFieldDef {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for TupleFieldDef {}
 impl ast::VisibilityOwner for TupleFieldDef {}
 impl TupleFieldDef {
     pub fn type_ref(&self) -> Option<TypeRef> {
@@ -1751,6 +1853,7 @@ impl AstNode for TypeAliasDef {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for TypeAliasDef {}
 impl ast::NameOwner for TypeAliasDef {}
 impl ast::VisibilityOwner for TypeAliasDef {}
 impl ast::DocCommentsOwner for TypeAliasDef {}
@@ -1839,6 +1942,7 @@ impl AstNode for Use {
         &self.syntax
     }
 }
+impl ast::AttrsOwner for Use {}
 impl ast::VisibilityOwner for Use {}
 impl Use {
     pub fn use_tree(&self) -> Option<UseTree> {
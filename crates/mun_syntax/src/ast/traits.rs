@@ -16,6 +16,12 @@ pub trait FunctionDefOwner: AstNode {
     }
 }
 
+pub trait AttrsOwner: AstNode {
+    fn attrs(&self) -> AstChildren<ast::Attr> {
+        children(self)
+    }
+}
+
 pub trait NameOwner: AstNode {
     fn name(&self) -> Option<ast::Name> {
         child_opt(self)
@@ -214,6 +214,7 @@ fn api_walkthrough() {
             ast::ModuleItemKind::FunctionDef(f) => func = Some(f),
             ast::ModuleItemKind::StructDef(_)
             | ast::ModuleItemKind::TypeAliasDef(_)
+            | ast::ModuleItemKind::ConstDef(_)
             | ast::ModuleItemKind::Use(_)
             | ast::ModuleItemKind::Impl(_) => (),
         }
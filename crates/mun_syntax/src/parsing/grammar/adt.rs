@@ -1,14 +1,16 @@
 use super::{
-    declarations, error_block, name, name_recovery, opt_visibility, types, Marker, Parser, EOF,
-    GC_KW, IDENT, MEMORY_TYPE_SPECIFIER, RECORD_FIELD_DEF, RECORD_FIELD_DEF_LIST, STRUCT_DEF,
-    TUPLE_FIELD_DEF, TUPLE_FIELD_DEF_LIST, TYPE_ALIAS_DEF, VALUE_KW, VISIBILITY_FIRST,
+    attributes, declarations, error_block, name, name_recovery, opt_visibility, types, Marker,
+    Parser, EOF, GC_KW, IDENT, MEMORY_TYPE_SPECIFIER, RECORD_FIELD_DEF, RECORD_FIELD_DEF_LIST,
+    STRUCT_DEF, TUPLE_FIELD_DEF, TUPLE_FIELD_DEF_LIST, TYPE_ALIAS_DEF, VALUE_KW, VISIBILITY_FIRST,
 };
 use crate::{
     parsing::{grammar::types::TYPE_FIRST, token_set::TokenSet},
     SyntaxKind::ERROR,
 };
 
-const TUPLE_FIELD_FIRST: TokenSet = types::TYPE_FIRST.union(VISIBILITY_FIRST);
+const TUPLE_FIELD_FIRST: TokenSet = types::TYPE_FIRST
+    .union(VISIBILITY_FIRST)
+    .union(TokenSet::new(&[T![#]]));
 
 pub(super) fn struct_def(p: &mut Parser<'_>, m: Marker) {
     assert!(p.at(T![struct]));
@@ -89,10 +91,12 @@ pub(super) fn tuple_field_def_list(p: &mut Parser<'_>) {
             p.error_and_bump("expected a tuple field");
             break;
         }
+        let has_attrs = p.at(T![#]);
+        attributes::outer_attrs(p);
         let has_vis = opt_visibility(p);
         if !p.at_ts(TYPE_FIRST) {
             p.error("expected a type");
-            if has_vis {
+            if has_attrs || has_vis {
                 m.complete(p, ERROR);
             } else {
                 m.abandon(p);
@@ -113,6 +117,7 @@ pub(super) fn tuple_field_def_list(p: &mut Parser<'_>) {
 
 fn record_field_def(p: &mut Parser<'_>) {
     let m = p.start();
+    attributes::outer_attrs(p);
     opt_visibility(p);
     if p.at(IDENT) {
         name(p);
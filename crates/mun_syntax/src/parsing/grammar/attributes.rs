@@ -0,0 +1,60 @@
+use super::{paths, Parser, ATTR, EOF, TOKEN_TREE};
+
+/// Parses all attributes that precede a declaration, field or parameter, e.g.
+/// `#[allow(dead_code)]`.
+pub(super) fn outer_attrs(p: &mut Parser<'_>) {
+    while p.at(T![#]) {
+        attr(p);
+    }
+}
+
+fn attr(p: &mut Parser<'_>) {
+    assert!(p.at(T![#]));
+    let m = p.start();
+    p.bump(T![#]);
+
+    if p.expect(T!['[']) {
+        if paths::is_path_start(p) {
+            paths::expr_path(p);
+        } else {
+            p.error("expected an attribute name");
+        }
+
+        if p.at(T!['(']) {
+            token_tree(p);
+        }
+
+        p.expect(T![']']);
+    }
+
+    m.complete(p, ATTR);
+}
+
+/// Parses the arguments of an attribute as an arbitrary sequence of tokens
+/// with balanced delimiters, e.g. `(dead_code, unused_variables)`. Parsing
+/// stops at the first closing delimiter that does not match.
+fn token_tree(p: &mut Parser<'_>) {
+    assert!(p.at(T!['(']));
+    let m = p.start();
+    p.bump(T!['(']);
+
+    let mut delimiters = Vec::new();
+    while !p.at(EOF) {
+        match p.current() {
+            T!['('] => delimiters.push(T![')']),
+            T!['['] => delimiters.push(T![']']),
+            T!['{'] => delimiters.push(T!['}']),
+            kind @ (T![')'] | T![']'] | T!['}']) => {
+                if delimiters.last() != Some(&kind) {
+                    break;
+                }
+                delimiters.pop();
+            }
+            _ => (),
+        }
+        p.bump_any();
+    }
+
+    p.expect(T![')']);
+    m.complete(p, TOKEN_TREE);
+}
@@ -1,12 +1,20 @@
 use super::{
-    adt, error_block, expressions, name, name_recovery, opt_visibility, params, paths, traits,
-    types, Marker, Parser, TokenSet, EOF, ERROR, EXTERN, FUNCTION_DEF, RENAME, RET_TYPE, USE,
-    USE_TREE, USE_TREE_LIST,
+    adt, attributes, error_block, expressions, name, name_recovery, opt_visibility, params, paths,
+    traits, types, Marker, Parser, TokenSet, CONST_DEF, EOF, ERROR, EXTERN, FUNCTION_DEF, RENAME,
+    RET_TYPE, USE, USE_TREE, USE_TREE_LIST,
 };
 use crate::{parsing::grammar::paths::is_use_path_start, T};
 
-pub(super) const DECLARATION_RECOVERY_SET: TokenSet =
-    TokenSet::new(&[T![fn], T![pub], T![struct], T![use], T![;], T![impl]]);
+pub(super) const DECLARATION_RECOVERY_SET: TokenSet = TokenSet::new(&[
+    T![fn],
+    T![pub],
+    T![struct],
+    T![use],
+    T![;],
+    T![impl],
+    T![const],
+    T![#],
+]);
 
 pub(super) fn mod_contents(p: &mut Parser<'_>) {
     while !p.at(EOF) {
@@ -16,6 +24,7 @@ pub(super) fn mod_contents(p: &mut Parser<'_>) {
 
 pub(super) fn declaration(p: &mut Parser<'_>, stop_on_r_curly: bool) {
     let m = p.start();
+    attributes::outer_attrs(p);
     let m = match maybe_declaration(p, m) {
         Ok(()) => return,
         Err(m) => m,
@@ -75,6 +84,9 @@ fn declarations_without_modifiers(p: &mut Parser<'_>, m: Marker) -> Result<(), M
         T![type] => {
             adt::type_alias_def(p, m);
         }
+        T![const] => {
+            const_def(p, m);
+        }
         T![impl] => {
             traits::impl_(p, m);
         }
@@ -116,6 +128,28 @@ fn opt_fn_ret_type(p: &mut Parser<'_>) -> bool {
     }
 }
 
+/// Parses a constant item, e.g. `const FOO: i32 = 5;`
+fn const_def(p: &mut Parser<'_>, m: Marker) {
+    assert!(p.at(T![const]));
+    p.bump(T![const]);
+    name_recovery(
+        p,
+        DECLARATION_RECOVERY_SET.union(TokenSet::new(&[T![:], T![=]])),
+    );
+    if p.at(T![:]) {
+        types::ascription(p);
+    } else {
+        p.error("missing type for `const`");
+    }
+    if p.eat(T![=]) {
+        expressions::expr(p);
+    } else {
+        p.error("expected `=`");
+    }
+    p.expect(T![;]);
+    m.complete(p, CONST_DEF);
+}
+
 fn use_(p: &mut Parser<'_>, m: Marker) {
     assert!(p.at(T![use]));
     p.bump(T![use]);
@@ -465,8 +465,16 @@ fn array_expr(p: &mut Parser<'_>) -> CompletedMarker {
     let m = p.start();
 
     p.bump(T!['[']);
+    let mut n_exprs = 0;
     while !p.at(EOF) && !p.at(T![']']) {
         expr(p);
+        n_exprs += 1;
+
+        // An array with a repeated initializer, e.g. `[0; 4]`
+        if n_exprs == 1 && p.eat(T![;]) {
+            expr(p);
+            break;
+        }
 
         if !p.at(T![']']) && !p.expect(T![,]) {
             break;
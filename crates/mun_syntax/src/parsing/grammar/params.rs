@@ -1,4 +1,6 @@
-use super::{patterns, types, Parser, TokenSet, EOF, NAME, PARAM, PARAM_LIST, SELF_PARAM};
+use super::{
+    attributes, patterns, types, Parser, TokenSet, EOF, NAME, PARAM, PARAM_LIST, SELF_PARAM,
+};
 
 pub(super) fn param_list(p: &mut Parser<'_>) {
     list(p);
@@ -26,10 +28,11 @@ fn list(p: &mut Parser<'_>) {
     m.complete(p, PARAM_LIST);
 }
 
-const VALUE_PARAMETER_FIRST: TokenSet = patterns::PATTERN_FIRST;
+const VALUE_PARAMETER_FIRST: TokenSet = patterns::PATTERN_FIRST.union(TokenSet::new(&[T![#]]));
 
 fn param(p: &mut Parser<'_>) {
     let m = p.start();
+    attributes::outer_attrs(p);
     patterns::pattern(p);
     types::ascription(p);
     m.complete(p, PARAM);
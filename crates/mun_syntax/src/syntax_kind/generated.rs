@@ -98,6 +98,7 @@ pub enum SyntaxKind {
     SELF_KW,
     EXTERN_KW,
     IMPL_KW,
+    CONST_KW,
     INT_NUMBER,
     FLOAT_NUMBER,
     STRING,
@@ -118,6 +119,7 @@ pub enum SyntaxKind {
     SELF_PARAM,
     STRUCT_DEF,
     TYPE_ALIAS_DEF,
+    CONST_DEF,
     MEMORY_TYPE_SPECIFIER,
     RECORD_FIELD_DEF_LIST,
     RECORD_FIELD_DEF,
@@ -162,6 +164,8 @@ pub enum SyntaxKind {
     IMPL,
     ASSOCIATED_ITEM_LIST,
     ASSOCIATED_ITEM,
+    ATTR,
+    TOKEN_TREE,
     // Technical kind so that we can cast from u16 safely
     #[doc(hidden)]
     __LAST,
@@ -392,6 +396,9 @@ macro_rules! T {
     (impl) => {
         $crate::SyntaxKind::IMPL_KW
     };
+    (const) => {
+        $crate::SyntaxKind::CONST_KW
+    };
 }
 
 impl From<u16> for SyntaxKind {
@@ -438,6 +445,7 @@ impl SyntaxKind {
         | SELF_KW
         | EXTERN_KW
         | IMPL_KW
+        | CONST_KW
         )
     }
 
@@ -580,6 +588,7 @@ impl SyntaxKind {
             SELF_KW => &SyntaxInfo { name: "SELF_KW" },
             EXTERN_KW => &SyntaxInfo { name: "EXTERN_KW" },
             IMPL_KW => &SyntaxInfo { name: "IMPL_KW" },
+            CONST_KW => &SyntaxInfo { name: "CONST_KW" },
             INT_NUMBER => &SyntaxInfo { name: "INT_NUMBER" },
             FLOAT_NUMBER => &SyntaxInfo { name: "FLOAT_NUMBER" },
             STRING => &SyntaxInfo { name: "STRING" },
@@ -600,6 +609,7 @@ impl SyntaxKind {
             SELF_PARAM => &SyntaxInfo { name: "SELF_PARAM" },
             STRUCT_DEF => &SyntaxInfo { name: "STRUCT_DEF" },
             TYPE_ALIAS_DEF => &SyntaxInfo { name: "TYPE_ALIAS_DEF" },
+            CONST_DEF => &SyntaxInfo { name: "CONST_DEF" },
             MEMORY_TYPE_SPECIFIER => &SyntaxInfo { name: "MEMORY_TYPE_SPECIFIER" },
             RECORD_FIELD_DEF_LIST => &SyntaxInfo { name: "RECORD_FIELD_DEF_LIST" },
             RECORD_FIELD_DEF => &SyntaxInfo { name: "RECORD_FIELD_DEF" },
@@ -644,6 +654,8 @@ impl SyntaxKind {
             IMPL => &SyntaxInfo { name: "IMPL" },
             ASSOCIATED_ITEM_LIST => &SyntaxInfo { name: "ASSOCIATED_ITEM_LIST" },
             ASSOCIATED_ITEM => &SyntaxInfo { name: "ASSOCIATED_ITEM" },
+            ATTR => &SyntaxInfo { name: "ATTR" },
+            TOKEN_TREE => &SyntaxInfo { name: "TOKEN_TREE" },
             TOMBSTONE => &SyntaxInfo { name: "TOMBSTONE" },
             EOF => &SyntaxInfo { name: "EOF" },
             __LAST => &SyntaxInfo { name: "__LAST" },
@@ -679,6 +691,7 @@ impl SyntaxKind {
             "self" => SELF_KW,
             "extern" => EXTERN_KW,
             "impl" => IMPL_KW,
+            "const" => CONST_KW,
             _ => return None,
         };
         Some(kw)
@@ -3688,3 +3688,513 @@ fn use_() {
     error Offset(369): expected a declaration
     "#);
 }
+
+#[test]
+fn attributes() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+        #[allow(dead_code)]
+        #[inline]
+        fn foo(#[allow(unused_variables)] a: i32) {}
+
+        struct Foo {
+            #[deprecated(note = "unused")]
+            a: i32,
+        }
+        struct Bar(#[allow(dead_code)] pub i32, i32);
+
+        #[allow(dead_code)]
+        impl Foo {
+            #[warn(unused_variables, dead_code)]
+            pub fn bar(self) {}
+        }
+
+        #[allow(unused_imports)]
+        use foo::Bar;
+        "#).debug_dump(), @r##"
+            SOURCE_FILE@0..452
+              FUNCTION_DEF@0..99
+                WHITESPACE@0..9 "\n        "
+                ATTR@9..28
+                  HASH@9..10 "#"
+                  L_BRACKET@10..11 "["
+                  PATH@11..16
+                    PATH_SEGMENT@11..16
+                      NAME_REF@11..16
+                        IDENT@11..16 "allow"
+                  TOKEN_TREE@16..27
+                    L_PAREN@16..17 "("
+                    IDENT@17..26 "dead_code"
+                    R_PAREN@26..27 ")"
+                  R_BRACKET@27..28 "]"
+                WHITESPACE@28..37 "\n        "
+                ATTR@37..46
+                  HASH@37..38 "#"
+                  L_BRACKET@38..39 "["
+                  PATH@39..45
+                    PATH_SEGMENT@39..45
+                      NAME_REF@39..45
+                        IDENT@39..45 "inline"
+                  R_BRACKET@45..46 "]"
+                WHITESPACE@46..55 "\n        "
+                FN_KW@55..57 "fn"
+                WHITESPACE@57..58 " "
+                NAME@58..61
+                  IDENT@58..61 "foo"
+                PARAM_LIST@61..96
+                  L_PAREN@61..62 "("
+                  PARAM@62..95
+                    ATTR@62..88
+                      HASH@62..63 "#"
+                      L_BRACKET@63..64 "["
+                      PATH@64..69
+                        PATH_SEGMENT@64..69
+                          NAME_REF@64..69
+                            IDENT@64..69 "allow"
+                      TOKEN_TREE@69..87
+                        L_PAREN@69..70 "("
+                        IDENT@70..86 "unused_variables"
+                        R_PAREN@86..87 ")"
+                      R_BRACKET@87..88 "]"
+                    WHITESPACE@88..89 " "
+                    BIND_PAT@89..90
+                      NAME@89..90
+                        IDENT@89..90 "a"
+                    COLON@90..91 ":"
+                    WHITESPACE@91..92 " "
+                    PATH_TYPE@92..95
+                      PATH@92..95
+                        PATH_SEGMENT@92..95
+                          NAME_REF@92..95
+                            IDENT@92..95 "i32"
+                  R_PAREN@95..96 ")"
+                WHITESPACE@96..97 " "
+                BLOCK_EXPR@97..99
+                  L_CURLY@97..98 "{"
+                  R_CURLY@98..99 "}"
+              WHITESPACE@99..109 "\n\n        "
+              STRUCT_DEF@109..194
+                STRUCT_KW@109..115 "struct"
+                WHITESPACE@115..116 " "
+                NAME@116..119
+                  IDENT@116..119 "Foo"
+                WHITESPACE@119..120 " "
+                RECORD_FIELD_DEF_LIST@120..194
+                  L_CURLY@120..121 "{"
+                  WHITESPACE@121..134 "\n            "
+                  RECORD_FIELD_DEF@134..183
+                    ATTR@134..164
+                      HASH@134..135 "#"
+                      L_BRACKET@135..136 "["
+                      PATH@136..146
+                        PATH_SEGMENT@136..146
+                          NAME_REF@136..146
+                            IDENT@136..146 "deprecated"
+                      TOKEN_TREE@146..163
+                        L_PAREN@146..147 "("
+                        IDENT@147..151 "note"
+                        WHITESPACE@151..152 " "
+                        EQ@152..153 "="
+                        WHITESPACE@153..154 " "
+                        STRING@154..162 "\"unused\""
+                        R_PAREN@162..163 ")"
+                      R_BRACKET@163..164 "]"
+                    WHITESPACE@164..177 "\n            "
+                    NAME@177..178
+                      IDENT@177..178 "a"
+                    COLON@178..179 ":"
+                    WHITESPACE@179..180 " "
+                    PATH_TYPE@180..183
+                      PATH@180..183
+                        PATH_SEGMENT@180..183
+                          NAME_REF@180..183
+                            IDENT@180..183 "i32"
+                  COMMA@183..184 ","
+                  WHITESPACE@184..193 "\n        "
+                  R_CURLY@193..194 "}"
+              WHITESPACE@194..203 "\n        "
+              STRUCT_DEF@203..248
+                STRUCT_KW@203..209 "struct"
+                WHITESPACE@209..210 " "
+                NAME@210..213
+                  IDENT@210..213 "Bar"
+                TUPLE_FIELD_DEF_LIST@213..248
+                  L_PAREN@213..214 "("
+                  TUPLE_FIELD_DEF@214..241
+                    ATTR@214..233
+                      HASH@214..215 "#"
+                      L_BRACKET@215..216 "["
+                      PATH@216..221
+                        PATH_SEGMENT@216..221
+                          NAME_REF@216..221
+                            IDENT@216..221 "allow"
+                      TOKEN_TREE@221..232
+                        L_PAREN@221..222 "("
+                        IDENT@222..231 "dead_code"
+                        R_PAREN@231..232 ")"
+                      R_BRACKET@232..233 "]"
+                    WHITESPACE@233..234 " "
+                    VISIBILITY@234..237
+                      PUB_KW@234..237 "pub"
+                    WHITESPACE@237..238 " "
+                    PATH_TYPE@238..241
+                      PATH@238..241
+                        PATH_SEGMENT@238..241
+                          NAME_REF@238..241
+                            IDENT@238..241 "i32"
+                  COMMA@241..242 ","
+                  WHITESPACE@242..243 " "
+                  TUPLE_FIELD_DEF@243..246
+                    PATH_TYPE@243..246
+                      PATH@243..246
+                        PATH_SEGMENT@243..246
+                          NAME_REF@243..246
+                            IDENT@243..246 "i32"
+                  R_PAREN@246..247 ")"
+                  SEMI@247..248 ";"
+              WHITESPACE@248..258 "\n\n        "
+              IMPL@258..387
+                ATTR@258..277
+                  HASH@258..259 "#"
+                  L_BRACKET@259..260 "["
+                  PATH@260..265
+                    PATH_SEGMENT@260..265
+                      NAME_REF@260..265
+                        IDENT@260..265 "allow"
+                  TOKEN_TREE@265..276
+                    L_PAREN@265..266 "("
+                    IDENT@266..275 "dead_code"
+                    R_PAREN@275..276 ")"
+                  R_BRACKET@276..277 "]"
+                WHITESPACE@277..286 "\n        "
+                IMPL_KW@286..290 "impl"
+                WHITESPACE@290..291 " "
+                PATH_TYPE@291..294
+                  PATH@291..294
+                    PATH_SEGMENT@291..294
+                      NAME_REF@291..294
+                        IDENT@291..294 "Foo"
+                WHITESPACE@294..295 " "
+                ASSOCIATED_ITEM_LIST@295..387
+                  L_CURLY@295..296 "{"
+                  FUNCTION_DEF@296..377
+                    WHITESPACE@296..309 "\n            "
+                    ATTR@309..345
+                      HASH@309..310 "#"
+                      L_BRACKET@310..311 "["
+                      PATH@311..315
+                        PATH_SEGMENT@311..315
+                          NAME_REF@311..315
+                            IDENT@311..315 "warn"
+                      TOKEN_TREE@315..344
+                        L_PAREN@315..316 "("
+                        IDENT@316..332 "unused_variables"
+                        COMMA@332..333 ","
+                        WHITESPACE@333..334 " "
+                        IDENT@334..343 "dead_code"
+                        R_PAREN@343..344 ")"
+                      R_BRACKET@344..345 "]"
+                    WHITESPACE@345..358 "\n            "
+                    VISIBILITY@358..361
+                      PUB_KW@358..361 "pub"
+                    WHITESPACE@361..362 " "
+                    FN_KW@362..364 "fn"
+                    WHITESPACE@364..365 " "
+                    NAME@365..368
+                      IDENT@365..368 "bar"
+                    PARAM_LIST@368..374
+                      L_PAREN@368..369 "("
+                      SELF_PARAM@369..373
+                        NAME@369..373
+                          SELF_KW@369..373 "self"
+                      R_PAREN@373..374 ")"
+                    WHITESPACE@374..375 " "
+                    BLOCK_EXPR@375..377
+                      L_CURLY@375..376 "{"
+                      R_CURLY@376..377 "}"
+                  WHITESPACE@377..386 "\n        "
+                  R_CURLY@386..387 "}"
+              WHITESPACE@387..397 "\n\n        "
+              USE@397..443
+                ATTR@397..421
+                  HASH@397..398 "#"
+                  L_BRACKET@398..399 "["
+                  PATH@399..404
+                    PATH_SEGMENT@399..404
+                      NAME_REF@399..404
+                        IDENT@399..404 "allow"
+                  TOKEN_TREE@404..420
+                    L_PAREN@404..405 "("
+                    IDENT@405..419 "unused_imports"
+                    R_PAREN@419..420 ")"
+                  R_BRACKET@420..421 "]"
+                WHITESPACE@421..430 "\n        "
+                USE_KW@430..433 "use"
+                WHITESPACE@433..434 " "
+                USE_TREE@434..442
+                  PATH@434..442
+                    PATH@434..437
+                      PATH_SEGMENT@434..437
+                        NAME_REF@434..437
+                          IDENT@434..437 "foo"
+                    COLONCOLON@437..439 "::"
+                    PATH_SEGMENT@439..442
+                      NAME_REF@439..442
+                        IDENT@439..442 "Bar"
+                SEMI@442..443 ";"
+              WHITESPACE@443..452 "\n        "
+            "##);
+}
+
+#[test]
+fn attributes_errors() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+        #[]
+        fn foo() {}
+        #[allow(a, (b]
+        fn bar() {}
+        #
+        "#).debug_dump(), @r##"
+            SOURCE_FILE@0..94
+              FUNCTION_DEF@0..32
+                WHITESPACE@0..9 "\n        "
+                ATTR@9..12
+                  HASH@9..10 "#"
+                  L_BRACKET@10..11 "["
+                  R_BRACKET@11..12 "]"
+                WHITESPACE@12..21 "\n        "
+                FN_KW@21..23 "fn"
+                WHITESPACE@23..24 " "
+                NAME@24..27
+                  IDENT@24..27 "foo"
+                PARAM_LIST@27..29
+                  L_PAREN@27..28 "("
+                  R_PAREN@28..29 ")"
+                WHITESPACE@29..30 " "
+                BLOCK_EXPR@30..32
+                  L_CURLY@30..31 "{"
+                  R_CURLY@31..32 "}"
+              FUNCTION_DEF@32..75
+                WHITESPACE@32..41 "\n        "
+                ATTR@41..55
+                  HASH@41..42 "#"
+                  L_BRACKET@42..43 "["
+                  PATH@43..48
+                    PATH_SEGMENT@43..48
+                      NAME_REF@43..48
+                        IDENT@43..48 "allow"
+                  TOKEN_TREE@48..54
+                    L_PAREN@48..49 "("
+                    IDENT@49..50 "a"
+                    COMMA@50..51 ","
+                    WHITESPACE@51..52 " "
+                    L_PAREN@52..53 "("
+                    IDENT@53..54 "b"
+                  R_BRACKET@54..55 "]"
+                WHITESPACE@55..64 "\n        "
+                FN_KW@64..66 "fn"
+                WHITESPACE@66..67 " "
+                NAME@67..70
+                  IDENT@67..70 "bar"
+                PARAM_LIST@70..72
+                  L_PAREN@70..71 "("
+                  R_PAREN@71..72 ")"
+                WHITESPACE@72..73 " "
+                BLOCK_EXPR@73..75
+                  L_CURLY@73..74 "{"
+                  R_CURLY@74..75 "}"
+              WHITESPACE@75..84 "\n        "
+              ATTR@84..85
+                HASH@84..85 "#"
+              WHITESPACE@85..94 "\n        "
+            error Offset(11): expected an attribute name
+            error Offset(54): expected R_PAREN
+            error Offset(85): expected L_BRACKET
+            error Offset(85): expected a declaration
+            "##);
+}
+
+#[test]
+fn const_def() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    const FOO: i32 = 5;
+    pub const BAR: i32 = FOO * 2;
+    const BAZ = 3;
+    const QUX: u8;
+    "#,
+    ).debug_dump(), @r#"
+    SOURCE_FILE@0..101
+      WHITESPACE@0..5 "\n    "
+      CONST_DEF@5..24
+        CONST_KW@5..10 "const"
+        WHITESPACE@10..11 " "
+        NAME@11..14
+          IDENT@11..14 "FOO"
+        COLON@14..15 ":"
+        WHITESPACE@15..16 " "
+        PATH_TYPE@16..19
+          PATH@16..19
+            PATH_SEGMENT@16..19
+              NAME_REF@16..19
+                IDENT@16..19 "i32"
+        WHITESPACE@19..20 " "
+        EQ@20..21 "="
+        WHITESPACE@21..22 " "
+        LITERAL@22..23
+          INT_NUMBER@22..23 "5"
+        SEMI@23..24 ";"
+      WHITESPACE@24..29 "\n    "
+      CONST_DEF@29..58
+        VISIBILITY@29..32
+          PUB_KW@29..32 "pub"
+        WHITESPACE@32..33 " "
+        CONST_KW@33..38 "const"
+        WHITESPACE@38..39 " "
+        NAME@39..42
+          IDENT@39..42 "BAR"
+        COLON@42..43 ":"
+        WHITESPACE@43..44 " "
+        PATH_TYPE@44..47
+          PATH@44..47
+            PATH_SEGMENT@44..47
+              NAME_REF@44..47
+                IDENT@44..47 "i32"
+        WHITESPACE@47..48 " "
+        EQ@48..49 "="
+        WHITESPACE@49..50 " "
+        BIN_EXPR@50..57
+          PATH_EXPR@50..53
+            PATH@50..53
+              PATH_SEGMENT@50..53
+                NAME_REF@50..53
+                  IDENT@50..53 "FOO"
+          WHITESPACE@53..54 " "
+          STAR@54..55 "*"
+          WHITESPACE@55..56 " "
+          LITERAL@56..57
+            INT_NUMBER@56..57 "2"
+        SEMI@57..58 ";"
+      WHITESPACE@58..63 "\n    "
+      CONST_DEF@63..77
+        CONST_KW@63..68 "const"
+        WHITESPACE@68..69 " "
+        NAME@69..72
+          IDENT@69..72 "BAZ"
+        WHITESPACE@72..73 " "
+        EQ@73..74 "="
+        WHITESPACE@74..75 " "
+        LITERAL@75..76
+          INT_NUMBER@75..76 "3"
+        SEMI@76..77 ";"
+      WHITESPACE@77..82 "\n    "
+      CONST_DEF@82..96
+        CONST_KW@82..87 "const"
+        WHITESPACE@87..88 " "
+        NAME@88..91
+          IDENT@88..91 "QUX"
+        COLON@91..92 ":"
+        WHITESPACE@92..93 " "
+        PATH_TYPE@93..95
+          PATH@93..95
+            PATH_SEGMENT@93..95
+              NAME_REF@93..95
+                IDENT@93..95 "u8"
+        SEMI@95..96 ";"
+      WHITESPACE@96..101 "\n    "
+    error Offset(72): missing type for `const`
+    error Offset(95): expected `=`
+    "#);
+}
+
+#[test]
+fn array_repeat_expr() {
+    insta::assert_snapshot!(SourceFile::parse(
+        r#"
+    fn main() {
+        let a = [0; 4]
+        let b = [Foo { a: 1 }; LEN * 2]
+    }"#,
+    ).debug_dump(), @r#"
+    SOURCE_FILE@0..85
+      FUNCTION_DEF@0..85
+        WHITESPACE@0..5 "\n    "
+        FN_KW@5..7 "fn"
+        WHITESPACE@7..8 " "
+        NAME@8..12
+          IDENT@8..12 "main"
+        PARAM_LIST@12..14
+          L_PAREN@12..13 "("
+          R_PAREN@13..14 ")"
+        WHITESPACE@14..15 " "
+        BLOCK_EXPR@15..85
+          L_CURLY@15..16 "{"
+          WHITESPACE@16..25 "\n        "
+          LET_STMT@25..39
+            LET_KW@25..28 "let"
+            WHITESPACE@28..29 " "
+            BIND_PAT@29..30
+              NAME@29..30
+                IDENT@29..30 "a"
+            WHITESPACE@30..31 " "
+            EQ@31..32 "="
+            WHITESPACE@32..33 " "
+            ARRAY_EXPR@33..39
+              L_BRACKET@33..34 "["
+              LITERAL@34..35
+                INT_NUMBER@34..35 "0"
+              SEMI@35..36 ";"
+              WHITESPACE@36..37 " "
+              LITERAL@37..38
+                INT_NUMBER@37..38 "4"
+              R_BRACKET@38..39 "]"
+          WHITESPACE@39..48 "\n        "
+          LET_STMT@48..79
+            LET_KW@48..51 "let"
+            WHITESPACE@51..52 " "
+            BIND_PAT@52..53
+              NAME@52..53
+                IDENT@52..53 "b"
+            WHITESPACE@53..54 " "
+            EQ@54..55 "="
+            WHITESPACE@55..56 " "
+            ARRAY_EXPR@56..79
+              L_BRACKET@56..57 "["
+              RECORD_LIT@57..69
+                PATH_TYPE@57..60
+                  PATH@57..60
+                    PATH_SEGMENT@57..60
+                      NAME_REF@57..60
+                        IDENT@57..60 "Foo"
+                WHITESPACE@60..61 " "
+                RECORD_FIELD_LIST@61..69
+                  L_CURLY@61..62 "{"
+                  WHITESPACE@62..63 " "
+                  RECORD_FIELD@63..67
+                    NAME_REF@63..64
+                      IDENT@63..64 "a"
+                    COLON@64..65 ":"
+                    WHITESPACE@65..66 " "
+                    LITERAL@66..67
+                      INT_NUMBER@66..67 "1"
+                  WHITESPACE@67..68 " "
+                  R_CURLY@68..69 "}"
+              SEMI@69..70 ";"
+              WHITESPACE@70..71 " "
+              BIN_EXPR@71..78
+                PATH_EXPR@71..74
+                  PATH@71..74
+                    PATH_SEGMENT@71..74
+                      NAME_REF@71..74
+                        IDENT@71..74 "LEN"
+                WHITESPACE@74..75 " "
+                STAR@75..76 "*"
+                WHITESPACE@76..77 " "
+                LITERAL@77..78
+                  INT_NUMBER@77..78 "2"
+              R_BRACKET@78..79 "]"
+          WHITESPACE@79..84 "\n    "
+          R_CURLY@84..85 "}"
+    "#);
+}
@@ -1,5 +1,7 @@
 mod apple_base;
 mod linux_base;
+mod linux_musl_base;
+mod wasm_base;
 mod windows_msvc_base;
 
 use std::borrow::Cow;
@@ -11,6 +13,7 @@ pub enum LinkerFlavor {
     Ld,
     Ld64,
     Msvc,
+    WasmLd,
 }
 
 /// Everything Mun knows about a target.
@@ -138,9 +141,13 @@ supported_targets!(
     ("x86_64-apple-ios", x86_64_apple_ios),
     ("x86_64-pc-windows-msvc", x86_64_pc_windows_msvc),
     ("x86_64-unknown-linux-gnu", x86_64_unknown_linux_gnu),
+    ("x86_64-unknown-linux-musl", x86_64_unknown_linux_musl),
     ("aarch64-apple-darwin", aarch64_apple_darwin),
     ("aarch64-apple-ios", aarch64_apple_ios),
     ("aarch64-apple-ios-sim", aarch64_apple_ios_sim),
+    ("aarch64-unknown-linux-gnu", aarch64_unknown_linux_gnu),
+    ("aarch64-unknown-linux-musl", aarch64_unknown_linux_musl),
+    ("wasm32-unknown-unknown", wasm32_unknown_unknown),
 );
 
 impl Target {
@@ -0,0 +1,11 @@
+use crate::spec::Target;
+
+pub fn target() -> Target {
+    Target {
+        llvm_target: "wasm32-unknown-unknown".into(),
+        pointer_width: 32,
+        arch: "wasm32".into(),
+        data_layout: "e-m:e-p:32:32-i64:64-n32:64-S128".into(),
+        options: super::wasm_base::opts(),
+    }
+}
@@ -0,0 +1,11 @@
+use crate::spec::{LinkerFlavor, TargetOptions};
+
+pub fn opts() -> TargetOptions {
+    TargetOptions {
+        os: "unknown".to_string(),
+        vendor: "unknown".to_string(),
+        linker_flavor: LinkerFlavor::WasmLd,
+        dll_prefix: "".to_string(),
+        ..Default::default()
+    }
+}
@@ -28,11 +28,18 @@ impl CompileTestDriver {
     ///    mod.mun
     /// ```
     pub fn from_fixture(text: &str) -> Self {
+        Self::from_fixture_with_config(text, |config| config)
+    }
+
+    /// Constructs a new `CompilerTestDriver` from a fixture that describes an
+    /// entire mun project and a `config_fn` that allows modification of the
+    /// compiler [`Config`].
+    pub fn from_fixture_with_config(text: &str, config_fn: impl FnOnce(Config) -> Config) -> Self {
         let temp_output_dir = tempfile::TempDir::new().unwrap();
-        let config = Config {
+        let config = config_fn(Config {
             out_dir: Some(temp_output_dir.path().to_path_buf()),
             ..Config::default()
-        };
+        });
 
         // Write the contents of the fixture to a temporary directory
         let temp_source_dir = tempfile::TempDir::new().unwrap();
@@ -65,11 +72,17 @@ impl CompileTestDriver {
 
     /// Constructs a new `CompileTestDriver` from a single Mun source.
     pub fn from_file(text: &str) -> Self {
+        Self::from_file_with_config(text, |config| config)
+    }
+
+    /// Constructs a new `CompileTestDriver` from a single Mun source and a
+    /// `config_fn` that allows modification of the compiler [`Config`].
+    pub fn from_file_with_config(text: &str, config_fn: impl FnOnce(Config) -> Config) -> Self {
         let temp_dir = tempfile::TempDir::new().unwrap();
-        let config = Config {
+        let config = config_fn(Config {
             out_dir: Some(temp_dir.path().to_path_buf()),
             ..Config::default()
-        };
+        });
         let input = PathOrInline::Inline {
             rel_path: RelativePathBuf::from("mod.mun"),
             contents: text.to_owned(),
@@ -149,7 +162,19 @@ impl CompileAndRunTestDriver {
         fixture: &str,
         config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
     ) -> Result<Self, InitError> {
-        let driver = CompileTestDriver::from_fixture(fixture);
+        Self::from_fixture_with_compiler_config(fixture, |config| config, config_fn)
+    }
+
+    /// Constructs a `CompileAndRunTestDriver` from a fixture that describes an
+    /// entire mun project, a `compiler_config_fn` that allows modification
+    /// of the compiler [`Config`], and a `config_fn` that allows modification
+    /// of a [`RuntimeBuilder`].
+    pub fn from_fixture_with_compiler_config(
+        fixture: &str,
+        compiler_config_fn: impl FnOnce(Config) -> Config,
+        config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
+    ) -> Result<Self, InitError> {
+        let driver = CompileTestDriver::from_fixture_with_config(fixture, compiler_config_fn);
         let builder = Runtime::builder(driver.lib_path());
 
         // Safety: We compiled the library ourselves, therefor loading the munlib is
@@ -166,7 +191,19 @@ impl CompileAndRunTestDriver {
         text: &str,
         config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
     ) -> Result<Self, InitError> {
-        let driver = CompileTestDriver::from_file(text);
+        Self::with_compiler_config(text, |config| config, config_fn)
+    }
+
+    /// Constructs a `CompileAndRunTestDriver` from a single Mun source file, a
+    /// `compiler_config_fn` that allows modification of the compiler
+    /// [`Config`], and a `config_fn` that allows modification of a
+    /// [`RuntimeBuilder`].
+    pub fn with_compiler_config(
+        text: &str,
+        compiler_config_fn: impl FnOnce(Config) -> Config,
+        config_fn: impl FnOnce(RuntimeBuilder) -> RuntimeBuilder,
+    ) -> Result<Self, InitError> {
+        let driver = CompileTestDriver::from_file_with_config(text, compiler_config_fn);
         let builder = Runtime::builder(driver.lib_path());
 
         // Safety: We compiled the library ourselves, therefor loading the munlib is
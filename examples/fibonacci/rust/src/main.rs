@@ -10,13 +10,22 @@ fn main() {
         unsafe { Runtime::builder(lib_dir).finish() }.expect("Failed to spawn Runtime");
 
     loop {
-        let n: i64 = runtime
+        let result = runtime
             .invoke("nth", ())
-            .unwrap_or_else(|e| e.wait(&mut runtime));
-        let result: i64 = runtime
-            .invoke("fibonacci", (n,))
-            .unwrap_or_else(|e| e.wait(&mut runtime));
-        println!("fibonacci({}) = {}", n, result);
+            .or_else(|e| e.try_wait(&mut runtime))
+            .map_err(|e| e.to_string())
+            .and_then(|n: i64| {
+                runtime
+                    .invoke("fibonacci", (n,))
+                    .or_else(|e| e.try_wait(&mut runtime))
+                    .map(|result: i64| (n, result))
+                    .map_err(|e| e.to_string())
+            });
+        match result {
+            Ok((n, result)) => println!("fibonacci({}) = {}", n, result),
+            // A panic is reported and the functions are called again after the next update
+            Err(e) => eprintln!("{}", e),
+        }
 
         // Safety: we assume the updates are safe.
         unsafe { runtime.update() };